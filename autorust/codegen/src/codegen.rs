@@ -210,6 +210,10 @@ impl TypeNameCode {
         self.type_name == Some(TypeName::String)
     }
 
+    pub fn is_int32(&self) -> bool {
+        self.type_name == Some(TypeName::Int32)
+    }
+
     pub fn is_reference(&self) -> bool {
         self.reference
     }
@@ -4,7 +4,9 @@ mod function_params;
 mod new_request_code;
 mod operation_module;
 mod operations;
+mod query_param_enum;
 mod request_builder_into_future;
+mod request_builder_into_stream;
 mod request_builder_send;
 mod request_builder_setter;
 mod request_builder_struct;
@@ -23,6 +25,7 @@ use quote::{quote, ToTokens};
 use std::collections::BTreeSet;
 
 use self::create_client_and_builder::create_client;
+use self::function_code::{ClientScope, ScopedClientFunctionCode};
 use self::operations::OperationCode;
 use self::web_operation_gen::WebOperationGen;
 
@@ -41,6 +44,13 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
         #![allow(clippy::module_inception)]
         pub mod models;
     });
+    // Handwritten helpers live in a `helpers` module next to the generated code, and are re-exported
+    if cg.output_folder().join("helpers").exists() {
+        file.extend(quote! {
+            pub mod helpers;
+            pub use helpers::*;
+        });
+    }
     let mut operations_code: IndexMap<Option<String>, OperationCode> = IndexMap::new();
     // println!("input_files {:?}", cg.input_files());
 
@@ -95,8 +105,12 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
             module_code,
         } = operation_code;
         let mut builders = TokenStream::new();
-        for builder in client_functions {
+        let mut organization_builders = TokenStream::new();
+        let mut project_builders = TokenStream::new();
+        for builder in &client_functions {
             builders.extend(builder.into_token_stream());
+            organization_builders.extend(ScopedClientFunctionCode::new(builder, ClientScope::Organization).into_token_stream());
+            project_builders.extend(ScopedClientFunctionCode::new(builder, ClientScope::Project).into_token_stream());
         }
         match module_name {
             Some(module_name) => {
@@ -112,6 +126,14 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
                         impl Client {
                             #builders
                         }
+                        pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+                        impl OrganizationClient {
+                            #organization_builders
+                        }
+                        pub struct ProjectClient(pub(crate) super::ProjectClient);
+                        impl ProjectClient {
+                            #project_builders
+                        }
                         #(#module_code)*
                     }
                 });
@@ -121,6 +143,12 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
                     impl Client {
                         #builders
                     }
+                    impl OrganizationClient {
+                        #organization_builders
+                    }
+                    impl ProjectClient {
+                        #project_builders
+                    }
                     #(#module_code)*
                 });
             }
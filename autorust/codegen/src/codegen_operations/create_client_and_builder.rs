@@ -4,6 +4,8 @@ use quote::quote;
 
 pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<TokenStream> {
     let mut clients = TokenStream::new();
+    let mut organization_clients = TokenStream::new();
+    let mut project_clients = TokenStream::new();
     for md in modules {
         let client = format!("{md}_client").to_snake_case_ident()?;
         let md = md.to_snake_case_ident()?;
@@ -12,6 +14,16 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                 #md::Client(self.clone())
             }
         });
+        organization_clients.extend(quote! {
+            pub fn #client(&self) -> #md::OrganizationClient {
+                #md::OrganizationClient(self.clone())
+            }
+        });
+        project_clients.extend(quote! {
+            pub fn #client(&self) -> #md::ProjectClient {
+                #md::ProjectClient(self.clone())
+            }
+        });
     }
 
     let public_cloud = quote! {
@@ -153,8 +165,8 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                     option_env!("CARGO_PKG_NAME"),
                     option_env!("CARGO_PKG_VERSION"),
                     options,
-                    Vec::new(),
-                    Vec::new(),
+                    vec![crate::error::retry_error_policy()],
+                    vec![crate::error::error_policy()],
                 );
                 Self {
                     endpoint,
@@ -164,8 +176,82 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                 }
             }
 
+            #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+            #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+            #[must_use]
+            pub(crate) fn from_pipeline(endpoint: Option<azure_core::Url>, credential: crate::Credential, scopes: Vec<String>, pipeline: azure_core::Pipeline) -> Self {
+                let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+                Self {
+                    endpoint,
+                    credential,
+                    scopes,
+                    pipeline,
+                }
+            }
+
+            #[doc = "Create a client scoped to an organization."]
+            #[doc = ""]
+            #[doc = "The functions of the scoped client omit the `organization` argument."]
+            #[must_use]
+            pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+                OrganizationClient {
+                    client: self.clone(),
+                    organization: organization.into(),
+                }
+            }
+
             #clients
         }
+
+        #[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+        #[derive(Clone)]
+        pub struct OrganizationClient {
+            client: Client,
+            organization: String,
+        }
+
+        impl OrganizationClient {
+            #[doc = "The organization this client is scoped to."]
+            pub fn organization(&self) -> &str {
+                &self.organization
+            }
+
+            #[doc = "Create a client scoped to a project within this organization."]
+            #[doc = ""]
+            #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+            #[must_use]
+            pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+                ProjectClient {
+                    client: self.client.clone(),
+                    organization: self.organization.clone(),
+                    project: project.into(),
+                }
+            }
+
+            #organization_clients
+        }
+
+        #[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+        #[derive(Clone)]
+        pub struct ProjectClient {
+            client: Client,
+            organization: String,
+            project: String,
+        }
+
+        impl ProjectClient {
+            #[doc = "The organization this client is scoped to."]
+            pub fn organization(&self) -> &str {
+                &self.organization
+            }
+
+            #[doc = "The project this client is scoped to."]
+            pub fn project(&self) -> &str {
+                &self.project
+            }
+
+            #project_clients
+        }
     });
     Ok(code)
 }
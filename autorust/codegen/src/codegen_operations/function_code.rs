@@ -29,6 +29,35 @@ impl ClientFunctionCode {
             in_operation_group,
         })
     }
+
+    /// The doc comments for the function, omitting the descriptions of the `omit` parameters.
+    fn doc_comments(&self, omit: impl Fn(&FunctionParam) -> bool) -> TokenStream {
+        let summary = DocCommentCode::new(self.summary.clone());
+        let description = DocCommentCode::new(self.description.clone());
+
+        let mut param_descriptions: Vec<TokenStream> = Vec::new();
+        let required_params: Vec<_> = self.parameters.required_params().into_iter().filter(|param| !omit(param)).collect();
+        if required_params.iter().any(|param| param.description.is_some()) {
+            // Add a blank link before the arguments if there is a summary or description.
+            if !summary.is_empty() || !description.is_empty() {
+                param_descriptions.push(quote! { #[doc = ""] });
+            }
+            param_descriptions.push(quote! { #[doc = "Arguments:"] });
+            for required_param in required_params.iter() {
+                if let Some(desc) = &required_param.description {
+                    if !desc.is_empty() {
+                        let doc_comment = format!("* `{}`: {desc}", required_param.variable_name);
+                        param_descriptions.push(quote! { #[doc = #doc_comment] });
+                    }
+                }
+            }
+        };
+        quote! {
+            #summary
+            #description
+            #(#param_descriptions)*
+        }
+    }
 }
 
 impl ToTokens for ClientFunctionCode {
@@ -63,37 +92,11 @@ impl ToTokens for ClientFunctionCode {
             }
         }
 
-        let summary = DocCommentCode::new(self.summary.clone());
-        let description = DocCommentCode::new(self.description.clone());
-
-        let mut param_descriptions: Vec<TokenStream> = Vec::new();
-        if self
-            .parameters
-            .required_params()
-            .into_iter()
-            .any(|param| param.description.is_some())
-        {
-            // Add a blank link before the arguments if there is a summary or description.
-            if !summary.is_empty() || !description.is_empty() {
-                param_descriptions.push(quote! { #[doc = ""] });
-            }
-            param_descriptions.push(quote! { #[doc = "Arguments:"] });
-            for required_param in self.parameters.required_params().iter() {
-                if let Some(desc) = &required_param.description {
-                    if !desc.is_empty() {
-                        let doc_comment = format!("* `{}`: {desc}", required_param.variable_name);
-                        param_descriptions.push(quote! { #[doc = #doc_comment] });
-                    }
-                }
-            }
-        };
-
+        let docs = self.doc_comments(|_| false);
         let fname = &self.fname;
         let parameters = FunctionCallParamsCode(self.parameters.clone());
         tokens.extend(quote! {
-            #summary
-            #description
-            #(#param_descriptions)*
+            #docs
             pub fn #fname(#parameters) -> #fname::RequestBuilder {
                 #fname::RequestBuilder {
                     #(#params),*
@@ -102,3 +105,71 @@ impl ToTokens for ClientFunctionCode {
         });
     }
 }
+
+/// The scope of a scoped client, which supplies the `organization` (and `project`)
+/// arguments of the client functions.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClientScope {
+    Organization,
+    Project,
+}
+
+impl ClientScope {
+    fn scoped_param_names(self) -> &'static [&'static str] {
+        match self {
+            ClientScope::Organization => &["organization"],
+            ClientScope::Project => &["organization", "project"],
+        }
+    }
+
+    /// Returns `true` if the parameter is supplied by the scoped client.
+    fn is_scoped(self, param: &FunctionParam) -> bool {
+        param.is_string() && !param.is_vec() && self.scoped_param_names().contains(&param.name.as_str())
+    }
+}
+
+/// Create the function of a scoped client, which calls the client function
+/// with the scoped `organization` (and `project`) arguments.
+pub(crate) struct ScopedClientFunctionCode<'a> {
+    function: &'a ClientFunctionCode,
+    scope: ClientScope,
+}
+
+impl<'a> ScopedClientFunctionCode<'a> {
+    pub fn new(function: &'a ClientFunctionCode, scope: ClientScope) -> Self {
+        Self { function, scope }
+    }
+}
+
+impl ToTokens for ScopedClientFunctionCode<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let scope = self.scope;
+        let (client, scoped) = if self.function.in_operation_group {
+            (quote! { Client(self.0.client.clone()) }, quote! { self.0 })
+        } else {
+            (quote! { self.client }, quote! { self })
+        };
+        let mut params: Vec<TokenStream> = vec![quote! { &self }];
+        let mut args: Vec<TokenStream> = Vec::new();
+        for param in self.function.parameters.required_params() {
+            let variable_name = &param.variable_name;
+            if scope.is_scoped(param) {
+                args.push(quote! { #scoped.#variable_name.clone() });
+            } else {
+                let mut type_name = param.type_name.clone();
+                let is_vec = type_name.is_vec();
+                type_name.impl_into(!is_vec);
+                params.push(quote! { #variable_name: #type_name });
+                args.push(quote! { #variable_name });
+            }
+        }
+        let docs = self.function.doc_comments(|param| scope.is_scoped(param));
+        let fname = &self.function.fname;
+        tokens.extend(quote! {
+            #docs
+            pub fn #fname(#(#params),*) -> #fname::RequestBuilder {
+                #client.#fname(#(#args),*)
+            }
+        });
+    }
+}
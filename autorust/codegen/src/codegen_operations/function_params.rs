@@ -5,12 +5,15 @@ use std::collections::HashSet;
 
 use crate::codegen::TypeNameCode;
 use crate::identifier::SnakeCaseIdent;
-use crate::spec::WebParameter;
+use crate::spec::{TypeName, WebParameter};
 use crate::{CodeGen, Result};
 
+use super::query_param_enum::QueryParamEnumCode;
 use super::web_operation_gen::WebOperationGen;
 use super::{API_VERSION, X_MS_VERSION};
 
+const ACCEPT: &str = "Accept";
+
 #[derive(Clone)]
 pub struct FunctionParams {
     params: Vec<FunctionParam>,
@@ -41,7 +44,11 @@ impl FunctionParams {
             let name = param.name().to_owned();
             let description = param.description().clone();
             let variable_name = name.to_snake_case_ident()?;
-            let mut type_name = TypeNameCode::new(&param.type_name()?)?;
+            let query_param_enum = QueryParamEnumCode::new(param)?;
+            let mut type_name = match &query_param_enum {
+                Some(query_param_enum) => TypeNameCode::from(query_param_enum.id.clone()),
+                None => TypeNameCode::new(&param.type_name()?)?,
+            };
             type_name.qualify_models(true);
             type_name.optional(!param.required());
             cg.set_if_union_type(&mut type_name);
@@ -54,8 +61,17 @@ impl FunctionParams {
                 type_name,
                 kind,
                 collection_format,
+                query_param_enum,
             });
         }
+        // Operations that can return files or archives let the caller choose the content type
+        let raw_produces = operation.raw_produces();
+        let has_accept_header = params
+            .iter()
+            .any(|p| p.name.eq_ignore_ascii_case(ACCEPT) && p.kind == ParamKind::Header);
+        if !raw_produces.is_empty() && !has_accept_header {
+            params.push(FunctionParam::accept(&operation.0.produces)?);
+        }
         Ok(Self {
             params,
             has_api_version,
@@ -99,6 +115,10 @@ impl FunctionParams {
     pub fn has_x_ms_version(&self) -> bool {
         self.has_x_ms_version
     }
+
+    pub fn query_param_enums(&self) -> Vec<&QueryParamEnumCode> {
+        self.params.iter().filter_map(|p| p.query_param_enum.as_ref()).collect()
+    }
 }
 
 #[derive(Clone)]
@@ -109,9 +129,26 @@ pub struct FunctionParam {
     pub type_name: TypeNameCode,
     pub kind: ParamKind,
     pub collection_format: CollectionFormat,
+    /// The enum used for the parameter, if it is a query parameter that lists its values.
+    pub query_param_enum: Option<QueryParamEnumCode>,
 }
 
 impl FunctionParam {
+    /// An optional `Accept` header parameter, for operations that can return files or archives.
+    fn accept(produces: &[String]) -> Result<Self> {
+        let mut type_name = TypeNameCode::new(&TypeName::String)?;
+        type_name.optional(true);
+        Ok(Self {
+            name: ACCEPT.to_string(),
+            description: Some(format!("The content type of the response: one of `{}`.", produces.join("`, `"))),
+            variable_name: ACCEPT.to_snake_case_ident()?,
+            type_name,
+            kind: ParamKind::Header,
+            collection_format: CollectionFormat::Csv,
+            query_param_enum: None,
+        })
+    }
+
     pub fn is_vec(&self) -> bool {
         self.type_name.is_vec()
     }
@@ -2,25 +2,30 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 
 use super::{
-    request_builder_into_future::RequestBuilderIntoFutureCode, request_builder_send::RequestBuilderSendCode,
-    request_builder_setter::RequestBuilderSettersCode, request_builder_struct::RequestBuilderStructCode, response_code::ResponseCode,
+    query_param_enum::QueryParamEnumCode, request_builder_into_future::RequestBuilderIntoFutureCode, request_builder_into_stream::RequestBuilderIntoStreamCode,
+    request_builder_send::RequestBuilderSendCode, request_builder_setter::RequestBuilderSettersCode,
+    request_builder_struct::RequestBuilderStructCode, response_code::ResponseCode,
 };
 pub struct OperationModuleCode {
     pub module_name: Ident,
+    pub query_param_enums: Vec<QueryParamEnumCode>,
     pub response_code: ResponseCode,
     pub request_builder_struct_code: RequestBuilderStructCode,
     pub request_builder_setters_code: RequestBuilderSettersCode,
     pub request_builder_send_code: RequestBuilderSendCode,
+    pub request_builder_into_stream_code: RequestBuilderIntoStreamCode,
     pub request_builder_intofuture_code: RequestBuilderIntoFutureCode,
 }
 impl ToTokens for OperationModuleCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self {
             module_name,
+            query_param_enums,
             response_code,
             request_builder_struct_code,
             request_builder_setters_code,
             request_builder_send_code,
+            request_builder_into_stream_code,
             request_builder_intofuture_code,
         } = &self;
         tokens.extend(quote! {
@@ -33,11 +38,14 @@ impl ToTokens for OperationModuleCode {
 
                 #response_code
 
+                #(#query_param_enums)*
+
                 #request_builder_struct_code
 
                 impl RequestBuilder {
                     #request_builder_setters_code
                     #request_builder_send_code
+                    #request_builder_into_stream_code
                 }
 
                 #request_builder_intofuture_code
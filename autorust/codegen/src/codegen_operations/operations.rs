@@ -6,6 +6,7 @@ use super::{
     new_request_code::{AuthCode, NewRequestCode},
     operation_module::OperationModuleCode,
     request_builder_into_future::RequestBuilderIntoFutureCode,
+    request_builder_into_stream::RequestBuilderIntoStreamCode,
     request_builder_send::RequestBuilderSendCode,
     request_builder_setter::RequestBuilderSettersCode,
     request_builder_struct::RequestBuilderStructCode,
@@ -52,13 +53,16 @@ impl OperationCode {
         let response_code = ResponseCode::new(cg, operation, produces)?;
         let request_builder_send_code = RequestBuilderSendCode::new(new_request_code, request_builder, response_code.clone())?;
         let request_builder_intofuture_code = RequestBuilderIntoFutureCode::new(response_code.clone(), lro, lro_options)?;
+        let request_builder_into_stream_code = RequestBuilderIntoStreamCode::new(cg, operation, parameters)?;
 
         let module_code = OperationModuleCode {
             module_name: operation.function_name()?,
+            query_param_enums: parameters.query_param_enums().into_iter().cloned().collect(),
             response_code,
             request_builder_struct_code,
             request_builder_setters_code,
             request_builder_send_code,
+            request_builder_into_stream_code,
             request_builder_intofuture_code,
         };
 
@@ -0,0 +1,80 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::identifier::CamelCaseIdent;
+use crate::spec::WebParameter;
+use crate::Result;
+
+/// The name of the variant that holds values that are not listed in the spec.
+const OTHER: &str = "Other";
+
+/// An enum for an optional string query parameter that lists its values in the spec,
+/// used in place of a `String` so that invalid values are caught at compile time.
+#[derive(Clone)]
+pub struct QueryParamEnumCode {
+    pub id: Ident,
+    description: Option<String>,
+    values: Vec<(Ident, String)>,
+}
+
+impl QueryParamEnumCode {
+    pub fn new(param: &WebParameter) -> Result<Option<Self>> {
+        if !param.in_query() || param.required() || !param.is_string() {
+            return Ok(None);
+        }
+        let enum_values = param.enum_values();
+        if enum_values.is_empty() {
+            return Ok(None);
+        }
+        let mut values = Vec::new();
+        for value in enum_values {
+            let variant = value.to_camel_case_ident()?;
+            // `Other` values are held by the `Other(String)` variant
+            if variant != OTHER && !values.iter().any(|(v, _)| v == &variant) {
+                values.push((variant, value));
+            }
+        }
+        Ok(Some(Self {
+            id: param.name().to_camel_case_ident()?,
+            description: param.description().clone(),
+            values,
+        }))
+    }
+}
+
+impl ToTokens for QueryParamEnumCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let id = &self.id;
+        let doc_comment = match &self.description {
+            Some(desc) if !desc.is_empty() => quote! { #[doc = #desc] },
+            _ => quote! {},
+        };
+        let variants = self.values.iter().map(|(variant, _)| variant);
+        let as_str = self.values.iter().map(|(variant, value)| {
+            quote! { Self::#variant => #value, }
+        });
+        tokens.extend(quote! {
+            #doc_comment
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub enum #id {
+                #(#variants,)*
+                #[doc = "A value that is not one of the values listed in the specification."]
+                Other(String),
+            }
+            impl #id {
+                #[doc = "Returns the value that is sent in the query string."]
+                pub fn as_str(&self) -> &str {
+                    match self {
+                        #(#as_str)*
+                        Self::Other(value) => value.as_str(),
+                    }
+                }
+            }
+            impl std::fmt::Display for #id {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        });
+    }
+}
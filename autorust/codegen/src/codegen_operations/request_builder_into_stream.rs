@@ -0,0 +1,156 @@
+use autorust_openapi::{DataType, ReferenceOr};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::codegen::TypeNameCode;
+use crate::spec::{get_schema_array_items, get_type_name_for_schema_ref, TypeName};
+use crate::{CodeGen, Result};
+
+use super::function_params::{FunctionParam, FunctionParams, ParamKind};
+use super::web_operation_gen::WebOperationGen;
+
+const CONTINUATION_TOKEN: &str = "continuationToken";
+const TOP: &str = "$top";
+const SKIP: &str = "$skip";
+
+/// How the next page of a list operation is requested.
+enum Paging {
+    /// The `x-ms-continuationtoken` response header is passed back as the `continuationToken` query parameter.
+    ContinuationToken { param: Ident, is_optional: bool },
+    /// The `$skip` query parameter is advanced by the number of items returned.
+    Skip { skip: Ident, top: Ident },
+}
+
+/// The `into_stream` function of the request builder, for list operations that return
+/// a `{ count, value }` list and support continuation tokens or `$top`/`$skip` paging.
+pub struct RequestBuilderIntoStreamCode {
+    paging: Option<Paging>,
+    item_type: Option<TypeNameCode>,
+}
+
+impl RequestBuilderIntoStreamCode {
+    pub fn new(cg: &CodeGen, operation: &WebOperationGen, parameters: &FunctionParams) -> Result<Self> {
+        // Operations with `x-ms-pageable` already get an `into_stream` function.
+        if operation.pageable().is_some() {
+            return Ok(Self {
+                paging: None,
+                item_type: None,
+            });
+        }
+        Ok(Self {
+            paging: get_paging(parameters),
+            item_type: get_list_item_type(cg, operation)?,
+        })
+    }
+}
+
+fn find_query_param<'a>(parameters: &'a FunctionParams, name: &str) -> Option<&'a FunctionParam> {
+    parameters
+        .params()
+        .into_iter()
+        .find(|p| p.kind == ParamKind::Query && p.name == name)
+}
+
+fn is_optional_i32(param: &FunctionParam) -> bool {
+    param.is_optional() && !param.is_vec() && param.type_name.is_int32()
+}
+
+fn get_paging(parameters: &FunctionParams) -> Option<Paging> {
+    if let Some(param) = find_query_param(parameters, CONTINUATION_TOKEN) {
+        if param.is_string() && !param.is_vec() {
+            return Some(Paging::ContinuationToken {
+                param: param.variable_name.clone(),
+                is_optional: param.is_optional(),
+            });
+        }
+    }
+    match (find_query_param(parameters, SKIP), find_query_param(parameters, TOP)) {
+        (Some(skip), Some(top)) if is_optional_i32(skip) && is_optional_i32(top) => Some(Paging::Skip {
+            skip: skip.variable_name.clone(),
+            top: top.variable_name.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Get the item type of a list response, i.e. the type of the `value` array
+/// in a `{ "count": n, "value": [...] }` response.
+fn get_list_item_type(cg: &CodeGen, operation: &WebOperationGen) -> Result<Option<TypeNameCode>> {
+    let responses = operation.success_responses();
+    let reference = match responses.values().next().and_then(|rsp| rsp.schema.as_ref()) {
+        Some(ReferenceOr::Reference { reference, .. }) => reference,
+        _ => return Ok(None),
+    };
+    let resolved = cg
+        .spec
+        .input_docs()
+        .find_map(|(doc_file, _)| cg.spec.resolve_schema_ref(doc_file, reference).ok());
+    let schema = match resolved {
+        Some(resolved) => resolved.schema,
+        None => return Ok(None),
+    };
+    let value = match schema.properties.get("value") {
+        Some(ReferenceOr::Item(value)) if matches!(value.common.type_, Some(DataType::Array)) => value,
+        _ => return Ok(None),
+    };
+    let item_type_name = get_type_name_for_schema_ref(get_schema_array_items(&value.common)?)?;
+    // Only stream lists of models
+    if !matches!(item_type_name, TypeName::Reference(_)) {
+        return Ok(None);
+    }
+    let mut item_type = TypeNameCode::new(&item_type_name)?;
+    item_type.qualify_models(true);
+    cg.set_if_union_type(&mut item_type);
+    Ok(Some(item_type))
+}
+
+impl ToTokens for RequestBuilderIntoStreamCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (paging, item_type) = match (&self.paging, &self.item_type) {
+            (Some(paging), Some(item_type)) => (paging, item_type),
+            _ => return,
+        };
+        let into_stream = match paging {
+            Paging::ContinuationToken { param, is_optional } => {
+                let set_continuation_token = if *is_optional {
+                    quote! { this.#param = Some(continuation_token); }
+                } else {
+                    quote! { this.#param = continuation_token; }
+                };
+                quote! {
+                    #[doc = "Returns a stream of pages of results."]
+                    #[doc = ""]
+                    #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+                    #[doc = "with the previous page, until all results have been fetched."]
+                    #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+                    pub fn into_stream(self) -> crate::paging::Pageable<#item_type> {
+                        crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                            let mut this = self.clone();
+                            if let Some(continuation_token) = continuation_token {
+                                #set_continuation_token
+                            }
+                            Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                        })
+                    }
+                }
+            }
+            Paging::Skip { skip, top } => {
+                quote! {
+                    #[doc = "Returns a stream of pages of results."]
+                    #[doc = ""]
+                    #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+                    #[doc = "returned with the previous page, until all results have been fetched."]
+                    #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+                    pub fn into_stream(self) -> crate::paging::Pageable<#item_type> {
+                        crate::paging::Pageable::by_skip(self.#skip, self.#top, move |skip| {
+                            let mut this = self.clone();
+                            this.#skip = Some(skip);
+                            Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                        })
+                    }
+                }
+            }
+        };
+        tokens.extend(into_stream);
+    }
+}
@@ -12,14 +12,25 @@ pub struct RequestBuilderSendCode {
     new_request_code: NewRequestCode,
     request_builder: SetRequestCode,
     response_code: ResponseCode,
-    url_args: Vec<Ident>,
+    url_args: Vec<(Ident, bool)>,
 }
 
 impl RequestBuilderSendCode {
     pub fn new(new_request_code: NewRequestCode, request_builder: SetRequestCode, response_code: ResponseCode) -> Result<Self> {
         let params = parse_path_params(&new_request_code.path);
         let url_args: Result<Vec<_>> = params.iter().map(|s| s.to_snake_case_ident()).collect();
-        let url_args = url_args?;
+        // Date-time path parameters are RFC3339 formatted
+        let url_args = url_args?
+            .into_iter()
+            .map(|url_arg| {
+                let is_date_time = request_builder
+                    .parameters
+                    .params()
+                    .iter()
+                    .any(|param| param.variable_name == url_arg && param.type_name.is_date_time());
+                (url_arg, is_date_time)
+            })
+            .collect();
         Ok(Self {
             new_request_code,
             request_builder,
@@ -34,8 +45,12 @@ impl ToTokens for RequestBuilderSendCode {
         let new_request_code = &self.new_request_code;
         let request_builder = &self.request_builder;
 
-        let url_args = self.url_args.iter().map(|url_arg| {
-            quote! { &self.#url_arg }
+        let url_args = self.url_args.iter().map(|(url_arg, is_date_time)| {
+            if *is_date_time {
+                quote! { &crate::date_time::format_date_time(&self.#url_arg)? }
+            } else {
+                quote! { &self.#url_arg }
+            }
         });
         let url_str_args = quote! { #(#url_args),* };
 
@@ -76,6 +91,14 @@ impl ToTokens for RequestBuilderSendCode {
             }
         };
 
+        // Operations that return files or archives request one by default; setting `accept` overrides this
+        let accept_header = match self.response_code.raw_accept() {
+            Some(accept) => quote! {
+                req.insert_header(azure_core::headers::ACCEPT, #accept);
+            },
+            None => quote! {},
+        };
+
         let send_future = quote! {
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
@@ -87,6 +110,7 @@ impl ToTokens for RequestBuilderSendCode {
                     async move {
                         let url = this.url()?;
                         #new_request_code
+                        #accept_header
                         #request_builder
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
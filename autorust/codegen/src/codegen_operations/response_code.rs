@@ -3,7 +3,7 @@ use indexmap::IndexMap;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 
-use crate::spec::get_type_name_for_schema_ref;
+use crate::spec::{get_type_name_for_schema_ref, TypeName};
 use crate::status_codes::get_status_code_ident;
 use crate::{codegen::TypeNameCode, CodeGen};
 use crate::{content_type, Result};
@@ -17,6 +17,8 @@ pub struct ResponseCode {
     pub status_responses: Vec<StatusResponseCode>,
     pub pageable: Option<Pageable>,
     produces: String,
+    /// The content types the operation can return as-is, such as files and archives
+    raw_produces: Vec<String>,
     headers: HeadersCode,
 }
 
@@ -56,12 +58,22 @@ impl ResponseCode {
             status_responses,
             pageable: operation.pageable(),
             produces,
+            raw_produces: operation.raw_produces().into_iter().map(String::from).collect(),
             headers: HeadersCode::new(headers),
         })
     }
 
     /// Get the response type for the HTTP response body
     pub fn response_type(&self) -> Option<TypeNameCode> {
+        if let Some(accept) = self.raw_accept() {
+            // Files and archives are returned as-is, rather than deserialized
+            let type_name = if content_type::is_text(accept) {
+                TypeName::String
+            } else {
+                TypeName::Bytes
+            };
+            return TypeNameCode::new(&type_name).ok();
+        }
         let responses = &self.status_responses;
         if responses.is_empty() {
             return None;
@@ -83,6 +95,27 @@ impl ResponseCode {
     fn produces_xml(&self) -> bool {
         self.produces == content_type::APPLICATION_XML
     }
+
+    /// Whether the operation can return a file or archive
+    pub fn has_raw_body(&self) -> bool {
+        !self.raw_produces.is_empty()
+    }
+
+    /// The default `Accept` header for operations whose response body is not a model, such as file downloads.
+    ///
+    /// Operations that return a model by default, but can also return files, do not set an `Accept` header
+    /// unless the caller does, so that the service picks the content type from the request parameters.
+    pub fn raw_accept(&self) -> Option<&str> {
+        let returns_model = self
+            .status_responses
+            .first()
+            .and_then(|rsp| rsp.response_type.as_ref())
+            .map_or(false, |tp| !tp.is_string() && !tp.is_bytes());
+        if returns_model {
+            return None;
+        }
+        self.raw_produces.first().map(String::as_str)
+    }
 }
 
 impl ToTokens for ResponseCode {
@@ -91,7 +124,18 @@ impl ToTokens for ResponseCode {
             #[derive(Debug)]
             pub struct Response(azure_core::Response);
         });
-        let body_fn = if let Some(response_type) = self.response_type() {
+        let body_fn = if let (Some(accept), Some(response_type)) = (self.raw_accept(), self.response_type()) {
+            let into_raw_body = if content_type::is_text(accept) {
+                quote! { self.into_text().await }
+            } else {
+                quote! { self.into_bytes().await }
+            };
+            quote! {
+                pub async fn into_body(self) -> azure_core::Result<#response_type> {
+                    #into_raw_body
+                }
+            }
+        } else if let Some(response_type) = self.response_type() {
             let deserialize_body = if response_type.is_bytes() {
                 quote! {
                     let body = bytes;
@@ -126,8 +170,34 @@ impl ToTokens for ResponseCode {
             quote! {}
         };
 
+        let raw_body_fns = if self.has_raw_body() {
+            quote! {
+                #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+                pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                    self.0.into_body().collect().await
+                }
+                #[doc = "Returns the response body as UTF-8 text."]
+                pub async fn into_text(self) -> azure_core::Result<String> {
+                    self.0.into_body().collect_string().await
+                }
+                #[doc = "Returns a stream of the response body, which is read as it is received."]
+                pub fn into_stream(self) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                    self.0.into_body()
+                }
+                #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+                pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+                where
+                    W: futures::io::AsyncWrite + Unpin + ?Sized,
+                {
+                    crate::response_body::write_to(self.0, writer).await
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         let headers_fn = if self.headers.has_headers() {
-            quote! { pub fn headers(&self) -> Headers { Headers(self.0.headers()) } }
+            quote! { pub fn headers(&self) -> Headers<'_> { Headers(self.0.headers()) } }
         } else {
             quote! {}
         };
@@ -135,6 +205,7 @@ impl ToTokens for ResponseCode {
         tokens.extend(quote! {
             impl Response {
                 #body_fn
+                #raw_body_fns
                 pub fn into_raw_response(self) -> azure_core::Response {
                     self.0
                 }
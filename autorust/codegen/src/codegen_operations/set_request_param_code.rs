@@ -51,6 +51,15 @@ impl ToTokens for SetRequestParamsCode {
                             quote! {
                                 req.url_mut().query_pairs_mut().append_pair(#param_name, #param_name_var);
                             }
+                        } else if param.query_param_enum.is_some() {
+                            quote! {
+                                req.url_mut().query_pairs_mut().append_pair(#param_name, #param_name_var.as_str());
+                            }
+                        } else if param.type_name.is_date_time() {
+                            // Date-time values must be RFC3339 formatted, which `to_string()` does not do.
+                            quote! {
+                                req.url_mut().query_pairs_mut().append_pair(#param_name, &crate::date_time::format_date_time(#param_name_var)?);
+                            }
                         } else {
                             quote! {
                                 req.url_mut().query_pairs_mut().append_pair(#param_name, &#param_name_var.to_string());
@@ -102,6 +102,11 @@ impl WebOperationGen {
         crate::content_type::pick(self.0.produces.iter().map(String::as_str))
     }
 
+    /// The content types the operation can return as-is, such as files and archives, rather than as JSON
+    pub fn raw_produces(&self) -> Vec<&str> {
+        crate::content_type::raw(self.0.produces.iter().map(String::as_str))
+    }
+
     pub fn pageable(&self) -> Option<Pageable> {
         self.0.pageable.as_ref().map(|p| Pageable {
             next_link_name: p.next_link_name.clone(),
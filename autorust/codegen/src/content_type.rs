@@ -1,6 +1,24 @@
 pub const APPLICATION_JSON: &str = "application/json";
 pub const APPLICATION_XML: &str = "application/xml";
 pub const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
+pub const APPLICATION_ZIP: &str = "application/zip";
+pub const TEXT_PLAIN: &str = "text/plain";
+
+/// Whether the content type is serialized as JSON or XML, rather than returned as-is
+pub fn is_serialized(content_type: &str) -> bool {
+    let content_type = content_type.split(';').next().unwrap_or_default().trim();
+    content_type == APPLICATION_XML || content_type.ends_with("/json") || content_type.ends_with("+json")
+}
+
+/// Whether the content type is text
+pub fn is_text(content_type: &str) -> bool {
+    content_type.starts_with("text/")
+}
+
+/// The content types that are returned as-is, such as files and archives
+pub fn raw<'a>(list: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    list.filter(|content_type| !is_serialized(content_type)).collect()
+}
 
 /// Pick `application/json` if it is an option, else the first one in the list
 pub fn pick<'a>(mut list: impl Iterator<Item = &'a str>) -> Option<&'a str> {
@@ -41,4 +59,14 @@ mod tests {
         assert_eq!(Some(APPLICATION_JSON), pick(consumes.into_iter()));
         Ok(())
     }
+
+    #[test]
+    fn test_produces_raw() -> Result<()> {
+        let produces = vec![APPLICATION_JSON, APPLICATION_OCTET_STREAM, APPLICATION_ZIP, TEXT_PLAIN, "text/json"];
+        assert_eq!(vec![APPLICATION_OCTET_STREAM, APPLICATION_ZIP, TEXT_PLAIN], raw(produces.into_iter()));
+        assert!(raw(vec![APPLICATION_JSON, APPLICATION_XML].into_iter()).is_empty());
+        assert!(is_text(TEXT_PLAIN));
+        assert!(!is_text(APPLICATION_ZIP));
+        Ok(())
+    }
 }
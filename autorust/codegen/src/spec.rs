@@ -557,6 +557,16 @@ impl WebParameter {
         matches!(self.data_type(), Some(DataType::String))
     }
 
+    /// The string values of the `enum` of the parameter, if any.
+    pub fn enum_values(&self) -> Vec<String> {
+        self.0
+            .common
+            .enum_
+            .iter()
+            .filter_map(|value| value.as_str().map(ToOwned::to_owned))
+            .collect()
+    }
+
     pub fn type_name(&self) -> Result<TypeName> {
         Ok(if let Some(_data_type) = self.data_type() {
             get_type_name_for_schema(&self.0.common)?
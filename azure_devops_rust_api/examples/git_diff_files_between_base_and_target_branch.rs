@@ -5,6 +5,7 @@
 // Getting files modified in the branch
 use anyhow::Result;
 use azure_devops_rust_api::git;
+use azure_devops_rust_api::git::models::git_item::GitObjectType;
 use std::collections::HashSet;
 use std::env;
 
@@ -53,10 +54,11 @@ async fn main() -> Result<()> {
 
     // Get files name which are present in the target branch
     for diff in diffs.iter() {
-        let git_object_type = diff.change.item["gitObjectType"].as_str().unwrap();
-        if git_object_type == "blob" {
-            let file_name = diff.change.item["path"].as_str().unwrap();
-            files_diff_between_branches.insert(file_name.to_string());
+        let item = &diff.change.item;
+        if item.git_object_type == Some(GitObjectType::Blob) {
+            if let Some(file_name) = &item.item_model.path {
+                files_diff_between_branches.insert(file_name.clone());
+            }
         }
     }
 
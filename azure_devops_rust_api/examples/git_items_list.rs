@@ -28,7 +28,7 @@ async fn main() -> Result<()> {
     let items = git_client
         .items_client()
         .list(organization, repository_name, project)
-        .recursion_level("Full")
+        .recursion_level(git::items::list::RecursionLevel::Full)
         .await?
         .value;
 
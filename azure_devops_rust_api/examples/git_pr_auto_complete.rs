@@ -0,0 +1,74 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+// git_pr_auto_complete.rs
+// Create a pull request, approve it, and wait for it to be auto-completed.
+use anyhow::Result;
+use azure_devops_rust_api::git;
+use git::models::GitPullRequestCreateOptions;
+use git::{CompletionOptions, MergeStrategy, PullRequestHandle, Vote};
+use std::env;
+
+mod utils;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Get authentication credential
+    let credential = utils::get_credential()?;
+
+    const USAGE: &str =
+        "Usage: git_pr_auto_complete <repository-name> <src_branch> <target_branch> <title>";
+
+    // Get ADO server configuration via environment variables
+    let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
+    let project = env::var("ADO_PROJECT").expect("Must define ADO_PROJECT");
+    // The ID of the authenticated user, who sets auto-complete and approves the PR
+    let identity_id = env::var("ADO_IDENTITY_ID").expect("Must define ADO_IDENTITY_ID");
+
+    let repo_name = env::args().nth(1).expect(USAGE);
+    let src_branch: String = env::args().nth(2).expect(USAGE);
+    let target_branch: String = env::args().nth(3).expect(USAGE);
+    let title: String = env::args().nth(4).expect(USAGE);
+
+    // Create a git client
+    let git_client = git::ClientBuilder::new(credential).build();
+
+    println!("Create PR to merge {} => {}", src_branch, target_branch);
+    let pr = PullRequestHandle::create(
+        &git_client,
+        &organization,
+        &project,
+        &repo_name,
+        GitPullRequestCreateOptions::new(
+            format!("refs/heads/{src_branch}"),
+            format!("refs/heads/{target_branch}"),
+            title,
+        ),
+    )
+    .await?
+    .identity(identity_id);
+    println!("Created PR {}", pr.pull_request_id());
+
+    // Squash merge once the branch policies pass, deleting the source branch
+    pr.set_auto_complete(
+        CompletionOptions::new(MergeStrategy::Squash)
+            .delete_source_branch(true)
+            .transition_work_items(true),
+    )
+    .await?;
+
+    // Approve the PR (as the creator, this may not satisfy the branch policies)
+    pr.vote(Vote::Approve).await?;
+
+    println!("Waiting for the PR to complete...");
+    let completed = pr.wait_for_merge().await?;
+    println!(
+        "Completed PR {}: {:?}",
+        completed.pull_request_id,
+        completed
+            .last_merge_commit
+            .and_then(|commit| commit.commit_id)
+    );
+
+    Ok(())
+}
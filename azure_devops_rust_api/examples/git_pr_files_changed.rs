@@ -5,6 +5,7 @@
 // Getting all the files changed in a PR example.
 use anyhow::Result;
 use azure_devops_rust_api::git;
+use azure_devops_rust_api::git::models::git_item::GitObjectType;
 use std::collections::HashSet;
 use std::env;
 
@@ -66,10 +67,10 @@ async fn main() -> Result<()> {
             let item = &change.change.item;
             // We are only interested in files not directories.
             // files are "blob" type, directories are "folder" type.
-            if let (Some("blob"), Some(filename)) =
-                (item["git_object_type"].as_str(), item["path"].as_str())
+            if let (Some(GitObjectType::Blob), Some(filename)) =
+                (&item.git_object_type, &item.item_model.path)
             {
-                files_changed.insert(filename.to_string());
+                files_changed.insert(filename.clone());
             }
         }
     }
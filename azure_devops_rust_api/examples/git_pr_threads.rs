@@ -0,0 +1,64 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+// git_pr_threads.rs
+// Add an inline comment to a pull request, and list the comments on each file.
+use anyhow::Result;
+use azure_devops_rust_api::git;
+use git::{CommentThreadBuilder, PullRequestHandle};
+use std::env;
+
+mod utils;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Get authentication credential
+    let credential = utils::get_credential()?;
+
+    const USAGE: &str =
+        "Usage: git_pr_threads <repository-name> <pr-id> [<file-path> <line> <comment>]";
+
+    // Get ADO server configuration via environment variables
+    let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
+    let project = env::var("ADO_PROJECT").expect("Must define ADO_PROJECT");
+
+    let repo_name = env::args().nth(1).expect(USAGE);
+    let pull_request_id: i32 = env::args().nth(2).expect(USAGE).parse()?;
+
+    // Create a git client
+    let git_client = git::ClientBuilder::new(credential).build();
+    let pr = PullRequestHandle::new(
+        &git_client,
+        &organization,
+        &project,
+        &repo_name,
+        pull_request_id,
+    );
+
+    // Add a comment on a line of a file, if specified
+    if let Some(file_path) = env::args().nth(3) {
+        let line: i32 = env::args().nth(4).expect(USAGE).parse()?;
+        let comment = env::args().nth(5).expect(USAGE);
+        let thread = pr
+            .create_thread(
+                CommentThreadBuilder::new(comment)
+                    .file(file_path)
+                    .line(line),
+            )
+            .await?;
+        println!("Created thread {:?}", thread.comment_thread.id);
+    }
+
+    // List the comments on each file, at their current lines
+    for thread in pr.file_threads().await? {
+        println!(
+            "{}:{}-{} ({:?})",
+            thread.path, thread.start_line, thread.end_line, thread.status
+        );
+        for comment in thread.comments {
+            println!("  {}", comment.content.unwrap_or_default());
+        }
+    }
+
+    Ok(())
+}
@@ -3,17 +3,14 @@
 
 // git_items_list.rs
 // Git items (files and folders) list example.
-use anyhow::Context as AnyhowContext;
 use anyhow::Result;
 use azure_devops_rust_api::git;
 use azure_devops_rust_api::git::models::git_item::GitObjectType;
 use azure_devops_rust_api::git::models::GitItem;
-use futures::StreamExt;
+use futures::io::AllowStdIo;
 use std::env;
-use std::io::Write;
 
 mod utils;
-use utils::AcceptZipPolicy;
 
 /// Get all items (files and folders) in the specified repository
 async fn all_repo_items(
@@ -25,7 +22,7 @@ async fn all_repo_items(
     let items = git_client
         .items_client()
         .list(organization, repository_name, project)
-        .recursion_level("Full")
+        .recursion_level(git::items::list::RecursionLevel::Full)
         .await?
         .value;
     Ok(items)
@@ -53,7 +50,7 @@ async fn main() -> Result<()> {
         .expect("Usage: git_items_list <repository-name>");
 
     // Create a git client
-    let git_client = git::ClientBuilder::new(credential.clone()).build();
+    let git_client = git::ClientBuilder::new(credential).build();
 
     // Get all items (files and folders) in the specified repository
     let items = all_repo_items(&git_client, &organization, &repository_name, &project).await?;
@@ -77,27 +74,16 @@ async fn main() -> Result<()> {
     //
     // NOTE: The filenames in the zip archive are the blob ids.
 
-    // The current version of the API doesn't set the `accept` header correctly for `get_blobs_zip()`.
-    // Work around this by adding a custom policy to set the header.
-    let git_zip_client = git::ClientBuilder::new(credential)
-        .per_call_policies(vec![AcceptZipPolicy::new_policy()])
-        .build();
-
-    let mut body = git_zip_client
+    let rsp = git_client
         .blobs_client()
         .get_blobs_zip(organization, blob_ids, repository_name, project)
         .send()
-        .await?
-        .into_raw_response()
-        .into_body();
+        .await?;
 
-    let mut file = std::fs::File::create("blobs.zip")?;
-    while let Some(chunk_result) = body.next().await {
-        let chunk = chunk_result.context("Error reading chunk")?;
-        println!("Writing chunk");
-        file.write_all(&chunk)?;
-    }
-    println!("Done writing blobs.zip");
+    // Stream the archive to a file, rather than holding it in memory.
+    let mut file = AllowStdIo::new(std::fs::File::create("blobs.zip")?);
+    let written = rsp.write_to(&mut file).await?;
+    println!("Done writing blobs.zip ({written} bytes)");
 
     Ok(())
 }
@@ -5,9 +5,9 @@
 // The log data is saved as a zip file - use `unzip` to extract
 use anyhow::{anyhow, Result};
 use azure_devops_rust_api::release;
+use futures::io::AllowStdIo;
 use std::env;
 use std::fs::File;
-use std::io::prelude::*;
 
 mod utils;
 
@@ -33,14 +33,13 @@ async fn main() -> Result<()> {
 
     // Get release logs
     println!("\nDownloading release logs for release {}", release_id);
-    let (status, _headers, body) = release_client
+    let rsp = release_client
         .releases_client()
         .get_logs(organization, project, release_id)
         .send()
-        .await?
-        .into_raw_response()
-        .deconstruct();
+        .await?;
 
+    let status = rsp.as_raw_response().status();
     if status != azure_core::StatusCode::Ok {
         println!("Request failed. status:{}", status);
         return Err(anyhow!("Request failed"));
@@ -48,10 +47,9 @@ async fn main() -> Result<()> {
 
     // Write the data as a zipfile
     println!("Writing data to zipfile: {}", output_file);
-    let data = body.collect().await?;
-    let mut file = File::create(&output_file)?;
-    file.write_all(&data)?;
-    println!("Logs saved");
+    let mut file = AllowStdIo::new(File::create(&output_file)?);
+    let written = rsp.write_to(&mut file).await?;
+    println!("Logs saved ({written} bytes)");
 
     println!("Use 'unzip {}' to extract the logs", output_file);
 
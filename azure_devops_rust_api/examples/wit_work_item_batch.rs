@@ -0,0 +1,52 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+// wit_work_item_batch.rs
+// Work Item batch update example.
+use anyhow::Result;
+use azure_devops_rust_api::wit;
+use azure_devops_rust_api::wit::{field_names, WorkItemPatch};
+use std::env;
+
+mod utils;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Get authentication credential
+    let credential = utils::get_credential()?;
+
+    // Get ADO configuration via environment variables
+    let organization = env::var("ADO_ORGANIZATION").expect("Must define ADO_ORGANIZATION");
+    let usage = "Usage: wit_work_item_batch <state> <work_item_id>...";
+    let mut args = env::args().skip(1);
+    let state = args.next().expect(usage);
+    let work_item_ids: Vec<i32> = args.map(|arg| arg.parse().expect("integer id")).collect();
+
+    // Create a wit client
+    let wit_client = wit::ClientBuilder::new(credential).build();
+
+    // Set the state of each of the work items. The updates are sent in batches of 200.
+    let batch = work_item_ids.into_iter().fold(
+        wit::update_work_items(&wit_client, &organization),
+        |batch, id| {
+            batch.update(
+                id,
+                WorkItemPatch::new().set_field(field_names::STATE, state.as_str()),
+            )
+        },
+    );
+    println!("Updating {} work items", batch.len());
+
+    for result in batch.await? {
+        match result.result {
+            Ok(work_item) => println!(
+                "[{}] {}",
+                work_item.id,
+                work_item.state().unwrap_or("<unknown>")
+            ),
+            Err(e) => println!("[{}] Update failed: {e}", result.id.unwrap_or_default()),
+        }
+    }
+
+    Ok(())
+}
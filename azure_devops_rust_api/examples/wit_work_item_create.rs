@@ -5,8 +5,7 @@
 // Work Item creation example.
 use anyhow::Result;
 use azure_devops_rust_api::wit;
-use azure_devops_rust_api::wit::models::{json_patch_operation::Op, JsonPatchOperation};
-use serde_json::json;
+use azure_devops_rust_api::wit::{field_names, WorkItemPatch};
 use std::env;
 
 mod utils;
@@ -26,17 +25,11 @@ async fn main() -> Result<()> {
     // Assign the type of work item to create
     let work_item_type = "User Story";
 
-    // Define the title of the work item to be created
-    let title = JsonPatchOperation {
-        from: None,
-        op: Some(Op::Add),
-        path: Some("/fields/System.Title".to_owned()),
-        value: Some(json!("Example User Story title")),
-    };
-
-    // Each operation lives in a vector, additional elements can be added to fill in other fields
-    // of a work item, see the comments at the end of this file for some examples
-    let body = vec![title];
+    // Define the fields of the work item to be created.
+    // Additional fields and relations can be added, see the comments at the end of this file for some examples
+    let body = WorkItemPatch::new()
+        .set_field(field_names::TITLE, "Example User Story title")
+        .into();
 
     // Create a work item
     let work_item = wit_client
@@ -50,30 +43,18 @@ async fn main() -> Result<()> {
 }
 
 // When creating a work item you can also assign an iteration
-
-// let iteration = JsonPatchOperation {
-// 	from: None,
-// 	op: Some(Op::Add),
-// 	path: Some("/fields/System.IterationPath".to_owned()),
-// 	value: Some(json!("my-iteration".to_owned()))
-// };
-//
-// e.g create a work item with a title and in an iteration
 //
-// let body = vec![title, iteration];
+// let body = WorkItemPatch::new()
+//     .set_field(field_names::TITLE, "Example User Story title")
+//     .set_field(field_names::ITERATION_PATH, "my-iteration")
+//     .into();
 
 // When creating a work item you can also assign a parent
-
-// let parent = JsonPatchOperation {
-// 	from: None,
-// 	op: Some(Op::Add),
-// 	path: Some("/relations/-".to_owned()),
-// 	value: Some(json!({
-// 		"rel": "System.LinkTypes.Hierarchy-Reverse",
-// 		"url": format!("https://dev.azure.com/{}/{}/_apis/wit/workItems/{}", organisation, project, parent_id)
-// 	}))
-// }
-//
-// e.g create a work item with a title, in an iteration and assign a parent item
 //
-// let body = vec![title, iteration, parent];
+// let body = WorkItemPatch::new()
+//     .set_field(field_names::TITLE, "Example User Story title")
+//     .add_relation(
+//         link_types::PARENT,
+//         &format!("https://dev.azure.com/{organization}/{project}/_apis/wit/workItems/{parent_id}"),
+//     )
+//     .into();
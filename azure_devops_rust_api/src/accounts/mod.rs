@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,10 +129,83 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn accounts_client(&self) -> accounts::Client {
         accounts::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn accounts_client(&self) -> accounts::OrganizationClient {
+        accounts::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn accounts_client(&self) -> accounts::ProjectClient {
+        accounts::ProjectClient(self.clone())
+    }
+}
 pub mod accounts {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -151,6 +224,20 @@ pub mod accounts {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of accounts for a specific owner or a specific member. One of the following parameters is required: ownerId, memberId."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list()
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of accounts for a specific owner or a specific member. One of the following parameters is required: ownerId, memberId."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list()
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn approvals_client(&self) -> approvals::Client {
         approvals::Client(self.clone())
     }
@@ -142,6 +169,70 @@ impl Client {
         pipeline_permissions::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn approvals_client(&self) -> approvals::OrganizationClient {
+        approvals::OrganizationClient(self.clone())
+    }
+    pub fn check_configurations_client(&self) -> check_configurations::OrganizationClient {
+        check_configurations::OrganizationClient(self.clone())
+    }
+    pub fn check_evaluations_client(&self) -> check_evaluations::OrganizationClient {
+        check_evaluations::OrganizationClient(self.clone())
+    }
+    pub fn pipeline_permissions_client(&self) -> pipeline_permissions::OrganizationClient {
+        pipeline_permissions::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn approvals_client(&self) -> approvals::ProjectClient {
+        approvals::ProjectClient(self.clone())
+    }
+    pub fn check_configurations_client(&self) -> check_configurations::ProjectClient {
+        check_configurations::ProjectClient(self.clone())
+    }
+    pub fn check_evaluations_client(&self) -> check_evaluations::ProjectClient {
+        check_evaluations::ProjectClient(self.clone())
+    }
+    pub fn pipeline_permissions_client(&self) -> pipeline_permissions::ProjectClient {
+        pipeline_permissions::ProjectClient(self.clone())
+    }
+}
 pub mod pipeline_permissions {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -211,6 +302,102 @@ pub mod pipeline_permissions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Batch API to authorize/unauthorize a list of definitions for a multiple resources."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pipeline_permisions_for_resources(
+            &self,
+            body: Vec<models::ResourcePipelinePermissions>,
+            project: impl Into<String>,
+        ) -> update_pipeline_permisions_for_resources::RequestBuilder {
+            Client(self.0.client.clone()).update_pipeline_permisions_for_resources(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Given a ResourceType and ResourceId, returns authorized definitions for that resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            resource_type: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                resource_type,
+                resource_id,
+            )
+        }
+        #[doc = "Authorizes/Unauthorizes a list of definitions for a given resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pipeline_permisions_for_resource(
+            &self,
+            body: impl Into<models::ResourcePipelinePermissions>,
+            project: impl Into<String>,
+            resource_type: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> update_pipeline_permisions_for_resource::RequestBuilder {
+            Client(self.0.client.clone()).update_pipeline_permisions_for_resource(
+                self.0.organization.clone(),
+                body,
+                project,
+                resource_type,
+                resource_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Batch API to authorize/unauthorize a list of definitions for a multiple resources."]
+        pub fn update_pipeline_permisions_for_resources(
+            &self,
+            body: Vec<models::ResourcePipelinePermissions>,
+        ) -> update_pipeline_permisions_for_resources::RequestBuilder {
+            Client(self.0.client.clone()).update_pipeline_permisions_for_resources(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Given a ResourceType and ResourceId, returns authorized definitions for that resource."]
+        pub fn get(
+            &self,
+            resource_type: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                resource_type,
+                resource_id,
+            )
+        }
+        #[doc = "Authorizes/Unauthorizes a list of definitions for a given resource."]
+        pub fn update_pipeline_permisions_for_resource(
+            &self,
+            body: impl Into<models::ResourcePipelinePermissions>,
+            resource_type: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> update_pipeline_permisions_for_resource::RequestBuilder {
+            Client(self.0.client.clone()).update_pipeline_permisions_for_resource(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                resource_type,
+                resource_id,
+            )
+        }
+    }
     pub mod update_pipeline_permisions_for_resources {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -710,6 +897,130 @@ pub mod check_configurations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get Check configuration by resource type and id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Add a check configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::CheckConfiguration>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get Check configuration by Id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, id)
+        }
+        #[doc = "Update check configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: check configuration"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: check configuration id"]
+        pub fn update(
+            &self,
+            body: impl Into<models::CheckConfiguration>,
+            project: impl Into<String>,
+            id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, id)
+        }
+        #[doc = "Delete check configuration by id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: check configuration id"]
+        pub fn delete(&self, project: impl Into<String>, id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, id)
+        }
+        #[doc = "Get check configurations for multiple resources by resource type and id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of resources."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: Vec<models::Resource>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get Check configuration by resource type and id"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Add a check configuration"]
+        pub fn add(&self, body: impl Into<models::CheckConfiguration>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get Check configuration by Id"]
+        pub fn get(&self, id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+            )
+        }
+        #[doc = "Update check configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: check configuration"]
+        #[doc = "* `id`: check configuration id"]
+        pub fn update(
+            &self,
+            body: impl Into<models::CheckConfiguration>,
+            id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                id,
+            )
+        }
+        #[doc = "Delete check configuration by id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: check configuration id"]
+        pub fn delete(&self, id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+            )
+        }
+        #[doc = "Get check configurations for multiple resources by resource type and id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of resources."]
+        pub fn query(&self, body: Vec<models::Resource>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1515,6 +1826,53 @@ pub mod check_evaluations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Initiate an evaluation for a check in a pipeline"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn evaluate(
+            &self,
+            body: impl Into<models::CheckSuiteRequest>,
+            project: impl Into<String>,
+        ) -> evaluate::RequestBuilder {
+            Client(self.0.client.clone()).evaluate(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get details for a specific check evaluation"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            check_suite_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, check_suite_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Initiate an evaluation for a check in a pipeline"]
+        pub fn evaluate(
+            &self,
+            body: impl Into<models::CheckSuiteRequest>,
+        ) -> evaluate::RequestBuilder {
+            Client(self.0.client.clone()).evaluate(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get details for a specific check evaluation"]
+        pub fn get(&self, check_suite_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                check_suite_id,
+            )
+        }
+    }
     pub mod evaluate {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1840,6 +2198,68 @@ pub mod approvals {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "List Approvals. This can be used to get a set of pending approvals in a pipeline, on an user or for a resource.."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(&self, project: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), project)
+        }
+        #[doc = "Update approvals."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: Vec<models::ApprovalUpdateParameters>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get an approval."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            approval_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, approval_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "List Approvals. This can be used to get a set of pending approvals in a pipeline, on an user or for a resource.."]
+        pub fn query(&self) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Update approvals."]
+        pub fn update(
+            &self,
+            body: Vec<models::ApprovalUpdateParameters>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get an approval."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn get(&self, approval_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                approval_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn artifact_details_client(&self) -> artifact_details::Client {
         artifact_details::Client(self.clone())
     }
@@ -154,6 +181,94 @@ impl Client {
         service_settings::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn artifact_details_client(&self) -> artifact_details::OrganizationClient {
+        artifact_details::OrganizationClient(self.clone())
+    }
+    pub fn change_tracking_client(&self) -> change_tracking::OrganizationClient {
+        change_tracking::OrganizationClient(self.clone())
+    }
+    pub fn feed_management_client(&self) -> feed_management::OrganizationClient {
+        feed_management::OrganizationClient(self.clone())
+    }
+    pub fn feed_recycle_bin_client(&self) -> feed_recycle_bin::OrganizationClient {
+        feed_recycle_bin::OrganizationClient(self.clone())
+    }
+    pub fn provenance_client(&self) -> provenance::OrganizationClient {
+        provenance::OrganizationClient(self.clone())
+    }
+    pub fn recycle_bin_client(&self) -> recycle_bin::OrganizationClient {
+        recycle_bin::OrganizationClient(self.clone())
+    }
+    pub fn retention_policies_client(&self) -> retention_policies::OrganizationClient {
+        retention_policies::OrganizationClient(self.clone())
+    }
+    pub fn service_settings_client(&self) -> service_settings::OrganizationClient {
+        service_settings::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifact_details_client(&self) -> artifact_details::ProjectClient {
+        artifact_details::ProjectClient(self.clone())
+    }
+    pub fn change_tracking_client(&self) -> change_tracking::ProjectClient {
+        change_tracking::ProjectClient(self.clone())
+    }
+    pub fn feed_management_client(&self) -> feed_management::ProjectClient {
+        feed_management::ProjectClient(self.clone())
+    }
+    pub fn feed_recycle_bin_client(&self) -> feed_recycle_bin::ProjectClient {
+        feed_recycle_bin::ProjectClient(self.clone())
+    }
+    pub fn provenance_client(&self) -> provenance::ProjectClient {
+        provenance::ProjectClient(self.clone())
+    }
+    pub fn recycle_bin_client(&self) -> recycle_bin::ProjectClient {
+        recycle_bin::ProjectClient(self.clone())
+    }
+    pub fn retention_policies_client(&self) -> retention_policies::ProjectClient {
+        retention_policies::ProjectClient(self.clone())
+    }
+    pub fn service_settings_client(&self) -> service_settings::ProjectClient {
+        service_settings::ProjectClient(self.clone())
+    }
+}
 pub mod service_settings {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -193,6 +308,40 @@ pub mod service_settings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all service-wide feed creation and administration permissions."]
+        pub fn get_global_permissions(&self) -> get_global_permissions::RequestBuilder {
+            Client(self.0.client.clone()).get_global_permissions(self.0.organization.clone())
+        }
+        #[doc = "Set service-wide permissions that govern feed creation and administration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New permissions for the organization."]
+        pub fn set_global_permissions(
+            &self,
+            body: Vec<models::GlobalPermission>,
+        ) -> set_global_permissions::RequestBuilder {
+            Client(self.0.client.clone()).set_global_permissions(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all service-wide feed creation and administration permissions."]
+        pub fn get_global_permissions(&self) -> get_global_permissions::RequestBuilder {
+            Client(self.0.client.clone()).get_global_permissions(self.0.organization.clone())
+        }
+        #[doc = "Set service-wide permissions that govern feed creation and administration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New permissions for the organization."]
+        pub fn set_global_permissions(
+            &self,
+            body: Vec<models::GlobalPermission>,
+        ) -> set_global_permissions::RequestBuilder {
+            Client(self.0.client.clone()).set_global_permissions(self.0.organization.clone(), body)
+        }
+    }
     pub mod get_global_permissions {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -505,6 +654,87 @@ pub mod change_tracking {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query to determine which feeds have changed since the last call, tracked through the provided continuation token. Only changes to a feed itself are returned and impact the continuation token, not additions or alterations to packages within the feeds.\n\nIf the project parameter is present, gets all feed changes in the given project.\nIf omitted, gets all feed changes in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_changes(
+            &self,
+            project: impl Into<String>,
+        ) -> get_feed_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_changes(self.0.organization.clone(), project)
+        }
+        #[doc = "Query a feed to determine its current state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_change(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_change::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_change(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get a batch of package changes made to a feed.  The changes returned are 'most recent change' so if an Add is followed by an Update before you begin enumerating, you'll only see one change in the batch.  While consuming batches using the continuation token, you may see changes to the same package version multiple times if they are happening as you enumerate.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_changes(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_package_changes(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query to determine which feeds have changed since the last call, tracked through the provided continuation token. Only changes to a feed itself are returned and impact the continuation token, not additions or alterations to packages within the feeds.\n\nIf the project parameter is present, gets all feed changes in the given project.\nIf omitted, gets all feed changes in the organization."]
+        pub fn get_feed_changes(&self) -> get_feed_changes::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_feed_changes(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Query a feed to determine its current state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn get_feed_change(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_feed_change::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_change(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a batch of package changes made to a feed.  The changes returned are 'most recent change' so if an Add is followed by an Update before you begin enumerating, you'll only see one change in the batch.  While consuming batches using the continuation token, you may see changes to the same package version multiple times if they are happening as you enumerate.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_package_changes(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_package_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_package_changes(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_feed_changes {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -975,6 +1205,73 @@ pub mod feed_recycle_bin {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query for feeds within the recycle bin.\n\nIf the project parameter is present, gets all feeds in recycle bin in the given project.\nIf omitted, gets all feeds in recycle bin in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_deleted_feed(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_deleted_feed::RequestBuilder {
+            Client(self.0.client.clone()).restore_deleted_feed(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn permanent_delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> permanent_delete_feed::RequestBuilder {
+            Client(self.0.client.clone()).permanent_delete_feed(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query for feeds within the recycle bin.\n\nIf the project parameter is present, gets all feeds in recycle bin in the given project.\nIf omitted, gets all feeds in recycle bin in the organization."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        pub fn restore_deleted_feed(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            feed_id: impl Into<String>,
+        ) -> restore_deleted_feed::RequestBuilder {
+            Client(self.0.client.clone()).restore_deleted_feed(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        pub fn permanent_delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> permanent_delete_feed::RequestBuilder {
+            Client(self.0.client.clone()).permanent_delete_feed(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1447,128 +1744,494 @@ pub mod feed_management {
         #[doc = "* `project`: Project ID or project name"]
         pub fn set_feed_permissions(
             &self,
-            organization: impl Into<String>,
+            organization: impl Into<String>,
+            body: Vec<models::FeedPermission>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> set_feed_permissions::RequestBuilder {
+            set_feed_permissions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get all views for a feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_views(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_views::RequestBuilder {
+            get_feed_views::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: View to be created."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed_view(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_feed_view::RequestBuilder {
+            create_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_view(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_view::RequestBuilder {
+            get_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: New settings to apply to the specified view."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed_view(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed_view::RequestBuilder {
+            update_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed_view(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed_view::RequestBuilder {
+            delete_feed_view::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                view_id: view_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all feeds in an account where you have the provided role access.\n\nIf the project parameter is present, gets all feeds in the given project.\nIf omitted, gets all feeds in the organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feeds(&self, project: impl Into<String>) -> get_feeds::RequestBuilder {
+            Client(self.0.client.clone()).get_feeds(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a feed, a container for various package types.\n\nFeeds can be created in a project if the project parameter is included in the request url.\nIf the project parameter is omitted, the feed will not be associated with a project and will be created at the organization level."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing both required and optional attributes for the feed. Name is the only required value."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed(
+            &self,
+            body: impl Into<models::Feed>,
+            project: impl Into<String>,
+        ) -> create_feed::RequestBuilder {
+            Client(self.0.client.clone()).create_feed(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get the settings for a specific feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed::RequestBuilder {
+            Client(self.0.client.clone()).get_feed(self.0.organization.clone(), feed_id, project)
+        }
+        #[doc = "Change the attributes of a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing the feed settings to be updated."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed(
+            &self,
+            body: impl Into<models::FeedUpdate>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed::RequestBuilder {
+            Client(self.0.client.clone()).update_feed(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Remove a feed and all its packages. The feed moves to the recycle bin and is reversible.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed::RequestBuilder {
+            Client(self.0.client.clone()).delete_feed(self.0.organization.clone(), feed_id, project)
+        }
+        #[doc = "Get the permissions for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_permissions(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_permissions::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_permissions(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Update the permissions on a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Permissions to set."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_feed_permissions(
+            &self,
+            body: Vec<models::FeedPermission>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> set_feed_permissions::RequestBuilder {
+            Client(self.0.client.clone()).set_feed_permissions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get all views for a feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_views(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_views::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_views(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: View to be created."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_feed_view(
+            &self,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_feed_view::RequestBuilder {
+            Client(self.0.client.clone()).create_feed_view(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_feed_view(
+            &self,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_feed_view::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_view(
+                self.0.organization.clone(),
+                feed_id,
+                view_id,
+                project,
+            )
+        }
+        #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New settings to apply to the specified view."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_feed_view(
+            &self,
+            body: impl Into<models::FeedView>,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_feed_view::RequestBuilder {
+            Client(self.0.client.clone()).update_feed_view(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                view_id,
+                project,
+            )
+        }
+        #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `view_id`: Name or Id of the view."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_feed_view(
+            &self,
+            feed_id: impl Into<String>,
+            view_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_feed_view::RequestBuilder {
+            Client(self.0.client.clone()).delete_feed_view(
+                self.0.organization.clone(),
+                feed_id,
+                view_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all feeds in an account where you have the provided role access.\n\nIf the project parameter is present, gets all feeds in the given project.\nIf omitted, gets all feeds in the organization."]
+        pub fn get_feeds(&self) -> get_feeds::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_feeds(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a feed, a container for various package types.\n\nFeeds can be created in a project if the project parameter is included in the request url.\nIf the project parameter is omitted, the feed will not be associated with a project and will be created at the organization level."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing both required and optional attributes for the feed. Name is the only required value."]
+        pub fn create_feed(&self, body: impl Into<models::Feed>) -> create_feed::RequestBuilder {
+            Client(self.0.client.clone()).create_feed(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the settings for a specific feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_feed(&self, feed_id: impl Into<String>) -> get_feed::RequestBuilder {
+            Client(self.0.client.clone()).get_feed(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Change the attributes of a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A JSON object containing the feed settings to be updated."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn update_feed(
+            &self,
+            body: impl Into<models::FeedUpdate>,
+            feed_id: impl Into<String>,
+        ) -> update_feed::RequestBuilder {
+            Client(self.0.client.clone()).update_feed(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Remove a feed and all its packages. The feed moves to the recycle bin and is reversible.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn delete_feed(&self, feed_id: impl Into<String>) -> delete_feed::RequestBuilder {
+            Client(self.0.client.clone()).delete_feed(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the permissions for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_feed_permissions(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_feed_permissions::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_permissions(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update the permissions on a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Permissions to set."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn set_feed_permissions(
+            &self,
             body: Vec<models::FeedPermission>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> set_feed_permissions::RequestBuilder {
-            set_feed_permissions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            Client(self.0.client.clone()).set_feed_permissions(
+                self.0.organization.clone(),
                 body,
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+                feed_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get all views for a feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
-        pub fn get_feed_views(
-            &self,
-            organization: impl Into<String>,
-            feed_id: impl Into<String>,
-            project: impl Into<String>,
-        ) -> get_feed_views::RequestBuilder {
-            get_feed_views::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+        pub fn get_feed_views(&self, feed_id: impl Into<String>) -> get_feed_views::RequestBuilder {
+            Client(self.0.client.clone()).get_feed_views(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Create a new view on the referenced feed.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: View to be created."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn create_feed_view(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::FeedView>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> create_feed_view::RequestBuilder {
-            create_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).create_feed_view(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get a view by Id.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_feed_view(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_feed_view::RequestBuilder {
-            get_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_feed_view(
+                self.0.organization.clone(),
+                feed_id,
+                view_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Update a view.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: New settings to apply to the specified view."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_feed_view(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::FeedView>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_feed_view::RequestBuilder {
-            update_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_feed_view(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                view_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete a feed view.\n\nThe project parameter must be supplied if the feed was created in a project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `view_id`: Name or Id of the view."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_feed_view(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             view_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_feed_view::RequestBuilder {
-            delete_feed_view::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                view_id: view_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_feed_view(
+                self.0.organization.clone(),
+                feed_id,
+                view_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod get_feeds {
@@ -3265,24 +3928,308 @@ pub mod artifact_details {
         #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_badge(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_badge::RequestBuilder {
+            get_badge::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_id: package_id.into(),
+                project: project.into(),
+                accept: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_package_metrics(
+            &self,
+            body: impl Into<models::PackageMetricsQuery>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> query_package_metrics::RequestBuilder {
+            Client(self.0.client.clone()).query_package_metrics(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get details about all of the packages in the feed. Use the various filters to include or exclude information from the result set.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_packages(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_packages::RequestBuilder {
+            Client(self.0.client.clone()).get_packages(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get details about a specific package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package::RequestBuilder {
+            Client(self.0.client.clone()).get_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_package_version_metrics(
+            &self,
+            body: impl Into<models::PackageVersionMetricsQuery>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> query_package_version_metrics::RequestBuilder {
+            Client(self.0.client.clone()).query_package_version_metrics(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get a list of package versions, optionally filtering by state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).get_package_versions(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get details about a specific package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+        #[doc = "Gets provenance for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_provenance(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_provenance::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_provenance(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+        #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_badge(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_badge::RequestBuilder {
+            Client(self.0.client.clone()).get_badge(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query_package_metrics(
+            &self,
+            body: impl Into<models::PackageMetricsQuery>,
+            feed_id: impl Into<String>,
+        ) -> query_package_metrics::RequestBuilder {
+            Client(self.0.client.clone()).query_package_metrics(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get details about all of the packages in the feed. Use the various filters to include or exclude information from the result set.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_packages(&self, feed_id: impl Into<String>) -> get_packages::RequestBuilder {
+            Client(self.0.client.clone()).get_packages(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get details about a specific package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_package::RequestBuilder {
+            Client(self.0.client.clone()).get_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
+        }
+        pub fn query_package_version_metrics(
+            &self,
+            body: impl Into<models::PackageVersionMetricsQuery>,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> query_package_version_metrics::RequestBuilder {
+            Client(self.0.client.clone()).query_package_version_metrics(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a list of package versions, optionally filtering by state.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        pub fn get_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).get_package_versions(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get details about a specific package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets provenance for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
+        #[doc = "* `package_version_id`: Id of the package version (GUID Id, not name)."]
+        pub fn get_package_version_provenance(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_package_version_provenance::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_provenance(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Generate a SVG badge for the latest version of a package.  The generated SVG is typically used as the image in an HTML link which takes users to the feed containing the package to accelerate discovery and consumption.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `feed_id`: Name or Id of the feed."]
         #[doc = "* `package_id`: Id of the package (GUID Id, not name)."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_badge(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_badge::RequestBuilder {
-            get_badge::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_id: package_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_badge(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod query_package_metrics {
@@ -3670,6 +4617,18 @@ pub mod artifact_details {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::Package> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PackageList>;
@@ -4438,19 +5397,29 @@ pub mod artifact_details {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4491,8 +5460,14 @@ pub mod artifact_details {
             pub(crate) feed_id: String,
             pub(crate) package_id: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `image/svg+xml`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4511,6 +5486,10 @@ pub mod artifact_details {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "image/svg+xml");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4537,8 +5516,8 @@ pub mod artifact_details {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -4675,6 +5654,186 @@ pub mod recycle_bin {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query for packages within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_packages(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_packages::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_packages(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Queues a job to remove all package versions from a feed's recycle bin"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn empty_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> empty_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).empty_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get information about a package and all its versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get a list of package versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                project,
+            )
+        }
+        #[doc = "Get information about a package version within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `package_version_id`: The package version Id 9guid Id, not the version string)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query for packages within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        pub fn get_recycle_bin_packages(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_recycle_bin_packages::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_packages(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Queues a job to remove all package versions from a feed's recycle bin"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed"]
+        pub fn empty_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> empty_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).empty_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package and all its versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_recycle_bin_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_recycle_bin_package::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a list of package versions within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        pub fn get_recycle_bin_package_versions(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+        ) -> get_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version within the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or Id of the feed."]
+        #[doc = "* `package_id`: The package Id (GUID Id, not the package name)."]
+        #[doc = "* `package_version_id`: The package version Id 9guid Id, not the version string)."]
+        pub fn get_recycle_bin_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_id: impl Into<String>,
+            package_version_id: impl Into<String>,
+        ) -> get_recycle_bin_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_recycle_bin_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_id,
+                package_version_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_recycle_bin_packages {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4846,6 +6005,18 @@ pub mod recycle_bin {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::Package> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PackageList>;
@@ -5450,6 +6621,108 @@ pub mod retention_policies {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_policy(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Set the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Feed retention policy."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_retention_policy(
+            &self,
+            body: impl Into<models::FeedRetentionPolicy>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> set_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).set_retention_policy(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Delete the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).delete_retention_policy(
+                self.0.organization.clone(),
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn get_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> get_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_policy(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Set the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Feed retention policy."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn set_retention_policy(
+            &self,
+            body: impl Into<models::FeedRetentionPolicy>,
+            feed_id: impl Into<String>,
+        ) -> set_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).set_retention_policy(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete the retention policy for a feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn delete_retention_policy(
+            &self,
+            feed_id: impl Into<String>,
+        ) -> delete_retention_policy::RequestBuilder {
+            Client(self.0.client.clone()).delete_retention_policy(
+                self.0.organization.clone(),
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_retention_policy {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5829,6 +7102,48 @@ pub mod provenance {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates a session, a wrapper around a feed that can store additional metadata on the packages published to it."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The feed and metadata for the session"]
+        #[doc = "* `protocol`: The protocol that the session will target"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_session(
+            &self,
+            body: impl Into<models::SessionRequest>,
+            protocol: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_session::RequestBuilder {
+            Client(self.0.client.clone()).create_session(
+                self.0.organization.clone(),
+                body,
+                protocol,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates a session, a wrapper around a feed that can store additional metadata on the packages published to it."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The feed and metadata for the session"]
+        #[doc = "* `protocol`: The protocol that the session will target"]
+        pub fn create_session(
+            &self,
+            body: impl Into<models::SessionRequest>,
+            protocol: impl Into<String>,
+        ) -> create_session::RequestBuilder {
+            Client(self.0.client.clone()).create_session(
+                self.0.organization.clone(),
+                body,
+                protocol,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod create_session {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
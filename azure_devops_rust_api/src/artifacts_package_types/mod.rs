@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn maven_client(&self) -> maven::Client {
         maven::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         universal::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn maven_client(&self) -> maven::OrganizationClient {
+        maven::OrganizationClient(self.clone())
+    }
+    pub fn npm_client(&self) -> npm::OrganizationClient {
+        npm::OrganizationClient(self.clone())
+    }
+    pub fn nu_get_client(&self) -> nu_get::OrganizationClient {
+        nu_get::OrganizationClient(self.clone())
+    }
+    pub fn python_client(&self) -> python::OrganizationClient {
+        python::OrganizationClient(self.clone())
+    }
+    pub fn universal_client(&self) -> universal::OrganizationClient {
+        universal::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn maven_client(&self) -> maven::ProjectClient {
+        maven::ProjectClient(self.clone())
+    }
+    pub fn npm_client(&self) -> npm::ProjectClient {
+        npm::ProjectClient(self.clone())
+    }
+    pub fn nu_get_client(&self) -> nu_get::ProjectClient {
+        nu_get::ProjectClient(self.clone())
+    }
+    pub fn python_client(&self) -> python::ProjectClient {
+        python::ProjectClient(self.clone())
+    }
+    pub fn universal_client(&self) -> universal::ProjectClient {
+        universal::ProjectClient(self.clone())
+    }
+}
 pub mod maven {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -429,6 +526,7 @@ pub mod maven {
                 version: version.into(),
                 file_name: file_name.into(),
                 project: project.into(),
+                accept: None,
             }
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
@@ -454,290 +552,571 @@ pub mod maven {
             }
         }
     }
-    pub mod get_upstreaming_behavior {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::UpstreamingBehavior> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::UpstreamingBehavior =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `group_id`: The group id of the package"]
+        #[doc = "* `artifact_id`: The artifact id of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                project,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                body,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) project: String,
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed,
-                    &self.group_id,
-                    &self.artifact_id
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
-            }
+        #[doc = "Delete a package version from the feed and move it to the feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::UpstreamingBehavior>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<models::UpstreamingBehavior>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-    }
-    pub mod set_upstreaming_behavior {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to false to restore the package."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::MavenRecycleBinPackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Permanently delete a package from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) feed: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) body: models::UpstreamingBehavior,
-            pub(crate) project: String,
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_packages(
+                self.0.organization.clone(),
+                body,
+                feed,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed,
-                    &self.group_id,
-                    &self.artifact_id
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
-            }
+        #[doc = "Fulfills Maven package file download requests by either returning the URL of the requested package file or, in the case of Azure DevOps Server (OnPrem), returning the content as a stream.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `group_id`: GroupId of the maven package"]
+        #[doc = "* `artifact_id`: ArtifactId of the maven package"]
+        #[doc = "* `version`: Version of the package"]
+        #[doc = "* `file_name`: File name to download"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            file_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                group_id,
+                artifact_id,
+                version,
+                file_name,
+                project,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
-            }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
         }
     }
-    pub mod get_package_version {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::Package> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Package = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `group_id`: The group id of the package"]
+        #[doc = "* `artifact_id`: The artifact id of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                self.0.project.clone(),
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: The name or id of the feed"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                body,
+                self.0.project.clone(),
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from the feed and move it to the feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to false to restore the package."]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::MavenRecycleBinPackageVersionDetails>,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Permanently delete a package from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed`: Name or ID of the feed."]
+        #[doc = "* `group_id`: Group ID of the package."]
+        #[doc = "* `artifact_id`: Artifact ID of the package."]
+        #[doc = "* `version`: Version of the package."]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed,
+                group_id,
+                artifact_id,
+                version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_packages(
+                self.0.organization.clone(),
+                body,
+                feed,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Fulfills Maven package file download requests by either returning the URL of the requested package file or, in the case of Azure DevOps Server (OnPrem), returning the content as a stream.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `group_id`: GroupId of the maven package"]
+        #[doc = "* `artifact_id`: ArtifactId of the maven package"]
+        #[doc = "* `version`: Version of the package"]
+        #[doc = "* `file_name`: File name to download"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            group_id: impl Into<String>,
+            artifact_id: impl Into<String>,
+            version: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                group_id,
+                artifact_id,
+                version,
+                file_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::MavenPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod get_upstreaming_behavior {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::UpstreamingBehavior> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::UpstreamingBehavior =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
         #[doc = r" executes the request and returns a `Result` with the parsed"]
         #[doc = r" response."]
         #[doc = r""]
@@ -752,16 +1131,9 @@ pub mod maven {
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
-            pub(crate) version: String,
             pub(crate) project: String,
-            pub(crate) show_deleted: Option<bool>,
         }
         impl RequestBuilder {
-            #[doc = "Set to true to show information for deleted packages."]
-            pub fn show_deleted(mut self, show_deleted: bool) -> Self {
-                self.show_deleted = Some(show_deleted);
-                self
-            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -780,11 +1152,6 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        if let Some(show_deleted) = &this.show_deleted {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("showDeleted", &show_deleted.to_string());
-                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -793,14 +1160,13 @@ pub mod maven {
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
                 let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}",
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming",
                     self.client.endpoint(),
                     &self.organization,
                     &self.project,
                     &self.feed,
                     &self.group_id,
-                    &self.artifact_id,
-                    &self.version
+                    &self.artifact_id
                 ))?;
                 let has_api_version_already = url
                     .query_pairs()
@@ -813,8 +1179,8 @@ pub mod maven {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::Package>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<models::Package>>;
+            type Output = azure_core::Result<models::UpstreamingBehavior>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<models::UpstreamingBehavior>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -825,7 +1191,7 @@ pub mod maven {
             }
         }
     }
-    pub mod update_package_version {
+    pub mod set_upstreaming_behavior {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -870,11 +1236,10 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::PackageVersionDetails,
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
-            pub(crate) version: String,
+            pub(crate) body: models::UpstreamingBehavior,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -905,14 +1270,13 @@ pub mod maven {
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
                 let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}",
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/upstreaming",
                     self.client.endpoint(),
                     &self.organization,
                     &self.project,
                     &self.feed,
                     &self.group_id,
-                    &self.artifact_id,
-                    &self.version
+                    &self.artifact_id
                 ))?;
                 let has_api_version_already = url
                     .query_pairs()
@@ -940,7 +1304,7 @@ pub mod maven {
             }
         }
     }
-    pub mod delete_package_version {
+    pub mod get_package_version {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -949,6 +1313,20 @@ pub mod maven {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::Package> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::Package = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -990,8 +1368,14 @@ pub mod maven {
             pub(crate) artifact_id: String,
             pub(crate) version: String,
             pub(crate) project: String,
+            pub(crate) show_deleted: Option<bool>,
         }
         impl RequestBuilder {
+            #[doc = "Set to true to show information for deleted packages."]
+            pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+                self.show_deleted = Some(show_deleted);
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1001,7 +1385,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1010,6 +1394,11 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        if let Some(show_deleted) = &this.show_deleted {
+                            req.url_mut()
+                                .query_pairs_mut()
+                                .append_pair("showDeleted", &show_deleted.to_string());
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1038,22 +1427,19 @@ pub mod maven {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
+            type Output = azure_core::Result<models::Package>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<models::Package>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
             #[doc = ""]
             #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
             fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
+                Box::pin(async move { self.send().await?.into_body().await })
             }
         }
     }
-    pub mod get_package_version_from_recycle_bin {
+    pub mod update_package_version {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1062,23 +1448,6 @@ pub mod maven {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::MavenPackageVersionDeletionState> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MavenPackageVersionDeletionState = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -1115,6 +1484,7 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
+            pub(crate) body: models::PackageVersionDetails,
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
@@ -1131,7 +1501,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1140,14 +1510,24 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        let req_body = azure_core::EMPTY_BODY;
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
                 })
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , self . client . endpoint () , & self . organization , & self . project , & self . feed , & self . group_id , & self . artifact_id , & self . version)) ? ;
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed,
+                    &self.group_id,
+                    &self.artifact_id,
+                    &self.version
+                ))?;
                 let has_api_version_already = url
                     .query_pairs()
                     .any(|(k, _)| k == azure_core::query_param::API_VERSION);
@@ -1159,20 +1539,22 @@ pub mod maven {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::MavenPackageVersionDeletionState>;
-            type IntoFuture =
-                BoxFuture<'static, azure_core::Result<models::MavenPackageVersionDeletionState>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
             #[doc = ""]
             #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
             fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
             }
         }
     }
-    pub mod restore_package_version_from_recycle_bin {
+    pub mod delete_package_version {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1217,7 +1599,6 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::MavenRecycleBinPackageVersionDetails,
             pub(crate) feed: String,
             pub(crate) group_id: String,
             pub(crate) artifact_id: String,
@@ -1234,7 +1615,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1243,15 +1624,23 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
+                        let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
                 })
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , self . client . endpoint () , & self . organization , & self . project , & self . feed , & self . group_id , & self . artifact_id , & self . version)) ? ;
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/groups/{}/artifacts/{}/versions/{}",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed,
+                    &self.group_id,
+                    &self.artifact_id,
+                    &self.version
+                ))?;
                 let has_api_version_already = url
                     .query_pairs()
                     .any(|(k, _)| k == azure_core::query_param::API_VERSION);
@@ -1278,7 +1667,7 @@ pub mod maven {
             }
         }
     }
-    pub mod delete_package_version_from_recycle_bin {
+    pub mod get_package_version_from_recycle_bin {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1287,6 +1676,23 @@ pub mod maven {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::MavenPackageVersionDeletionState> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::MavenPackageVersionDeletionState = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -1339,7 +1745,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1366,6 +1772,110 @@ pub mod maven {
                 Ok(url)
             }
         }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::MavenPackageVersionDeletionState>;
+            type IntoFuture =
+                BoxFuture<'static, azure_core::Result<models::MavenPackageVersionDeletionState>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod restore_package_version_from_recycle_bin {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::MavenRecycleBinPackageVersionDetails,
+            pub(crate) feed: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , self . client . endpoint () , & self . organization , & self . project , & self . feed , & self . group_id , & self . artifact_id , & self . version)) ? ;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<()>;
             type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
@@ -1382,7 +1892,7 @@ pub mod maven {
             }
         }
     }
-    pub mod update_recycle_bin_packages {
+    pub mod delete_package_version_from_recycle_bin {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1427,8 +1937,10 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) body: models::MavenPackagesBatchRequest,
             pub(crate) feed: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -1441,7 +1953,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1450,21 +1962,14 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
+                        let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
                 })
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/packagesBatch",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed
-                ))?;
+                let mut url = azure_core :: Url :: parse (& format ! ("{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/groups/{}/artifacts/{}/versions/{}" , self . client . endpoint () , & self . organization , & self . project , & self . feed , & self . group_id , & self . artifact_id , & self . version)) ? ;
                 let has_api_version_already = url
                     .query_pairs()
                     .any(|(k, _)| k == azure_core::query_param::API_VERSION);
@@ -1491,7 +1996,7 @@ pub mod maven {
             }
         }
     }
-    pub mod download_package {
+    pub mod update_recycle_bin_packages {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1500,20 +2005,6 @@ pub mod maven {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -1550,11 +2041,8 @@ pub mod maven {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
-            pub(crate) feed_id: String,
-            pub(crate) group_id: String,
-            pub(crate) artifact_id: String,
-            pub(crate) version: String,
-            pub(crate) file_name: String,
+            pub(crate) body: models::MavenPackagesBatchRequest,
+            pub(crate) feed: String,
             pub(crate) project: String,
         }
         impl RequestBuilder {
@@ -1567,7 +2055,7 @@ pub mod maven {
                     let this = self.clone();
                     async move {
                         let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
                         if let Some(auth_header) = this
                             .client
                             .token_credential()
@@ -1576,7 +2064,8 @@ pub mod maven {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
-                        let req_body = azure_core::EMPTY_BODY;
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -1584,15 +2073,11 @@ pub mod maven {
             }
             fn url(&self) -> azure_core::Result<azure_core::Url> {
                 let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/{}/{}/{}/{}/content",
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/RecycleBin/packagesBatch",
                     self.client.endpoint(),
                     &self.organization,
                     &self.project,
-                    &self.feed_id,
-                    &self.group_id,
-                    &self.artifact_id,
-                    &self.version,
-                    &self.file_name
+                    &self.feed
                 ))?;
                 let has_api_version_already = url
                     .query_pairs()
@@ -1605,19 +2090,22 @@ pub mod maven {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
             #[doc = ""]
             #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
             fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
             }
         }
     }
-    pub mod update_package_versions {
+    pub mod download_package {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
@@ -1626,6 +2114,30 @@ pub mod maven {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -1633,666 +2145,1737 @@ pub mod maven {
                 &self.0
             }
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) feed_id: String,
+            pub(crate) group_id: String,
+            pub(crate) artifact_id: String,
+            pub(crate) version: String,
+            pub(crate) file_name: String,
+            pub(crate) project: String,
+            pub(crate) accept: Option<String>,
+        }
+        impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/{}/{}/{}/{}/content",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed_id,
+                    &self.group_id,
+                    &self.artifact_id,
+                    &self.version,
+                    &self.file_name
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+    pub mod update_package_versions {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::MavenPackagesBatchRequest,
+            pub(crate) feed_id: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/packagesbatch",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed_id
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
+            }
+        }
+    }
+}
+pub mod npm {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version::RequestBuilder {
+            get_scoped_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_scoped_package::RequestBuilder {
+            update_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_scoped_package::RequestBuilder {
+            unpublish_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package::RequestBuilder {
+            update_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_package::RequestBuilder {
+            unpublish_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_upstreaming_behavior::RequestBuilder {
+            get_package_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_scoped_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_scoped_upstreaming_behavior::RequestBuilder {
+            set_scoped_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_scoped_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_scoped_package::RequestBuilder {
+            download_scoped_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                accept: None,
+            }
+        }
+        #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_readme(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_readme::RequestBuilder {
+            get_scoped_package_readme::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                accept: None,
+            }
+        }
+        #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
+            get_scoped_package_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                accept: None,
+            }
+        }
+        #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_readme(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_readme::RequestBuilder {
+            get_package_readme::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                accept: None,
+            }
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_packages(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_packages::RequestBuilder {
+            update_packages::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
+            get_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+            restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_scoped_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+            delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_scope: package_scope.into(),
+                unscoped_package_name: unscoped_package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            update_recycle_bin_packages::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_scoped_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_scoped_package(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_scoped_package::RequestBuilder {
+            Client(self.0.client.clone()).update_scoped_package(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_scoped_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_scoped_package::RequestBuilder {
+            Client(self.0.client.clone()).unpublish_scoped_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package::RequestBuilder {
+            Client(self.0.client.clone()).update_package(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn unpublish_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> unpublish_package::RequestBuilder {
+            Client(self.0.client.clone()).unpublish_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_package_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                project,
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_scope`: The scope of the package"]
+        #[doc = "* `unscoped_package_name`: The name of the scoped package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_scoped_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_scoped_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_scoped_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                body,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_scoped_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_scoped_package::RequestBuilder {
+            Client(self.0.client.clone()).download_scoped_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_readme(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_readme::RequestBuilder {
+            Client(self.0.client.clone()).get_scoped_package_readme(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_scoped_package_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
+        }
+        #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_readme(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_readme::RequestBuilder {
+            Client(self.0.client.clone()).get_package_readme(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_packages(
+            &self,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_packages::RequestBuilder {
+            Client(self.0.client.clone()).update_packages(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_scoped_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_scoped_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
+        #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_scoped_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_scope: impl Into<String>,
+            unscoped_package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::MavenPackagesBatchRequest,
-            pub(crate) feed_id: String,
-            pub(crate) project: String,
+        #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/maven/packagesbatch",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed_id
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
-            }
+        #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
-            }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_packages(
+            &self,
+            body: impl Into<models::NpmPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_packages::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_packages(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
         }
     }
-}
-pub mod npm {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
         #[doc = "Get information about a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_version::RequestBuilder {
-            get_scoped_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_scoped_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_scoped_package(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_scoped_package::RequestBuilder {
-            update_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_scoped_package(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Unpublish a scoped package version (such as @scope/name).\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn unpublish_scoped_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> unpublish_scoped_package::RequestBuilder {
-            unpublish_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).unpublish_scoped_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get information about an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_package(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_package::RequestBuilder {
-            update_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_package(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Unpublish an unscoped package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn unpublish_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> unpublish_package::RequestBuilder {
-            unpublish_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).unpublish_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get the upstreaming behavior of the (scoped) package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_scope`: The scope of the package"]
         #[doc = "* `unscoped_package_name`: The name of the scoped package"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_upstreaming_behavior::RequestBuilder {
-            get_package_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_scope`: The scope of the package"]
         #[doc = "* `unscoped_package_name`: The name of the scoped package"]
         #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn set_scoped_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
-            project: impl Into<String>,
         ) -> set_scoped_upstreaming_behavior::RequestBuilder {
-            set_scoped_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).set_scoped_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                body,
+                self.0.project.clone(),
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn download_scoped_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> download_scoped_package::RequestBuilder {
-            download_scoped_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).download_scoped_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get the Readme for a package version with an npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope\\name)"]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope\\name)"]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_readme(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_readme::RequestBuilder {
-            get_scoped_package_readme::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_scoped_package_readme(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get the upstreaming behavior of the (unscoped) package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_upstreaming_behavior::RequestBuilder {
-            get_scoped_package_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_scoped_package_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Set the upstreaming behavior of a (scoped) package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the scoped package within the scope of the feed"]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
-            project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get an unscoped npm package.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get the Readme for a package version that has no npm scope.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_readme(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_readme::RequestBuilder {
-            get_package_readme::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_readme(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_packages(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_packages::RequestBuilder {
-            update_packages::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_packages(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get information about a scoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)"]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_scoped_package_version_from_recycle_bin::RequestBuilder {
-            get_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Restore a package version with an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_scoped_package_version_from_recycle_bin::RequestBuilder {
-            restore_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).restore_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete a package version with an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_scope`: Scope of the package (the 'scope' part of @scope/name)."]
         #[doc = "* `unscoped_package_name`: Name of the package (the 'name' part of @scope/name)."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_scoped_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_scope: impl Into<String>,
             unscoped_package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_scoped_package_version_from_recycle_bin::RequestBuilder {
-            delete_scoped_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_scope: package_scope.into(),
-                unscoped_package_name: unscoped_package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_scoped_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_scope,
+                unscoped_package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get information about an unscoped package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Restore a package version without an npm scope from the recycle bin to its feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete a package version without an npm scope from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_packages(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NpmPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_packages::RequestBuilder {
-            update_recycle_bin_packages::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_recycle_bin_packages(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod get_scoped_package_version {
@@ -3282,19 +4865,29 @@ pub mod npm {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3337,8 +4930,14 @@ pub mod npm {
             pub(crate) unscoped_package_name: String,
             pub(crate) package_version: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3357,6 +4956,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3385,8 +4988,8 @@ pub mod npm {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -3407,18 +5010,28 @@ pub mod npm {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3461,8 +5074,14 @@ pub mod npm {
             pub(crate) unscoped_package_name: String,
             pub(crate) package_version: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3481,6 +5100,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3762,19 +5385,29 @@ pub mod npm {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3816,8 +5449,14 @@ pub mod npm {
             pub(crate) package_name: String,
             pub(crate) package_version: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3836,6 +5475,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3863,8 +5506,8 @@ pub mod npm {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -3885,18 +5528,28 @@ pub mod npm {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3938,8 +5591,14 @@ pub mod npm {
             pub(crate) package_name: String,
             pub(crate) package_version: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3958,6 +5617,10 @@ pub mod npm {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4796,217 +6459,484 @@ pub mod npm {
                 Ok(url)
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
+            }
+        }
+    }
+    pub mod update_recycle_bin_packages {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::NpmPackagesBatchRequest,
+            pub(crate) feed_id: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/npm/RecycleBin/PackagesBatch",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed_id
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
+            }
+        }
+    }
+}
+pub mod nu_get {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            get_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                show_deleted: None,
+            }
+        }
+        #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: New state to apply to the referenced package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to update."]
+        #[doc = "* `package_version`: Version of the package to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            update_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to delete."]
+        #[doc = "* `package_version`: Version of the package to delete."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            delete_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-    }
-    pub mod update_recycle_bin_packages {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
+        #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                source_protocol_version: None,
+                accept: None,
             }
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            update_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
             }
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
+        #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::NpmPackagesBatchRequest,
-            pub(crate) feed_id: String,
-            pub(crate) project: String,
-        }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+        #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/npm/RecycleBin/PackagesBatch",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed_id
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            update_recycle_bin_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
             }
         }
     }
-}
-pub mod nu_get {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
         #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             project: impl Into<String>,
         ) -> get_upstreaming_behavior::RequestBuilder {
-            get_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
         }
         #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
             project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
         }
         #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                show_deleted: None,
-            }
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: New state to apply to the referenced package."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package to update."]
@@ -5014,195 +6944,391 @@ pub mod nu_get {
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_version(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_version::RequestBuilder {
-            update_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package to delete."]
         #[doc = "* `package_version`: Version of the package to delete."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> delete_package_version::RequestBuilder {
-            delete_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                source_protocol_version: None,
-            }
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetPackagesBatchRequest>,
             feed_id: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_versions::RequestBuilder {
-            update_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
         }
         #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Set mutable state on a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New state to apply to the referenced package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to update."]
+        #[doc = "* `package_version`: Version of the package to update."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Send a package version from the feed to its paired recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package to delete."]
+        #[doc = "* `package_version`: Version of the package to delete."]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Download a package version directly.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::NuGetPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "View a package version's deletion/recycled status\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Restore a package version from a feed's recycle bin back into the active feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Set the 'Deleted' member to 'false' to apply the restore operation"]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
         #[doc = "* `feed_id`: Name or ID of the feed."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::NuGetPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_package_versions::RequestBuilder {
-            update_recycle_bin_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod get_upstreaming_behavior {
@@ -5814,19 +7940,29 @@ pub mod nu_get {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5869,6 +8005,7 @@ pub mod nu_get {
             pub(crate) package_version: String,
             pub(crate) project: String,
             pub(crate) source_protocol_version: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Unused"]
@@ -5879,6 +8016,11 @@ pub mod nu_get {
                 self.source_protocol_version = Some(source_protocol_version.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5897,11 +8039,15 @@ pub mod nu_get {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         if let Some(source_protocol_version) = &this.source_protocol_version {
                             req.url_mut()
                                 .query_pairs_mut()
                                 .append_pair("sourceProtocolVersion", source_protocol_version);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5929,8 +8075,8 @@ pub mod nu_get {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -6416,185 +8562,454 @@ pub mod nu_get {
                 &self.0
             }
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: models::NuGetPackagesBatchRequest,
+            pub(crate) feed_id: String,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packagesBatch",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.feed_id
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
+            }
+        }
+    }
+}
+pub mod python {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            get_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+            project: impl Into<String>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            set_upstreaming_behavior::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            get_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+                show_deleted: None,
+            }
+        }
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            update_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            delete_package_version::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
+        #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `file_name`: Name of the file in the package"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn download_package(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            file_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            download_package::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                file_name: file_name.into(),
+                project: project.into(),
+                accept: None,
             }
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) body: models::NuGetPackagesBatchRequest,
-            pub(crate) feed_id: String,
-            pub(crate) project: String,
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            update_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.body)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            get_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/packaging/feeds/{}/nuget/RecycleBin/packagesBatch",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.feed_id
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            restore_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
+        #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            organization: impl Into<String>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            delete_package_version_from_recycle_bin::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                feed_id: feed_id.into(),
+                package_name: package_name.into(),
+                package_version: package_version.into(),
+                project: project.into(),
             }
         }
-    }
-}
-pub mod python {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            update_recycle_bin_package_versions::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                feed_id: feed_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
         #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             project: impl Into<String>,
         ) -> get_upstreaming_behavior::RequestBuilder {
-            get_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                project,
+            )
         }
         #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: The name or id of the feed"]
         #[doc = "* `package_name`: The name of the package"]
         #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn set_upstreaming_behavior(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             body: impl Into<models::UpstreamingBehavior>,
             project: impl Into<String>,
         ) -> set_upstreaming_behavior::RequestBuilder {
-            set_upstreaming_behavior::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                project,
+            )
         }
         #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version::RequestBuilder {
-            get_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-                show_deleted: None,
-            }
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Details to be updated."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
@@ -6602,52 +9017,46 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_version(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_version::RequestBuilder {
-            update_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> delete_package_version::RequestBuilder {
-            delete_package_version::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
@@ -6655,74 +9064,65 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn download_package(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             file_name: impl Into<String>,
             project: impl Into<String>,
         ) -> download_package::RequestBuilder {
-            download_package::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                file_name: file_name.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                file_name,
+                project,
+            )
         }
         #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn update_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiPackagesBatchRequest>,
             feed_id: impl Into<String>,
             project: impl Into<String>,
         ) -> update_package_versions::RequestBuilder {
-            update_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
         }
         #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn get_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> get_package_version_from_recycle_bin::RequestBuilder {
-            get_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
@@ -6730,69 +9130,284 @@ pub mod python {
         #[doc = "* `project`: Project ID or project name"]
         pub fn restore_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> restore_package_version_from_recycle_bin::RequestBuilder {
-            restore_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
         }
         #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `feed_id`: Name or ID of the feed."]
         #[doc = "* `package_name`: Name of the package."]
         #[doc = "* `package_version`: Version of the package."]
         #[doc = "* `project`: Project ID or project name"]
         pub fn delete_package_version_from_recycle_bin(
             &self,
-            organization: impl Into<String>,
             feed_id: impl Into<String>,
             package_name: impl Into<String>,
             package_version: impl Into<String>,
             project: impl Into<String>,
         ) -> delete_package_version_from_recycle_bin::RequestBuilder {
-            delete_package_version_from_recycle_bin::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                feed_id: feed_id.into(),
-                package_name: package_name.into(),
-                package_version: package_version.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the upstreaming behavior of a package within the context of a feed"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        pub fn get_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+        ) -> get_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).get_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Set the upstreaming behavior of a package within the context of a feed\n\nThe package does not need to necessarily exist in the feed prior to setting the behavior.\nThis assists with packages that are not yet ingested from an upstream, yet the feed owner wants\nto apply a specific behavior on the first ingestion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: The name or id of the feed"]
+        #[doc = "* `package_name`: The name of the package"]
+        #[doc = "* `body`: The behavior to apply to the package within the scope of the feed"]
+        pub fn set_upstreaming_behavior(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            body: impl Into<models::UpstreamingBehavior>,
+        ) -> set_upstreaming_behavior::RequestBuilder {
+            Client(self.0.client.clone()).set_upstreaming_behavior(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update state for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details to be updated."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Download a python package file directly. This API is intended for manual UI download options, not for programmatic access and scripting.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `file_name`: Name of the file in the package"]
+        pub fn download_package(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> download_package::RequestBuilder {
+            Client(self.0.client.clone()).download_package(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                file_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::PyPiPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' state to 'false' to restore the package to its feed."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::PyPiRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from the feed, moving it to the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
         #[doc = "* `feed_id`: Feed which contains the packages to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_recycle_bin_package_versions(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PyPiPackagesBatchRequest>,
             feed_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_recycle_bin_package_versions::RequestBuilder {
-            update_recycle_bin_package_versions::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                feed_id: feed_id.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod get_upstreaming_behavior {
@@ -7404,19 +10019,29 @@ pub mod python {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -7459,8 +10084,14 @@ pub mod python {
             pub(crate) package_version: String,
             pub(crate) file_name: String,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -7479,6 +10110,10 @@ pub mod python {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7507,8 +10142,8 @@ pub mod python {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -8297,6 +10932,346 @@ pub mod universal {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Show information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update information for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to 'false' to restore the package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::UPackRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete a package version from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                project,
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Show information about a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update information for a package version.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn update_package_version(
+            &self,
+            body: impl Into<models::PackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> update_package_version::RequestBuilder {
+            Client(self.0.client.clone()).update_package_version(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from a feed's recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update several packages from a single feed in a single request. The updates to the packages do not happen atomically.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        pub fn update_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about a package version in the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn get_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> get_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).get_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Restore a package version from the recycle bin to its associated feed.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Set the 'Deleted' property to 'false' to restore the package."]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn restore_package_version_from_recycle_bin(
+            &self,
+            body: impl Into<models::UPackRecycleBinPackageVersionDetails>,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> restore_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a package version from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `feed_id`: Name or ID of the feed."]
+        #[doc = "* `package_name`: Name of the package."]
+        #[doc = "* `package_version`: Version of the package."]
+        pub fn delete_package_version_from_recycle_bin(
+            &self,
+            feed_id: impl Into<String>,
+            package_name: impl Into<String>,
+            package_version: impl Into<String>,
+        ) -> delete_package_version_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_package_version_from_recycle_bin(
+                self.0.organization.clone(),
+                feed_id,
+                package_name,
+                package_version,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete or restore several package versions from the recycle bin.\n\nThe project parameter must be supplied if the feed was created in a project.\nIf the feed is not associated with any project, omit the project parameter from the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Information about the packages to update, the operation to perform, and its associated data. <c>Operation</c> must be <c>PermanentDelete</c> or <c>RestoreToFeed</c>"]
+        #[doc = "* `feed_id`: Feed which contains the packages to update."]
+        pub fn update_recycle_bin_package_versions(
+            &self,
+            body: impl Into<models::UPackPackagesBatchRequest>,
+            feed_id: impl Into<String>,
+        ) -> update_recycle_bin_package_versions::RequestBuilder {
+            Client(self.0.client.clone()).update_recycle_bin_package_versions(
+                self.0.organization.clone(),
+                body,
+                feed_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_package_version {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
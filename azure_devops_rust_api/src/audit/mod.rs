@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn actions_client(&self) -> actions::Client {
         actions::Client(self.clone())
     }
@@ -142,6 +169,70 @@ impl Client {
         streams::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn actions_client(&self) -> actions::OrganizationClient {
+        actions::OrganizationClient(self.clone())
+    }
+    pub fn audit_log_client(&self) -> audit_log::OrganizationClient {
+        audit_log::OrganizationClient(self.clone())
+    }
+    pub fn download_log_client(&self) -> download_log::OrganizationClient {
+        download_log::OrganizationClient(self.clone())
+    }
+    pub fn streams_client(&self) -> streams::OrganizationClient {
+        streams::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn actions_client(&self) -> actions::ProjectClient {
+        actions::ProjectClient(self.clone())
+    }
+    pub fn audit_log_client(&self) -> audit_log::ProjectClient {
+        audit_log::ProjectClient(self.clone())
+    }
+    pub fn download_log_client(&self) -> download_log::ProjectClient {
+        download_log::ProjectClient(self.clone())
+    }
+    pub fn streams_client(&self) -> streams::ProjectClient {
+        streams::ProjectClient(self.clone())
+    }
+}
 pub mod actions {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -162,6 +253,20 @@ pub mod actions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all auditable actions filterable by area."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all auditable actions filterable by area."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -313,6 +418,20 @@ pub mod audit_log {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Queries audit log entries"]
+        pub fn query(&self) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Queries audit log entries"]
+        pub fn query(&self) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone())
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -424,14 +543,16 @@ pub mod audit_log {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
                         if let Some(start_time) = &this.start_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startTime", &start_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startTime",
+                                &crate::date_time::format_date_time(start_time)?,
+                            );
                         }
                         if let Some(end_time) = &this.end_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("endTime", &end_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "endTime",
+                                &crate::date_time::format_date_time(end_time)?,
+                            );
                         }
                         if let Some(batch_size) = &this.batch_size {
                             req.url_mut()
@@ -508,9 +629,30 @@ pub mod download_log {
                 format: format.into(),
                 start_time: None,
                 end_time: None,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Downloads audit log entries."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `format`: File format for download. Can be \"json\" or \"csv\"."]
+        pub fn download_log(&self, format: impl Into<String>) -> download_log::RequestBuilder {
+            Client(self.0.client.clone()).download_log(self.0.organization.clone(), format)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Downloads audit log entries."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `format`: File format for download. Can be \"json\" or \"csv\"."]
+        pub fn download_log(&self, format: impl Into<String>) -> download_log::RequestBuilder {
+            Client(self.0.client.clone()).download_log(self.0.organization.clone(), format)
+        }
+    }
     pub mod download_log {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -520,19 +662,29 @@ pub mod download_log {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -573,6 +725,7 @@ pub mod download_log {
             pub(crate) format: String,
             pub(crate) start_time: Option<time::OffsetDateTime>,
             pub(crate) end_time: Option<time::OffsetDateTime>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Start time of download window. Optional"]
@@ -585,6 +738,11 @@ pub mod download_log {
                 self.end_time = Some(end_time.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -603,19 +761,25 @@ pub mod download_log {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let format = &this.format;
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("format", format);
                         if let Some(start_time) = &this.start_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startTime", &start_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startTime",
+                                &crate::date_time::format_date_time(start_time)?,
+                            );
                         }
                         if let Some(end_time) = &this.end_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("endTime", &end_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "endTime",
+                                &crate::date_time::format_date_time(end_time)?,
+                            );
+                        }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -640,8 +804,8 @@ pub mod download_log {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -761,6 +925,132 @@ pub mod streams {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Return all Audit Streams scoped to an organization"]
+        pub fn query_all_streams(&self) -> query_all_streams::RequestBuilder {
+            Client(self.0.client.clone()).query_all_streams(self.0.organization.clone())
+        }
+        #[doc = "Create new Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        #[doc = "* `days_to_backfill`: The number of days of previously recorded audit data that will be replayed into the stream. A value of zero will result in only new events being streamed."]
+        pub fn create(
+            &self,
+            body: impl Into<models::AuditStream>,
+            days_to_backfill: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                days_to_backfill,
+            )
+        }
+        #[doc = "Update existing Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        pub fn update_stream(
+            &self,
+            body: impl Into<models::AuditStream>,
+        ) -> update_stream::RequestBuilder {
+            Client(self.0.client.clone()).update_stream(self.0.organization.clone(), body)
+        }
+        #[doc = "Return Audit Stream with id of streamId if one exists otherwise throw"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to retrieve"]
+        pub fn query_stream_by_id(&self, stream_id: i32) -> query_stream_by_id::RequestBuilder {
+            Client(self.0.client.clone()).query_stream_by_id(self.0.organization.clone(), stream_id)
+        }
+        #[doc = "Update existing Audit Stream status"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to be updated"]
+        #[doc = "* `status`: Status of the stream"]
+        pub fn update_status(
+            &self,
+            stream_id: i32,
+            status: impl Into<String>,
+        ) -> update_status::RequestBuilder {
+            Client(self.0.client.clone()).update_status(
+                self.0.organization.clone(),
+                stream_id,
+                status,
+            )
+        }
+        #[doc = "Delete Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to delete"]
+        pub fn delete(&self, stream_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), stream_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Return all Audit Streams scoped to an organization"]
+        pub fn query_all_streams(&self) -> query_all_streams::RequestBuilder {
+            Client(self.0.client.clone()).query_all_streams(self.0.organization.clone())
+        }
+        #[doc = "Create new Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        #[doc = "* `days_to_backfill`: The number of days of previously recorded audit data that will be replayed into the stream. A value of zero will result in only new events being streamed."]
+        pub fn create(
+            &self,
+            body: impl Into<models::AuditStream>,
+            days_to_backfill: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                days_to_backfill,
+            )
+        }
+        #[doc = "Update existing Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream entry"]
+        pub fn update_stream(
+            &self,
+            body: impl Into<models::AuditStream>,
+        ) -> update_stream::RequestBuilder {
+            Client(self.0.client.clone()).update_stream(self.0.organization.clone(), body)
+        }
+        #[doc = "Return Audit Stream with id of streamId if one exists otherwise throw"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to retrieve"]
+        pub fn query_stream_by_id(&self, stream_id: i32) -> query_stream_by_id::RequestBuilder {
+            Client(self.0.client.clone()).query_stream_by_id(self.0.organization.clone(), stream_id)
+        }
+        #[doc = "Update existing Audit Stream status"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to be updated"]
+        #[doc = "* `status`: Status of the stream"]
+        pub fn update_status(
+            &self,
+            stream_id: i32,
+            status: impl Into<String>,
+        ) -> update_status::RequestBuilder {
+            Client(self.0.client.clone()).update_status(
+                self.0.organization.clone(),
+                stream_id,
+                status,
+            )
+        }
+        #[doc = "Delete Audit Stream"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `stream_id`: Id of stream entry to delete"]
+        pub fn delete(&self, stream_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), stream_id)
+        }
+    }
     pub mod query_all_streams {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
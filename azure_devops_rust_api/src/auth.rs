@@ -5,21 +5,48 @@
 //!
 //! For more background information on Azure DevOps authentication see: [Azure DevOps authentication](https://docs.microsoft.com/en-us/azure/devops/integrate/get-started/authentication/authentication-guidance)
 
-use azure_core::auth::TokenCredential;
+use async_trait::async_trait;
+use azure_core::auth::{AccessToken, TokenCredential};
 use azure_core::error::{Result, ResultExt};
 use base64::{prelude::BASE64_STANDARD, Engine};
+use futures::lock::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+
+/// Environment variables checked by [`Credential::from_env()`], in order.
+const ENV_SYSTEM_ACCESSTOKEN: &str = "SYSTEM_ACCESSTOKEN";
+const ENV_AZURE_DEVOPS_EXT_PAT: &str = "AZURE_DEVOPS_EXT_PAT";
+const ENV_ADO_TOKEN: &str = "ADO_TOKEN";
+
+/// Cached tokens are refreshed when they are within this time of expiring.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::minutes(5);
 
 /// A credential for authenticating with Azure DevOps.
 ///
 /// Supports:
 /// - [Azure DevOps Personal Access Token (PAT)](https://docs.microsoft.com/en-us/azure/devops/organizations/accounts/use-personal-access-tokens-to-authenticate).
 /// - OAuth token credential obtained via the [`azure_identity`](https://crates.io/crates/azure_identity) crate.
+/// - A static bearer token, such as the pipeline [`System.AccessToken`](https://learn.microsoft.com/en-us/azure/devops/pipelines/build/variables#systemaccesstoken).
+/// - Username and password (Basic authentication), for Azure DevOps Server.
+/// - A [`TokenProvider`] callback that returns refreshable bearer tokens.
+///
+/// New authentication methods may be added, so matches on `Credential` must have a wildcard arm.
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum Credential {
     Unauthenticated,
     Pat(String),
-    TokenCredential(Arc<dyn TokenCredential>),
+    TokenCredential(CachingTokenCredential),
+    BearerToken(String),
+    Basic { username: String, password: String },
+    TokenProvider(TokenProvider),
 }
 
 impl Credential {
@@ -37,12 +64,67 @@ impl Credential {
     /// Creates a new `Credential` using the supplied object that implements [`TokenCredential`](https://docs.rs/azure_core/latest/azure_core/auth/trait.TokenCredential.html).
     ///
     /// Note that the supplied object must be wrapped in an `Arc<...>`.
+    ///
+    /// Tokens are cached per set of scopes, and only requested from the supplied object
+    /// when there is no cached token or the cached token is about to expire.
     pub fn from_token_credential<T>(token_credential: Arc<T>) -> Self
     where
         T: TokenCredential + 'static,
     {
-        let token_credential = token_credential as Arc<dyn TokenCredential>;
-        Credential::TokenCredential(token_credential)
+        Credential::TokenCredential(CachingTokenCredential::new(token_credential))
+    }
+
+    /// Creates a new `Credential` using the supplied bearer token.
+    ///
+    /// The token is used as-is and is never refreshed. This is typically used with the
+    /// `System.AccessToken` of an Azure Pipelines job.
+    pub fn from_bearer_token(token: impl Into<String>) -> Self {
+        Credential::BearerToken(token.into())
+    }
+
+    /// Creates a new `Credential` using the supplied username and password (Basic authentication).
+    ///
+    /// This is typically used with Azure DevOps Server.
+    pub fn from_basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Credential::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Creates a new `Credential` that gets bearer tokens by calling the supplied function.
+    ///
+    /// The returned token is cached until it is about to expire, and then the function
+    /// is called again to refresh it.
+    pub fn from_token_provider<F, Fut>(get_token: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AccessToken>> + Send + 'static,
+    {
+        Credential::TokenProvider(TokenProvider::new(get_token))
+    }
+
+    /// Creates a new `Credential` from the environment, if a token is available.
+    ///
+    /// The following environment variables are checked, in order:
+    /// - `SYSTEM_ACCESSTOKEN`: a bearer token, as exposed to Azure Pipelines jobs.
+    /// - `AZURE_DEVOPS_EXT_PAT`: a PAT, as used by the Azure DevOps CLI extension.
+    /// - `ADO_TOKEN`: a PAT.
+    ///
+    /// Empty values are ignored.
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Creates a new `Credential` from the variables returned by `lookup`, see [`Credential::from_env()`].
+    fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let var = |name| lookup(name).filter(|value| !value.is_empty());
+        if let Some(token) = var(ENV_SYSTEM_ACCESSTOKEN) {
+            return Some(Credential::from_bearer_token(token));
+        }
+        var(ENV_AZURE_DEVOPS_EXT_PAT)
+            .or_else(|| var(ENV_ADO_TOKEN))
+            .map(Credential::from_pat)
     }
 
     /// Returns the HTTP authorization header value containing the credential.
@@ -66,6 +148,304 @@ impl Credential {
                     .context(azure_core::error::ErrorKind::Other, "get bearer token")?;
                 Ok(Some(format!("Bearer {}", token_response.token.secret())))
             }
+            Credential::BearerToken(token) => Ok(Some(format!("Bearer {token}"))),
+            Credential::Basic { username, password } => Ok(Some(format!(
+                "Basic {}",
+                BASE64_STANDARD.encode(format!("{username}:{password}"))
+            ))),
+            Credential::TokenProvider(token_provider) => {
+                let token = token_provider.get_token().await?;
+                Ok(Some(format!("Bearer {}", token.token.secret())))
+            }
         }
     }
 }
+
+/// A refreshable bearer token, obtained by calling a user supplied function.
+///
+/// Create using [`Credential::from_token_provider()`].
+#[derive(Clone)]
+pub struct TokenProvider {
+    get_token: Arc<dyn Fn() -> BoxFuture<'static, Result<AccessToken>> + Send + Sync>,
+    cache: Arc<CachedToken>,
+}
+
+impl TokenProvider {
+    fn new<F, Fut>(get_token: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AccessToken>> + Send + 'static,
+    {
+        Self {
+            get_token: Arc::new(move || Box::pin(get_token())),
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns the cached token, refreshing it if it is about to expire.
+    pub async fn get_token(&self) -> Result<AccessToken> {
+        self.cache
+            .get_or_refresh(|| (self.get_token)())
+            .await
+            .context(azure_core::error::ErrorKind::Other, "get bearer token")
+    }
+
+    /// Returns the expiry time of the cached token, if any.
+    pub async fn expires_on(&self) -> Option<OffsetDateTime> {
+        self.cache.expires_on().await
+    }
+}
+
+impl std::fmt::Debug for TokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenProvider").finish_non_exhaustive()
+    }
+}
+
+/// A [`TokenCredential`] that caches the tokens returned by another `TokenCredential`.
+///
+/// Some credentials (e.g. `AzureCliCredential`) run a process or make an HTTP request
+/// for every token, so tokens are cached per set of scopes until they are about to expire.
+///
+/// Clones share the same cache. Create using [`Credential::from_token_credential()`].
+#[derive(Debug, Clone)]
+pub struct CachingTokenCredential {
+    credential: Arc<dyn TokenCredential>,
+    cache: Arc<std::sync::Mutex<HashMap<Vec<String>, Arc<CachedToken>>>>,
+}
+
+impl CachingTokenCredential {
+    fn new<T>(credential: Arc<T>) -> Self
+    where
+        T: TokenCredential + 'static,
+    {
+        let credential = credential as Arc<dyn TokenCredential>;
+        Self {
+            credential,
+            cache: Default::default(),
+        }
+    }
+
+    fn cached_token(&self, scopes: &[&str]) -> Arc<CachedToken> {
+        let mut key: Vec<String> = scopes.iter().map(|scope| (*scope).to_owned()).collect();
+        key.sort();
+        self.cache.lock().unwrap().entry(key).or_default().clone()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl TokenCredential for CachingTokenCredential {
+    async fn get_token(&self, scopes: &[&str]) -> Result<AccessToken> {
+        self.cached_token(scopes)
+            .get_or_refresh(|| self.credential.get_token(scopes))
+            .await
+    }
+
+    async fn clear_cache(&self) -> Result<()> {
+        self.cache.lock().unwrap().clear();
+        self.credential.clear_cache().await
+    }
+}
+
+/// A cached access token.
+///
+/// Concurrent callers wait on the same refresh, rather than each requesting a new token.
+#[derive(Debug, Default)]
+struct CachedToken {
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl CachedToken {
+    async fn get_or_refresh<F, Fut>(&self, refresh: F) -> Result<AccessToken>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<AccessToken>>,
+    {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref().filter(|token| !is_expiring(token)) {
+            return Ok(token.clone());
+        }
+        let refreshed = refresh().await?;
+        *token = Some(refreshed.clone());
+        Ok(refreshed)
+    }
+
+    async fn expires_on(&self) -> Option<OffsetDateTime> {
+        self.token
+            .lock()
+            .await
+            .as_ref()
+            .map(|token| token.expires_on)
+    }
+}
+
+fn is_expiring(token: &AccessToken) -> bool {
+    token.expires_on - TOKEN_REFRESH_MARGIN <= OffsetDateTime::now_utc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingCredential {
+        calls: AtomicUsize,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl TokenCredential for CountingCredential {
+        async fn get_token(&self, scopes: &[&str]) -> Result<AccessToken> {
+            let calls = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(AccessToken::new(
+                format!("{}-{calls}", scopes.join(",")),
+                OffsetDateTime::now_utc() + Duration::hours(1),
+            ))
+        }
+
+        async fn clear_cache(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn authorization_header(credential: &Credential) -> Option<String> {
+        futures::executor::block_on(credential.http_authorization_header(&["scope"])).unwrap()
+    }
+
+    #[test]
+    fn test_authorization_header() {
+        assert_eq!(authorization_header(&Credential::unauthenticated()), None);
+        // PATs are sent as the password of Basic authentication, with an empty username
+        assert_eq!(
+            authorization_header(&Credential::from_pat("abc")).as_deref(),
+            Some("Basic OmFiYw==")
+        );
+        assert_eq!(
+            authorization_header(&Credential::from_basic("user", "pass")).as_deref(),
+            Some("Basic dXNlcjpwYXNz")
+        );
+        assert_eq!(
+            authorization_header(&Credential::from_bearer_token("token")).as_deref(),
+            Some("Bearer token")
+        );
+    }
+
+    #[test]
+    fn test_from_env() {
+        fn from_vars(vars: &[(&str, &str)]) -> Option<String> {
+            let vars: HashMap<String, String> = vars
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            Credential::from_vars(|name| vars.get(name).cloned())
+                .map(|credential| authorization_header(&credential).unwrap())
+        }
+
+        assert_eq!(from_vars(&[]), None);
+        assert_eq!(from_vars(&[(ENV_SYSTEM_ACCESSTOKEN, "")]), None);
+        assert_eq!(
+            from_vars(&[(ENV_ADO_TOKEN, "abc")]).as_deref(),
+            Some("Basic OmFiYw==")
+        );
+        // AZURE_DEVOPS_EXT_PAT takes precedence over ADO_TOKEN
+        assert_eq!(
+            from_vars(&[(ENV_AZURE_DEVOPS_EXT_PAT, "abc"), (ENV_ADO_TOKEN, "def")]).as_deref(),
+            Some("Basic OmFiYw==")
+        );
+        // SYSTEM_ACCESSTOKEN takes precedence over the PATs, unless it is empty
+        assert_eq!(
+            from_vars(&[
+                (ENV_SYSTEM_ACCESSTOKEN, "token"),
+                (ENV_AZURE_DEVOPS_EXT_PAT, "abc"),
+                (ENV_ADO_TOKEN, "def"),
+            ])
+            .as_deref(),
+            Some("Bearer token")
+        );
+        assert_eq!(
+            from_vars(&[(ENV_SYSTEM_ACCESSTOKEN, ""), (ENV_ADO_TOKEN, "def")]).as_deref(),
+            Some("Basic OmRlZg==")
+        );
+    }
+
+    #[test]
+    fn test_token_provider() {
+        let counting = Arc::new(CountingCredential::default());
+        let provider = counting.clone();
+        let credential = Credential::from_token_provider(move || {
+            let provider = provider.clone();
+            async move { provider.get_token(&["provider"]).await }
+        });
+        // The token is requested once, and then cached until it is about to expire
+        assert_eq!(
+            authorization_header(&credential).as_deref(),
+            Some("Bearer provider-0")
+        );
+        assert_eq!(
+            authorization_header(&credential).as_deref(),
+            Some("Bearer provider-0")
+        );
+        assert_eq!(counting.calls.load(Ordering::SeqCst), 1);
+
+        // A token that is about to expire is refreshed on every request
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = calls.clone();
+        let credential = Credential::from_token_provider(move || {
+            let calls = provider.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(AccessToken::new(
+                    format!("expiring-{calls}"),
+                    OffsetDateTime::now_utc() + Duration::minutes(1),
+                ))
+            }
+        });
+        for i in 0..3 {
+            assert_eq!(
+                authorization_header(&credential),
+                Some(format!("Bearer expiring-{i}"))
+            );
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_token_credential() {
+        let counting = Arc::new(CountingCredential::default());
+        let credential = Credential::from_token_credential(counting.clone());
+        let clone = credential.clone();
+        assert_eq!(
+            authorization_header(&credential).as_deref(),
+            Some("Bearer scope-0")
+        );
+        assert_eq!(
+            authorization_header(&clone).as_deref(),
+            Some("Bearer scope-0")
+        );
+        assert_eq!(counting.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_token_credential_cache() {
+        let counting = Arc::new(CountingCredential::default());
+        let credential = CachingTokenCredential::new(counting.clone());
+        futures::executor::block_on(async {
+            let (a, b) = futures::join!(
+                credential.get_token(&["scope1"]),
+                credential.get_token(&["scope1"])
+            );
+            assert_eq!(a.unwrap().token.secret(), "scope1-0");
+            assert_eq!(b.unwrap().token.secret(), "scope1-0");
+            assert_eq!(counting.calls.load(Ordering::SeqCst), 1);
+
+            let c = credential.get_token(&["scope2"]).await.unwrap();
+            assert_eq!(c.token.secret(), "scope2-1");
+            assert_eq!(counting.calls.load(Ordering::SeqCst), 2);
+
+            credential.clear_cache().await.unwrap();
+            let d = credential.get_token(&["scope1"]).await.unwrap();
+            assert_eq!(d.token.secret(), "scope1-2");
+        });
+    }
+}
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn artifacts_client(&self) -> artifacts::Client {
         artifacts::Client(self.clone())
     }
@@ -211,6 +238,208 @@ impl Client {
         yaml::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn artifacts_client(&self) -> artifacts::OrganizationClient {
+        artifacts::OrganizationClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn authorizedresources_client(&self) -> authorizedresources::OrganizationClient {
+        authorizedresources::OrganizationClient(self.clone())
+    }
+    pub fn badge_client(&self) -> badge::OrganizationClient {
+        badge::OrganizationClient(self.clone())
+    }
+    pub fn builds_client(&self) -> builds::OrganizationClient {
+        builds::OrganizationClient(self.clone())
+    }
+    pub fn controllers_client(&self) -> controllers::OrganizationClient {
+        controllers::OrganizationClient(self.clone())
+    }
+    pub fn definitions_client(&self) -> definitions::OrganizationClient {
+        definitions::OrganizationClient(self.clone())
+    }
+    pub fn folders_client(&self) -> folders::OrganizationClient {
+        folders::OrganizationClient(self.clone())
+    }
+    pub fn general_settings_client(&self) -> general_settings::OrganizationClient {
+        general_settings::OrganizationClient(self.clone())
+    }
+    pub fn history_client(&self) -> history::OrganizationClient {
+        history::OrganizationClient(self.clone())
+    }
+    pub fn latest_client(&self) -> latest::OrganizationClient {
+        latest::OrganizationClient(self.clone())
+    }
+    pub fn leases_client(&self) -> leases::OrganizationClient {
+        leases::OrganizationClient(self.clone())
+    }
+    pub fn metrics_client(&self) -> metrics::OrganizationClient {
+        metrics::OrganizationClient(self.clone())
+    }
+    pub fn options_client(&self) -> options::OrganizationClient {
+        options::OrganizationClient(self.clone())
+    }
+    pub fn properties_client(&self) -> properties::OrganizationClient {
+        properties::OrganizationClient(self.clone())
+    }
+    pub fn report_client(&self) -> report::OrganizationClient {
+        report::OrganizationClient(self.clone())
+    }
+    pub fn resource_usage_client(&self) -> resource_usage::OrganizationClient {
+        resource_usage::OrganizationClient(self.clone())
+    }
+    pub fn resources_client(&self) -> resources::OrganizationClient {
+        resources::OrganizationClient(self.clone())
+    }
+    pub fn retention_client(&self) -> retention::OrganizationClient {
+        retention::OrganizationClient(self.clone())
+    }
+    pub fn settings_client(&self) -> settings::OrganizationClient {
+        settings::OrganizationClient(self.clone())
+    }
+    pub fn source_providers_client(&self) -> source_providers::OrganizationClient {
+        source_providers::OrganizationClient(self.clone())
+    }
+    pub fn stages_client(&self) -> stages::OrganizationClient {
+        stages::OrganizationClient(self.clone())
+    }
+    pub fn status_client(&self) -> status::OrganizationClient {
+        status::OrganizationClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::OrganizationClient {
+        tags::OrganizationClient(self.clone())
+    }
+    pub fn templates_client(&self) -> templates::OrganizationClient {
+        templates::OrganizationClient(self.clone())
+    }
+    pub fn timeline_client(&self) -> timeline::OrganizationClient {
+        timeline::OrganizationClient(self.clone())
+    }
+    pub fn yaml_client(&self) -> yaml::OrganizationClient {
+        yaml::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifacts_client(&self) -> artifacts::ProjectClient {
+        artifacts::ProjectClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn authorizedresources_client(&self) -> authorizedresources::ProjectClient {
+        authorizedresources::ProjectClient(self.clone())
+    }
+    pub fn badge_client(&self) -> badge::ProjectClient {
+        badge::ProjectClient(self.clone())
+    }
+    pub fn builds_client(&self) -> builds::ProjectClient {
+        builds::ProjectClient(self.clone())
+    }
+    pub fn controllers_client(&self) -> controllers::ProjectClient {
+        controllers::ProjectClient(self.clone())
+    }
+    pub fn definitions_client(&self) -> definitions::ProjectClient {
+        definitions::ProjectClient(self.clone())
+    }
+    pub fn folders_client(&self) -> folders::ProjectClient {
+        folders::ProjectClient(self.clone())
+    }
+    pub fn general_settings_client(&self) -> general_settings::ProjectClient {
+        general_settings::ProjectClient(self.clone())
+    }
+    pub fn history_client(&self) -> history::ProjectClient {
+        history::ProjectClient(self.clone())
+    }
+    pub fn latest_client(&self) -> latest::ProjectClient {
+        latest::ProjectClient(self.clone())
+    }
+    pub fn leases_client(&self) -> leases::ProjectClient {
+        leases::ProjectClient(self.clone())
+    }
+    pub fn metrics_client(&self) -> metrics::ProjectClient {
+        metrics::ProjectClient(self.clone())
+    }
+    pub fn options_client(&self) -> options::ProjectClient {
+        options::ProjectClient(self.clone())
+    }
+    pub fn properties_client(&self) -> properties::ProjectClient {
+        properties::ProjectClient(self.clone())
+    }
+    pub fn report_client(&self) -> report::ProjectClient {
+        report::ProjectClient(self.clone())
+    }
+    pub fn resource_usage_client(&self) -> resource_usage::ProjectClient {
+        resource_usage::ProjectClient(self.clone())
+    }
+    pub fn resources_client(&self) -> resources::ProjectClient {
+        resources::ProjectClient(self.clone())
+    }
+    pub fn retention_client(&self) -> retention::ProjectClient {
+        retention::ProjectClient(self.clone())
+    }
+    pub fn settings_client(&self) -> settings::ProjectClient {
+        settings::ProjectClient(self.clone())
+    }
+    pub fn source_providers_client(&self) -> source_providers::ProjectClient {
+        source_providers::ProjectClient(self.clone())
+    }
+    pub fn stages_client(&self) -> stages::ProjectClient {
+        stages::ProjectClient(self.clone())
+    }
+    pub fn status_client(&self) -> status::ProjectClient {
+        status::ProjectClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::ProjectClient {
+        tags::ProjectClient(self.clone())
+    }
+    pub fn templates_client(&self) -> templates::ProjectClient {
+        templates::ProjectClient(self.clone())
+    }
+    pub fn timeline_client(&self) -> timeline::ProjectClient {
+        timeline::ProjectClient(self.clone())
+    }
+    pub fn yaml_client(&self) -> yaml::ProjectClient {
+        yaml::ProjectClient(self.clone())
+    }
+}
 pub mod artifacts {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -267,6 +496,7 @@ pub mod artifacts {
                 artifact_name: artifact_name.into(),
                 file_id: file_id.into(),
                 file_name: file_name.into(),
+                accept: None,
             }
         }
         #[doc = "Gets all artifacts for a build."]
@@ -311,6 +541,151 @@ pub mod artifacts {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a specific artifact for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        pub fn get_artifact(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get_artifact::RequestBuilder {
+            Client(self.0.client.clone()).get_artifact(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                artifact_name,
+            )
+        }
+        #[doc = "Gets a file from the build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        #[doc = "* `file_id`: The primary key for the file."]
+        #[doc = "* `file_name`: The name that the file will be set to."]
+        pub fn get_file(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+            file_id: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> get_file::RequestBuilder {
+            Client(self.0.client.clone()).get_file(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                artifact_name,
+                file_id,
+                file_name,
+            )
+        }
+        #[doc = "Gets all artifacts for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn list(&self, project: impl Into<String>, build_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, build_id)
+        }
+        #[doc = "Associates an artifact with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The artifact."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildArtifact>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a specific artifact for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        pub fn get_artifact(
+            &self,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get_artifact::RequestBuilder {
+            Client(self.0.client.clone()).get_artifact(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                artifact_name,
+            )
+        }
+        #[doc = "Gets a file from the build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `artifact_name`: The name of the artifact."]
+        #[doc = "* `file_id`: The primary key for the file."]
+        #[doc = "* `file_name`: The name that the file will be set to."]
+        pub fn get_file(
+            &self,
+            build_id: i32,
+            artifact_name: impl Into<String>,
+            file_id: impl Into<String>,
+            file_name: impl Into<String>,
+        ) -> get_file::RequestBuilder {
+            Client(self.0.client.clone()).get_file(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                artifact_name,
+                file_id,
+                file_name,
+            )
+        }
+        #[doc = "Gets all artifacts for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn list(&self, build_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Associates an artifact with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The artifact."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildArtifact>,
+            build_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get_artifact {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -444,19 +819,29 @@ pub mod artifacts {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -499,8 +884,14 @@ pub mod artifacts {
             pub(crate) artifact_name: String,
             pub(crate) file_id: String,
             pub(crate) file_name: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -519,6 +910,7 @@ pub mod artifacts {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let artifact_name = &this.artifact_name;
                         req.url_mut()
                             .query_pairs_mut()
@@ -531,6 +923,9 @@ pub mod artifacts {
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("fileName", file_name);
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -550,8 +945,8 @@ pub mod artifacts {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -946,48 +1341,223 @@ pub mod leases {
             }
         }
     }
-    pub mod get_retention_leases_by_user_id {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::RetentionLeaseList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::RetentionLeaseList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns any leases owned by the specified user, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `user_owner_id`: The user id to search for."]
+        pub fn get_retention_leases_by_user_id(
+            &self,
+            project: impl Into<String>,
+            user_owner_id: impl Into<String>,
+        ) -> get_retention_leases_by_user_id::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_by_user_id(
+                self.0.organization.clone(),
+                project,
+                user_owner_id,
+            )
         }
-        #[derive(Clone)]
+        #[doc = "Returns any leases owned by the specified entity, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_retention_leases_by_owner_id(
+            &self,
+            project: impl Into<String>,
+        ) -> get_retention_leases_by_owner_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_retention_leases_by_owner_id(self.0.organization.clone(), project)
+        }
+        #[doc = "Returns any leases matching the specified MinimalRetentionLeases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `leases_to_fetch`: List of JSON-serialized MinimalRetentionLeases separated by '|'"]
+        pub fn get_retention_leases_by_minimal_retention_leases(
+            &self,
+            project: impl Into<String>,
+            leases_to_fetch: impl Into<String>,
+        ) -> get_retention_leases_by_minimal_retention_leases::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_by_minimal_retention_leases(
+                self.0.organization.clone(),
+                project,
+                leases_to_fetch,
+            )
+        }
+        #[doc = "Adds new leases for pipeline runs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: Vec<models::NewRetentionLease>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Removes specific retention leases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, ids)
+        }
+        #[doc = "Returns the details of the retention lease given a lease id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, lease_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, lease_id)
+        }
+        #[doc = "Updates the duration or pipeline protection status of a retention lease."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new data for the retention lease."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `lease_id`: The ID of the lease to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RetentionLeaseUpdate>,
+            project: impl Into<String>,
+            lease_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                lease_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns any leases owned by the specified user, optionally scoped to a single pipeline definition and run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_owner_id`: The user id to search for."]
+        pub fn get_retention_leases_by_user_id(
+            &self,
+            user_owner_id: impl Into<String>,
+        ) -> get_retention_leases_by_user_id::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_by_user_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                user_owner_id,
+            )
+        }
+        #[doc = "Returns any leases owned by the specified entity, optionally scoped to a single pipeline definition and run."]
+        pub fn get_retention_leases_by_owner_id(
+            &self,
+        ) -> get_retention_leases_by_owner_id::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_by_owner_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Returns any leases matching the specified MinimalRetentionLeases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `leases_to_fetch`: List of JSON-serialized MinimalRetentionLeases separated by '|'"]
+        pub fn get_retention_leases_by_minimal_retention_leases(
+            &self,
+            leases_to_fetch: impl Into<String>,
+        ) -> get_retention_leases_by_minimal_retention_leases::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_by_minimal_retention_leases(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                leases_to_fetch,
+            )
+        }
+        #[doc = "Adds new leases for pipeline runs."]
+        pub fn add(&self, body: Vec<models::NewRetentionLease>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Removes specific retention leases."]
+        pub fn delete(&self, ids: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                ids,
+            )
+        }
+        #[doc = "Returns the details of the retention lease given a lease id."]
+        pub fn get(&self, lease_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                lease_id,
+            )
+        }
+        #[doc = "Updates the duration or pipeline protection status of a retention lease."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new data for the retention lease."]
+        #[doc = "* `lease_id`: The ID of the lease to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RetentionLeaseUpdate>,
+            lease_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                lease_id,
+            )
+        }
+    }
+    pub mod get_retention_leases_by_user_id {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::RetentionLeaseList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::RetentionLeaseList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
         #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
@@ -1863,6 +2433,28 @@ pub mod controllers {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets controller, optionally filtered by name"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets a controller"]
+        pub fn get(&self, controller_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), controller_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets controller, optionally filtered by name"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets a controller"]
+        pub fn get(&self, controller_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), controller_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2123,6 +2715,20 @@ pub mod resource_usage {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets information about build resources in the system."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets information about build resources in the system."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2259,6 +2865,20 @@ pub mod history {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the retention history for the project collection. This includes pipelines that have custom retention rules that may prevent the retention job from cleaning them up, runs per pipeline with retention type, files associated with pipelines owned by the collection with retention type, and the number of files per pipeline."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the retention history for the project collection. This includes pipelines that have custom retention rules that may prevent the retention job from cleaning them up, runs per pipeline with retention type, files associated with pipelines owned by the collection with retention type, and the number of files per pipeline."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2411,6 +3031,7 @@ pub mod badge {
                 project: project.into(),
                 definition_id,
                 branch_name: None,
+                accept: None,
             }
         }
         #[doc = "Gets a badge that indicates the status of the most recent build for the specified branch."]
@@ -2435,6 +3056,61 @@ pub mod badge {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "This endpoint is deprecated. Please see the Build Status REST endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: The project ID or name."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition_id)
+        }
+        #[doc = "Gets a badge that indicates the status of the most recent build for the specified branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repo_type`: The repository type."]
+        pub fn get_build_badge_data(
+            &self,
+            project: impl Into<String>,
+            repo_type: impl Into<String>,
+        ) -> get_build_badge_data::RequestBuilder {
+            Client(self.0.client.clone()).get_build_badge_data(
+                self.0.organization.clone(),
+                project,
+                repo_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "This endpoint is deprecated. Please see the Build Status REST endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Gets a badge that indicates the status of the most recent build for the specified branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repo_type`: The repository type."]
+        pub fn get_build_badge_data(
+            &self,
+            repo_type: impl Into<String>,
+        ) -> get_build_badge_data::RequestBuilder {
+            Client(self.0.client.clone()).get_build_badge_data(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repo_type,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2444,19 +3120,29 @@ pub mod badge {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -2497,6 +3183,7 @@ pub mod badge {
             pub(crate) project: String,
             pub(crate) definition_id: i32,
             pub(crate) branch_name: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The name of the branch."]
@@ -2504,6 +3191,11 @@ pub mod badge {
                 self.branch_name = Some(branch_name.into());
                 self
             }
+            #[doc = "The content type of the response: one of `image/svg+xml`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -2522,11 +3214,15 @@ pub mod badge {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "image/svg+xml");
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
                                 .append_pair("branchName", branch_name);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2552,8 +3248,8 @@ pub mod badge {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -2746,6 +3442,43 @@ pub mod authorizedresources {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn authorize_project_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            project: impl Into<String>,
+        ) -> authorize_project_resources::RequestBuilder {
+            Client(self.0.client.clone()).authorize_project_resources(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        pub fn authorize_project_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+        ) -> authorize_project_resources::RequestBuilder {
+            Client(self.0.client.clone()).authorize_project_resources(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3232,6 +3965,7 @@ pub mod builds {
                 log_id,
                 start_line: None,
                 end_line: None,
+                accept: None,
             }
         }
         #[doc = "Gets the work items associated with a build. Only work items in the same project are returned."]
@@ -3320,14 +4054,373 @@ pub mod builds {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a list of builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Queues a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn queue(
+            &self,
+            body: impl Into<models::Build>,
+            project: impl Into<String>,
+        ) -> queue::RequestBuilder {
+            Client(self.0.client.clone()).queue(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Updates multiple builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The builds to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_builds(
+            &self,
+            body: Vec<models::Build>,
+            project: impl Into<String>,
+        ) -> update_builds::RequestBuilder {
+            Client(self.0.client.clone()).update_builds(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, build_id)
+        }
+        #[doc = "Updates a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The build."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build(
+            &self,
+            body: impl Into<models::Build>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build::RequestBuilder {
+            Client(self.0.client.clone()).update_build(
+                self.0.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Deletes a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn delete(&self, project: impl Into<String>, build_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, build_id)
+        }
+        #[doc = "Gets the changes associated with a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_build_changes(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_build_changes(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets all retention leases that apply to a specific build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_retention_leases_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_retention_leases_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_for_build(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets the logs for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_logs(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_build_logs(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets an individual log file for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `log_id`: The ID of the log file."]
+        pub fn get_build_log(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            log_id: i32,
+        ) -> get_build_log::RequestBuilder {
+            Client(self.0.client.clone()).get_build_log(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                log_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build. Only work items in the same project are returned."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs::RequestBuilder {
+            Client(self.0.client.clone()).get_build_work_items_refs(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build, filtered to specific commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A comma-delimited list of commit IDs."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs_from_commits(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs_from_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_build_work_items_refs_from_commits(
+                self.0.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets the changes made to the repository between two given builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changes_between_builds(
+            &self,
+            project: impl Into<String>,
+        ) -> get_changes_between_builds::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_changes_between_builds(self.0.organization.clone(), project)
+        }
+        #[doc = "Gets all the work items between two builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `from_build_id`: The ID of the first build."]
+        #[doc = "* `to_build_id`: The ID of the last build."]
+        pub fn get_work_items_between_builds(
+            &self,
+            project: impl Into<String>,
+            from_build_id: i32,
+            to_build_id: i32,
+        ) -> get_work_items_between_builds::RequestBuilder {
+            Client(self.0.client.clone()).get_work_items_between_builds(
+                self.0.organization.clone(),
+                project,
+                from_build_id,
+                to_build_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a list of builds."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Queues a build"]
+        pub fn queue(&self, body: impl Into<models::Build>) -> queue::RequestBuilder {
+            Client(self.0.client.clone()).queue(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Updates multiple builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The builds to update."]
+        pub fn update_builds(&self, body: Vec<models::Build>) -> update_builds::RequestBuilder {
+            Client(self.0.client.clone()).update_builds(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets a build"]
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Updates a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The build."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build(
+            &self,
+            body: impl Into<models::Build>,
+            build_id: i32,
+        ) -> update_build::RequestBuilder {
+            Client(self.0.client.clone()).update_build(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Deletes a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn delete(&self, build_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the changes associated with a build"]
+        pub fn get_build_changes(&self, build_id: i32) -> get_build_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_build_changes(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets all retention leases that apply to a specific build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_retention_leases_for_build(
+            &self,
+            build_id: i32,
+        ) -> get_retention_leases_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_retention_leases_for_build(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the logs for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_logs(&self, build_id: i32) -> get_build_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_build_logs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets an individual log file for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `log_id`: The ID of the log file."]
+        pub fn get_build_log(&self, build_id: i32, log_id: i32) -> get_build_log::RequestBuilder {
+            Client(self.0.client.clone()).get_build_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                log_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build. Only work items in the same project are returned."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs(
+            &self,
+            build_id: i32,
+        ) -> get_build_work_items_refs::RequestBuilder {
+            Client(self.0.client.clone()).get_build_work_items_refs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the work items associated with a build, filtered to specific commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A comma-delimited list of commit IDs."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_work_items_refs_from_commits(
+            &self,
+            body: Vec<String>,
+            build_id: i32,
+        ) -> get_build_work_items_refs_from_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_build_work_items_refs_from_commits(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets the changes made to the repository between two given builds."]
+        pub fn get_changes_between_builds(&self) -> get_changes_between_builds::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_changes_between_builds(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Gets all the work items between two builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `from_build_id`: The ID of the first build."]
+        #[doc = "* `to_build_id`: The ID of the last build."]
+        pub fn get_work_items_between_builds(
+            &self,
+            from_build_id: i32,
+            to_build_id: i32,
+        ) -> get_work_items_between_builds::RequestBuilder {
+            Client(self.0.client.clone()).get_work_items_between_builds(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                from_build_id,
+                to_build_id,
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::BuildList> {
                 let bytes = self.0.into_body().collect().await?;
@@ -3360,6 +4453,171 @@ pub mod builds {
                 self.as_raw_response()
             }
         }
+        #[doc = "If specified, filters to builds that match this status."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum StatusFilter {
+            None,
+            InProgress,
+            Completed,
+            Cancelling,
+            Postponed,
+            NotStarted,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl StatusFilter {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::InProgress => "inProgress",
+                    Self::Completed => "completed",
+                    Self::Cancelling => "cancelling",
+                    Self::Postponed => "postponed",
+                    Self::NotStarted => "notStarted",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for StatusFilter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "If specified, filters to builds that match this result."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum ResultFilter {
+            None,
+            Succeeded,
+            PartiallySucceeded,
+            Failed,
+            Canceled,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl ResultFilter {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Succeeded => "succeeded",
+                    Self::PartiallySucceeded => "partiallySucceeded",
+                    Self::Failed => "failed",
+                    Self::Canceled => "canceled",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for ResultFilter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "The order in which builds should be returned."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum QueryOrder {
+            FinishTimeAscending,
+            FinishTimeDescending,
+            QueueTimeDescending,
+            QueueTimeAscending,
+            StartTimeDescending,
+            StartTimeAscending,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl QueryOrder {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::FinishTimeAscending => "finishTimeAscending",
+                    Self::FinishTimeDescending => "finishTimeDescending",
+                    Self::QueueTimeDescending => "queueTimeDescending",
+                    Self::QueueTimeAscending => "queueTimeAscending",
+                    Self::StartTimeDescending => "startTimeDescending",
+                    Self::StartTimeAscending => "startTimeAscending",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for QueryOrder {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "If specified, filters to builds that match this reason."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum ReasonFilter {
+            None,
+            Manual,
+            IndividualCi,
+            BatchedCi,
+            Schedule,
+            ScheduleForced,
+            UserCreated,
+            ValidateShelveset,
+            CheckInShelveset,
+            PullRequest,
+            BuildCompletion,
+            ResourceTrigger,
+            Triggered,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl ReasonFilter {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Manual => "manual",
+                    Self::IndividualCi => "individualCI",
+                    Self::BatchedCi => "batchedCI",
+                    Self::Schedule => "schedule",
+                    Self::ScheduleForced => "scheduleForced",
+                    Self::UserCreated => "userCreated",
+                    Self::ValidateShelveset => "validateShelveset",
+                    Self::CheckInShelveset => "checkInShelveset",
+                    Self::PullRequest => "pullRequest",
+                    Self::BuildCompletion => "buildCompletion",
+                    Self::ResourceTrigger => "resourceTrigger",
+                    Self::Triggered => "triggered",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for ReasonFilter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Indicates whether to exclude, include, or only return deleted builds."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum DeletedFilter {
+            ExcludeDeleted,
+            IncludeDeleted,
+            OnlyDeleted,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl DeletedFilter {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::ExcludeDeleted => "excludeDeleted",
+                    Self::IncludeDeleted => "includeDeleted",
+                    Self::OnlyDeleted => "onlyDeleted",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for DeletedFilter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3386,16 +4644,16 @@ pub mod builds {
             pub(crate) min_time: Option<time::OffsetDateTime>,
             pub(crate) max_time: Option<time::OffsetDateTime>,
             pub(crate) requested_for: Option<String>,
-            pub(crate) reason_filter: Option<String>,
-            pub(crate) status_filter: Option<String>,
-            pub(crate) result_filter: Option<String>,
+            pub(crate) reason_filter: Option<ReasonFilter>,
+            pub(crate) status_filter: Option<StatusFilter>,
+            pub(crate) result_filter: Option<ResultFilter>,
             pub(crate) tag_filters: Option<String>,
             pub(crate) properties: Option<String>,
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<String>,
             pub(crate) max_builds_per_definition: Option<i32>,
-            pub(crate) deleted_filter: Option<String>,
-            pub(crate) query_order: Option<String>,
+            pub(crate) deleted_filter: Option<DeletedFilter>,
+            pub(crate) query_order: Option<QueryOrder>,
             pub(crate) branch_name: Option<String>,
             pub(crate) build_ids: Option<String>,
             pub(crate) repository_id: Option<String>,
@@ -3433,17 +4691,17 @@ pub mod builds {
                 self
             }
             #[doc = "If specified, filters to builds that match this reason."]
-            pub fn reason_filter(mut self, reason_filter: impl Into<String>) -> Self {
+            pub fn reason_filter(mut self, reason_filter: impl Into<ReasonFilter>) -> Self {
                 self.reason_filter = Some(reason_filter.into());
                 self
             }
             #[doc = "If specified, filters to builds that match this status."]
-            pub fn status_filter(mut self, status_filter: impl Into<String>) -> Self {
+            pub fn status_filter(mut self, status_filter: impl Into<StatusFilter>) -> Self {
                 self.status_filter = Some(status_filter.into());
                 self
             }
             #[doc = "If specified, filters to builds that match this result."]
-            pub fn result_filter(mut self, result_filter: impl Into<String>) -> Self {
+            pub fn result_filter(mut self, result_filter: impl Into<ResultFilter>) -> Self {
                 self.result_filter = Some(result_filter.into());
                 self
             }
@@ -3473,12 +4731,12 @@ pub mod builds {
                 self
             }
             #[doc = "Indicates whether to exclude, include, or only return deleted builds."]
-            pub fn deleted_filter(mut self, deleted_filter: impl Into<String>) -> Self {
+            pub fn deleted_filter(mut self, deleted_filter: impl Into<DeletedFilter>) -> Self {
                 self.deleted_filter = Some(deleted_filter.into());
                 self
             }
             #[doc = "The order in which builds should be returned."]
-            pub fn query_order(mut self, query_order: impl Into<String>) -> Self {
+            pub fn query_order(mut self, query_order: impl Into<QueryOrder>) -> Self {
                 self.query_order = Some(query_order.into());
                 self
             }
@@ -3536,14 +4794,16 @@ pub mod builds {
                                 .append_pair("buildNumber", build_number);
                         }
                         if let Some(min_time) = &this.min_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minTime", &min_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minTime",
+                                &crate::date_time::format_date_time(min_time)?,
+                            );
                         }
                         if let Some(max_time) = &this.max_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("maxTime", &max_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "maxTime",
+                                &crate::date_time::format_date_time(max_time)?,
+                            );
                         }
                         if let Some(requested_for) = &this.requested_for {
                             req.url_mut()
@@ -3553,17 +4813,17 @@ pub mod builds {
                         if let Some(reason_filter) = &this.reason_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("reasonFilter", reason_filter);
+                                .append_pair("reasonFilter", reason_filter.as_str());
                         }
                         if let Some(status_filter) = &this.status_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("statusFilter", status_filter);
+                                .append_pair("statusFilter", status_filter.as_str());
                         }
                         if let Some(result_filter) = &this.result_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("resultFilter", result_filter);
+                                .append_pair("resultFilter", result_filter.as_str());
                         }
                         if let Some(tag_filters) = &this.tag_filters {
                             req.url_mut()
@@ -3594,12 +4854,12 @@ pub mod builds {
                         if let Some(deleted_filter) = &this.deleted_filter {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("deletedFilter", deleted_filter);
+                                .append_pair("deletedFilter", deleted_filter.as_str());
                         }
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("queryOrder", query_order);
+                                .append_pair("queryOrder", query_order.as_str());
                         }
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
@@ -3643,6 +4903,20 @@ pub mod builds {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::Build> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::BuildList>;
@@ -4435,6 +5709,20 @@ pub mod builds {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::Change> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ChangeList>;
@@ -4696,18 +5984,28 @@ pub mod builds {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4750,6 +6048,7 @@ pub mod builds {
             pub(crate) log_id: i32,
             pub(crate) start_line: Option<i64>,
             pub(crate) end_line: Option<i64>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The start line."]
@@ -4762,6 +6061,11 @@ pub mod builds {
                 self.end_line = Some(end_line);
                 self
             }
+            #[doc = "The content type of the response: one of `text/plain`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4780,6 +6084,7 @@ pub mod builds {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
                         if let Some(start_line) = &this.start_line {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4790,6 +6095,9 @@ pub mod builds {
                                 .query_pairs_mut()
                                 .append_pair("endLine", &end_line.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5415,6 +6723,7 @@ pub mod attachments {
                 record_id: record_id.into(),
                 type_: type_.into(),
                 name: name.into(),
+                accept: None,
             }
         }
         #[doc = "Gets the list of attachments of a specific type that are associated with a build."]
@@ -5440,6 +6749,98 @@ pub mod attachments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a specific attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        #[doc = "* `record_id`: The ID of the timeline record."]
+        #[doc = "* `type_`: The type of the attachment."]
+        #[doc = "* `name`: The name of the attachment."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "Gets the list of attachments of a specific type that are associated with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `type_`: The type of attachment."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                type_,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a specific attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        #[doc = "* `record_id`: The ID of the timeline record."]
+        #[doc = "* `type_`: The type of the attachment."]
+        #[doc = "* `name`: The name of the attachment."]
+        pub fn get(
+            &self,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "Gets the list of attachments of a specific type that are associated with a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `type_`: The type of attachment."]
+        pub fn list(&self, build_id: i32, type_: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                type_,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5449,19 +6850,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5505,8 +6916,14 @@ pub mod attachments {
             pub(crate) record_id: String,
             pub(crate) type_: String,
             pub(crate) name: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5525,6 +6942,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5554,8 +6975,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5780,6 +7201,141 @@ pub mod properties {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_properties(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_build_properties(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Updates properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build_properties::RequestBuilder {
+            Client(self.0.client.clone()).update_build_properties(
+                self.0.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+        #[doc = "Gets properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_properties(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_properties(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Updates properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update_definition_properties::RequestBuilder {
+            Client(self.0.client.clone()).update_definition_properties(
+                self.0.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_properties(&self, build_id: i32) -> get_build_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_build_properties(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Updates properties for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            build_id: i32,
+        ) -> update_build_properties::RequestBuilder {
+            Client(self.0.client.clone()).update_build_properties(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Gets properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_properties(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_properties(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Updates properties for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A json-patch document describing the properties to update."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_properties(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            definition_id: i32,
+        ) -> update_definition_properties::RequestBuilder {
+            Client(self.0.client.clone()).update_definition_properties(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod get_build_properties {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6312,6 +7868,31 @@ pub mod report {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a build report."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, build_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a build report."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6471,6 +8052,46 @@ pub mod stages {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Update a build stage"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateStageParameters>,
+            build_id: i32,
+            stage_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                build_id,
+                stage_ref_name,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Update a build stage"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateStageParameters>,
+            build_id: i32,
+            stage_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                build_id,
+                stage_ref_name,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod update {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6582,264 +8203,650 @@ pub mod stages {
             }
         }
     }
-}
-pub mod tags {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod tags {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Gets the tags for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_tags(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_build_tags::RequestBuilder {
+            get_build_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                build_id,
+            }
+        }
+        #[doc = "Adds tags to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The tags to add. Request body is composed directly from listed tags."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn add_build_tags(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<String>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> add_build_tags::RequestBuilder {
+            add_build_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                build_id,
+            }
+        }
+        #[doc = "Adds/Removes tags from a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_tags(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateTagParameters>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> update_build_tags::RequestBuilder {
+            update_build_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                build_id,
+            }
+        }
+        #[doc = "Adds a tag to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_build_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> add_build_tag::RequestBuilder {
+            add_build_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                build_id,
+                tag: tag.into(),
+            }
+        }
+        #[doc = "Removes a tag from a build. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_build_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_build_tag::RequestBuilder {
+            delete_build_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                build_id,
+                tag: tag.into(),
+            }
+        }
+        #[doc = "Gets the tags for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_tags(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_tags::RequestBuilder {
+            get_definition_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+                revision: None,
+            }
+        }
+        #[doc = "Adds multiple tags to a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn add_definition_tags(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> add_definition_tags::RequestBuilder {
+            add_definition_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                definition_id,
+            }
+        }
+        #[doc = "Adds/Removes tags from a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_tags(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateTagParameters>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update_definition_tags::RequestBuilder {
+            update_definition_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                definition_id,
+            }
+        }
+        #[doc = "Adds a tag to a definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_definition_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> add_definition_tag::RequestBuilder {
+            add_definition_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+                tag: tag.into(),
+            }
+        }
+        #[doc = "Removes a tag from a definition. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_definition_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_definition_tag::RequestBuilder {
+            delete_definition_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                definition_id,
+                tag: tag.into(),
+            }
+        }
+        #[doc = "Gets a list of all build tags in the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_tags(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_tags::RequestBuilder {
+            get_tags::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Removes a tag from builds, definitions, and from the tag store"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_tag(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            tag: impl Into<String>,
+        ) -> delete_tag::RequestBuilder {
+            delete_tag::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                tag: tag.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
         #[doc = "Gets the tags for a build."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: The ID of the build."]
         pub fn get_build_tags(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             build_id: i32,
         ) -> get_build_tags::RequestBuilder {
-            get_build_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_build_tags(
+                self.0.organization.clone(),
+                project,
                 build_id,
-            }
+            )
         }
         #[doc = "Adds tags to a build."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The tags to add. Request body is composed directly from listed tags."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: The ID of the build."]
         pub fn add_build_tags(
             &self,
-            organization: impl Into<String>,
             body: Vec<String>,
             project: impl Into<String>,
             build_id: i32,
         ) -> add_build_tags::RequestBuilder {
-            add_build_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            Client(self.0.client.clone()).add_build_tags(
+                self.0.organization.clone(),
                 body,
-                project: project.into(),
+                project,
                 build_id,
-            }
+            )
         }
         #[doc = "Adds/Removes tags from a build."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The tags to add/remove."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: The ID of the build."]
         pub fn update_build_tags(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateTagParameters>,
             project: impl Into<String>,
             build_id: i32,
         ) -> update_build_tags::RequestBuilder {
-            update_build_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).update_build_tags(
+                self.0.organization.clone(),
+                body,
+                project,
                 build_id,
-            }
+            )
         }
         #[doc = "Adds a tag to a build."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: The ID of the build."]
         #[doc = "* `tag`: The tag to add."]
         pub fn add_build_tag(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             build_id: i32,
             tag: impl Into<String>,
         ) -> add_build_tag::RequestBuilder {
-            add_build_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).add_build_tag(
+                self.0.organization.clone(),
+                project,
                 build_id,
-                tag: tag.into(),
-            }
+                tag,
+            )
         }
         #[doc = "Removes a tag from a build. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: The ID of the build."]
         #[doc = "* `tag`: The tag to remove."]
         pub fn delete_build_tag(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             build_id: i32,
             tag: impl Into<String>,
         ) -> delete_build_tag::RequestBuilder {
-            delete_build_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).delete_build_tag(
+                self.0.organization.clone(),
+                project,
                 build_id,
-                tag: tag.into(),
-            }
+                tag,
+            )
         }
         #[doc = "Gets the tags for a definition."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         pub fn get_definition_tags(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             definition_id: i32,
         ) -> get_definition_tags::RequestBuilder {
-            get_definition_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_definition_tags(
+                self.0.organization.clone(),
+                project,
                 definition_id,
-                revision: None,
-            }
+            )
         }
         #[doc = "Adds multiple tags to a definition."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The tags to add."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         pub fn add_definition_tags(
             &self,
-            organization: impl Into<String>,
             body: Vec<String>,
             project: impl Into<String>,
             definition_id: i32,
         ) -> add_definition_tags::RequestBuilder {
-            add_definition_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            Client(self.0.client.clone()).add_definition_tags(
+                self.0.organization.clone(),
                 body,
-                project: project.into(),
+                project,
                 definition_id,
-            }
+            )
         }
         #[doc = "Adds/Removes tags from a definition."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The tags to add/remove."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         pub fn update_definition_tags(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::UpdateTagParameters>,
             project: impl Into<String>,
             definition_id: i32,
         ) -> update_definition_tags::RequestBuilder {
-            update_definition_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).update_definition_tags(
+                self.0.organization.clone(),
+                body,
+                project,
                 definition_id,
-            }
+            )
         }
         #[doc = "Adds a tag to a definition"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         #[doc = "* `tag`: The tag to add."]
         pub fn add_definition_tag(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             definition_id: i32,
             tag: impl Into<String>,
         ) -> add_definition_tag::RequestBuilder {
-            add_definition_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).add_definition_tag(
+                self.0.organization.clone(),
+                project,
                 definition_id,
-                tag: tag.into(),
-            }
+                tag,
+            )
         }
         #[doc = "Removes a tag from a definition. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `definition_id`: The ID of the definition."]
         #[doc = "* `tag`: The tag to remove."]
         pub fn delete_definition_tag(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             definition_id: i32,
             tag: impl Into<String>,
         ) -> delete_definition_tag::RequestBuilder {
-            delete_definition_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).delete_definition_tag(
+                self.0.organization.clone(),
+                project,
                 definition_id,
-                tag: tag.into(),
-            }
+                tag,
+            )
         }
         #[doc = "Gets a list of all build tags in the project."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn get_tags(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-        ) -> get_tags::RequestBuilder {
-            get_tags::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-            }
+        pub fn get_tags(&self, project: impl Into<String>) -> get_tags::RequestBuilder {
+            Client(self.0.client.clone()).get_tags(self.0.organization.clone(), project)
         }
         #[doc = "Removes a tag from builds, definitions, and from the tag store"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `tag`: The tag to remove."]
         pub fn delete_tag(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             tag: impl Into<String>,
         ) -> delete_tag::RequestBuilder {
-            delete_tag::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                tag: tag.into(),
-            }
+            Client(self.0.client.clone()).delete_tag(self.0.organization.clone(), project, tag)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the tags for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn get_build_tags(&self, build_id: i32) -> get_build_tags::RequestBuilder {
+            Client(self.0.client.clone()).get_build_tags(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Adds tags to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add. Request body is composed directly from listed tags."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn add_build_tags(
+            &self,
+            body: Vec<String>,
+            build_id: i32,
+        ) -> add_build_tags::RequestBuilder {
+            Client(self.0.client.clone()).add_build_tags(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Adds/Removes tags from a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `build_id`: The ID of the build."]
+        pub fn update_build_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            build_id: i32,
+        ) -> update_build_tags::RequestBuilder {
+            Client(self.0.client.clone()).update_build_tags(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "Adds a tag to a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_build_tag(
+            &self,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> add_build_tag::RequestBuilder {
+            Client(self.0.client.clone()).add_build_tag(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                tag,
+            )
+        }
+        #[doc = "Removes a tag from a build. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: The ID of the build."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_build_tag(
+            &self,
+            build_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_build_tag::RequestBuilder {
+            Client(self.0.client.clone()).delete_build_tag(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                tag,
+            )
+        }
+        #[doc = "Gets the tags for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_tags(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_tags::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_tags(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds multiple tags to a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn add_definition_tags(
+            &self,
+            body: Vec<String>,
+            definition_id: i32,
+        ) -> add_definition_tags::RequestBuilder {
+            Client(self.0.client.clone()).add_definition_tags(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds/Removes tags from a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The tags to add/remove."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update_definition_tags(
+            &self,
+            body: impl Into<models::UpdateTagParameters>,
+            definition_id: i32,
+        ) -> update_definition_tags::RequestBuilder {
+            Client(self.0.client.clone()).update_definition_tags(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Adds a tag to a definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to add."]
+        pub fn add_definition_tag(
+            &self,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> add_definition_tag::RequestBuilder {
+            Client(self.0.client.clone()).add_definition_tag(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+                tag,
+            )
+        }
+        #[doc = "Removes a tag from a definition. NOTE: This API will not work for tags with special characters. To remove tags with special characters, use the PATCH method instead (in 6.0+)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_definition_tag(
+            &self,
+            definition_id: i32,
+            tag: impl Into<String>,
+        ) -> delete_definition_tag::RequestBuilder {
+            Client(self.0.client.clone()).delete_definition_tag(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+                tag,
+            )
+        }
+        #[doc = "Gets a list of all build tags in the project."]
+        pub fn get_tags(&self) -> get_tags::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_tags(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Removes a tag from builds, definitions, and from the tag store"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `tag`: The tag to remove."]
+        pub fn delete_tag(&self, tag: impl Into<String>) -> delete_tag::RequestBuilder {
+            Client(self.0.client.clone()).delete_tag(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                tag,
+            )
         }
     }
     pub mod get_build_tags {
@@ -8309,10 +10316,42 @@ pub mod timeline {
                 organization: organization.into(),
                 project: project.into(),
                 build_id,
-                timeline_id: timeline_id.into(),
-                change_id: None,
-                plan_id: None,
-            }
+                timeline_id: timeline_id.into(),
+                change_id: None,
+                plan_id: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets details for a build"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            timeline_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                timeline_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets details for a build"]
+        pub fn get(&self, build_id: i32, timeline_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                timeline_id,
+            )
         }
     }
     pub mod get {
@@ -8625,6 +10664,194 @@ pub mod definitions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a list of definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Creates a new definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The definition."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::BuildDefinition>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets a definition, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition_id)
+        }
+        #[doc = "Updates an existing build definition.  In order for this operation to succeed, the value of the \"Revision\" property of the request body must match the existing build definition's. It is recommended that you obtain the existing build definition by using GET, modify the build definition as necessary, and then submit the modified definition with PUT."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the definition. Its \"Revision\" property must match the existing definition for the update to be accepted."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update(
+            &self,
+            body: impl Into<models::BuildDefinition>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Restores a deleted definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The identifier of the definition to restore."]
+        #[doc = "* `deleted`: When false, restores a deleted definition."]
+        pub fn restore_definition(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            deleted: bool,
+        ) -> restore_definition::RequestBuilder {
+            Client(self.0.client.clone()).restore_definition(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+                deleted,
+            )
+        }
+        #[doc = "Deletes a definition and all associated builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Gets all revisions of a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_revisions(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_revisions::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_revisions(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a list of definitions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Creates a new definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The definition."]
+        pub fn create(&self, body: impl Into<models::BuildDefinition>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets a definition, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Updates an existing build definition.  In order for this operation to succeed, the value of the \"Revision\" property of the request body must match the existing build definition's. It is recommended that you obtain the existing build definition by using GET, modify the build definition as necessary, and then submit the modified definition with PUT."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the definition. Its \"Revision\" property must match the existing definition for the update to be accepted."]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn update(
+            &self,
+            body: impl Into<models::BuildDefinition>,
+            definition_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Restores a deleted definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The identifier of the definition to restore."]
+        #[doc = "* `deleted`: When false, restores a deleted definition."]
+        pub fn restore_definition(
+            &self,
+            definition_id: i32,
+            deleted: bool,
+        ) -> restore_definition::RequestBuilder {
+            Client(self.0.client.clone()).restore_definition(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+                deleted,
+            )
+        }
+        #[doc = "Deletes a definition and all associated builds."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn delete(&self, definition_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Gets all revisions of a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_revisions(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_revisions::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_revisions(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8668,6 +10895,35 @@ pub mod definitions {
                 self.as_raw_response()
             }
         }
+        #[doc = "Indicates the order in which definitions should be returned."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum QueryOrder {
+            None,
+            LastModifiedAscending,
+            LastModifiedDescending,
+            DefinitionNameAscending,
+            DefinitionNameDescending,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl QueryOrder {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::LastModifiedAscending => "lastModifiedAscending",
+                    Self::LastModifiedDescending => "lastModifiedDescending",
+                    Self::DefinitionNameAscending => "definitionNameAscending",
+                    Self::DefinitionNameDescending => "definitionNameDescending",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for QueryOrder {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -8691,7 +10947,7 @@ pub mod definitions {
             pub(crate) name: Option<String>,
             pub(crate) repository_id: Option<String>,
             pub(crate) repository_type: Option<String>,
-            pub(crate) query_order: Option<String>,
+            pub(crate) query_order: Option<QueryOrder>,
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<String>,
             pub(crate) min_metrics_time: Option<time::OffsetDateTime>,
@@ -8722,7 +10978,7 @@ pub mod definitions {
                 self
             }
             #[doc = "Indicates the order in which definitions should be returned."]
-            pub fn query_order(mut self, query_order: impl Into<String>) -> Self {
+            pub fn query_order(mut self, query_order: impl Into<QueryOrder>) -> Self {
                 self.query_order = Some(query_order.into());
                 self
             }
@@ -8826,7 +11082,7 @@ pub mod definitions {
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("queryOrder", query_order);
+                                .append_pair("queryOrder", query_order.as_str());
                         }
                         if let Some(top) = &this.top {
                             req.url_mut()
@@ -8839,9 +11095,10 @@ pub mod definitions {
                                 .append_pair("continuationToken", continuation_token);
                         }
                         if let Some(min_metrics_time) = &this.min_metrics_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minMetricsTime", &min_metrics_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minMetricsTime",
+                                &crate::date_time::format_date_time(min_metrics_time)?,
+                            );
                         }
                         if let Some(definition_ids) = &this.definition_ids {
                             req.url_mut()
@@ -8852,14 +11109,16 @@ pub mod definitions {
                             req.url_mut().query_pairs_mut().append_pair("path", path);
                         }
                         if let Some(built_after) = &this.built_after {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("builtAfter", &built_after.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "builtAfter",
+                                &crate::date_time::format_date_time(built_after)?,
+                            );
                         }
                         if let Some(not_built_after) = &this.not_built_after {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("notBuiltAfter", &not_built_after.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "notBuiltAfter",
+                                &crate::date_time::format_date_time(not_built_after)?,
+                            );
                         }
                         if let Some(include_all_properties) = &this.include_all_properties {
                             req.url_mut().query_pairs_mut().append_pair(
@@ -8910,6 +11169,20 @@ pub mod definitions {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::BuildDefinitionReference> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::BuildDefinitionReferenceList>;
@@ -9185,9 +11458,10 @@ pub mod definitions {
                                 .append_pair("revision", &revision.to_string());
                         }
                         if let Some(min_metrics_time) = &this.min_metrics_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minMetricsTime", &min_metrics_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minMetricsTime",
+                                &crate::date_time::format_date_time(min_metrics_time)?,
+                            );
                         }
                         if let Some(property_filters) = &this.property_filters {
                             req.url_mut()
@@ -9793,6 +12067,72 @@ pub mod metrics {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets build metrics for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_metrics(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_definition_metrics::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_metrics(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Gets build metrics for a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `metric_aggregation_type`: The aggregation type to use (hourly, daily)."]
+        pub fn get_project_metrics(
+            &self,
+            project: impl Into<String>,
+            metric_aggregation_type: impl Into<String>,
+        ) -> get_project_metrics::RequestBuilder {
+            Client(self.0.client.clone()).get_project_metrics(
+                self.0.organization.clone(),
+                project,
+                metric_aggregation_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets build metrics for a definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get_definition_metrics(
+            &self,
+            definition_id: i32,
+        ) -> get_definition_metrics::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_metrics(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Gets build metrics for a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `metric_aggregation_type`: The aggregation type to use (hourly, daily)."]
+        pub fn get_project_metrics(
+            &self,
+            metric_aggregation_type: impl Into<String>,
+        ) -> get_project_metrics::RequestBuilder {
+            Client(self.0.client.clone()).get_project_metrics(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                metric_aggregation_type,
+            )
+        }
+    }
     pub mod get_definition_metrics {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9885,9 +12225,10 @@ pub mod metrics {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
                         if let Some(min_metrics_time) = &this.min_metrics_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minMetricsTime", &min_metrics_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minMetricsTime",
+                                &crate::date_time::format_date_time(min_metrics_time)?,
+                            );
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -10018,9 +12359,10 @@ pub mod metrics {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
                         if let Some(min_metrics_time) = &this.min_metrics_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minMetricsTime", &min_metrics_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minMetricsTime",
+                                &crate::date_time::format_date_time(min_metrics_time)?,
+                            );
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -10103,6 +12445,51 @@ pub mod resources {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>, definition_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, definition_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn authorize_definition_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> authorize_definition_resources::RequestBuilder {
+            Client(self.0.client.clone()).authorize_definition_resources(
+                self.0.organization.clone(),
+                body,
+                project,
+                definition_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, definition_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        pub fn authorize_definition_resources(
+            &self,
+            body: Vec<models::DefinitionResourceReference>,
+            definition_id: i32,
+        ) -> authorize_definition_resources::RequestBuilder {
+            Client(self.0.client.clone()).authorize_definition_resources(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10375,11 +12762,36 @@ pub mod yaml {
                 organization: organization.into(),
                 project: project.into(),
                 definition_id,
-                revision: None,
-                min_metrics_time: None,
-                property_filters: None,
-                include_latest_builds: None,
-            }
+                revision: None,
+                min_metrics_time: None,
+                property_filters: None,
+                include_latest_builds: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Converts a definition to YAML, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Converts a definition to YAML, optionally at a specific revision."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: The ID of the definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
         }
     }
     pub mod get {
@@ -10495,9 +12907,10 @@ pub mod yaml {
                                 .append_pair("revision", &revision.to_string());
                         }
                         if let Some(min_metrics_time) = &this.min_metrics_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minMetricsTime", &min_metrics_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minMetricsTime",
+                                &crate::date_time::format_date_time(min_metrics_time)?,
+                            );
                         }
                         if let Some(property_filters) = &this.property_filters {
                             req.url_mut()
@@ -10633,6 +13046,105 @@ pub mod templates {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets all definition templates."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Gets a specific build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the requested template."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, template_id)
+        }
+        #[doc = "Updates an existing build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the template."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn save_template(
+            &self,
+            body: impl Into<models::BuildDefinitionTemplate>,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> save_template::RequestBuilder {
+            Client(self.0.client.clone()).save_template(
+                self.0.organization.clone(),
+                body,
+                project,
+                template_id,
+            )
+        }
+        #[doc = "Deletes a build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, template_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets all definition templates."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Gets a specific build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `template_id`: The ID of the requested template."]
+        pub fn get(&self, template_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                template_id,
+            )
+        }
+        #[doc = "Updates an existing build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the template."]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn save_template(
+            &self,
+            body: impl Into<models::BuildDefinitionTemplate>,
+            template_id: impl Into<String>,
+        ) -> save_template::RequestBuilder {
+            Client(self.0.client.clone()).save_template(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                template_id,
+            )
+        }
+        #[doc = "Deletes a build definition template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `template_id`: The ID of the template."]
+        pub fn delete(&self, template_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                template_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11195,6 +13707,120 @@ pub mod folders {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Updates an existing folder at given  existing path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, path)
+        }
+        #[doc = "Creates a new folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, path)
+        }
+        #[doc = "Deletes a definition folder. Definitions and their corresponding builds will also be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, path)
+        }
+        #[doc = "Gets a list of build definition folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: The path to start with."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, path)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Updates an existing folder at given  existing path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new version of the folder."]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "Creates a new folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The folder."]
+        #[doc = "* `path`: The full path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "Deletes a definition folder. Definitions and their corresponding builds will also be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: The full path to the folder."]
+        pub fn delete(&self, path: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "Gets a list of build definition folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: The path to start with."]
+        pub fn list(&self, path: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                path,
+            )
+        }
+    }
     pub mod update {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11585,6 +14211,31 @@ pub mod folders {
                 self.as_raw_response()
             }
         }
+        #[doc = "The order in which folders should be returned."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum QueryOrder {
+            None,
+            FolderAscending,
+            FolderDescending,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl QueryOrder {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::FolderAscending => "folderAscending",
+                    Self::FolderDescending => "folderDescending",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for QueryOrder {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -11606,11 +14257,11 @@ pub mod folders {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) path: String,
-            pub(crate) query_order: Option<String>,
+            pub(crate) query_order: Option<QueryOrder>,
         }
         impl RequestBuilder {
             #[doc = "The order in which folders should be returned."]
-            pub fn query_order(mut self, query_order: impl Into<String>) -> Self {
+            pub fn query_order(mut self, query_order: impl Into<QueryOrder>) -> Self {
                 self.query_order = Some(query_order.into());
                 self
             }
@@ -11635,7 +14286,7 @@ pub mod folders {
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("queryOrder", query_order);
+                                .append_pair("queryOrder", query_order.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -11718,6 +14369,45 @@ pub mod general_settings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets pipeline general settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+        #[doc = "Updates pipeline general settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PipelineGeneralSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets pipeline general settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Updates pipeline general settings."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PipelineGeneralSettings>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11986,6 +14676,35 @@ pub mod latest {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the latest build for a definition, optionally scoped to a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition`: definition name with optional leading folder path, or the definition id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            definition: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the latest build for a definition, optionally scoped to a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition`: definition name with optional leading folder path, or the definition id"]
+        pub fn get(&self, definition: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12141,6 +14860,23 @@ pub mod options {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets all build definition options supported by the system."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets all build definition options supported by the system."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12291,16 +15027,55 @@ pub mod retention {
         #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
+            organization: impl Into<String>,
+            body: impl Into<models::UpdateProjectRetentionSettingModel>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the project's retention settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+        #[doc = "Updates the project's retention settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProjectRetentionSettingModel>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the project's retention settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Updates the project's retention settings."]
+        pub fn update(
+            &self,
             body: impl Into<models::UpdateProjectRetentionSettingModel>,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-            }
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod get {
@@ -12586,6 +15361,46 @@ pub mod settings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+        #[doc = "Updates the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new settings."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::BuildSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the build settings."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Updates the build settings."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The new settings."]
+        pub fn update(&self, body: impl Into<models::BuildSettings>) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12851,9 +15666,39 @@ pub mod status {
                 job_name: None,
                 configuration: None,
                 label: None,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "<p>Gets the build status for a definition, optionally scoped to a specific branch, stage, job, and configuration.</p> <p>If there are more than one, then it is required to pass in a stageName value when specifying a jobName, and the same rule then applies for both if passing a configuration parameter.</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition`: Either the definition name with optional leading folder path, or the definition id."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            definition: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "<p>Gets the build status for a definition, optionally scoped to a specific branch, stage, job, and configuration.</p> <p>If there are more than one, then it is required to pass in a stageName value when specifying a jobName, and the same rule then applies for both if passing a configuration parameter.</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition`: Either the definition name with optional leading folder path, or the definition id."]
+        pub fn get(&self, definition: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12863,19 +15708,29 @@ pub mod status {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -12920,6 +15775,7 @@ pub mod status {
             pub(crate) job_name: Option<String>,
             pub(crate) configuration: Option<String>,
             pub(crate) label: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Only consider the most recent build for this branch. If not specified, the default branch is used."]
@@ -12947,6 +15803,11 @@ pub mod status {
                 self.label = Some(label.into());
                 self
             }
+            #[doc = "The content type of the response: one of `image/svg+xml`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -12965,6 +15826,7 @@ pub mod status {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "image/svg+xml");
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -12988,6 +15850,9 @@ pub mod status {
                         if let Some(label) = &this.label {
                             req.url_mut().query_pairs_mut().append_pair("label", label);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -13013,8 +15878,8 @@ pub mod status {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -13093,6 +15958,7 @@ pub mod source_providers {
                 repository: None,
                 commit_or_branch: None,
                 path: None,
+                accept: None,
             }
         }
         #[doc = "Gets the contents of a directory in the given source code repository."]
@@ -13212,6 +16078,245 @@ pub mod source_providers {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of source providers and their capabilities."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Gets a list of branches for the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_branches(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_branches::RequestBuilder {
+            Client(self.0.client.clone()).list_branches(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a file in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_file_contents(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> get_file_contents::RequestBuilder {
+            Client(self.0.client.clone()).get_file_contents(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a directory in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_path_contents(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> get_path_contents::RequestBuilder {
+            Client(self.0.client.clone()).get_path_contents(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+            )
+        }
+        #[doc = "Gets a pull request object from source provider."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        #[doc = "* `pull_request_id`: Vendor-specific id of the pull request."]
+        pub fn get_pull_request(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+            pull_request_id: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+                pull_request_id,
+            )
+        }
+        #[doc = "Gets a list of source code repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_repositories(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_repositories::RequestBuilder {
+            Client(self.0.client.clone()).list_repositories(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+            )
+        }
+        #[doc = "Gets a list of webhooks installed in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_webhooks(
+            &self,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> list_webhooks::RequestBuilder {
+            Client(self.0.client.clone()).list_webhooks(
+                self.0.organization.clone(),
+                project,
+                provider_name,
+            )
+        }
+        #[doc = "Recreates the webhooks for the specified triggers in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The types of triggers to restore webhooks for."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn restore_webhooks(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+            provider_name: impl Into<String>,
+        ) -> restore_webhooks::RequestBuilder {
+            Client(self.0.client.clone()).restore_webhooks(
+                self.0.organization.clone(),
+                body,
+                project,
+                provider_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of source providers and their capabilities."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Gets a list of branches for the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_branches(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_branches::RequestBuilder {
+            Client(self.0.client.clone()).list_branches(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a file in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_file_contents(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> get_file_contents::RequestBuilder {
+            Client(self.0.client.clone()).get_file_contents(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets the contents of a directory in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn get_path_contents(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> get_path_contents::RequestBuilder {
+            Client(self.0.client.clone()).get_path_contents(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets a pull request object from source provider."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        #[doc = "* `pull_request_id`: Vendor-specific id of the pull request."]
+        pub fn get_pull_request(
+            &self,
+            provider_name: impl Into<String>,
+            pull_request_id: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+                pull_request_id,
+            )
+        }
+        #[doc = "Gets a list of source code repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_repositories(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_repositories::RequestBuilder {
+            Client(self.0.client.clone()).list_repositories(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Gets a list of webhooks installed in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn list_webhooks(
+            &self,
+            provider_name: impl Into<String>,
+        ) -> list_webhooks::RequestBuilder {
+            Client(self.0.client.clone()).list_webhooks(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+        #[doc = "Recreates the webhooks for the specified triggers in the given source code repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The types of triggers to restore webhooks for."]
+        #[doc = "* `provider_name`: The name of the source provider."]
+        pub fn restore_webhooks(
+            &self,
+            body: Vec<String>,
+            provider_name: impl Into<String>,
+        ) -> restore_webhooks::RequestBuilder {
+            Client(self.0.client.clone()).restore_webhooks(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                provider_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13493,18 +16598,28 @@ pub mod source_providers {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -13548,6 +16663,7 @@ pub mod source_providers {
             pub(crate) repository: Option<String>,
             pub(crate) commit_or_branch: Option<String>,
             pub(crate) path: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "If specified, the ID of the service endpoint to query. Can only be omitted for providers that do not use service endpoints, e.g. TFVC or TFGit."]
@@ -13570,6 +16686,11 @@ pub mod source_providers {
                 self.path = Some(path.into());
                 self
             }
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -13588,6 +16709,7 @@ pub mod source_providers {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
                         if let Some(service_endpoint_id) = &this.service_endpoint_id {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -13606,6 +16728,9 @@ pub mod source_providers {
                         if let Some(path) = &this.path {
                             req.url_mut().query_pairs_mut().append_pair("path", path);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -0,0 +1,357 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Unified Azure DevOps client.
+use crate::server::{ApiVersion, CollectionUrl};
+use crate::Credential;
+use std::sync::Arc;
+
+/// The host of a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ServiceHost {
+    /// Hosted on the main Azure DevOps Services host, `https://dev.azure.com`.
+    Main,
+    /// Hosted on its own sub-host, e.g. `https://vssps.dev.azure.com`.
+    Own,
+}
+
+/// The endpoints of the services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Endpoint {
+    /// The default Azure DevOps Services endpoint of each service.
+    Services,
+    /// The endpoint of the services on the main host. Services on their own sub-host use their default endpoint.
+    MainHost(azure_core::Url),
+    /// An Azure DevOps Server, which hosts all of the services.
+    Server(azure_core::Url),
+}
+
+impl Endpoint {
+    /// The endpoint of a service, or `None` for its default endpoint.
+    fn for_service(&self, host: ServiceHost) -> Option<azure_core::Url> {
+        match (self, host) {
+            (Endpoint::MainHost(endpoint), ServiceHost::Main) | (Endpoint::Server(endpoint), _) => {
+                Some(endpoint.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Builder for an [`AdoClient`].
+#[derive(Clone)]
+pub struct AdoClientBuilder {
+    credential: Credential,
+    organization: String,
+    endpoint: Endpoint,
+    scopes: Option<Vec<String>>,
+    api_version: Option<ApiVersion>,
+    options: azure_core::ClientOptions,
+}
+
+impl AdoClientBuilder {
+    /// Create a new `AdoClientBuilder` for an Azure DevOps Services organization.
+    #[must_use]
+    pub fn new(credential: Credential, organization: impl Into<String>) -> Self {
+        Self {
+            credential,
+            organization: organization.into(),
+            endpoint: Endpoint::Services,
+            scopes: None,
+            api_version: None,
+            options: azure_core::ClientOptions::default(),
+        }
+    }
+
+    /// Create a new `AdoClientBuilder` for an Azure DevOps Server (on-premises) collection.
+    ///
+    /// All services use the server endpoint, and the collection takes the place of the organization.
+    #[must_use]
+    pub fn for_server(credential: Credential, collection: CollectionUrl) -> Self {
+        let mut builder = Self::new(credential, collection.collection());
+        builder.endpoint = Endpoint::Server(collection.endpoint().clone());
+        builder
+    }
+
+    /// Set the endpoint of the services hosted on `https://dev.azure.com`.
+    ///
+    /// Services hosted on their own sub-host (e.g. `https://vssps.dev.azure.com`,
+    /// `https://vsrm.dev.azure.com`) keep their default endpoint.
+    /// Use [`for_server()`](Self::for_server) for an Azure DevOps Server.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<azure_core::Url>) -> Self {
+        self.endpoint = Endpoint::MainHost(endpoint.into());
+        self
+    }
+
+    /// Set the API version, for servers that do not support the latest version.
+    #[must_use]
+    pub fn api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Set the scopes.
+    #[must_use]
+    pub fn scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = Some(scopes.iter().map(|scope| (*scope).to_owned()).collect());
+        self
+    }
+
+    /// Set the retry options.
+    #[must_use]
+    pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
+        self.options = self.options.retry(retry);
+        self
+    }
+
+    /// Set the transport options.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
+        self.options = self.options.transport(transport);
+        self
+    }
+
+    /// Set per-call policies.
+    #[must_use]
+    pub fn per_call_policies(
+        mut self,
+        policies: impl Into<Vec<Arc<dyn azure_core::Policy>>>,
+    ) -> Self {
+        self.options = self.options.per_call_policies(policies);
+        self
+    }
+
+    /// Set per-retry policies.
+    #[must_use]
+    pub fn per_retry_policies(
+        mut self,
+        policies: impl Into<Vec<Arc<dyn azure_core::Policy>>>,
+    ) -> Self {
+        self.options = self.options.per_retry_policies(policies);
+        self
+    }
+
+    /// Convert the builder into an `AdoClient` instance.
+    pub fn build(self) -> AdoClient {
+        let per_call_policies = std::iter::once(crate::error::retry_error_policy())
+            .chain(self.api_version.map(crate::server::api_version_policy))
+            .collect();
+        let pipeline = azure_core::Pipeline::new(
+            option_env!("CARGO_PKG_NAME"),
+            option_env!("CARGO_PKG_VERSION"),
+            self.options,
+            per_call_policies,
+            vec![crate::error::error_policy()],
+        );
+        AdoClient {
+            organization: self.organization,
+            endpoint: self.endpoint,
+            credential: self.credential,
+            scopes: self
+                .scopes
+                .unwrap_or_else(|| vec![crate::ADO_SCOPE.to_string()]),
+            pipeline,
+        }
+    }
+}
+
+/// A single entry point to all of the Azure DevOps service clients.
+///
+/// The credential, endpoint and policies are configured once, and all of the
+/// service clients share the same pipeline (and so the same retry, transport and
+/// policy configuration, and any state held by the policies).
+///
+/// A client scoped to the organization is available for each enabled cargo feature:
+/// ```rust
+///     let client = AdoClient::builder(credential, organization).build();
+///     let repos = client
+///         .git()
+///         .repositories_client()
+///         .list(project)
+///         .await?;
+/// ```
+#[derive(Clone)]
+pub struct AdoClient {
+    organization: String,
+    endpoint: Endpoint,
+    credential: Credential,
+    scopes: Vec<String>,
+    pipeline: azure_core::Pipeline,
+}
+
+impl AdoClient {
+    /// Create a new `AdoClientBuilder` for an Azure DevOps Services organization.
+    #[must_use]
+    pub fn builder(credential: Credential, organization: impl Into<String>) -> AdoClientBuilder {
+        AdoClientBuilder::new(credential, organization)
+    }
+
+    /// Create a new `AdoClientBuilder` for an Azure DevOps Server (on-premises) collection.
+    #[must_use]
+    pub fn server_builder(credential: Credential, collection: CollectionUrl) -> AdoClientBuilder {
+        AdoClientBuilder::for_server(credential, collection)
+    }
+
+    /// The organization (or collection) that the service clients are scoped to.
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    /// The credential used by all service clients.
+    pub fn credential(&self) -> &Credential {
+        &self.credential
+    }
+}
+
+/// Defines an `AdoClient` function for each service, returning a client scoped to the organization.
+macro_rules! service_clients {
+    (@host main_host) => {
+        ServiceHost::Main
+    };
+    (@host own_host) => {
+        ServiceHost::Own
+    };
+    ($($(#[doc = $doc:literal])* $module:ident: $feature:literal, $host:ident;)*) => {
+        impl AdoClient {
+            $(
+                $(#[doc = $doc])*
+                #[cfg(feature = $feature)]
+                pub fn $module(&self) -> crate::$module::OrganizationClient {
+                    crate::$module::Client::from_pipeline(
+                        self.endpoint.for_service(service_clients!(@host $host)),
+                        self.credential.clone(),
+                        self.scopes.clone(),
+                        self.pipeline.clone(),
+                    )
+                    .for_organization(self.organization.clone())
+                }
+            )*
+        }
+    };
+}
+
+service_clients! {
+    /// Returns a client for the Accounts API.
+    accounts: "accounts", own_host;
+    /// Returns a client for the Approvals and checks API.
+    approvals_and_checks: "approvals_and_checks", main_host;
+    /// Returns a client for the Artifacts API.
+    artifacts: "artifacts", own_host;
+    /// Returns a client for the Artifacts Package Types API.
+    artifacts_package_types: "artifacts_package_types", own_host;
+    /// Returns a client for the Audit API.
+    audit: "audit", own_host;
+    /// Returns a client for the Build API.
+    build: "build", main_host;
+    /// Returns a client for the Core API.
+    core: "core", main_host;
+    /// Returns a client for the Dashboard API.
+    dashboard: "dashboard", main_host;
+    /// Returns a client for the Distributed tasks API.
+    distributed_task: "distributed_task", main_host;
+    /// Returns a client for the Extension Management API.
+    extension_management: "extension_management", own_host;
+    /// Returns a client for the Favorite API.
+    favorite: "favorite", main_host;
+    /// Returns a client for the Git API.
+    git: "git", main_host;
+    /// Returns a client for the Graph API.
+    graph: "graph", own_host;
+    /// Returns a client for the Service Hooks API.
+    hooks: "hooks", main_host;
+    /// Returns a client for the Identities API.
+    ims: "ims", own_host;
+    /// Returns a client for the Member Entitlement Management API.
+    member_entitlement_management: "member_entitlement_management", own_host;
+    /// Returns a client for the Operations API.
+    operations: "operations", main_host;
+    /// Returns a client for the Permissions Report API.
+    permissions_report: "permissions_report", main_host;
+    /// Returns a client for the Pipelines API.
+    pipelines: "pipelines", main_host;
+    /// Returns a client for the Policy API.
+    policy: "policy", main_host;
+    /// Returns a client for the Process admin API.
+    processadmin: "processadmin", main_host;
+    /// Returns a client for the Processes API.
+    processes: "processes", main_host;
+    /// Returns a client for the Profile API.
+    profile: "profile", own_host;
+    /// Returns a client for the Release API.
+    release: "release", own_host;
+    /// Returns a client for the Search API.
+    search: "search", own_host;
+    /// Returns a client for the Security API.
+    security: "security", main_host;
+    /// Returns a client for the Security roles API.
+    security_roles: "security_roles", main_host;
+    /// Returns a client for the Service Endpoint (aka Service Connection) API.
+    service_endpoint: "service_endpoint", main_host;
+    /// Returns a client for the Status API.
+    status: "status", own_host;
+    /// Returns a client for the Symbol API.
+    symbol: "symbol", own_host;
+    /// Returns a client for the Tests API.
+    test: "test", main_host;
+    /// Returns a client for the Test plans API.
+    test_plan: "test_plan", main_host;
+    /// Returns a client for the Test results API.
+    test_results: "test_results", own_host;
+    /// Returns a client for the Team Foundation Version Control (TFVC) API.
+    tfvc: "tfvc", main_host;
+    /// Returns a client for the Token Admin API.
+    token_admin: "token_admin", own_host;
+    /// Returns a client for the Tokens API.
+    tokens: "tokens", own_host;
+    /// Returns a client for the Wiki API.
+    wiki: "wiki", main_host;
+    /// Returns a client for the Work Item Tracking API.
+    wit: "wit", main_host;
+    /// Returns a client for the Work (boards, backlogs, iterations, plans) API.
+    work: "work", main_host;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_endpoints() {
+        let credential = Credential::unauthenticated();
+        let client = AdoClient::builder(credential.clone(), "myorg").build();
+        assert_eq!(client.organization(), "myorg");
+        assert_eq!(client.endpoint.for_service(ServiceHost::Main), None);
+        assert_eq!(client.endpoint.for_service(ServiceHost::Own), None);
+
+        // Services on their own sub-host keep their default endpoint
+        let endpoint = azure_core::Url::parse("https://ado.example.com").unwrap();
+        let client = AdoClient::builder(credential.clone(), "myorg")
+            .endpoint(endpoint.clone())
+            .build();
+        assert_eq!(
+            client.endpoint.for_service(ServiceHost::Main),
+            Some(endpoint)
+        );
+        assert_eq!(client.endpoint.for_service(ServiceHost::Own), None);
+
+        // A server hosts all of the services, and the collection replaces the organization
+        let collection = CollectionUrl::parse("https://tfs.corp/tfs/DefaultCollection").unwrap();
+        let client = AdoClient::server_builder(credential, collection.clone()).build();
+        assert_eq!(client.organization(), "DefaultCollection");
+        for host in [ServiceHost::Main, ServiceHost::Own] {
+            assert_eq!(
+                client.endpoint.for_service(host).as_ref(),
+                Some(collection.endpoint())
+            );
+        }
+    }
+
+    #[cfg(all(feature = "git", feature = "graph"))]
+    #[test]
+    fn test_service_clients() {
+        let client = AdoClient::builder(Credential::unauthenticated(), "myorg").build();
+        assert_eq!(client.git().organization(), "myorg");
+        assert_eq!(client.graph().organization(), "myorg");
+    }
+}
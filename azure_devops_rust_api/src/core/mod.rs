@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn avatar_client(&self) -> avatar::Client {
         avatar::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         teams::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn avatar_client(&self) -> avatar::OrganizationClient {
+        avatar::OrganizationClient(self.clone())
+    }
+    pub fn categorized_teams_client(&self) -> categorized_teams::OrganizationClient {
+        categorized_teams::OrganizationClient(self.clone())
+    }
+    pub fn processes_client(&self) -> processes::OrganizationClient {
+        processes::OrganizationClient(self.clone())
+    }
+    pub fn projects_client(&self) -> projects::OrganizationClient {
+        projects::OrganizationClient(self.clone())
+    }
+    pub fn teams_client(&self) -> teams::OrganizationClient {
+        teams::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn avatar_client(&self) -> avatar::ProjectClient {
+        avatar::ProjectClient(self.clone())
+    }
+    pub fn categorized_teams_client(&self) -> categorized_teams::ProjectClient {
+        categorized_teams::ProjectClient(self.clone())
+    }
+    pub fn processes_client(&self) -> processes::ProjectClient {
+        processes::ProjectClient(self.clone())
+    }
+    pub fn projects_client(&self) -> projects::ProjectClient {
+        projects::ProjectClient(self.clone())
+    }
+    pub fn teams_client(&self) -> teams::ProjectClient {
+        teams::ProjectClient(self.clone())
+    }
+}
 pub mod processes {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -180,6 +277,34 @@ pub mod processes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of processes."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a process by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID for a process."]
+        pub fn get(&self, process_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of processes."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a process by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID for a process."]
+        pub fn get(&self, process_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -539,6 +664,134 @@ pub mod projects {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all projects in the organization that the authenticated user has access to."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Queues a project to be created. Use the [GetOperation](../../operations/operations/get) to periodically check for create project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The project to create."]
+        pub fn create(&self, body: impl Into<models::TeamProject>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get project with the specified id or name, optionally including capabilities."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Update an existing project's name, abbreviation, description, or restore a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updates for the project. The state must be set to wellFormed to restore the project."]
+        #[doc = "* `project_id`: The project id of the project to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamProject>,
+            project_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project_id)
+        }
+        #[doc = "Queues a project to be deleted. Use the [GetOperation](../../operations/operations/get) to periodically check for delete project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The project id of the project to delete."]
+        pub fn delete(&self, project_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Get a collection of team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        pub fn get_project_properties(
+            &self,
+            project_id: impl Into<String>,
+        ) -> get_project_properties::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_project_properties(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Create, update, and delete team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        #[doc = "* `body`: A JSON Patch document that represents an array of property operations. See RFC 6902 for more details on JSON Patch. The accepted operation verbs are Add and Remove, where Add is used for both creating and updating properties. The path consists of a forward slash and a property name."]
+        pub fn set_project_properties(
+            &self,
+            project_id: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> set_project_properties::RequestBuilder {
+            Client(self.0.client.clone()).set_project_properties(
+                self.0.organization.clone(),
+                project_id,
+                body,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all projects in the organization that the authenticated user has access to."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Queues a project to be created. Use the [GetOperation](../../operations/operations/get) to periodically check for create project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The project to create."]
+        pub fn create(&self, body: impl Into<models::TeamProject>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get project with the specified id or name, optionally including capabilities."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Update an existing project's name, abbreviation, description, or restore a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updates for the project. The state must be set to wellFormed to restore the project."]
+        #[doc = "* `project_id`: The project id of the project to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamProject>,
+            project_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project_id)
+        }
+        #[doc = "Queues a project to be deleted. Use the [GetOperation](../../operations/operations/get) to periodically check for delete project status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The project id of the project to delete."]
+        pub fn delete(&self, project_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Get a collection of team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        pub fn get_project_properties(
+            &self,
+            project_id: impl Into<String>,
+        ) -> get_project_properties::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_project_properties(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Create, update, and delete team project properties."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The team project ID."]
+        #[doc = "* `body`: A JSON Patch document that represents an array of property operations. See RFC 6902 for more details on JSON Patch. The accepted operation verbs are Add and Remove, where Add is used for both creating and updating properties. The path consists of a forward slash and a property name."]
+        pub fn set_project_properties(
+            &self,
+            project_id: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> set_project_properties::RequestBuilder {
+            Client(self.0.client.clone()).set_project_properties(
+                self.0.organization.clone(),
+                project_id,
+                body,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -693,6 +946,18 @@ pub mod projects {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TeamProjectReference> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TeamProjectReferenceList>;
@@ -1478,6 +1743,66 @@ pub mod avatar {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Sets the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The avatar blob data object to upload."]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn set_project_avatar(
+            &self,
+            body: impl Into<models::ProjectAvatar>,
+            project_id: impl Into<String>,
+        ) -> set_project_avatar::RequestBuilder {
+            Client(self.0.client.clone()).set_project_avatar(
+                self.0.organization.clone(),
+                body,
+                project_id,
+            )
+        }
+        #[doc = "Removes the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn remove_project_avatar(
+            &self,
+            project_id: impl Into<String>,
+        ) -> remove_project_avatar::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_project_avatar(self.0.organization.clone(), project_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Sets the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The avatar blob data object to upload."]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn set_project_avatar(
+            &self,
+            body: impl Into<models::ProjectAvatar>,
+            project_id: impl Into<String>,
+        ) -> set_project_avatar::RequestBuilder {
+            Client(self.0.client.clone()).set_project_avatar(
+                self.0.organization.clone(),
+                body,
+                project_id,
+            )
+        }
+        #[doc = "Removes the avatar for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The ID or name of the project."]
+        pub fn remove_project_avatar(
+            &self,
+            project_id: impl Into<String>,
+        ) -> remove_project_avatar::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_project_avatar(self.0.organization.clone(), project_id)
+        }
+    }
     pub mod set_project_avatar {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1719,6 +2044,26 @@ pub mod categorized_teams {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets list of user readable teams in a project and teams user is member of (excluded from readable list)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the teams to retrieve."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets list of user readable teams in a project and teams user is member of (excluded from readable list)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the teams to retrieve."]
+        pub fn get(&self, project_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id)
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2016,6 +2361,168 @@ pub mod teams {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of teams."]
+        pub fn get_teams(&self, project_id: impl Into<String>) -> get_teams::RequestBuilder {
+            Client(self.0.client.clone()).get_teams(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Create a team in a team project.\n\nPossible failure scenarios\nInvalid project name/ID (project doesn't exist) 404\nInvalid team name or description 400\nTeam already exists 400\nInsufficient privileges 400"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The team data used to create the team."]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project in which to create the team."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project_id)
+        }
+        #[doc = "Get a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team."]
+        pub fn get(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Update a team's name and/or description."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to update."]
+        #[doc = "* `team_id`: The name of ID of the team to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Delete a team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to delete."]
+        #[doc = "* `team_id`: The name or ID of the team to delete."]
+        pub fn delete(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Get a list of members for a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project the team belongs to."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team ."]
+        pub fn get_team_members_with_extended_properties(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get_team_members_with_extended_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_team_members_with_extended_properties(
+                self.0.organization.clone(),
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Get a list of all teams."]
+        pub fn get_all_teams(&self) -> get_all_teams::RequestBuilder {
+            Client(self.0.client.clone()).get_all_teams(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of teams."]
+        pub fn get_teams(&self, project_id: impl Into<String>) -> get_teams::RequestBuilder {
+            Client(self.0.client.clone()).get_teams(self.0.organization.clone(), project_id)
+        }
+        #[doc = "Create a team in a team project.\n\nPossible failure scenarios\nInvalid project name/ID (project doesn't exist) 404\nInvalid team name or description 400\nTeam already exists 400\nInsufficient privileges 400"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The team data used to create the team."]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project in which to create the team."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project_id)
+        }
+        #[doc = "Get a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team."]
+        pub fn get(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Update a team's name and/or description."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to update."]
+        #[doc = "* `team_id`: The name of ID of the team to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WebApiTeam>,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Delete a team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project containing the team to delete."]
+        #[doc = "* `team_id`: The name or ID of the team to delete."]
+        pub fn delete(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project_id, team_id)
+        }
+        #[doc = "Get a list of members for a specific team."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project_id`: The name or ID (GUID) of the team project the team belongs to."]
+        #[doc = "* `team_id`: The name or ID (GUID) of the team ."]
+        pub fn get_team_members_with_extended_properties(
+            &self,
+            project_id: impl Into<String>,
+            team_id: impl Into<String>,
+        ) -> get_team_members_with_extended_properties::RequestBuilder {
+            Client(self.0.client.clone()).get_team_members_with_extended_properties(
+                self.0.organization.clone(),
+                project_id,
+                team_id,
+            )
+        }
+        #[doc = "Get a list of all teams."]
+        pub fn get_all_teams(&self) -> get_all_teams::RequestBuilder {
+            Client(self.0.client.clone()).get_all_teams(self.0.organization.clone())
+        }
+    }
     pub mod get_teams {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2162,6 +2669,18 @@ pub mod teams {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::WebApiTeam> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WebApiTeamList>;
@@ -2774,6 +3293,18 @@ pub mod teams {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TeamMember> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TeamMemberList>;
@@ -2932,6 +3463,18 @@ pub mod teams {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::WebApiTeam> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WebApiTeamList>;
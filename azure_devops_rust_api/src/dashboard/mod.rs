@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn dashboards_client(&self) -> dashboards::Client {
         dashboards::Client(self.clone())
     }
@@ -139,6 +166,64 @@ impl Client {
         widgets::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn dashboards_client(&self) -> dashboards::OrganizationClient {
+        dashboards::OrganizationClient(self.clone())
+    }
+    pub fn widget_types_client(&self) -> widget_types::OrganizationClient {
+        widget_types::OrganizationClient(self.clone())
+    }
+    pub fn widgets_client(&self) -> widgets::OrganizationClient {
+        widgets::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn dashboards_client(&self) -> dashboards::ProjectClient {
+        dashboards::ProjectClient(self.clone())
+    }
+    pub fn widget_types_client(&self) -> widget_types::ProjectClient {
+        widget_types::ProjectClient(self.clone())
+    }
+    pub fn widgets_client(&self) -> widgets::ProjectClient {
+        widgets::ProjectClient(self.clone())
+    }
+}
 pub mod widget_types {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -185,6 +270,68 @@ pub mod widget_types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all available widget metadata in alphabetical order, including widgets marked with isVisibleFromCatalog == false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_widget_types(
+            &self,
+            scope: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_widget_types::RequestBuilder {
+            Client(self.0.client.clone()).get_widget_types(
+                self.0.organization.clone(),
+                scope,
+                project,
+            )
+        }
+        #[doc = "Get the widget metadata satisfying the specified contribution ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `contribution_id`: The ID of Contribution for the Widget"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_widget_metadata(
+            &self,
+            contribution_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_widget_metadata::RequestBuilder {
+            Client(self.0.client.clone()).get_widget_metadata(
+                self.0.organization.clone(),
+                contribution_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all available widget metadata in alphabetical order, including widgets marked with isVisibleFromCatalog == false."]
+        pub fn get_widget_types(
+            &self,
+            scope: impl Into<String>,
+        ) -> get_widget_types::RequestBuilder {
+            Client(self.0.client.clone()).get_widget_types(
+                self.0.organization.clone(),
+                scope,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the widget metadata satisfying the specified contribution ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `contribution_id`: The ID of Contribution for the Widget"]
+        pub fn get_widget_metadata(
+            &self,
+            contribution_id: impl Into<String>,
+        ) -> get_widget_metadata::RequestBuilder {
+            Client(self.0.client.clone()).get_widget_metadata(
+                self.0.organization.clone(),
+                contribution_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_widget_types {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -565,6 +712,212 @@ pub mod dashboards {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of dashboards under a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Create the supplied dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The initial state of the dashboard"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Dashboard>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, team)
+        }
+        #[doc = "Update the name and position of dashboards in the supplied group, and remove omitted dashboards. Does not modify dashboard content."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboards(
+            &self,
+            body: impl Into<models::DashboardGroup>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboards::RequestBuilder {
+            Client(self.0.client.clone()).replace_dashboards(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+        #[doc = "Get a dashboard by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace configuration for the specified dashboard. Replaces Widget list on Dashboard, only if property is supplied."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Configuration of the dashboard to replace."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to replace."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboard(
+            &self,
+            body: impl Into<models::Dashboard>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboard::RequestBuilder {
+            Client(self.0.client.clone()).replace_dashboard(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Delete a dashboard given its ID. This also deletes the widgets associated with this dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to delete."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of dashboards under a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Create the supplied dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The initial state of the dashboard"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Dashboard>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Update the name and position of dashboards in the supplied group, and remove omitted dashboards. Does not modify dashboard content."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboards(
+            &self,
+            body: impl Into<models::DashboardGroup>,
+            team: impl Into<String>,
+        ) -> replace_dashboards::RequestBuilder {
+            Client(self.0.client.clone()).replace_dashboards(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get a dashboard by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace configuration for the specified dashboard. Replaces Widget list on Dashboard, only if property is supplied."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Configuration of the dashboard to replace."]
+        #[doc = "* `dashboard_id`: ID of the dashboard to replace."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_dashboard(
+            &self,
+            body: impl Into<models::Dashboard>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_dashboard::RequestBuilder {
+            Client(self.0.client.clone()).replace_dashboard(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Delete a dashboard given its ID. This also deletes the widgets associated with this dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to delete."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1490,6 +1843,354 @@ pub mod widgets {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get widgets contained on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widgets(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widgets::RequestBuilder {
+            Client(self.0.client.clone()).get_widgets(
+                self.0.organization.clone(),
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Create a widget on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State of the widget to add"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of dashboard the widget will be added to."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace the widgets on specified dashboard with the supplied widgets."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Revised state of widgets to store for the dashboard."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widgets::RequestBuilder {
+            Client(self.0.client.clone()).replace_widgets(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Update the supplied widgets on the dashboard using supplied state. State of existing Widgets not passed in the widget list is preserved."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of widget states to update on the dashboard."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widgets::RequestBuilder {
+            Client(self.0.client.clone()).update_widgets(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Get the current state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widget(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widget::RequestBuilder {
+            Client(self.0.client.clone()).get_widget(
+                self.0.organization.clone(),
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Override the  state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State to be written for the widget."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widget::RequestBuilder {
+            Client(self.0.client.clone()).replace_widget(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Perform a partial update of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Description of the widget changes to apply. All non-null fields will be replaced."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widget::RequestBuilder {
+            Client(self.0.client.clone()).update_widget(
+                self.0.organization.clone(),
+                body,
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Delete the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get widgets contained on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widgets(
+            &self,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widgets::RequestBuilder {
+            Client(self.0.client.clone()).get_widgets(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Create a widget on the specified dashboard."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State of the widget to add"]
+        #[doc = "* `dashboard_id`: ID of dashboard the widget will be added to."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Replace the widgets on specified dashboard with the supplied widgets."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Revised state of widgets to store for the dashboard."]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widgets::RequestBuilder {
+            Client(self.0.client.clone()).replace_widgets(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Update the supplied widgets on the dashboard using supplied state. State of existing Widgets not passed in the widget list is preserved."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of widget states to update on the dashboard."]
+        #[doc = "* `dashboard_id`: ID of the Dashboard to modify."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widgets(
+            &self,
+            body: Vec<models::Widget>,
+            dashboard_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widgets::RequestBuilder {
+            Client(self.0.client.clone()).update_widgets(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                team,
+            )
+        }
+        #[doc = "Get the current state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to read."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_widget(
+            &self,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_widget::RequestBuilder {
+            Client(self.0.client.clone()).get_widget(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Override the  state of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: State to be written for the widget."]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_widget::RequestBuilder {
+            Client(self.0.client.clone()).replace_widget(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Perform a partial update of the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Description of the widget changes to apply. All non-null fields will be replaced."]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_widget(
+            &self,
+            body: impl Into<models::Widget>,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_widget::RequestBuilder {
+            Client(self.0.client.clone()).update_widget(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+        #[doc = "Delete the specified widget."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `dashboard_id`: ID of the dashboard containing the widget."]
+        #[doc = "* `widget_id`: ID of the widget to update."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            dashboard_id: impl Into<String>,
+            widget_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                dashboard_id,
+                widget_id,
+                team,
+            )
+        }
+    }
     pub mod get_widgets {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1519,7 +2220,7 @@ pub mod widgets {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1780,7 +2481,7 @@ pub mod widgets {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1921,7 +2622,7 @@ pub mod widgets {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn agentclouds_client(&self) -> agentclouds::Client {
         agentclouds::Client(self.clone())
     }
@@ -193,6 +220,176 @@ impl Client {
         yamlschema::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn agentclouds_client(&self) -> agentclouds::OrganizationClient {
+        agentclouds::OrganizationClient(self.clone())
+    }
+    pub fn agentcloudtypes_client(&self) -> agentcloudtypes::OrganizationClient {
+        agentcloudtypes::OrganizationClient(self.clone())
+    }
+    pub fn agents_client(&self) -> agents::OrganizationClient {
+        agents::OrganizationClient(self.clone())
+    }
+    pub fn deploymentgroups_client(&self) -> deploymentgroups::OrganizationClient {
+        deploymentgroups::OrganizationClient(self.clone())
+    }
+    pub fn elasticpoollogs_client(&self) -> elasticpoollogs::OrganizationClient {
+        elasticpoollogs::OrganizationClient(self.clone())
+    }
+    pub fn elasticpools_client(&self) -> elasticpools::OrganizationClient {
+        elasticpools::OrganizationClient(self.clone())
+    }
+    pub fn environmentdeployment_records_client(
+        &self,
+    ) -> environmentdeployment_records::OrganizationClient {
+        environmentdeployment_records::OrganizationClient(self.clone())
+    }
+    pub fn environments_client(&self) -> environments::OrganizationClient {
+        environments::OrganizationClient(self.clone())
+    }
+    pub fn events_client(&self) -> events::OrganizationClient {
+        events::OrganizationClient(self.clone())
+    }
+    pub fn kubernetes_client(&self) -> kubernetes::OrganizationClient {
+        kubernetes::OrganizationClient(self.clone())
+    }
+    pub fn logs_client(&self) -> logs::OrganizationClient {
+        logs::OrganizationClient(self.clone())
+    }
+    pub fn nodes_client(&self) -> nodes::OrganizationClient {
+        nodes::OrganizationClient(self.clone())
+    }
+    pub fn oidctoken_client(&self) -> oidctoken::OrganizationClient {
+        oidctoken::OrganizationClient(self.clone())
+    }
+    pub fn pools_client(&self) -> pools::OrganizationClient {
+        pools::OrganizationClient(self.clone())
+    }
+    pub fn queues_client(&self) -> queues::OrganizationClient {
+        queues::OrganizationClient(self.clone())
+    }
+    pub fn records_client(&self) -> records::OrganizationClient {
+        records::OrganizationClient(self.clone())
+    }
+    pub fn requests_client(&self) -> requests::OrganizationClient {
+        requests::OrganizationClient(self.clone())
+    }
+    pub fn targets_client(&self) -> targets::OrganizationClient {
+        targets::OrganizationClient(self.clone())
+    }
+    pub fn taskgroups_client(&self) -> taskgroups::OrganizationClient {
+        taskgroups::OrganizationClient(self.clone())
+    }
+    pub fn variablegroups_client(&self) -> variablegroups::OrganizationClient {
+        variablegroups::OrganizationClient(self.clone())
+    }
+    pub fn yamlschema_client(&self) -> yamlschema::OrganizationClient {
+        yamlschema::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn agentclouds_client(&self) -> agentclouds::ProjectClient {
+        agentclouds::ProjectClient(self.clone())
+    }
+    pub fn agentcloudtypes_client(&self) -> agentcloudtypes::ProjectClient {
+        agentcloudtypes::ProjectClient(self.clone())
+    }
+    pub fn agents_client(&self) -> agents::ProjectClient {
+        agents::ProjectClient(self.clone())
+    }
+    pub fn deploymentgroups_client(&self) -> deploymentgroups::ProjectClient {
+        deploymentgroups::ProjectClient(self.clone())
+    }
+    pub fn elasticpoollogs_client(&self) -> elasticpoollogs::ProjectClient {
+        elasticpoollogs::ProjectClient(self.clone())
+    }
+    pub fn elasticpools_client(&self) -> elasticpools::ProjectClient {
+        elasticpools::ProjectClient(self.clone())
+    }
+    pub fn environmentdeployment_records_client(
+        &self,
+    ) -> environmentdeployment_records::ProjectClient {
+        environmentdeployment_records::ProjectClient(self.clone())
+    }
+    pub fn environments_client(&self) -> environments::ProjectClient {
+        environments::ProjectClient(self.clone())
+    }
+    pub fn events_client(&self) -> events::ProjectClient {
+        events::ProjectClient(self.clone())
+    }
+    pub fn kubernetes_client(&self) -> kubernetes::ProjectClient {
+        kubernetes::ProjectClient(self.clone())
+    }
+    pub fn logs_client(&self) -> logs::ProjectClient {
+        logs::ProjectClient(self.clone())
+    }
+    pub fn nodes_client(&self) -> nodes::ProjectClient {
+        nodes::ProjectClient(self.clone())
+    }
+    pub fn oidctoken_client(&self) -> oidctoken::ProjectClient {
+        oidctoken::ProjectClient(self.clone())
+    }
+    pub fn pools_client(&self) -> pools::ProjectClient {
+        pools::ProjectClient(self.clone())
+    }
+    pub fn queues_client(&self) -> queues::ProjectClient {
+        queues::ProjectClient(self.clone())
+    }
+    pub fn records_client(&self) -> records::ProjectClient {
+        records::ProjectClient(self.clone())
+    }
+    pub fn requests_client(&self) -> requests::ProjectClient {
+        requests::ProjectClient(self.clone())
+    }
+    pub fn targets_client(&self) -> targets::ProjectClient {
+        targets::ProjectClient(self.clone())
+    }
+    pub fn taskgroups_client(&self) -> taskgroups::ProjectClient {
+        taskgroups::ProjectClient(self.clone())
+    }
+    pub fn variablegroups_client(&self) -> variablegroups::ProjectClient {
+        variablegroups::ProjectClient(self.clone())
+    }
+    pub fn yamlschema_client(&self) -> yamlschema::ProjectClient {
+        yamlschema::ProjectClient(self.clone())
+    }
+}
 pub mod events {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -227,6 +424,56 @@ pub mod events {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Send a pipeline job event to be processed by the execution plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The event data to be processed by the plan."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        pub fn post_event(
+            &self,
+            body: impl Into<models::JobEvent>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+        ) -> post_event::RequestBuilder {
+            Client(self.0.client.clone()).post_event(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Send a pipeline job event to be processed by the execution plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The event data to be processed by the plan."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        pub fn post_event(
+            &self,
+            body: impl Into<models::JobEvent>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+        ) -> post_event::RequestBuilder {
+            Client(self.0.client.clone()).post_event(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+            )
+        }
+    }
     pub mod post_event {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -372,6 +619,52 @@ pub mod oidctoken {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        pub fn create(
+            &self,
+            body: impl Into<serde_json::Value>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            job_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                job_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        pub fn create(
+            &self,
+            body: impl Into<serde_json::Value>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            job_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                job_id,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -573,6 +866,106 @@ pub mod logs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Create a log and connect it to a pipeline run's execution plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains information about log's path."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TaskLog>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+            )
+        }
+        #[doc = "Append a log to a task's log. The log should be sent in the body of the request as a TaskLog object stream."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        #[doc = "* `log_id`: The ID of the log."]
+        pub fn append_log_content(
+            &self,
+            body: impl Into<String>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            log_id: i32,
+        ) -> append_log_content::RequestBuilder {
+            Client(self.0.client.clone()).append_log_content(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                log_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Create a log and connect it to a pipeline run's execution plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains information about log's path."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TaskLog>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+            )
+        }
+        #[doc = "Append a log to a task's log. The log should be sent in the body of the request as a TaskLog object stream."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        #[doc = "* `log_id`: The ID of the log."]
+        pub fn append_log_content(
+            &self,
+            body: impl Into<String>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            log_id: i32,
+        ) -> append_log_content::RequestBuilder {
+            Client(self.0.client.clone()).append_log_content(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                log_id,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -857,6 +1250,62 @@ pub mod records {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Update timeline records if they already exist, otherwise create new ones for the same timeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The array of timeline records to be updated."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        pub fn update(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                timeline_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Update timeline records if they already exist, otherwise create new ones for the same timeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The array of timeline records to be updated."]
+        #[doc = "* `scope_identifier`: The project GUID to scope the request"]
+        #[doc = "* `hub_name`: The name of the server hub. Common examples: \"build\", \"rm\", \"checks\""]
+        #[doc = "* `plan_id`: The ID of the plan."]
+        #[doc = "* `timeline_id`: The ID of the timeline."]
+        pub fn update(
+            &self,
+            body: impl Into<models::VssJsonCollectionWrapper>,
+            scope_identifier: impl Into<String>,
+            hub_name: impl Into<String>,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                scope_identifier,
+                hub_name,
+                plan_id,
+                timeline_id,
+            )
+        }
+    }
     pub mod update {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1077,18 +1526,120 @@ pub mod pools {
         #[doc = "Delete an agent pool."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `pool_id`: ID of the agent pool to delete"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            pool_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                pool_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of agent pools."]
+        pub fn get_agent_pools(&self) -> get_agent_pools::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_pools(self.0.organization.clone())
+        }
+        #[doc = "Get a list of agent pools."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: pool Ids to fetch"]
+        pub fn get_agent_pools_by_ids(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_pools_by_ids::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_agent_pools_by_ids(self.0.organization.clone(), pool_ids)
+        }
+        #[doc = "Create an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the new agent pool"]
+        pub fn add(&self, body: impl Into<models::TaskAgentPool>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Get information about an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: An agent pool ID"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on an agent pool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated agent pool details"]
+        #[doc = "* `pool_id`: The agent pool to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentPool>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, pool_id)
+        }
+        #[doc = "Delete an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: ID of the agent pool to delete"]
+        pub fn delete(&self, pool_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), pool_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of agent pools."]
+        pub fn get_agent_pools(&self) -> get_agent_pools::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_pools(self.0.organization.clone())
+        }
+        #[doc = "Get a list of agent pools."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: pool Ids to fetch"]
+        pub fn get_agent_pools_by_ids(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_pools_by_ids::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_agent_pools_by_ids(self.0.organization.clone(), pool_ids)
+        }
+        #[doc = "Create an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the new agent pool"]
+        pub fn add(&self, body: impl Into<models::TaskAgentPool>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Get information about an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: An agent pool ID"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on an agent pool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated agent pool details"]
+        #[doc = "* `pool_id`: The agent pool to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentPool>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, pool_id)
+        }
+        #[doc = "Delete an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `pool_id`: ID of the agent pool to delete"]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            pool_id: i32,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                pool_id,
-            }
+        pub fn delete(&self, pool_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), pool_id)
         }
     }
     pub mod get_agent_pools {
@@ -2005,6 +2556,178 @@ pub mod queues {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of agent queues by their names"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_names`: A comma-separated list of agent names to retrieve"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_by_names(
+            &self,
+            queue_names: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_by_names::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_by_names(
+                self.0.organization.clone(),
+                queue_names,
+                project,
+            )
+        }
+        #[doc = "Get a list of agent queues by their IDs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_ids`: A comma-separated list of agent queue IDs to retrieve"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_by_ids(
+            &self,
+            queue_ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_by_ids::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_by_ids(
+                self.0.organization.clone(),
+                queue_ids,
+                project,
+            )
+        }
+        #[doc = "Get a list of agent queues."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues(
+            &self,
+            project: impl Into<String>,
+        ) -> get_agent_queues::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues(self.0.organization.clone(), project)
+        }
+        #[doc = "Get a list of agent queues by pool ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: A comma-separated list of pool ids to get the corresponding queues for"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_agent_queues_for_pools(
+            &self,
+            pool_ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_agent_queues_for_pools::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_for_pools(
+                self.0.organization.clone(),
+                pool_ids,
+                project,
+            )
+        }
+        #[doc = "Create a new agent queue to connect a project to an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the queue to create"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskAgentQueue>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get information about an agent queue."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to get information about"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, queue_id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), queue_id, project)
+        }
+        #[doc = "Removes an agent queue from a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to remove"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(&self, queue_id: i32, project: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), queue_id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of agent queues by their names"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_names`: A comma-separated list of agent names to retrieve"]
+        pub fn get_agent_queues_by_names(
+            &self,
+            queue_names: impl Into<String>,
+        ) -> get_agent_queues_by_names::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_by_names(
+                self.0.organization.clone(),
+                queue_names,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a list of agent queues by their IDs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_ids`: A comma-separated list of agent queue IDs to retrieve"]
+        pub fn get_agent_queues_by_ids(
+            &self,
+            queue_ids: impl Into<String>,
+        ) -> get_agent_queues_by_ids::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_by_ids(
+                self.0.organization.clone(),
+                queue_ids,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a list of agent queues."]
+        pub fn get_agent_queues(&self) -> get_agent_queues::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_agent_queues(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get a list of agent queues by pool ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_ids`: A comma-separated list of pool ids to get the corresponding queues for"]
+        pub fn get_agent_queues_for_pools(
+            &self,
+            pool_ids: impl Into<String>,
+        ) -> get_agent_queues_for_pools::RequestBuilder {
+            Client(self.0.client.clone()).get_agent_queues_for_pools(
+                self.0.organization.clone(),
+                pool_ids,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create a new agent queue to connect a project to an agent pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the queue to create"]
+        pub fn add(&self, body: impl Into<models::TaskAgentQueue>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get information about an agent queue."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to get information about"]
+        pub fn get(&self, queue_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                queue_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Removes an agent queue from a project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `queue_id`: The agent queue to remove"]
+        pub fn delete(&self, queue_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                queue_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_agent_queues_by_names {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3048,6 +3771,152 @@ pub mod variablegroups {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get variable groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_variable_groups(
+            &self,
+            project: impl Into<String>,
+        ) -> get_variable_groups::RequestBuilder {
+            Client(self.0.client.clone()).get_variable_groups(self.0.organization.clone(), project)
+        }
+        #[doc = "Add a variable group."]
+        pub fn add(&self, body: impl Into<models::VariableGroupParameters>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Add a variable group."]
+        pub fn share_variable_group(
+            &self,
+            body: Vec<models::VariableGroupProjectReference>,
+            variable_group_id: i32,
+        ) -> share_variable_group::RequestBuilder {
+            Client(self.0.client.clone()).share_variable_group(
+                self.0.organization.clone(),
+                body,
+                variable_group_id,
+            )
+        }
+        #[doc = "Update a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::VariableGroupParameters>,
+            group_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a variable group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn delete(
+            &self,
+            group_id: i32,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_id, project_ids)
+        }
+        #[doc = "Get variable groups by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_ids`: Comma separated list of Ids of variable groups."]
+        pub fn get_variable_groups_by_id(
+            &self,
+            project: impl Into<String>,
+            group_ids: impl Into<String>,
+        ) -> get_variable_groups_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_variable_groups_by_id(
+                self.0.organization.clone(),
+                project,
+                group_ids,
+            )
+        }
+        #[doc = "Get a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn get(&self, project: impl Into<String>, group_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, group_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get variable groups."]
+        pub fn get_variable_groups(&self) -> get_variable_groups::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_variable_groups(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Add a variable group."]
+        pub fn add(&self, body: impl Into<models::VariableGroupParameters>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Add a variable group."]
+        pub fn share_variable_group(
+            &self,
+            body: Vec<models::VariableGroupProjectReference>,
+            variable_group_id: i32,
+        ) -> share_variable_group::RequestBuilder {
+            Client(self.0.client.clone()).share_variable_group(
+                self.0.organization.clone(),
+                body,
+                variable_group_id,
+            )
+        }
+        #[doc = "Update a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::VariableGroupParameters>,
+            group_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a variable group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn delete(
+            &self,
+            group_id: i32,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_id, project_ids)
+        }
+        #[doc = "Get variable groups by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_ids`: Comma separated list of Ids of variable groups."]
+        pub fn get_variable_groups_by_id(
+            &self,
+            group_ids: impl Into<String>,
+        ) -> get_variable_groups_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_variable_groups_by_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                group_ids,
+            )
+        }
+        #[doc = "Get a variable group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the variable group."]
+        pub fn get(&self, group_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                group_id,
+            )
+        }
+    }
     pub mod get_variable_groups {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3986,6 +4855,50 @@ pub mod agentclouds {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        pub fn add(&self, body: impl Into<models::TaskAgentCloud>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        pub fn get(&self, agent_cloud_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), agent_cloud_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentCloud>,
+            agent_cloud_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, agent_cloud_id)
+        }
+        pub fn delete(&self, agent_cloud_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), agent_cloud_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        pub fn add(&self, body: impl Into<models::TaskAgentCloud>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        pub fn get(&self, agent_cloud_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), agent_cloud_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgentCloud>,
+            agent_cloud_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, agent_cloud_id)
+        }
+        pub fn delete(&self, agent_cloud_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), agent_cloud_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4590,6 +5503,18 @@ pub mod requests {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn list(&self, agent_cloud_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), agent_cloud_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, agent_cloud_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), agent_cloud_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4728,6 +5653,20 @@ pub mod agentcloudtypes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get agent cloud types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get agent cloud types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4977,6 +5916,150 @@ pub mod agents {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of agents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agents"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Adds an agent to a pool.  You probably don't want to call this endpoint directly. Instead, [configure an agent](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) using the agent download package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the agent being added"]
+        #[doc = "* `pool_id`: The agent pool in which to add the agent"]
+        pub fn add(&self, body: impl Into<models::TaskAgent>, pool_id: i32) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, pool_id)
+        }
+        #[doc = "Get information about an agent."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agent"]
+        #[doc = "* `agent_id`: The agent ID to get information about"]
+        pub fn get(&self, pool_id: i32, agent_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id, agent_id)
+        }
+        #[doc = "Replace an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove and reconfigure an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the replacing agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to replace"]
+        pub fn replace_agent(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> replace_agent::RequestBuilder {
+            Client(self.0.client.clone()).replace_agent(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                agent_id,
+            )
+        }
+        #[doc = "Update agent details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                agent_id,
+            )
+        }
+        #[doc = "Delete an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The pool ID to remove the agent from"]
+        #[doc = "* `agent_id`: The agent ID to remove"]
+        pub fn delete(&self, pool_id: i32, agent_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), pool_id, agent_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of agents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agents"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Adds an agent to a pool.  You probably don't want to call this endpoint directly. Instead, [configure an agent](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) using the agent download package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Details about the agent being added"]
+        #[doc = "* `pool_id`: The agent pool in which to add the agent"]
+        pub fn add(&self, body: impl Into<models::TaskAgent>, pool_id: i32) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, pool_id)
+        }
+        #[doc = "Get information about an agent."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The agent pool containing the agent"]
+        #[doc = "* `agent_id`: The agent ID to get information about"]
+        pub fn get(&self, pool_id: i32, agent_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id, agent_id)
+        }
+        #[doc = "Replace an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove and reconfigure an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the replacing agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to replace"]
+        pub fn replace_agent(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> replace_agent::RequestBuilder {
+            Client(self.0.client.clone()).replace_agent(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                agent_id,
+            )
+        }
+        #[doc = "Update agent details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated details about the agent"]
+        #[doc = "* `pool_id`: The agent pool to use"]
+        #[doc = "* `agent_id`: The agent to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskAgent>,
+            pool_id: i32,
+            agent_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                agent_id,
+            )
+        }
+        #[doc = "Delete an agent.  You probably don't want to call this endpoint directly. Instead, [use the agent configuration script](https://docs.microsoft.com/azure/devops/pipelines/agents/agents) to remove an agent from your organization."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: The pool ID to remove the agent from"]
+        #[doc = "* `agent_id`: The agent ID to remove"]
+        pub fn delete(&self, pool_id: i32, agent_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), pool_id, agent_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5823,6 +6906,20 @@ pub mod yamlschema {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "GET the Yaml schema used for Yaml file validation."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "GET the Yaml schema used for Yaml file validation."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6001,64 +7098,197 @@ pub mod deploymentgroups {
         #[doc = "Get a deployment group by its ID."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                deployment_group_id,
+                action_filter: None,
+                expand: None,
+            }
+        }
+        #[doc = "Update a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Deployment group to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::DeploymentGroupUpdateParameter>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                deployment_group_id,
+            }
+        }
+        #[doc = "Delete a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to be deleted."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                deployment_group_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of deployment groups by name or IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::DeploymentGroupCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a deployment group by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                deployment_group_id,
+            )
+        }
+        #[doc = "Update a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::DeploymentGroupUpdateParameter>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                deployment_group_id,
+            )
+        }
+        #[doc = "Delete a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                deployment_group_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of deployment groups by name or IDs."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment group to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::DeploymentGroupCreateParameter>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a deployment group by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `deployment_group_id`: ID of the deployment group."]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            deployment_group_id: i32,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn get(&self, deployment_group_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 deployment_group_id,
-                action_filter: None,
-                expand: None,
-            }
+            )
         }
         #[doc = "Update a deployment group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Deployment group to update."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `deployment_group_id`: ID of the deployment group."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::DeploymentGroupUpdateParameter>,
-            project: impl Into<String>,
             deployment_group_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
                 deployment_group_id,
-            }
+            )
         }
         #[doc = "Delete a deployment group."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `deployment_group_id`: ID of the deployment group to be deleted."]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            deployment_group_id: i32,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn delete(&self, deployment_group_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 deployment_group_id,
-            }
+            )
         }
     }
     pub mod list {
@@ -6226,6 +7456,20 @@ pub mod deploymentgroups {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::DeploymentGroup> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::DeploymentGroupList>;
@@ -6834,6 +8078,139 @@ pub mod targets {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                deployment_group_id,
+            )
+        }
+        #[doc = "Update tags of a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment targets with tags to udpdate."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment targets are updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::DeploymentTargetUpdateParameter>,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                deployment_group_id,
+            )
+        }
+        #[doc = "Get a deployment target by its ID in a deployment group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to which deployment target belongs."]
+        #[doc = "* `target_id`: ID of the deployment target to return."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+            target_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                deployment_group_id,
+                target_id,
+            )
+        }
+        #[doc = "Delete a deployment target in a deployment group. This deletes the agent from associated deployment pool too."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment target is deleted."]
+        #[doc = "* `target_id`: ID of the deployment target to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            deployment_group_id: i32,
+            target_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                deployment_group_id,
+                target_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group."]
+        pub fn list(&self, deployment_group_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Update tags of a list of deployment targets in a deployment group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Deployment targets with tags to udpdate."]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment targets are updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::DeploymentTargetUpdateParameter>,
+            deployment_group_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                deployment_group_id,
+            )
+        }
+        #[doc = "Get a deployment target by its ID in a deployment group"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group to which deployment target belongs."]
+        #[doc = "* `target_id`: ID of the deployment target to return."]
+        pub fn get(&self, deployment_group_id: i32, target_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                deployment_group_id,
+                target_id,
+            )
+        }
+        #[doc = "Delete a deployment target in a deployment group. This deletes the agent from associated deployment pool too."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `deployment_group_id`: ID of the deployment group in which deployment target is deleted."]
+        #[doc = "* `target_id`: ID of the deployment target to delete."]
+        pub fn delete(&self, deployment_group_id: i32, target_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                deployment_group_id,
+                target_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7044,6 +8421,20 @@ pub mod targets {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::DeploymentMachine> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::DeploymentMachineList>;
@@ -7529,6 +8920,131 @@ pub mod environments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all environments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create an environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::EnvironmentCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get an environment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn get(&self, project: impl Into<String>, environment_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, environment_id)
+        }
+        #[doc = "Update the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment data to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn update(
+            &self,
+            body: impl Into<models::EnvironmentUpdateParameter>,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                environment_id,
+            )
+        }
+        #[doc = "Delete the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                environment_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all environments."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create an environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::EnvironmentCreateParameter>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get an environment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn get(&self, environment_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                environment_id,
+            )
+        }
+        #[doc = "Update the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment data to update."]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn update(
+            &self,
+            body: impl Into<models::EnvironmentUpdateParameter>,
+            environment_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                environment_id,
+            )
+        }
+        #[doc = "Delete the specified environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `environment_id`: ID of the environment."]
+        pub fn delete(&self, environment_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                environment_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7660,6 +9176,20 @@ pub mod environments {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::EnvironmentInstance> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::EnvironmentInstanceList>;
@@ -8164,22 +9694,47 @@ pub mod environmentdeployment_records {
         #[doc = "Get environment deployment execution history"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                environment_id,
+                continuation_token: None,
+                top: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get environment deployment execution history"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             environment_id: i32,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, environment_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get environment deployment execution history"]
+        pub fn list(&self, environment_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 environment_id,
-                continuation_token: None,
-                top: None,
-            }
+            )
         }
     }
     pub mod list {
@@ -8309,6 +9864,22 @@ pub mod environmentdeployment_records {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(
+                self,
+            ) -> crate::paging::Pageable<models::EnvironmentDeploymentExecutionRecord> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::EnvironmentDeploymentExecutionRecordList>;
@@ -8390,6 +9961,85 @@ pub mod kubernetes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::KubernetesResourceCreateParameters>,
+            project: impl Into<String>,
+            environment_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                project,
+                environment_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+            resource_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                environment_id,
+                resource_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            environment_id: i32,
+            resource_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                environment_id,
+                resource_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn add(
+            &self,
+            body: impl Into<models::KubernetesResourceCreateParameters>,
+            environment_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                environment_id,
+            )
+        }
+        pub fn get(&self, environment_id: i32, resource_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                environment_id,
+                resource_id,
+            )
+        }
+        pub fn delete(&self, environment_id: i32, resource_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                environment_id,
+                resource_id,
+            )
+        }
+    }
     pub mod add {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8837,6 +10487,124 @@ pub mod taskgroups {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Create a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group object to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskGroupCreateParameter>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "List task groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, task_group_id)
+        }
+        #[doc = "Update a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskGroupUpdateParameter>,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                task_group_id,
+            )
+        }
+        #[doc = "Delete a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `task_group_id`: Id of the task group to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            task_group_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                task_group_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Create a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group object to create."]
+        pub fn add(
+            &self,
+            body: impl Into<models::TaskGroupCreateParameter>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "List task groups."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `task_group_id`: Id of the task group."]
+        pub fn list(&self, task_group_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                task_group_id,
+            )
+        }
+        #[doc = "Update a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Task group to update."]
+        #[doc = "* `task_group_id`: Id of the task group to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TaskGroupUpdateParameter>,
+            task_group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                task_group_id,
+            )
+        }
+        #[doc = "Delete a task group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `task_group_id`: Id of the task group to be deleted."]
+        pub fn delete(&self, task_group_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                task_group_id,
+            )
+        }
+    }
     pub mod add {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9096,9 +10864,10 @@ pub mod taskgroups {
                                 .append_pair("$top", &top.to_string());
                         }
                         if let Some(continuation_token) = &this.continuation_token {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("continuationToken", &continuation_token.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "continuationToken",
+                                &crate::date_time::format_date_time(continuation_token)?,
+                            );
                         }
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
@@ -9452,6 +11221,80 @@ pub mod elasticpools {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of all Elastic Pools."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a new elastic pool. This will create a new TaskAgentPool at the organization level. If a project id is provided, this will create a new TaskAgentQueue in the specified project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Elastic pool to create. Contains the properties necessary for configuring a new ElasticPool."]
+        #[doc = "* `pool_name`: Name to use for the new TaskAgentPool"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ElasticPool>,
+            pool_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, pool_name)
+        }
+        #[doc = "Returns the Elastic Pool with the specified Pool Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the associated TaskAgentPool"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update settings on a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New Elastic Pool settings data"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticPoolSettings>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, pool_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of all Elastic Pools."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a new elastic pool. This will create a new TaskAgentPool at the organization level. If a project id is provided, this will create a new TaskAgentQueue in the specified project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Elastic pool to create. Contains the properties necessary for configuring a new ElasticPool."]
+        #[doc = "* `pool_name`: Name to use for the new TaskAgentPool"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ElasticPool>,
+            pool_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, pool_name)
+        }
+        #[doc = "Returns the Elastic Pool with the specified Pool Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the associated TaskAgentPool"]
+        pub fn get(&self, pool_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update settings on a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New Elastic Pool settings data"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticPoolSettings>,
+            pool_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, pool_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9987,6 +11830,26 @@ pub mod elasticpoollogs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get elastic pool diagnostics logs for a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the Elastic Pool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get elastic pool diagnostics logs for a specified Elastic Pool."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool Id of the Elastic Pool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10157,6 +12020,54 @@ pub mod nodes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of ElasticNodes currently in the ElasticPool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool id of the ElasticPool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on a specified ElasticNode"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticNodeSettings>,
+            pool_id: i32,
+            elastic_node_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                elastic_node_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of ElasticNodes currently in the ElasticPool"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pool_id`: Pool id of the ElasticPool"]
+        pub fn list(&self, pool_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), pool_id)
+        }
+        #[doc = "Update properties on a specified ElasticNode"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ElasticNodeSettings>,
+            pool_id: i32,
+            elastic_node_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                pool_id,
+                elastic_node_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
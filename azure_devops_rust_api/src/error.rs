@@ -0,0 +1,458 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps error responses.
+//!
+//! When a request fails, Azure DevOps returns a JSON error payload such as:
+//!
+//! ```json
+//! {
+//!   "$id": "1",
+//!   "innerException": null,
+//!   "message": "TF401019: The Git repository with name or identifier foo does not exist or you do not have permissions for the operation you are attempting.",
+//!   "typeName": "Microsoft.TeamFoundation.Git.Server.GitRepositoryNotFoundException, Microsoft.TeamFoundation.Git.Server",
+//!   "typeKey": "GitRepositoryNotFoundException",
+//!   "errorCode": 0,
+//!   "eventId": 3000
+//! }
+//! ```
+//!
+//! The clients convert unsuccessful responses into an `azure_core::Error` of kind
+//! `ErrorKind::HttpResponse`, wrapping an [`AdoError`] that holds the parsed payload.
+//! Use [`ErrorExt::ado_error()`] to get the [`AdoError`] from a returned error:
+//!
+//! ```rust
+//!     use azure_devops_rust_api::error::ErrorExt;
+//!
+//!     match git_client.repositories_client().get(organization, "foo", project).await {
+//!         Ok(repo) => println!("{}", repo.name),
+//!         Err(err) if err.ado_error().and_then(|e| e.type_key()) == Some("GitRepositoryNotFoundException") => {
+//!             println!("Repository not found")
+//!         }
+//!         Err(err) => return Err(err.into()),
+//!     }
+//! ```
+use async_trait::async_trait;
+use azure_core::error::{Error, ErrorKind};
+use azure_core::headers::{HeaderName, Headers};
+use azure_core::{Context, Policy, PolicyResult, Request, Response, StatusCode};
+use bytes::Bytes;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+/// Response header containing the activity id of the request, used by Azure DevOps support to trace failures.
+pub const ACTIVITY_ID: HeaderName = HeaderName::from_static("activityid");
+
+/// Status codes that the `azure_core` retry policy retries.
+///
+/// Responses with these status codes are passed through so that they can be retried.
+/// If the retries are exhausted, [`RetryErrorPolicy`] returns the [`AdoError`] of the last response.
+const RETRY_STATUSES: &[StatusCode] = &[
+    StatusCode::RequestTimeout,
+    StatusCode::TooManyRequests,
+    StatusCode::InternalServerError,
+    StatusCode::BadGateway,
+    StatusCode::ServiceUnavailable,
+    StatusCode::GatewayTimeout,
+];
+
+/// The standard Azure DevOps error payload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorPayload {
+    #[serde(rename = "$id", default)]
+    pub id: Option<String>,
+    /// The error that caused this error, if any.
+    #[serde(default)]
+    pub inner_exception: Option<Box<ErrorPayload>>,
+    #[serde(default)]
+    pub message: String,
+    /// The fully qualified .NET type name of the server exception.
+    #[serde(default)]
+    pub type_name: Option<String>,
+    /// The short name of the server exception, e.g. `GitRepositoryNotFoundException`.
+    #[serde(default)]
+    pub type_key: Option<String>,
+    #[serde(default)]
+    pub error_code: i32,
+    #[serde(default)]
+    pub event_id: i32,
+}
+
+/// An unsuccessful response from Azure DevOps.
+#[derive(Debug, Clone)]
+pub struct AdoError {
+    status: StatusCode,
+    headers: Headers,
+    activity_id: Option<String>,
+    payload: Option<ErrorPayload>,
+    body: Bytes,
+}
+
+impl AdoError {
+    /// Creates an `AdoError` from the status, headers and body of an unsuccessful response.
+    pub fn new(status: StatusCode, headers: &Headers, body: Bytes) -> Self {
+        let payload = serde_json::from_slice::<ErrorPayload>(&body).ok();
+        Self {
+            status,
+            headers: headers.clone(),
+            activity_id: headers.get_optional_string(&ACTIVITY_ID),
+            payload,
+            body,
+        }
+    }
+
+    /// Reads the body of an unsuccessful response and creates an `AdoError` from it.
+    pub async fn from_response(rsp: Response) -> azure_core::Result<Self> {
+        let (status, headers, body) = rsp.deconstruct();
+        let body = body.collect().await?;
+        Ok(Self::new(status, &headers, body))
+    }
+
+    /// The HTTP status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// The activity id of the request, if returned.
+    pub fn activity_id(&self) -> Option<&str> {
+        self.activity_id.as_deref()
+    }
+
+    /// The parsed error payload, if the response body contained one.
+    pub fn payload(&self) -> Option<&ErrorPayload> {
+        self.payload.as_ref()
+    }
+
+    /// The error message, if the response body contained an error payload.
+    pub fn message(&self) -> Option<&str> {
+        self.payload
+            .as_ref()
+            .map(|payload| payload.message.as_str())
+    }
+
+    /// The short name of the server exception, e.g. `GitRepositoryNotFoundException`.
+    pub fn type_key(&self) -> Option<&str> {
+        self.payload.as_ref()?.type_key.as_deref()
+    }
+
+    /// The fully qualified name of the server exception.
+    pub fn type_name(&self) -> Option<&str> {
+        self.payload.as_ref()?.type_name.as_deref()
+    }
+
+    /// The error code from the error payload.
+    pub fn error_code(&self) -> Option<i32> {
+        self.payload.as_ref().map(|payload| payload.error_code)
+    }
+
+    /// The event id from the error payload.
+    pub fn event_id(&self) -> Option<i32> {
+        self.payload.as_ref().map(|payload| payload.event_id)
+    }
+
+    /// The raw response body.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn into_error(self) -> Error {
+        let kind = ErrorKind::HttpResponse {
+            status: self.status,
+            error_code: self.type_key().map(ToOwned::to_owned),
+        };
+        let message = self.to_string();
+        Error::full(kind, self, message)
+    }
+}
+
+impl std::fmt::Display for AdoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u16::from(self.status))?;
+        match &self.payload {
+            Some(payload) => {
+                write!(f, ": {}", payload.message)?;
+                if let Some(type_key) = &payload.type_key {
+                    write!(f, " ({type_key})")?;
+                }
+            }
+            // Azure DevOps returns a 203 with a sign-in page rather than a 401
+            // when the credential is not accepted.
+            None if self.status == StatusCode::NonAuthoritativeInformation => {
+                write!(
+                    f,
+                    ": non-authoritative response, check the credential is valid"
+                )?;
+            }
+            None if !self.body.is_empty() => {
+                write!(f, ": {}", String::from_utf8_lossy(&self.body))?;
+            }
+            None => {}
+        }
+        if let Some(activity_id) = &self.activity_id {
+            write!(f, " [activity id: {activity_id}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AdoError {}
+
+/// Extension trait to get the [`AdoError`] from an `azure_core::Error`.
+pub trait ErrorExt {
+    /// Returns the [`AdoError`] that caused this error, if any.
+    fn ado_error(&self) -> Option<&AdoError>;
+}
+
+impl ErrorExt for Error {
+    fn ado_error(&self) -> Option<&AdoError> {
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            if let Some(ado_error) = err.downcast_ref::<AdoError>() {
+                return Some(ado_error);
+            }
+            source = err.source();
+        }
+        None
+    }
+}
+
+/// The [`AdoError`] of the last response that was passed through to the retry policy.
+#[derive(Debug, Default)]
+struct LastRetryError(Mutex<Option<AdoError>>);
+
+/// Policy that converts unsuccessful responses into errors wrapping an [`AdoError`].
+///
+/// This is added to the per-retry policies of every client, so responses with a status
+/// that will be retried are passed through, after recording their [`AdoError`] for
+/// [`RetryErrorPolicy`].
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPolicy {}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Policy for ErrorPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let rsp = next[0].send(ctx, request, &next[1..]).await?;
+        let status = rsp.status();
+        let is_error = !status.is_success() || status == StatusCode::NonAuthoritativeInformation;
+        if !is_error {
+            return Ok(rsp);
+        }
+        let ado_error = AdoError::from_response(rsp).await?;
+        if !RETRY_STATUSES.contains(&status) {
+            return Err(ado_error.into_error());
+        }
+        // Pass the response on to the retry policy, which needs the body to be readable again
+        let rsp = Response::new(
+            status,
+            ado_error.headers.clone(),
+            Box::pin(futures::stream::once(futures::future::ready(Ok(ado_error
+                .body
+                .clone())))),
+        );
+        if let Some(last) = ctx.get::<Arc<LastRetryError>>() {
+            *last.0.lock().unwrap() = Some(ado_error);
+        }
+        Ok(rsp)
+    }
+}
+
+/// Create a new [`ErrorPolicy`].
+pub fn error_policy() -> Arc<dyn Policy> {
+    Arc::new(ErrorPolicy::default())
+}
+
+/// Policy that replaces the error returned when the retries of a request are exhausted
+/// with one wrapping the [`AdoError`] of the last response.
+///
+/// This is added to the per-call policies of every client, so that e.g. a 429 or 503 response
+/// is returned as an [`AdoError`] like any other unsuccessful response.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RetryErrorPolicy {}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Policy for RetryErrorPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let last = Arc::new(LastRetryError::default());
+        let mut ctx = ctx.clone();
+        ctx.insert(last.clone());
+        match next[0].send(&ctx, request, &next[1..]).await {
+            // Without a retry policy, the response is returned as-is
+            Ok(rsp) if RETRY_STATUSES.contains(&rsp.status()) => {
+                Err(AdoError::from_response(rsp).await?.into_error())
+            }
+            Err(err) if err.ado_error().is_none() => {
+                let ado_error = last.0.lock().unwrap().take();
+                match (err.kind(), ado_error) {
+                    (ErrorKind::HttpResponse { status, .. }, Some(ado_error))
+                        if *status == ado_error.status =>
+                    {
+                        Err(ado_error.into_error())
+                    }
+                    _ => Err(err),
+                }
+            }
+            result => result,
+        }
+    }
+}
+
+/// Create a new [`RetryErrorPolicy`].
+pub fn retry_error_policy() -> Arc<dyn Policy> {
+    Arc::new(RetryErrorPolicy::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Transport that returns the same response to every request.
+    #[derive(Debug)]
+    struct MockTransport {
+        status: StatusCode,
+        body: &'static [u8],
+        requests: AtomicUsize,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Policy for MockTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let body = Bytes::from_static(self.body);
+            Ok(Response::new(
+                self.status,
+                Headers::new(),
+                Box::pin(futures::stream::once(futures::future::ready(Ok(body)))),
+            ))
+        }
+    }
+
+    async fn send(
+        status: StatusCode,
+        retry: azure_core::RetryOptions,
+    ) -> (azure_core::Result<Response>, usize) {
+        let transport = Arc::new(MockTransport {
+            status,
+            body: br#"{"message":"Try again later.","typeKey":"ServiceUnavailableException"}"#,
+            requests: AtomicUsize::new(0),
+        });
+        let options = azure_core::ClientOptions::default().retry(retry).transport(
+            azure_core::TransportOptions::new_custom_policy(transport.clone()),
+        );
+        let pipeline = azure_core::Pipeline::new(
+            None,
+            None,
+            options,
+            vec![retry_error_policy()],
+            vec![error_policy()],
+        );
+        let mut request = Request::new(
+            azure_core::Url::parse("https://dev.azure.com/org/_apis/projects").unwrap(),
+            azure_core::Method::Get,
+        );
+        let result = pipeline.send(&Context::new(), &mut request).await;
+        (result, transport.requests.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted() {
+        let retry = azure_core::RetryOptions::fixed(
+            azure_core::FixedRetryOptions::default()
+                .max_retries(2u32)
+                .delay(Duration::from_millis(1)),
+        );
+        let (result, requests) = send(StatusCode::ServiceUnavailable, retry).await;
+        assert_eq!(requests, 3);
+        let err = result.unwrap_err();
+        let ado_error = err.ado_error().unwrap();
+        assert_eq!(ado_error.status(), StatusCode::ServiceUnavailable);
+        assert_eq!(ado_error.type_key(), Some("ServiceUnavailableException"));
+        assert_eq!(ado_error.message(), Some("Try again later."));
+    }
+
+    #[test]
+    fn test_retry_status_without_retries() {
+        let (result, requests) = futures::executor::block_on(send(
+            StatusCode::TooManyRequests,
+            azure_core::RetryOptions::none(),
+        ));
+        assert_eq!(requests, 1);
+        let err = result.unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::HttpResponse {
+                status: StatusCode::TooManyRequests,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.ado_error().unwrap().status(),
+            StatusCode::TooManyRequests
+        );
+    }
+
+    #[test]
+    fn test_ado_error_payload() {
+        let body = Bytes::from_static(
+            br#"{"$id":"1","innerException":null,"message":"TF401019: The Git repository with name or identifier foo does not exist.","typeName":"Microsoft.TeamFoundation.Git.Server.GitRepositoryNotFoundException, Microsoft.TeamFoundation.Git.Server","typeKey":"GitRepositoryNotFoundException","errorCode":0,"eventId":3000}"#,
+        );
+        let mut headers = Headers::new();
+        headers.insert(ACTIVITY_ID, "6b3d3c2e-0000-0000-0000-000000000000");
+        let err = AdoError::new(StatusCode::NotFound, &headers, body).into_error();
+
+        let ado_error = err.ado_error().unwrap();
+        assert_eq!(ado_error.status(), StatusCode::NotFound);
+        assert_eq!(ado_error.type_key(), Some("GitRepositoryNotFoundException"));
+        assert_eq!(ado_error.event_id(), Some(3000));
+        assert_eq!(
+            ado_error.activity_id(),
+            Some("6b3d3c2e-0000-0000-0000-000000000000")
+        );
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::HttpResponse { status: StatusCode::NotFound, error_code: Some(code) }
+                if code == "GitRepositoryNotFoundException"
+        ));
+    }
+
+    #[test]
+    fn test_ado_error_without_payload() {
+        let body = Bytes::from_static(b"<html>Sign in</html>");
+        let err = AdoError::new(
+            StatusCode::NonAuthoritativeInformation,
+            &Headers::new(),
+            body,
+        );
+        assert!(err.payload().is_none());
+        assert_eq!(err.type_key(), None);
+        assert_eq!(
+            err.to_string(),
+            "203: non-authoritative response, check the credential is valid"
+        );
+    }
+}
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,10 +129,83 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn installed_extensions_client(&self) -> installed_extensions::Client {
         installed_extensions::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn installed_extensions_client(&self) -> installed_extensions::OrganizationClient {
+        installed_extensions::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn installed_extensions_client(&self) -> installed_extensions::ProjectClient {
+        installed_extensions::ProjectClient(self.clone())
+    }
+}
 pub mod installed_extensions {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -233,6 +306,134 @@ pub mod installed_extensions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "List the installed extensions in the account / project collection."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Update an installed extension. Typically this API is used to enable or disable an extension."]
+        pub fn update(
+            &self,
+            body: impl Into<models::InstalledExtension>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body)
+        }
+        #[doc = "Get an installed extension by its publisher and extension name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn get(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+            )
+        }
+        #[doc = "Uninstall the specified extension from the account / project collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn uninstall_extension_by_name(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+        ) -> uninstall_extension_by_name::RequestBuilder {
+            Client(self.0.client.clone()).uninstall_extension_by_name(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+            )
+        }
+        #[doc = "Install the specified extension into the account / project collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn install_extension_by_name(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+            version: impl Into<String>,
+        ) -> install_extension_by_name::RequestBuilder {
+            Client(self.0.client.clone()).install_extension_by_name(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+                version,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "List the installed extensions in the account / project collection."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Update an installed extension. Typically this API is used to enable or disable an extension."]
+        pub fn update(
+            &self,
+            body: impl Into<models::InstalledExtension>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body)
+        }
+        #[doc = "Get an installed extension by its publisher and extension name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn get(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+            )
+        }
+        #[doc = "Uninstall the specified extension from the account / project collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn uninstall_extension_by_name(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+        ) -> uninstall_extension_by_name::RequestBuilder {
+            Client(self.0.client.clone()).uninstall_extension_by_name(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+            )
+        }
+        #[doc = "Install the specified extension into the account / project collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_name`: Name of the publisher. Example: \"fabrikam\"."]
+        #[doc = "* `extension_name`: Name of the extension. Example: \"ops-tools\"."]
+        pub fn install_extension_by_name(
+            &self,
+            publisher_name: impl Into<String>,
+            extension_name: impl Into<String>,
+            version: impl Into<String>,
+        ) -> install_extension_by_name::RequestBuilder {
+            Client(self.0.client.clone()).install_extension_by_name(
+                self.0.organization.clone(),
+                publisher_name,
+                extension_name,
+                version,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
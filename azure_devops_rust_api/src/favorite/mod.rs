@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,10 +129,83 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn favorites_client(&self) -> favorites::Client {
         favorites::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn favorites_client(&self) -> favorites::OrganizationClient {
+        favorites::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn favorites_client(&self) -> favorites::ProjectClient {
+        favorites::ProjectClient(self.clone())
+    }
+}
 pub mod favorites {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -307,6 +380,224 @@ pub mod favorites {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn get_favorites_of_owner(
+            &self,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+        ) -> get_favorites_of_owner::RequestBuilder {
+            Client(self.0.client.clone()).get_favorites_of_owner(
+                self.0.organization.clone(),
+                owner_scope_type,
+                owner_scope_id,
+            )
+        }
+        pub fn create_favorite_of_owner(
+            &self,
+            body: impl Into<models::FavoriteCreateParameters>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+        ) -> create_favorite_of_owner::RequestBuilder {
+            Client(self.0.client.clone()).create_favorite_of_owner(
+                self.0.organization.clone(),
+                body,
+                owner_scope_type,
+                owner_scope_id,
+            )
+        }
+        pub fn get_favorite_by_artifact(
+            &self,
+            artifact_type: impl Into<String>,
+            artifact_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> get_favorite_by_artifact::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_by_artifact(
+                self.0.organization.clone(),
+                artifact_type,
+                artifact_id,
+                artifact_scope_type,
+            )
+        }
+        pub fn delete_favorite_of_owner_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+            artifact_type: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> delete_favorite_of_owner_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_favorite_of_owner_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                owner_scope_type,
+                owner_scope_id,
+                artifact_type,
+                artifact_scope_type,
+            )
+        }
+        pub fn get_favorite_of_owner_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+            artifact_type: impl Into<String>,
+        ) -> get_favorite_of_owner_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_of_owner_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                owner_scope_type,
+                owner_scope_id,
+                artifact_scope_type,
+                artifact_type,
+            )
+        }
+        pub fn get_favorites(&self) -> get_favorites::RequestBuilder {
+            Client(self.0.client.clone()).get_favorites(self.0.organization.clone())
+        }
+        pub fn create_favorite(
+            &self,
+            body: impl Into<models::FavoriteCreateParameters>,
+        ) -> create_favorite::RequestBuilder {
+            Client(self.0.client.clone()).create_favorite(self.0.organization.clone(), body)
+        }
+        pub fn get_favorite_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+            artifact_type: impl Into<String>,
+        ) -> get_favorite_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                artifact_scope_type,
+                artifact_type,
+            )
+        }
+        pub fn delete_favorite_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            artifact_type: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> delete_favorite_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_favorite_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                artifact_type,
+                artifact_scope_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get_favorites_of_owner(
+            &self,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+        ) -> get_favorites_of_owner::RequestBuilder {
+            Client(self.0.client.clone()).get_favorites_of_owner(
+                self.0.organization.clone(),
+                owner_scope_type,
+                owner_scope_id,
+            )
+        }
+        pub fn create_favorite_of_owner(
+            &self,
+            body: impl Into<models::FavoriteCreateParameters>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+        ) -> create_favorite_of_owner::RequestBuilder {
+            Client(self.0.client.clone()).create_favorite_of_owner(
+                self.0.organization.clone(),
+                body,
+                owner_scope_type,
+                owner_scope_id,
+            )
+        }
+        pub fn get_favorite_by_artifact(
+            &self,
+            artifact_type: impl Into<String>,
+            artifact_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> get_favorite_by_artifact::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_by_artifact(
+                self.0.organization.clone(),
+                artifact_type,
+                artifact_id,
+                artifact_scope_type,
+            )
+        }
+        pub fn delete_favorite_of_owner_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+            artifact_type: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> delete_favorite_of_owner_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_favorite_of_owner_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                owner_scope_type,
+                owner_scope_id,
+                artifact_type,
+                artifact_scope_type,
+            )
+        }
+        pub fn get_favorite_of_owner_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            owner_scope_type: impl Into<String>,
+            owner_scope_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+            artifact_type: impl Into<String>,
+        ) -> get_favorite_of_owner_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_of_owner_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                owner_scope_type,
+                owner_scope_id,
+                artifact_scope_type,
+                artifact_type,
+            )
+        }
+        pub fn get_favorites(&self) -> get_favorites::RequestBuilder {
+            Client(self.0.client.clone()).get_favorites(self.0.organization.clone())
+        }
+        pub fn create_favorite(
+            &self,
+            body: impl Into<models::FavoriteCreateParameters>,
+        ) -> create_favorite::RequestBuilder {
+            Client(self.0.client.clone()).create_favorite(self.0.organization.clone(), body)
+        }
+        pub fn get_favorite_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+            artifact_type: impl Into<String>,
+        ) -> get_favorite_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_favorite_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                artifact_scope_type,
+                artifact_type,
+            )
+        }
+        pub fn delete_favorite_by_id(
+            &self,
+            favorite_id: impl Into<String>,
+            artifact_type: impl Into<String>,
+            artifact_scope_type: impl Into<String>,
+        ) -> delete_favorite_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_favorite_by_id(
+                self.0.organization.clone(),
+                favorite_id,
+                artifact_type,
+                artifact_scope_type,
+            )
+        }
+    }
     pub mod get_favorites_of_owner {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,465 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Pull request comment threads anchored to files and lines.
+//!
+//! [`CommentThreadBuilder`] builds the `GitPullRequestCommentThread` used by
+//! `pull_request_threads_client().create()`, and [`PullRequestHandle`] has helpers to create,
+//! reply to and resolve threads:
+//!
+//! ```rust
+//!     let thread = pr
+//!         .create_thread(
+//!             git::CommentThreadBuilder::new("This can overflow")
+//!                 .file("/src/lib.rs")
+//!                 .lines(10, 12),
+//!         )
+//!         .await?;
+//!     let thread_id = thread.comment_thread.id.unwrap_or_default();
+//!     pr.reply(thread_id, "Fixed in the next iteration").await?;
+//!     pr.set_thread_status(thread_id, git::ThreadStatus::Fixed).await?;
+//!
+//!     for thread in pr.file_threads().await? {
+//!         println!("{}:{}: {:?}", thread.path, thread.start_line, thread.status);
+//!     }
+//! ```
+use super::PullRequestHandle;
+use crate::git::models::{
+    comment::CommentType, Comment, CommentIterationContext, CommentPosition, CommentThread,
+    CommentThreadContext, GitPullRequestCommentThread, GitPullRequestCommentThreadContext,
+};
+
+pub use crate::git::models::comment_thread::Status as ThreadStatus;
+
+/// The side of a pull request diff.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The file in the target branch, before the pull request's changes.
+    Left,
+    /// The file in the source branch, with the pull request's changes.
+    Right,
+}
+
+/// Builds a pull request comment thread, optionally anchored to lines of a file.
+///
+/// Threads that are not anchored to a file are shown on the pull request overview.
+#[derive(Clone, Debug)]
+pub struct CommentThreadBuilder {
+    content: String,
+    status: ThreadStatus,
+    file_path: Option<String>,
+    lines: Option<(i32, i32)>,
+    side: Side,
+    iterations: Option<(i64, i64)>,
+    change_tracking_id: Option<i32>,
+}
+
+impl CommentThreadBuilder {
+    /// Arguments:
+    /// * `content`: The content of the first comment in the thread (markdown).
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            status: ThreadStatus::Active,
+            file_path: None,
+            lines: None,
+            side: Side::Right,
+            iterations: None,
+            change_tracking_id: None,
+        }
+    }
+
+    /// Anchors the thread to a file, e.g. `/src/lib.rs`.
+    #[must_use]
+    pub fn file(mut self, path: impl Into<String>) -> Self {
+        let path = path.into();
+        self.file_path = Some(if path.starts_with('/') {
+            path
+        } else {
+            format!("/{path}")
+        });
+        self
+    }
+
+    /// Anchors the thread to a line of the file. Lines start at 1.
+    #[must_use]
+    pub fn line(self, line: i32) -> Self {
+        self.lines(line, line)
+    }
+
+    /// Anchors the thread to a range of lines of the file, including `end`. Lines start at 1.
+    #[must_use]
+    pub fn lines(mut self, start: i32, end: i32) -> Self {
+        self.lines = Some((start, end.max(start)));
+        self
+    }
+
+    /// The side of the diff that the lines are on. Defaults to [`Side::Right`].
+    #[must_use]
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// The iteration of the pull request that the lines refer to, compared to its base.
+    ///
+    /// [`PullRequestHandle::create_thread()`] defaults to the latest iteration.
+    #[must_use]
+    pub fn iteration(self, iteration: i64) -> Self {
+        self.iterations(1, iteration)
+    }
+
+    /// The iterations of the pull request on the left and right side of the diff that the lines
+    /// refer to.
+    #[must_use]
+    pub fn iterations(mut self, first: i64, second: i64) -> Self {
+        self.iterations = Some((first, second));
+        self
+    }
+
+    /// The change tracking ID of the file, from the iteration's changes. Used to track the
+    /// thread across iterations.
+    #[must_use]
+    pub fn change_tracking_id(mut self, change_tracking_id: i32) -> Self {
+        self.change_tracking_id = Some(change_tracking_id);
+        self
+    }
+
+    /// The initial status of the thread. Defaults to [`ThreadStatus::Active`].
+    #[must_use]
+    pub fn status(mut self, status: ThreadStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    fn thread_context(&self) -> Option<CommentThreadContext> {
+        let file_path = self.file_path.clone()?;
+        let mut context = CommentThreadContext::new();
+        context.file_path = Some(file_path);
+        if let Some((start, end)) = self.lines {
+            // The end position is exclusive, so the thread ends at the start of the next line.
+            let start = Some(position(start));
+            let end = Some(position(end + 1));
+            match self.side {
+                Side::Left => {
+                    context.left_file_start = start;
+                    context.left_file_end = end;
+                }
+                Side::Right => {
+                    context.right_file_start = start;
+                    context.right_file_end = end;
+                }
+            }
+        }
+        Some(context)
+    }
+
+    fn pull_request_thread_context(&self) -> Option<GitPullRequestCommentThreadContext> {
+        if self.iterations.is_none() && self.change_tracking_id.is_none() {
+            return None;
+        }
+        let mut context = GitPullRequestCommentThreadContext::new();
+        context.change_tracking_id = self.change_tracking_id;
+        context.iteration_context = self.iterations.map(|(first, second)| {
+            let mut iteration_context = CommentIterationContext::new();
+            iteration_context.first_comparing_iteration = Some(first);
+            iteration_context.second_comparing_iteration = Some(second);
+            iteration_context
+        });
+        Some(context)
+    }
+}
+
+impl From<CommentThreadBuilder> for GitPullRequestCommentThread {
+    fn from(builder: CommentThreadBuilder) -> Self {
+        let mut thread = GitPullRequestCommentThread::new();
+        thread.comment_thread.thread_context = builder.thread_context();
+        thread.pull_request_thread_context = builder.pull_request_thread_context();
+        thread.comment_thread.comments = vec![text_comment(builder.content, None)];
+        thread.comment_thread.status = Some(builder.status);
+        thread
+    }
+}
+
+/// A comment thread on lines of a file in a pull request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileThread {
+    pub thread_id: i32,
+    /// The path of the file, e.g. `/src/lib.rs`.
+    pub path: String,
+    /// The side of the diff that the lines are on.
+    pub side: Side,
+    /// The first line of the thread. Lines start at 1.
+    pub start_line: i32,
+    /// The last line of the thread, inclusive.
+    pub end_line: i32,
+    pub status: Option<ThreadStatus>,
+    /// The comments in the thread, in the order they were added.
+    pub comments: Vec<Comment>,
+}
+
+impl FileThread {
+    /// The file and lines of a thread, if it is anchored to lines of a file and not deleted.
+    pub fn from_thread(thread: GitPullRequestCommentThread) -> Option<Self> {
+        let CommentThread {
+            id,
+            comments,
+            is_deleted,
+            status,
+            thread_context,
+            ..
+        } = thread.comment_thread;
+        if is_deleted == Some(true) {
+            return None;
+        }
+        let context = thread_context?;
+        let (side, start, end) = match (context.right_file_start, context.left_file_start) {
+            (Some(start), _) => (Side::Right, start, context.right_file_end),
+            (None, Some(start)) => (Side::Left, start, context.left_file_end),
+            (None, None) => return None,
+        };
+        let start_line = start.line?;
+        let end_line = match end.and_then(|end| Some((end.line?, end.offset))) {
+            // An end at the start of a line excludes that line.
+            Some((line, Some(1))) if line > start_line => line - 1,
+            Some((line, _)) => line,
+            None => start_line,
+        };
+        let mut comments: Vec<Comment> = comments
+            .into_iter()
+            .filter(|comment| comment.is_deleted != Some(true))
+            .collect();
+        comments.sort_by_key(|comment| comment.id);
+        Some(Self {
+            thread_id: id?,
+            path: context.file_path?,
+            side,
+            start_line,
+            end_line,
+            status,
+            comments,
+        })
+    }
+}
+
+impl PullRequestHandle {
+    /// Creates a comment thread.
+    ///
+    /// Threads anchored to a file refer to the latest iteration of the pull request, unless
+    /// [`CommentThreadBuilder::iteration()`] is set.
+    pub async fn create_thread(
+        &self,
+        thread: CommentThreadBuilder,
+    ) -> azure_core::Result<GitPullRequestCommentThread> {
+        let thread = if thread.file_path.is_some() && thread.iterations.is_none() {
+            let iteration = self.latest_iteration().await?;
+            thread.iteration(iteration.into())
+        } else {
+            thread
+        };
+        self.client
+            .pull_request_threads_client()
+            .create(
+                &self.organization,
+                thread,
+                &self.repository,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Adds a reply to a comment thread.
+    pub async fn reply(
+        &self,
+        thread_id: i32,
+        content: impl Into<String>,
+    ) -> azure_core::Result<Comment> {
+        self.client
+            .pull_request_thread_comments_client()
+            .create(
+                &self.organization,
+                // Replies are made to the thread's first comment, whose ID is always 1.
+                text_comment(content.into(), Some(1)),
+                &self.repository,
+                self.pull_request_id,
+                thread_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Sets the status of a comment thread, e.g. to resolve it as [`ThreadStatus::Fixed`].
+    pub async fn set_thread_status(
+        &self,
+        thread_id: i32,
+        status: ThreadStatus,
+    ) -> azure_core::Result<GitPullRequestCommentThread> {
+        let mut thread = GitPullRequestCommentThread::new();
+        thread.comment_thread.status = Some(status);
+        self.client
+            .pull_request_threads_client()
+            .update(
+                &self.organization,
+                thread,
+                &self.repository,
+                self.pull_request_id,
+                thread_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Lists the comment threads anchored to lines of files.
+    ///
+    /// The lines are tracked to the latest iteration of the pull request, so they refer to the
+    /// current version of each file.
+    pub async fn file_threads(&self) -> azure_core::Result<Vec<FileThread>> {
+        let iteration = self.latest_iteration().await?;
+        let threads = self
+            .client
+            .pull_request_threads_client()
+            .list(
+                &self.organization,
+                &self.repository,
+                self.pull_request_id,
+                &self.project,
+            )
+            .iteration(iteration)
+            .await?
+            .value;
+        Ok(threads
+            .into_iter()
+            .filter_map(FileThread::from_thread)
+            .collect())
+    }
+
+    /// The ID of the latest iteration of the pull request.
+    async fn latest_iteration(&self) -> azure_core::Result<i32> {
+        let iterations = self
+            .client
+            .pull_request_iterations_client()
+            .list(
+                &self.organization,
+                &self.repository,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await?
+            .value;
+        Ok(iterations
+            .iter()
+            .filter_map(|iteration| iteration.id)
+            .max()
+            .unwrap_or(1))
+    }
+}
+
+/// The start of a line.
+fn position(line: i32) -> CommentPosition {
+    let mut position = CommentPosition::new();
+    position.line = Some(line);
+    position.offset = Some(1);
+    position
+}
+
+fn text_comment(content: String, parent_comment_id: Option<i64>) -> Comment {
+    let mut comment = Comment::new();
+    comment.content = Some(content);
+    comment.comment_type = Some(CommentType::Text);
+    comment.parent_comment_id = parent_comment_id;
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_file_thread() {
+        let thread: GitPullRequestCommentThread = CommentThreadBuilder::new("This can overflow")
+            .file("src/lib.rs")
+            .lines(10, 12)
+            .iteration(3)
+            .into();
+        assert_eq!(
+            serde_json::to_value(&thread).unwrap(),
+            json!({
+                "comments": [{ "commentType": "text", "content": "This can overflow" }],
+                "status": "active",
+                "threadContext": {
+                    "filePath": "/src/lib.rs",
+                    "rightFileStart": { "line": 10, "offset": 1 },
+                    "rightFileEnd": { "line": 13, "offset": 1 },
+                },
+                "pullRequestThreadContext": {
+                    "iterationContext": {
+                        "firstComparingIteration": 1,
+                        "secondComparingIteration": 3,
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_left_side_thread() {
+        let thread: GitPullRequestCommentThread =
+            CommentThreadBuilder::new("Why was this removed?")
+                .file("/README.md")
+                .line(4)
+                .side(Side::Left)
+                .status(ThreadStatus::Pending)
+                .into();
+        let context = thread.comment_thread.thread_context.clone().unwrap();
+        assert_eq!(context.left_file_start.unwrap().line, Some(4));
+        assert_eq!(context.left_file_end.unwrap().line, Some(5));
+        assert_eq!(context.right_file_start, None);
+        assert_eq!(thread.comment_thread.status, Some(ThreadStatus::Pending));
+        assert_eq!(thread.pull_request_thread_context, None);
+
+        let mut thread = thread;
+        thread.comment_thread.id = Some(1);
+        let file_thread = FileThread::from_thread(thread).unwrap();
+        assert_eq!((file_thread.start_line, file_thread.end_line), (4, 4));
+    }
+
+    #[test]
+    fn test_general_thread() {
+        let thread: GitPullRequestCommentThread = CommentThreadBuilder::new("LGTM").into();
+        assert_eq!(thread.comment_thread.thread_context, None);
+        assert_eq!(FileThread::from_thread(thread), None);
+    }
+
+    #[test]
+    fn test_file_thread_from_thread() {
+        let thread: GitPullRequestCommentThread = serde_json::from_value(json!({
+            "id": 7,
+            "status": "fixed",
+            "comments": [
+                { "id": 2, "parentCommentId": 1, "content": "Done" },
+                { "id": 1, "content": "Rename this" },
+                { "id": 3, "content": "Deleted", "isDeleted": true },
+            ],
+            "threadContext": {
+                "filePath": "/src/main.rs",
+                "rightFileStart": { "line": 20, "offset": 1 },
+                "rightFileEnd": { "line": 22, "offset": 5 },
+            },
+        }))
+        .unwrap();
+        let file_thread = FileThread::from_thread(thread).unwrap();
+        assert_eq!(file_thread.thread_id, 7);
+        assert_eq!(file_thread.path, "/src/main.rs");
+        assert_eq!(file_thread.side, Side::Right);
+        assert_eq!((file_thread.start_line, file_thread.end_line), (20, 22));
+        assert_eq!(file_thread.status, Some(ThreadStatus::Fixed));
+        let comments: Vec<_> = file_thread
+            .comments
+            .iter()
+            .map(|comment| comment.content.as_deref().unwrap())
+            .collect();
+        assert_eq!(comments, ["Rename this", "Done"]);
+    }
+}
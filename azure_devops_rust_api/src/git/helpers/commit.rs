@@ -0,0 +1,443 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Commit file changes to a branch.
+//!
+//! [`CommitBuilder`] collects file changes into a single commit and pushes it with
+//! `pushes_client().create()`. It looks up the tip of the branch, so the caller does not need
+//! to know the current commit id:
+//!
+//! ```rust
+//!     let push = git::CommitBuilder::new(&git_client, organization, project, repository, "feature/docs")
+//!         .from_branch("main")
+//!         .message("Update the docs")
+//!         .add_file("/docs/new.md", "# New page\n")
+//!         .edit_file("/README.md", readme)
+//!         .add_file("/docs/logo.png", std::fs::read("logo.png")?)
+//!         .rename_file("/docs/old.md", "/docs/archive/old.md")
+//!         .delete_file("/docs/obsolete.md")
+//!         .await?;
+//!     println!("Pushed {}", push.commits[0].commit_id.as_deref().unwrap_or_default());
+//! ```
+//!
+//! If the branch does not exist, it is created from the branch given to
+//! [`from_branch()`](CommitBuilder::from_branch).
+//! If another client updates the branch before the push completes, the push is retried on
+//! the new tip of the branch.
+use crate::error::ErrorExt;
+use crate::git::models::{
+    change::ChangeType, item_content::ContentType, Change, GitChange, GitCommitRef, GitItem,
+    GitPush, GitRefUpdate, GitUserDate, ItemContent,
+};
+use crate::git::Client;
+use azure_core::error::{Error, ErrorKind};
+use azure_core::StatusCode;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use std::future::IntoFuture;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+
+/// The default number of times a push is retried when the branch was updated by another client.
+pub const DEFAULT_COMMIT_RETRIES: u32 = 3;
+
+const REFS_HEADS: &str = "refs/heads/";
+
+/// The content of a file in a commit.
+///
+/// Text is committed as-is. Binary content is base64 encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileContent {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl FileContent {
+    fn into_item_content(self) -> ItemContent {
+        match self {
+            FileContent::Text(text) => ItemContent::new(text, ContentType::RawText),
+            FileContent::Binary(bytes) => {
+                ItemContent::new(BASE64_STANDARD.encode(bytes), ContentType::Base64Encoded)
+            }
+        }
+    }
+}
+
+impl From<String> for FileContent {
+    fn from(text: String) -> Self {
+        FileContent::Text(text)
+    }
+}
+
+impl From<&str> for FileContent {
+    fn from(text: &str) -> Self {
+        FileContent::Text(text.to_string())
+    }
+}
+
+impl From<Vec<u8>> for FileContent {
+    fn from(bytes: Vec<u8>) -> Self {
+        FileContent::Binary(bytes)
+    }
+}
+
+impl From<&[u8]> for FileContent {
+    fn from(bytes: &[u8]) -> Self {
+        FileContent::Binary(bytes.to_vec())
+    }
+}
+
+/// A change to a file, added to a [`CommitBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChange {
+    Add { path: String, content: FileContent },
+    Edit { path: String, content: FileContent },
+    Delete { path: String },
+    Rename { from: String, to: String },
+}
+
+impl FileChange {
+    fn to_git_change(&self) -> GitChange {
+        let (change_type, path, content, source) = match self {
+            FileChange::Add { path, content } => (ChangeType::Add, path, Some(content), None),
+            FileChange::Edit { path, content } => (ChangeType::Edit, path, Some(content), None),
+            FileChange::Delete { path } => (ChangeType::Delete, path, None, None),
+            FileChange::Rename { from, to } => (ChangeType::Rename, to, None, Some(from)),
+        };
+        let mut item = GitItem::new();
+        item.item_model.path = Some(path.clone());
+        let mut change = Change::new(change_type, item);
+        change.new_content = content.cloned().map(FileContent::into_item_content);
+        change.source_server_item = source.cloned();
+        GitChange::new(change)
+    }
+}
+
+/// Commits file changes to a branch of a repository.
+///
+/// Add the changes, then await the builder to push the commit. The returned [`GitPush`]
+/// contains the new commit.
+#[derive(Clone)]
+pub struct CommitBuilder {
+    client: Client,
+    organization: String,
+    project: String,
+    repository: String,
+    branch: String,
+    from_branch: Option<String>,
+    message: Option<String>,
+    author: Option<GitUserDate>,
+    changes: Vec<FileChange>,
+    max_retries: u32,
+}
+
+impl CommitBuilder {
+    /// Arguments:
+    /// * `client`: The git client.
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    /// * `repository`: The name or ID of the repository.
+    /// * `branch`: The branch to commit to, e.g. `main` or `refs/heads/main`.
+    pub fn new(
+        client: &Client,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+        repository: impl Into<String>,
+        branch: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            organization: organization.into(),
+            project: project.into(),
+            repository: repository.into(),
+            branch: branch_name(&branch.into()).to_string(),
+            from_branch: None,
+            message: None,
+            author: None,
+            changes: Vec::new(),
+            max_retries: DEFAULT_COMMIT_RETRIES,
+        }
+    }
+
+    /// Creates the branch from the tip of `from_branch` if it does not exist.
+    #[must_use]
+    pub fn from_branch(mut self, from_branch: impl Into<String>) -> Self {
+        self.from_branch = Some(branch_name(&from_branch.into()).to_string());
+        self
+    }
+
+    /// The commit message. This is required.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The author of the commit. Defaults to the authenticated user.
+    #[must_use]
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        let mut author = GitUserDate::new();
+        author.name = Some(name.into());
+        author.email = Some(email.into());
+        self.author = Some(author);
+        self
+    }
+
+    /// The number of times the push is retried if another client updates the branch first.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Adds a new file.
+    #[must_use]
+    pub fn add_file(self, path: impl Into<String>, content: impl Into<FileContent>) -> Self {
+        let path = item_path(path.into());
+        self.push(FileChange::Add {
+            path,
+            content: content.into(),
+        })
+    }
+
+    /// Replaces the content of an existing file.
+    #[must_use]
+    pub fn edit_file(self, path: impl Into<String>, content: impl Into<FileContent>) -> Self {
+        let path = item_path(path.into());
+        self.push(FileChange::Edit {
+            path,
+            content: content.into(),
+        })
+    }
+
+    /// Deletes a file.
+    #[must_use]
+    pub fn delete_file(self, path: impl Into<String>) -> Self {
+        let path = item_path(path.into());
+        self.push(FileChange::Delete { path })
+    }
+
+    /// Renames (moves) a file.
+    #[must_use]
+    pub fn rename_file(self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        let from = item_path(from.into());
+        let to = item_path(to.into());
+        self.push(FileChange::Rename { from, to })
+    }
+
+    /// Adds a change.
+    #[must_use]
+    pub fn push(mut self, change: FileChange) -> Self {
+        self.changes.push(change);
+        self
+    }
+
+    /// The changes added so far.
+    pub fn changes(&self) -> &[FileChange] {
+        &self.changes
+    }
+
+    /// The push request, given the current commit id of the branch.
+    fn git_push(&self, old_object_id: String) -> GitPush {
+        let mut commit = GitCommitRef::new();
+        commit.comment = self.message.clone();
+        commit.author = self.author.clone();
+        commit.changes = self.changes.iter().map(FileChange::to_git_change).collect();
+        let mut ref_update = GitRefUpdate::new();
+        ref_update.name = Some(format!("{REFS_HEADS}{}", self.branch));
+        ref_update.old_object_id = Some(old_object_id);
+        let mut push = GitPush::new();
+        push.commits = vec![commit];
+        push.ref_updates = vec![ref_update];
+        push
+    }
+
+    /// The commit id at the tip of a branch, if the branch exists.
+    async fn branch_tip(&self, branch: &str) -> azure_core::Result<Option<String>> {
+        let name = format!("{REFS_HEADS}{branch}");
+        let refs = self
+            .client
+            .refs_client()
+            .list(&self.organization, &self.repository, &self.project)
+            .filter(format!("heads/{branch}"))
+            .await?
+            .value;
+        Ok(refs
+            .into_iter()
+            .find(|git_ref| git_ref.name == name)
+            .map(|git_ref| git_ref.object_id))
+    }
+
+    /// The commit the new commit is based on: the tip of the branch, or of `from_branch` if
+    /// the branch does not exist yet.
+    async fn base_commit(&self) -> azure_core::Result<String> {
+        if let Some(tip) = self.branch_tip(&self.branch).await? {
+            return Ok(tip);
+        }
+        let from_branch = self.from_branch.as_deref().ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                format!(
+                    "branch {} does not exist, use from_branch() to create it",
+                    self.branch
+                ),
+            )
+        })?;
+        self.branch_tip(from_branch).await?.ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                format!("branch {from_branch} does not exist"),
+            )
+        })
+    }
+
+    async fn commit(self) -> azure_core::Result<GitPush> {
+        if self.message.as_deref().map_or(true, str::is_empty) {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "a commit message is required",
+            ));
+        }
+        if self.changes.is_empty() {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "the commit has no changes",
+            ));
+        }
+        let mut retries = 0;
+        loop {
+            let base_commit = self.base_commit().await?;
+            let result = self
+                .client
+                .pushes_client()
+                .create(
+                    &self.organization,
+                    self.git_push(base_commit),
+                    &self.repository,
+                    &self.project,
+                )
+                .await;
+            match result {
+                Err(err) if is_stale_ref(&err) && retries < self.max_retries => retries += 1,
+                result => return result,
+            }
+        }
+    }
+}
+
+impl IntoFuture for CommitBuilder {
+    type Output = azure_core::Result<GitPush>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<GitPush>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.commit())
+    }
+}
+
+/// Whether a push failed because the branch was updated after its tip was looked up.
+fn is_stale_ref(err: &Error) -> bool {
+    if err.ado_error().and_then(|e| e.type_key()) == Some("GitReferenceStaleException") {
+        return true;
+    }
+    matches!(
+        err.kind(),
+        ErrorKind::HttpResponse {
+            status: StatusCode::Conflict,
+            ..
+        }
+    )
+}
+
+/// The name of a branch, without the `refs/heads/` prefix.
+fn branch_name(branch: &str) -> &str {
+    branch.strip_prefix(REFS_HEADS).unwrap_or(branch)
+}
+
+/// The path of an item, which is relative to the repository root.
+fn item_path(path: String) -> String {
+    if path.starts_with('/') {
+        path
+    } else {
+        format!("/{path}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_git_change() {
+        let change = FileChange::Add {
+            path: "/docs/a.md".to_string(),
+            content: "hello".into(),
+        };
+        assert_eq!(
+            serde_json::to_value(change.to_git_change()).unwrap(),
+            json!({
+                "changeType": "add",
+                "item": { "path": "/docs/a.md" },
+                "newContent": { "content": "hello", "contentType": "rawText" }
+            })
+        );
+
+        let change = FileChange::Edit {
+            path: "/logo.png".to_string(),
+            content: vec![0u8, 159, 146, 150].into(),
+        };
+        let value = serde_json::to_value(change.to_git_change()).unwrap();
+        assert_eq!(value["newContent"]["content"], "AJ+Slg==");
+        assert_eq!(value["newContent"]["contentType"], "base64Encoded");
+
+        let change = FileChange::Rename {
+            from: "/a.md".to_string(),
+            to: "/b.md".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(change.to_git_change()).unwrap(),
+            json!({
+                "changeType": "rename",
+                "item": { "path": "/b.md" },
+                "sourceServerItem": "/a.md"
+            })
+        );
+
+        let change = FileChange::Delete {
+            path: "/a.md".to_string(),
+        };
+        let value = serde_json::to_value(change.to_git_change()).unwrap();
+        assert_eq!(value["changeType"], "delete");
+        assert_eq!(value.get("newContent"), None::<&Value>);
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(branch_name("refs/heads/feature/x"), "feature/x");
+        assert_eq!(branch_name("main"), "main");
+        assert_eq!(item_path("docs/a.md".to_string()), "/docs/a.md");
+        assert_eq!(item_path("/docs/a.md".to_string()), "/docs/a.md");
+    }
+
+    #[test]
+    fn test_is_stale_ref() {
+        let conflict = Error::message(
+            ErrorKind::HttpResponse {
+                status: StatusCode::Conflict,
+                error_code: Some("GitReferenceStaleException".to_string()),
+            },
+            "TF401028: The reference 'refs/heads/main' has already been updated by another client",
+        );
+        assert!(is_stale_ref(&conflict));
+        let not_found = Error::message(
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotFound,
+                error_code: None,
+            },
+            "not found",
+        );
+        assert!(!is_stale_ref(&not_found));
+    }
+}
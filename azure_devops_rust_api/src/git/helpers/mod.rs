@@ -0,0 +1,15 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Handwritten helpers for working with git repositories.
+//!
+//! These are re-exported from the `git` module.
+mod comment_thread;
+mod commit;
+mod pull_request;
+
+pub use comment_thread::{CommentThreadBuilder, FileThread, Side, ThreadStatus};
+pub use commit::{CommitBuilder, FileChange, FileContent, DEFAULT_COMMIT_RETRIES};
+pub use pull_request::{
+    CompletionOptions, MergeStrategy, PullRequestHandle, Vote, DEFAULT_MERGE_POLL_INTERVAL,
+};
@@ -0,0 +1,514 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Manage a pull request through to completion.
+//!
+//! [`PullRequestHandle`] wraps the `pull_requests_client()` and `pull_request_reviewers_client()`
+//! operations used to review and complete a pull request:
+//!
+//! ```rust
+//!     let pr = git::PullRequestHandle::create(&git_client, organization, project, repository, create_options).await?;
+//!     pr.add_required_reviewer(reviewer_id).await?;
+//!     pr.set_auto_complete(
+//!         git::CompletionOptions::new(git::MergeStrategy::Squash)
+//!             .delete_source_branch(true)
+//!             .transition_work_items(true),
+//!     )
+//!     .await?;
+//!     let completed = pr.wait_for_merge().await?;
+//! ```
+use crate::git::models::{
+    git_pull_request::{MergeStatus, Status},
+    GitCommitRef, GitPullRequest, GitPullRequestCompletionOptions, GitPullRequestCreateOptions,
+    GitPullRequestUpdateOptions, IdentityRef, IdentityRefWithVote, PullRequestStatus,
+};
+use crate::git::Client;
+use azure_core::error::{Error, ErrorKind};
+use std::time::Duration;
+
+pub use crate::git::models::git_pull_request_completion_options::MergeStrategy;
+
+/// The default interval between requests made by [`PullRequestHandle::wait_for_merge()`].
+pub const DEFAULT_MERGE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A reviewer's vote on a pull request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Vote {
+    Approve,
+    ApproveWithSuggestions,
+    NoVote,
+    WaitForAuthor,
+    Reject,
+}
+
+impl Vote {
+    /// The value of the vote used by the service.
+    pub fn value(self) -> i64 {
+        match self {
+            Vote::Approve => 10,
+            Vote::ApproveWithSuggestions => 5,
+            Vote::NoVote => 0,
+            Vote::WaitForAuthor => -5,
+            Vote::Reject => -10,
+        }
+    }
+
+    /// The vote with the given service value, if it is valid.
+    pub fn from_value(value: i64) -> Option<Self> {
+        match value {
+            10 => Some(Vote::Approve),
+            5 => Some(Vote::ApproveWithSuggestions),
+            0 => Some(Vote::NoVote),
+            -5 => Some(Vote::WaitForAuthor),
+            -10 => Some(Vote::Reject),
+            _ => None,
+        }
+    }
+}
+
+impl From<Vote> for i64 {
+    fn from(vote: Vote) -> Self {
+        vote.value()
+    }
+}
+
+/// How a pull request is completed.
+///
+/// Converts into the [`GitPullRequestCompletionOptions`] sent to the service. A
+/// [`MergeStrategy`] converts into the options for that strategy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompletionOptions {
+    merge_strategy: MergeStrategy,
+    delete_source_branch: bool,
+    transition_work_items: bool,
+    merge_commit_message: Option<String>,
+    bypass_reason: Option<String>,
+}
+
+impl CompletionOptions {
+    pub fn new(merge_strategy: MergeStrategy) -> Self {
+        Self {
+            merge_strategy,
+            delete_source_branch: false,
+            transition_work_items: false,
+            merge_commit_message: None,
+            bypass_reason: None,
+        }
+    }
+
+    /// Deletes the source branch once the pull request is completed.
+    #[must_use]
+    pub fn delete_source_branch(mut self, delete_source_branch: bool) -> Self {
+        self.delete_source_branch = delete_source_branch;
+        self
+    }
+
+    /// Transitions the linked work items to their next state (e.g. Active -> Resolved).
+    #[must_use]
+    pub fn transition_work_items(mut self, transition_work_items: bool) -> Self {
+        self.transition_work_items = transition_work_items;
+        self
+    }
+
+    /// The commit message of the merge commit.
+    #[must_use]
+    pub fn merge_commit_message(mut self, merge_commit_message: impl Into<String>) -> Self {
+        self.merge_commit_message = Some(merge_commit_message.into());
+        self
+    }
+
+    /// Bypasses the branch policies, recording the reason.
+    ///
+    /// Requires the "Bypass policies when completing pull requests" permission.
+    #[must_use]
+    pub fn bypass_policy(mut self, reason: impl Into<String>) -> Self {
+        self.bypass_reason = Some(reason.into());
+        self
+    }
+}
+
+impl From<MergeStrategy> for CompletionOptions {
+    fn from(merge_strategy: MergeStrategy) -> Self {
+        Self::new(merge_strategy)
+    }
+}
+
+impl From<CompletionOptions> for GitPullRequestCompletionOptions {
+    fn from(options: CompletionOptions) -> Self {
+        let mut completion_options = GitPullRequestCompletionOptions::new();
+        completion_options.merge_strategy = Some(options.merge_strategy);
+        completion_options.delete_source_branch = Some(options.delete_source_branch);
+        completion_options.transition_work_items = Some(options.transition_work_items);
+        completion_options.merge_commit_message = options.merge_commit_message;
+        completion_options.bypass_policy = Some(options.bypass_reason.is_some());
+        completion_options.bypass_reason = options.bypass_reason;
+        completion_options
+    }
+}
+
+/// A pull request, and the operations to review and complete it.
+#[derive(Clone)]
+pub struct PullRequestHandle {
+    pub(super) client: Client,
+    pub(super) organization: String,
+    pub(super) project: String,
+    pub(super) repository: String,
+    pub(super) pull_request_id: i32,
+    identity_id: Option<String>,
+    poll_interval: Duration,
+}
+
+impl PullRequestHandle {
+    /// Arguments:
+    /// * `client`: The git client.
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    /// * `repository`: The name or ID of the repository.
+    /// * `pull_request_id`: The ID of the pull request.
+    pub fn new(
+        client: &Client,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+        repository: impl Into<String>,
+        pull_request_id: i32,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            organization: organization.into(),
+            project: project.into(),
+            repository: repository.into(),
+            pull_request_id,
+            identity_id: None,
+            poll_interval: DEFAULT_MERGE_POLL_INTERVAL,
+        }
+    }
+
+    /// A handle to a pull request returned by the service.
+    pub fn from_pull_request(
+        client: &Client,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+        pull_request: &GitPullRequest,
+    ) -> Self {
+        Self::new(
+            client,
+            organization,
+            project,
+            pull_request.repository.id.clone(),
+            pull_request.pull_request_id,
+        )
+    }
+
+    /// Creates a pull request and returns a handle to it.
+    pub async fn create(
+        client: &Client,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+        repository: impl Into<String>,
+        create_options: impl Into<GitPullRequestCreateOptions>,
+    ) -> azure_core::Result<Self> {
+        let organization = organization.into();
+        let project = project.into();
+        let pull_request = client
+            .pull_requests_client()
+            .create(&organization, repository, &project, create_options)
+            .await?;
+        Ok(Self::from_pull_request(
+            client,
+            organization,
+            project,
+            &pull_request,
+        ))
+    }
+
+    /// The ID of the authenticated identity, which votes and sets auto-complete.
+    ///
+    /// Must be set before calling [`vote()`](Self::vote) or
+    /// [`set_auto_complete()`](Self::set_auto_complete).
+    #[must_use]
+    pub fn identity(mut self, identity_id: impl Into<String>) -> Self {
+        self.identity_id = Some(identity_id.into());
+        self
+    }
+
+    /// The interval between requests made by [`wait_for_merge()`](Self::wait_for_merge).
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn pull_request_id(&self) -> i32 {
+        self.pull_request_id
+    }
+
+    /// Gets the current state of the pull request.
+    pub async fn get(&self) -> azure_core::Result<GitPullRequest> {
+        self.client
+            .pull_requests_client()
+            .get_pull_request(
+                &self.organization,
+                &self.repository,
+                self.pull_request_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// Enables auto-complete, so that the pull request is completed with `options` once all
+    /// required policies pass.
+    ///
+    /// Auto-complete is set by the handle's [`identity()`](Self::identity), which must be set.
+    pub async fn set_auto_complete(
+        &self,
+        options: impl Into<CompletionOptions>,
+    ) -> azure_core::Result<GitPullRequest> {
+        let identity_id = self.identity_id()?;
+        let mut update = GitPullRequestUpdateOptions::new();
+        update.auto_complete_set_by = Some(IdentityRef::new(identity_id));
+        update.completion_options = Some(options.into().into());
+        self.update(update).await
+    }
+
+    /// Votes on the pull request as the handle's [`identity()`](Self::identity), which must be set.
+    pub async fn vote(&self, vote: Vote) -> azure_core::Result<IdentityRefWithVote> {
+        let identity_id = self.identity_id()?;
+        let mut reviewer = IdentityRefWithVote::new(IdentityRef::new(identity_id.clone()));
+        reviewer.vote = Some(vote.value());
+        self.create_reviewer(reviewer, identity_id).await
+    }
+
+    /// Adds a required reviewer, or makes an existing reviewer required.
+    pub async fn add_required_reviewer(
+        &self,
+        reviewer_id: impl Into<String>,
+    ) -> azure_core::Result<IdentityRefWithVote> {
+        let reviewer_id = reviewer_id.into();
+        let mut reviewer = IdentityRefWithVote::new(IdentityRef::new(reviewer_id.clone()));
+        reviewer.is_required = Some(true);
+        self.create_reviewer(reviewer, reviewer_id).await
+    }
+
+    /// Abandons the pull request.
+    pub async fn abandon(&self) -> azure_core::Result<GitPullRequest> {
+        let mut update = GitPullRequestUpdateOptions::new();
+        update.status = Some(PullRequestStatus::Abandoned);
+        self.update(update).await
+    }
+
+    /// Completes the pull request, merging the current head of the source branch.
+    ///
+    /// The merge runs asynchronously: use [`wait_for_merge()`](Self::wait_for_merge) to wait
+    /// for it to finish.
+    pub async fn complete(
+        &self,
+        options: impl Into<CompletionOptions>,
+    ) -> azure_core::Result<GitPullRequest> {
+        let pull_request = self.get().await?;
+        let source_commit_id = pull_request
+            .last_merge_source_commit
+            .and_then(|commit| commit.commit_id)
+            .ok_or_else(|| {
+                Error::message(
+                    ErrorKind::Other,
+                    format!(
+                        "pull request {} has no source commit to merge",
+                        self.pull_request_id
+                    ),
+                )
+            })?;
+        let mut last_merge_source_commit = GitCommitRef::new();
+        last_merge_source_commit.commit_id = Some(source_commit_id);
+        let mut update = GitPullRequestUpdateOptions::new();
+        update.status = Some(PullRequestStatus::Completed);
+        update.last_merge_source_commit = Some(last_merge_source_commit);
+        update.completion_options = Some(options.into().into());
+        self.update(update).await
+    }
+
+    /// Waits until the pull request is completed, polling every
+    /// [`poll_interval()`](Self::poll_interval).
+    ///
+    /// Fails if the pull request is abandoned, or cannot be merged because of conflicts, a
+    /// policy or a merge failure. There is no timeout: after
+    /// [`set_auto_complete()`](Self::set_auto_complete) this waits for the required policies
+    /// and reviewers.
+    pub async fn wait_for_merge(&self) -> azure_core::Result<GitPullRequest> {
+        loop {
+            let pull_request = self.get().await?;
+            match merge_outcome(
+                &pull_request.status,
+                pull_request.merge_status.as_ref(),
+                pull_request.merge_failure_message.as_deref(),
+            ) {
+                Some(Ok(())) => return Ok(pull_request),
+                Some(Err(message)) => {
+                    return Err(Error::message(
+                        ErrorKind::Other,
+                        format!("pull request {} {message}", self.pull_request_id),
+                    ))
+                }
+                None => azure_core::sleep::sleep(self.poll_interval).await,
+            }
+        }
+    }
+
+    async fn update(
+        &self,
+        update: GitPullRequestUpdateOptions,
+    ) -> azure_core::Result<GitPullRequest> {
+        self.client
+            .pull_requests_client()
+            .update(
+                &self.organization,
+                &self.repository,
+                &self.project,
+                self.pull_request_id,
+                update,
+            )
+            .await
+    }
+
+    async fn create_reviewer(
+        &self,
+        reviewer: IdentityRefWithVote,
+        reviewer_id: String,
+    ) -> azure_core::Result<IdentityRefWithVote> {
+        self.client
+            .pull_request_reviewers_client()
+            .create_pull_request_reviewer(
+                &self.organization,
+                reviewer,
+                &self.repository,
+                self.pull_request_id,
+                reviewer_id,
+                &self.project,
+            )
+            .await
+    }
+
+    /// The identity set with [`identity()`](Self::identity).
+    fn identity_id(&self) -> azure_core::Result<String> {
+        self.identity_id.clone().ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                "identity() must be set before voting or setting auto-complete",
+            )
+        })
+    }
+}
+
+/// Whether a pull request has finished merging: `Ok` if it is completed, or `Err` with the
+/// reason it cannot be merged. `None` while the merge is pending.
+fn merge_outcome(
+    status: &Status,
+    merge_status: Option<&MergeStatus>,
+    merge_failure_message: Option<&str>,
+) -> Option<Result<(), String>> {
+    match status {
+        Status::Completed => return Some(Ok(())),
+        Status::Abandoned => return Some(Err("was abandoned".to_string())),
+        _ => {}
+    }
+    let reason = match merge_status {
+        Some(MergeStatus::Conflicts) => "has merge conflicts",
+        Some(MergeStatus::RejectedByPolicy) => "was rejected by policy",
+        Some(MergeStatus::Failure) => "failed to merge",
+        _ => return None,
+    };
+    Some(Err(match merge_failure_message {
+        Some(message) => format!("{reason}: {message}"),
+        None => reason.to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_vote() {
+        for vote in [
+            Vote::Approve,
+            Vote::ApproveWithSuggestions,
+            Vote::NoVote,
+            Vote::WaitForAuthor,
+            Vote::Reject,
+        ] {
+            assert_eq!(Vote::from_value(vote.into()), Some(vote));
+        }
+        assert_eq!(Vote::Approve.value(), 10);
+        assert_eq!(Vote::Reject.value(), -10);
+        assert_eq!(Vote::from_value(1), None);
+    }
+
+    #[test]
+    fn test_completion_options() {
+        let options: GitPullRequestCompletionOptions =
+            CompletionOptions::new(MergeStrategy::Squash)
+                .delete_source_branch(true)
+                .transition_work_items(true)
+                .merge_commit_message("Merged PR 1")
+                .into();
+        assert_eq!(
+            serde_json::to_value(options).unwrap(),
+            json!({
+                "bypassPolicy": false,
+                "deleteSourceBranch": true,
+                "mergeCommitMessage": "Merged PR 1",
+                "mergeStrategy": "squash",
+                "transitionWorkItems": true,
+            })
+        );
+
+        let options: GitPullRequestCompletionOptions =
+            CompletionOptions::from(MergeStrategy::RebaseMerge)
+                .bypass_policy("hotfix")
+                .into();
+        assert_eq!(options.merge_strategy, Some(MergeStrategy::RebaseMerge));
+        assert_eq!(options.bypass_policy, Some(true));
+        assert_eq!(options.bypass_reason.as_deref(), Some("hotfix"));
+    }
+
+    #[test]
+    fn test_merge_outcome() {
+        assert_eq!(merge_outcome(&Status::Completed, None, None), Some(Ok(())));
+        assert_eq!(
+            merge_outcome(&Status::Abandoned, None, None),
+            Some(Err("was abandoned".to_string()))
+        );
+        assert_eq!(
+            merge_outcome(&Status::Active, Some(&MergeStatus::Queued), None),
+            None
+        );
+        assert_eq!(
+            merge_outcome(&Status::Active, Some(&MergeStatus::Succeeded), None),
+            None
+        );
+        assert_eq!(
+            merge_outcome(
+                &Status::Active,
+                Some(&MergeStatus::Conflicts),
+                Some("README.md")
+            ),
+            Some(Err("has merge conflicts: README.md".to_string()))
+        );
+        assert_eq!(
+            merge_outcome(&Status::Active, Some(&MergeStatus::RejectedByPolicy), None),
+            Some(Err("was rejected by policy".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_identity_required() {
+        let client = crate::git::ClientBuilder::new(crate::Credential::from_pat("abc")).build();
+        let pr = PullRequestHandle::new(&client, "org", "project", "repo", 1);
+        let err = futures::executor::block_on(pr.vote(Vote::Approve)).unwrap_err();
+        assert!(err.to_string().contains("identity()"));
+        let err =
+            futures::executor::block_on(pr.set_auto_complete(MergeStrategy::Squash)).unwrap_err();
+        assert!(err.to_string().contains("identity()"));
+        assert_eq!(pr.identity("id").identity_id().unwrap(), "id");
+    }
+}
@@ -6,7 +6,9 @@
 #![allow(clippy::redundant_clone)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::module_inception)]
+pub mod helpers;
 pub mod models;
+pub use helpers::*;
 #[derive(Clone)]
 pub struct Client {
     endpoint: azure_core::Url,
@@ -119,8 +121,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +131,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn annotated_tags_client(&self) -> annotated_tags::Client {
         annotated_tags::Client(self.clone())
     }
@@ -240,6 +269,276 @@ impl Client {
         trees::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn annotated_tags_client(&self) -> annotated_tags::OrganizationClient {
+        annotated_tags::OrganizationClient(self.clone())
+    }
+    pub fn blobs_client(&self) -> blobs::OrganizationClient {
+        blobs::OrganizationClient(self.clone())
+    }
+    pub fn cherry_picks_client(&self) -> cherry_picks::OrganizationClient {
+        cherry_picks::OrganizationClient(self.clone())
+    }
+    pub fn commits_client(&self) -> commits::OrganizationClient {
+        commits::OrganizationClient(self.clone())
+    }
+    pub fn diffs_client(&self) -> diffs::OrganizationClient {
+        diffs::OrganizationClient(self.clone())
+    }
+    pub fn forks_client(&self) -> forks::OrganizationClient {
+        forks::OrganizationClient(self.clone())
+    }
+    pub fn import_requests_client(&self) -> import_requests::OrganizationClient {
+        import_requests::OrganizationClient(self.clone())
+    }
+    pub fn items_client(&self) -> items::OrganizationClient {
+        items::OrganizationClient(self.clone())
+    }
+    pub fn merge_bases_client(&self) -> merge_bases::OrganizationClient {
+        merge_bases::OrganizationClient(self.clone())
+    }
+    pub fn merges_client(&self) -> merges::OrganizationClient {
+        merges::OrganizationClient(self.clone())
+    }
+    pub fn policy_configurations_client(&self) -> policy_configurations::OrganizationClient {
+        policy_configurations::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_attachments_client(&self) -> pull_request_attachments::OrganizationClient {
+        pull_request_attachments::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_comment_likes_client(
+        &self,
+    ) -> pull_request_comment_likes::OrganizationClient {
+        pull_request_comment_likes::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_commits_client(&self) -> pull_request_commits::OrganizationClient {
+        pull_request_commits::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_iteration_changes_client(
+        &self,
+    ) -> pull_request_iteration_changes::OrganizationClient {
+        pull_request_iteration_changes::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_iteration_statuses_client(
+        &self,
+    ) -> pull_request_iteration_statuses::OrganizationClient {
+        pull_request_iteration_statuses::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_iterations_client(&self) -> pull_request_iterations::OrganizationClient {
+        pull_request_iterations::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_labels_client(&self) -> pull_request_labels::OrganizationClient {
+        pull_request_labels::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_properties_client(&self) -> pull_request_properties::OrganizationClient {
+        pull_request_properties::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_query_client(&self) -> pull_request_query::OrganizationClient {
+        pull_request_query::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_reviewers_client(&self) -> pull_request_reviewers::OrganizationClient {
+        pull_request_reviewers::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_share_client(&self) -> pull_request_share::OrganizationClient {
+        pull_request_share::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_statuses_client(&self) -> pull_request_statuses::OrganizationClient {
+        pull_request_statuses::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_thread_comments_client(
+        &self,
+    ) -> pull_request_thread_comments::OrganizationClient {
+        pull_request_thread_comments::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_threads_client(&self) -> pull_request_threads::OrganizationClient {
+        pull_request_threads::OrganizationClient(self.clone())
+    }
+    pub fn pull_request_work_items_client(&self) -> pull_request_work_items::OrganizationClient {
+        pull_request_work_items::OrganizationClient(self.clone())
+    }
+    pub fn pull_requests_client(&self) -> pull_requests::OrganizationClient {
+        pull_requests::OrganizationClient(self.clone())
+    }
+    pub fn pushes_client(&self) -> pushes::OrganizationClient {
+        pushes::OrganizationClient(self.clone())
+    }
+    pub fn refs_client(&self) -> refs::OrganizationClient {
+        refs::OrganizationClient(self.clone())
+    }
+    pub fn refs_favorites_client(&self) -> refs_favorites::OrganizationClient {
+        refs_favorites::OrganizationClient(self.clone())
+    }
+    pub fn repositories_client(&self) -> repositories::OrganizationClient {
+        repositories::OrganizationClient(self.clone())
+    }
+    pub fn reverts_client(&self) -> reverts::OrganizationClient {
+        reverts::OrganizationClient(self.clone())
+    }
+    pub fn stats_client(&self) -> stats::OrganizationClient {
+        stats::OrganizationClient(self.clone())
+    }
+    pub fn statuses_client(&self) -> statuses::OrganizationClient {
+        statuses::OrganizationClient(self.clone())
+    }
+    pub fn suggestions_client(&self) -> suggestions::OrganizationClient {
+        suggestions::OrganizationClient(self.clone())
+    }
+    pub fn trees_client(&self) -> trees::OrganizationClient {
+        trees::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn annotated_tags_client(&self) -> annotated_tags::ProjectClient {
+        annotated_tags::ProjectClient(self.clone())
+    }
+    pub fn blobs_client(&self) -> blobs::ProjectClient {
+        blobs::ProjectClient(self.clone())
+    }
+    pub fn cherry_picks_client(&self) -> cherry_picks::ProjectClient {
+        cherry_picks::ProjectClient(self.clone())
+    }
+    pub fn commits_client(&self) -> commits::ProjectClient {
+        commits::ProjectClient(self.clone())
+    }
+    pub fn diffs_client(&self) -> diffs::ProjectClient {
+        diffs::ProjectClient(self.clone())
+    }
+    pub fn forks_client(&self) -> forks::ProjectClient {
+        forks::ProjectClient(self.clone())
+    }
+    pub fn import_requests_client(&self) -> import_requests::ProjectClient {
+        import_requests::ProjectClient(self.clone())
+    }
+    pub fn items_client(&self) -> items::ProjectClient {
+        items::ProjectClient(self.clone())
+    }
+    pub fn merge_bases_client(&self) -> merge_bases::ProjectClient {
+        merge_bases::ProjectClient(self.clone())
+    }
+    pub fn merges_client(&self) -> merges::ProjectClient {
+        merges::ProjectClient(self.clone())
+    }
+    pub fn policy_configurations_client(&self) -> policy_configurations::ProjectClient {
+        policy_configurations::ProjectClient(self.clone())
+    }
+    pub fn pull_request_attachments_client(&self) -> pull_request_attachments::ProjectClient {
+        pull_request_attachments::ProjectClient(self.clone())
+    }
+    pub fn pull_request_comment_likes_client(&self) -> pull_request_comment_likes::ProjectClient {
+        pull_request_comment_likes::ProjectClient(self.clone())
+    }
+    pub fn pull_request_commits_client(&self) -> pull_request_commits::ProjectClient {
+        pull_request_commits::ProjectClient(self.clone())
+    }
+    pub fn pull_request_iteration_changes_client(
+        &self,
+    ) -> pull_request_iteration_changes::ProjectClient {
+        pull_request_iteration_changes::ProjectClient(self.clone())
+    }
+    pub fn pull_request_iteration_statuses_client(
+        &self,
+    ) -> pull_request_iteration_statuses::ProjectClient {
+        pull_request_iteration_statuses::ProjectClient(self.clone())
+    }
+    pub fn pull_request_iterations_client(&self) -> pull_request_iterations::ProjectClient {
+        pull_request_iterations::ProjectClient(self.clone())
+    }
+    pub fn pull_request_labels_client(&self) -> pull_request_labels::ProjectClient {
+        pull_request_labels::ProjectClient(self.clone())
+    }
+    pub fn pull_request_properties_client(&self) -> pull_request_properties::ProjectClient {
+        pull_request_properties::ProjectClient(self.clone())
+    }
+    pub fn pull_request_query_client(&self) -> pull_request_query::ProjectClient {
+        pull_request_query::ProjectClient(self.clone())
+    }
+    pub fn pull_request_reviewers_client(&self) -> pull_request_reviewers::ProjectClient {
+        pull_request_reviewers::ProjectClient(self.clone())
+    }
+    pub fn pull_request_share_client(&self) -> pull_request_share::ProjectClient {
+        pull_request_share::ProjectClient(self.clone())
+    }
+    pub fn pull_request_statuses_client(&self) -> pull_request_statuses::ProjectClient {
+        pull_request_statuses::ProjectClient(self.clone())
+    }
+    pub fn pull_request_thread_comments_client(
+        &self,
+    ) -> pull_request_thread_comments::ProjectClient {
+        pull_request_thread_comments::ProjectClient(self.clone())
+    }
+    pub fn pull_request_threads_client(&self) -> pull_request_threads::ProjectClient {
+        pull_request_threads::ProjectClient(self.clone())
+    }
+    pub fn pull_request_work_items_client(&self) -> pull_request_work_items::ProjectClient {
+        pull_request_work_items::ProjectClient(self.clone())
+    }
+    pub fn pull_requests_client(&self) -> pull_requests::ProjectClient {
+        pull_requests::ProjectClient(self.clone())
+    }
+    pub fn pushes_client(&self) -> pushes::ProjectClient {
+        pushes::ProjectClient(self.clone())
+    }
+    pub fn refs_client(&self) -> refs::ProjectClient {
+        refs::ProjectClient(self.clone())
+    }
+    pub fn refs_favorites_client(&self) -> refs_favorites::ProjectClient {
+        refs_favorites::ProjectClient(self.clone())
+    }
+    pub fn repositories_client(&self) -> repositories::ProjectClient {
+        repositories::ProjectClient(self.clone())
+    }
+    pub fn reverts_client(&self) -> reverts::ProjectClient {
+        reverts::ProjectClient(self.clone())
+    }
+    pub fn stats_client(&self) -> stats::ProjectClient {
+        stats::ProjectClient(self.clone())
+    }
+    pub fn statuses_client(&self) -> statuses::ProjectClient {
+        statuses::ProjectClient(self.clone())
+    }
+    pub fn suggestions_client(&self) -> suggestions::ProjectClient {
+        suggestions::ProjectClient(self.clone())
+    }
+    pub fn trees_client(&self) -> trees::ProjectClient {
+        trees::ProjectClient(self.clone())
+    }
+}
 pub mod repositories {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -442,118 +741,386 @@ pub mod repositories {
             }
         }
     }
-    pub mod get_repository_with_parent {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitRepository> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitRepository = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `include_parent`: Set to true to include parent repository. Only available in authenticated calls."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_repository_with_parent(
+            &self,
+            repository_id: impl Into<String>,
+            include_parent: bool,
+            project: impl Into<String>,
+        ) -> get_repository_with_parent::RequestBuilder {
+            Client(self.0.client.clone()).get_repository_with_parent(
+                self.0.organization.clone(),
+                repository_id,
+                include_parent,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Retrieve deleted git repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_repositories(
+            &self,
+            project: impl Into<String>,
+        ) -> get_deleted_repositories::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_deleted_repositories(self.0.organization.clone(), project)
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) repository_id: String,
-            pub(crate) include_parent: bool,
-            pub(crate) project: String,
+        #[doc = "Retrieve soft-deleted git repositories from the recycle bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_recycle_bin_repositories(
+            &self,
+            project: impl Into<String>,
+        ) -> get_recycle_bin_repositories::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_recycle_bin_repositories(self.0.organization.clone(), project)
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = this.url()?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        if let Some(auth_header) = this
-                            .client
-                            .token_credential()
-                            .http_authorization_header(&this.client.scopes())
-                            .await?
-                        {
-                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
-                        }
-                        let include_parent = &this.include_parent;
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair("includeParent", &include_parent.to_string());
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
-            }
-            fn url(&self) -> azure_core::Result<azure_core::Url> {
-                let mut url = azure_core::Url::parse(&format!(
-                    "{}/{}/{}/_apis/git/repositories/{}?includeParent={}",
-                    self.client.endpoint(),
-                    &self.organization,
-                    &self.project,
-                    &self.repository_id,
-                    &self.include_parent
-                ))?;
-                let has_api_version_already = url
-                    .query_pairs()
-                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                if !has_api_version_already {
-                    url.query_pairs_mut()
-                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
-                }
-                Ok(url)
-            }
+        #[doc = "Recover a soft-deleted Git repository. Recently deleted repositories go into a soft-delete state for a period of time before they are hard deleted and become unrecoverable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn restore_repository_from_recycle_bin(
+            &self,
+            body: impl Into<models::GitRecycleBinRepositoryDetails>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> restore_repository_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_repository_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
         }
-        impl std::future::IntoFuture for RequestBuilder {
+        #[doc = "Destroy (hard delete) a soft-deleted Git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete_repository_from_recycle_bin(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> delete_repository_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_repository_from_recycle_bin(
+                self.0.organization.clone(),
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve git repositories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a git repository in a team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify the repo name, team project and/or parent repository. Team project information can be omitted from gitRepositoryToCreate if the request is project-scoped (i.e., includes project Id)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRepositoryCreateOptions>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_repository(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_repository::RequestBuilder {
+            Client(self.0.client.clone()).get_repository(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Updates the Git repository with either a new repo name or a new default branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify a new repo name or a new default branch of the repository"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitRepository>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Delete a git repository"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `include_parent`: Set to true to include parent repository. Only available in authenticated calls."]
+        pub fn get_repository_with_parent(
+            &self,
+            repository_id: impl Into<String>,
+            include_parent: bool,
+        ) -> get_repository_with_parent::RequestBuilder {
+            Client(self.0.client.clone()).get_repository_with_parent(
+                self.0.organization.clone(),
+                repository_id,
+                include_parent,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve deleted git repositories."]
+        pub fn get_deleted_repositories(&self) -> get_deleted_repositories::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_deleted_repositories(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Retrieve soft-deleted git repositories from the recycle bin."]
+        pub fn get_recycle_bin_repositories(&self) -> get_recycle_bin_repositories::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_recycle_bin_repositories(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Recover a soft-deleted Git repository. Recently deleted repositories go into a soft-delete state for a period of time before they are hard deleted and become unrecoverable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn restore_repository_from_recycle_bin(
+            &self,
+            body: impl Into<models::GitRecycleBinRepositoryDetails>,
+            repository_id: impl Into<String>,
+        ) -> restore_repository_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).restore_repository_from_recycle_bin(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Destroy (hard delete) a soft-deleted Git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete_repository_from_recycle_bin(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> delete_repository_from_recycle_bin::RequestBuilder {
+            Client(self.0.client.clone()).delete_repository_from_recycle_bin(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve git repositories."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a git repository in a team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify the repo name, team project and/or parent repository. Team project information can be omitted from gitRepositoryToCreate if the request is project-scoped (i.e., includes project Id)."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRepositoryCreateOptions>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a git repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_repository(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> get_repository::RequestBuilder {
+            Client(self.0.client.clone()).get_repository(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Updates the Git repository with either a new repo name or a new default branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Specify a new repo name or a new default branch of the repository"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitRepository>,
+            repository_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a git repository"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The ID of the repository."]
+        pub fn delete(&self, repository_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod get_repository_with_parent {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitRepository> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitRepository = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) include_parent: bool,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        let include_parent = &this.include_parent;
+                        req.url_mut()
+                            .query_pairs_mut()
+                            .append_pair("includeParent", &include_parent.to_string());
+                        let req_body = azure_core::EMPTY_BODY;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/{}/_apis/git/repositories/{}?includeParent={}",
+                    self.client.endpoint(),
+                    &self.organization,
+                    &self.project,
+                    &self.repository_id,
+                    &self.include_parent
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitRepository>;
             type IntoFuture = BoxFuture<'static, azure_core::Result<models::GitRepository>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
@@ -1802,11 +2369,188 @@ pub mod commits {
             }
         }
     }
-    pub mod get_commits {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve git commits for a project\n\nParameters that use the searchCriteria prefix in their name can be specified without it as query parameters, e.g. searchCriteria.$top -> $top"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_commits(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_commits(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a list of commits associated with a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `push_id`: The id of the push."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_push_commits(
+            &self,
+            repository_id: impl Into<String>,
+            push_id: i32,
+            project: impl Into<String>,
+        ) -> get_push_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_push_commits(
+                self.0.organization.clone(),
+                repository_id,
+                push_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve changes for a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changes(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_changes(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve git commits for a project matching the search criteria"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Search options"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_commits_batch(
+            &self,
+            body: impl Into<models::GitQueryCommitsCriteria>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_commits_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_commits_batch(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve git commits for a project\n\nParameters that use the searchCriteria prefix in their name can be specified without it as query parameters, e.g. searchCriteria.$top -> $top"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get_commits(&self, repository_id: impl Into<String>) -> get_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_commits(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a list of commits associated with a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        #[doc = "* `push_id`: The id of the push."]
+        pub fn get_push_commits(
+            &self,
+            repository_id: impl Into<String>,
+            push_id: i32,
+        ) -> get_push_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_push_commits(
+                self.0.organization.clone(),
+                repository_id,
+                push_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve changes for a particular commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: The id of the commit."]
+        #[doc = "* `repository_id`: The id or friendly name of the repository. To use the friendly name, projectId must also be specified."]
+        pub fn get_changes(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> get_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_changes(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve git commits for a project matching the search criteria"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Search options"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_commits_batch(
+            &self,
+            body: impl Into<models::GitQueryCommitsCriteria>,
+            repository_id: impl Into<String>,
+        ) -> get_commits_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_commits_batch(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod get_commits {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
         use futures::future::LocalBoxFuture as BoxFuture;
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
@@ -1843,6 +2587,106 @@ pub mod commits {
                 self.as_raw_response()
             }
         }
+        #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaCompareVersionVersionOptions {
+            None,
+            PreviousChange,
+            FirstParent,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaCompareVersionVersionOptions {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::PreviousChange => "previousChange",
+                    Self::FirstParent => "firstParent",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaCompareVersionVersionOptions {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaCompareVersionVersionType {
+            Branch,
+            Tag,
+            Commit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaCompareVersionVersionType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Branch => "branch",
+                    Self::Tag => "tag",
+                    Self::Commit => "commit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaCompareVersionVersionType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaItemVersionVersionOptions {
+            None,
+            PreviousChange,
+            FirstParent,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaItemVersionVersionOptions {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::PreviousChange => "previousChange",
+                    Self::FirstParent => "firstParent",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaItemVersionVersionOptions {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaItemVersionVersionType {
+            Branch,
+            Tag,
+            Commit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaItemVersionVersionType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Branch => "branch",
+                    Self::Tag => "tag",
+                    Self::Commit => "commit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaItemVersionVersionType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -1868,8 +2712,10 @@ pub mod commits {
             pub(crate) search_criteria_top: Option<i32>,
             pub(crate) search_criteria_author: Option<String>,
             pub(crate) search_criteria_compare_version_version: Option<String>,
-            pub(crate) search_criteria_compare_version_version_options: Option<String>,
-            pub(crate) search_criteria_compare_version_version_type: Option<String>,
+            pub(crate) search_criteria_compare_version_version_options:
+                Option<SearchCriteriaCompareVersionVersionOptions>,
+            pub(crate) search_criteria_compare_version_version_type:
+                Option<SearchCriteriaCompareVersionVersionType>,
             pub(crate) search_criteria_exclude_deletes: Option<bool>,
             pub(crate) search_criteria_from_commit_id: Option<String>,
             pub(crate) search_criteria_from_date: Option<String>,
@@ -1881,8 +2727,10 @@ pub mod commits {
             pub(crate) search_criteria_include_work_items: Option<bool>,
             pub(crate) search_criteria_item_path: Option<String>,
             pub(crate) search_criteria_item_version_version: Option<String>,
-            pub(crate) search_criteria_item_version_version_options: Option<String>,
-            pub(crate) search_criteria_item_version_version_type: Option<String>,
+            pub(crate) search_criteria_item_version_version_options:
+                Option<SearchCriteriaItemVersionVersionOptions>,
+            pub(crate) search_criteria_item_version_version_type:
+                Option<SearchCriteriaItemVersionVersionType>,
             pub(crate) search_criteria_show_oldest_commits_first: Option<bool>,
             pub(crate) search_criteria_to_commit_id: Option<String>,
             pub(crate) search_criteria_to_date: Option<String>,
@@ -1919,7 +2767,9 @@ pub mod commits {
             #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
             pub fn search_criteria_compare_version_version_options(
                 mut self,
-                search_criteria_compare_version_version_options: impl Into<String>,
+                search_criteria_compare_version_version_options: impl Into<
+                    SearchCriteriaCompareVersionVersionOptions,
+                >,
             ) -> Self {
                 self.search_criteria_compare_version_version_options =
                     Some(search_criteria_compare_version_version_options.into());
@@ -1928,7 +2778,9 @@ pub mod commits {
             #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
             pub fn search_criteria_compare_version_version_type(
                 mut self,
-                search_criteria_compare_version_version_type: impl Into<String>,
+                search_criteria_compare_version_version_type: impl Into<
+                    SearchCriteriaCompareVersionVersionType,
+                >,
             ) -> Self {
                 self.search_criteria_compare_version_version_type =
                     Some(search_criteria_compare_version_version_type.into());
@@ -2024,7 +2876,9 @@ pub mod commits {
             #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
             pub fn search_criteria_item_version_version_options(
                 mut self,
-                search_criteria_item_version_version_options: impl Into<String>,
+                search_criteria_item_version_version_options: impl Into<
+                    SearchCriteriaItemVersionVersionOptions,
+                >,
             ) -> Self {
                 self.search_criteria_item_version_version_options =
                     Some(search_criteria_item_version_version_options.into());
@@ -2033,7 +2887,9 @@ pub mod commits {
             #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
             pub fn search_criteria_item_version_version_type(
                 mut self,
-                search_criteria_item_version_version_type: impl Into<String>,
+                search_criteria_item_version_version_type: impl Into<
+                    SearchCriteriaItemVersionVersionType,
+                >,
             ) -> Self {
                 self.search_criteria_item_version_version_type =
                     Some(search_criteria_item_version_version_type.into());
@@ -2117,7 +2973,7 @@ pub mod commits {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.compareVersion.versionOptions",
-                                search_criteria_compare_version_version_options,
+                                search_criteria_compare_version_version_options.as_str(),
                             );
                         }
                         if let Some(search_criteria_compare_version_version_type) =
@@ -2125,7 +2981,7 @@ pub mod commits {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.compareVersion.versionType",
-                                search_criteria_compare_version_version_type,
+                                search_criteria_compare_version_version_type.as_str(),
                             );
                         }
                         if let Some(search_criteria_exclude_deletes) =
@@ -2207,7 +3063,7 @@ pub mod commits {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.itemVersion.versionOptions",
-                                search_criteria_item_version_version_options,
+                                search_criteria_item_version_version_options.as_str(),
                             );
                         }
                         if let Some(search_criteria_item_version_version_type) =
@@ -2215,7 +3071,7 @@ pub mod commits {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.itemVersion.versionType",
-                                search_criteria_item_version_version_type,
+                                search_criteria_item_version_version_type.as_str(),
                             );
                         }
                         if let Some(search_criteria_show_oldest_commits_first) =
@@ -2852,6 +3708,18 @@ pub mod commits {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitCommitRef> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -2907,6 +3775,7 @@ pub mod items {
                 include_content: None,
                 resolve_lfs: None,
                 sanitize: None,
+                accept: None,
             }
         }
         #[doc = "Get Item Metadata and/or Content for a collection of items. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
@@ -2962,6 +3831,107 @@ pub mod items {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content, which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `path`: The item path."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                path,
+                project,
+            )
+        }
+        #[doc = "Get Item Metadata and/or Content for a collection of items. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), repository_id, project)
+        }
+        #[doc = "Post for retrieving a creating a batch out of a set of items in a repo / project given a list of paths or a long path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request data attributes: ItemDescriptors, IncludeContentMetadata, LatestProcessedChange, IncludeLinks. ItemDescriptors: Collection of items to fetch, including path, version, and recursion level. IncludeContentMetadata: Whether to include metadata for all items LatestProcessedChange: Whether to include shallow ref to commit that last changed each item. IncludeLinks: Whether to include the _links field on the shallow references."]
+        #[doc = "* `repository_id`: The name or ID of the repository"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::GitItemRequestData>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_items_batch(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content, which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `path`: The item path."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                path,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get Item Metadata and/or Content for a collection of items. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Post for retrieving a creating a batch out of a set of items in a repo / project given a list of paths or a long path"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request data attributes: ItemDescriptors, IncludeContentMetadata, LatestProcessedChange, IncludeLinks. ItemDescriptors: Collection of items to fetch, including path, version, and recursion level. IncludeContentMetadata: Whether to include metadata for all items LatestProcessedChange: Whether to include shallow ref to commit that last changed each item. IncludeLinks: Whether to include the _links field on the shallow references."]
+        #[doc = "* `repository_id`: The name or ID of the repository"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::GitItemRequestData>,
+            repository_id: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_items_batch(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2985,6 +3955,27 @@ pub mod items {
                 })?;
                 Ok(body)
             }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -3002,6 +3993,33 @@ pub mod items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The recursion level of this request. The default is 'none', no recursion."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum RecursionLevel {
+            None,
+            OneLevel,
+            OneLevelPlusNestedEmptyFolders,
+            Full,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl RecursionLevel {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::OneLevel => "oneLevel",
+                    Self::OneLevelPlusNestedEmptyFolders => "oneLevelPlusNestedEmptyFolders",
+                    Self::Full => "full",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for RecursionLevel {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3025,7 +4043,7 @@ pub mod items {
             pub(crate) path: String,
             pub(crate) project: String,
             pub(crate) scope_path: Option<String>,
-            pub(crate) recursion_level: Option<String>,
+            pub(crate) recursion_level: Option<RecursionLevel>,
             pub(crate) include_content_metadata: Option<bool>,
             pub(crate) latest_processed_change: Option<bool>,
             pub(crate) download: Option<bool>,
@@ -3036,6 +4054,7 @@ pub mod items {
             pub(crate) include_content: Option<bool>,
             pub(crate) resolve_lfs: Option<bool>,
             pub(crate) sanitize: Option<bool>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The path scope.  The default is null."]
@@ -3044,7 +4063,7 @@ pub mod items {
                 self
             }
             #[doc = "The recursion level of this request. The default is 'none', no recursion."]
-            pub fn recursion_level(mut self, recursion_level: impl Into<String>) -> Self {
+            pub fn recursion_level(mut self, recursion_level: impl Into<RecursionLevel>) -> Self {
                 self.recursion_level = Some(recursion_level.into());
                 self
             }
@@ -3108,6 +4127,11 @@ pub mod items {
                 self.sanitize = Some(sanitize);
                 self
             }
+            #[doc = "The content type of the response: one of `application/json`, `application/octet-stream`, `application/zip`, `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3136,7 +4160,7 @@ pub mod items {
                         if let Some(recursion_level) = &this.recursion_level {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("recursionLevel", recursion_level);
+                                .append_pair("recursionLevel", recursion_level.as_str());
                         }
                         if let Some(include_content_metadata) = &this.include_content_metadata {
                             req.url_mut().query_pairs_mut().append_pair(
@@ -3197,6 +4221,9 @@ pub mod items {
                                 .query_pairs_mut()
                                 .append_pair("sanitize", &sanitize.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3275,6 +4302,33 @@ pub mod items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The recursion level of this request. The default is 'none', no recursion."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum RecursionLevel {
+            None,
+            OneLevel,
+            OneLevelPlusNestedEmptyFolders,
+            Full,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl RecursionLevel {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::OneLevel => "oneLevel",
+                    Self::OneLevelPlusNestedEmptyFolders => "oneLevelPlusNestedEmptyFolders",
+                    Self::Full => "full",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for RecursionLevel {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3297,7 +4351,7 @@ pub mod items {
             pub(crate) repository_id: String,
             pub(crate) project: String,
             pub(crate) scope_path: Option<String>,
-            pub(crate) recursion_level: Option<String>,
+            pub(crate) recursion_level: Option<RecursionLevel>,
             pub(crate) include_content_metadata: Option<bool>,
             pub(crate) latest_processed_change: Option<bool>,
             pub(crate) download: Option<bool>,
@@ -3315,7 +4369,7 @@ pub mod items {
                 self
             }
             #[doc = "The recursion level of this request. The default is 'none', no recursion."]
-            pub fn recursion_level(mut self, recursion_level: impl Into<String>) -> Self {
+            pub fn recursion_level(mut self, recursion_level: impl Into<RecursionLevel>) -> Self {
                 self.recursion_level = Some(recursion_level.into());
                 self
             }
@@ -3400,7 +4454,7 @@ pub mod items {
                         if let Some(recursion_level) = &this.recursion_level {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("recursionLevel", recursion_level);
+                                .append_pair("recursionLevel", recursion_level.as_str());
                         }
                         if let Some(include_content_metadata) = &this.include_content_metadata {
                             req.url_mut().query_pairs_mut().append_pair(
@@ -3670,36 +4724,101 @@ pub mod stats {
             }
         }
     }
-    pub mod get {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitBranchStats> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitBranchStats = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve statistics about a single branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `name`: Name of the branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                name,
+                project,
+            )
+        }
+        #[doc = "Retrieve statistics about all branches within a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), repository_id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve statistics about a single branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `name`: Name of the branch."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve statistics about all branches within a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod get {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitBranchStats> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitBranchStats = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
                 rsp.into_raw_response()
@@ -4102,6 +5221,88 @@ pub mod refs_favorites {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the refs favorites for a repo and an identity."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Creates a ref favorite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref favorite to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitRefFavorite>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets the refs favorite for a favorite Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `favorite_id`: The Id of the requested ref favorite."]
+        pub fn get(&self, project: impl Into<String>, favorite_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, favorite_id)
+        }
+        #[doc = "Deletes the refs favorite specified"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `favorite_id`: The Id of the ref favorite to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            favorite_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, favorite_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the refs favorites for a repo and an identity."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Creates a ref favorite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref favorite to create."]
+        pub fn create(&self, body: impl Into<models::GitRefFavorite>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets the refs favorite for a favorite Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `favorite_id`: The Id of the requested ref favorite."]
+        pub fn get(&self, favorite_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                favorite_id,
+            )
+        }
+        #[doc = "Deletes the refs favorite specified"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `favorite_id`: The Id of the ref favorite to delete."]
+        pub fn delete(&self, favorite_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                favorite_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4615,6 +5816,23 @@ pub mod policy_configurations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve a list of policy configurations by a given set of scope/filtering criteria.\n\nBelow is a short description of how all of the query parameters interact with each other:\n- repositoryId set, refName set: returns all policy configurations that *apply* to a particular branch in a repository\n- repositoryId set, refName unset: returns all policy configurations that *apply* to a particular repository\n- repositoryId unset, refName unset: returns all policy configurations that are *defined* at the project level\n- repositoryId unset, refName set: returns all project-level branch policies, plus the project level configurations\nFor all of the examples above, when policyType is set, it'll restrict results to the given policy type"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve a list of policy configurations by a given set of scope/filtering criteria.\n\nBelow is a short description of how all of the query parameters interact with each other:\n- repositoryId set, refName set: returns all policy configurations that *apply* to a particular branch in a repository\n- repositoryId set, refName unset: returns all policy configurations that *apply* to a particular repository\n- repositoryId unset, refName unset: returns all policy configurations that are *defined* at the project level\n- repositoryId unset, refName set: returns all project-level branch policies, plus the project level configurations\nFor all of the examples above, when policyType is set, it'll restrict results to the given policy type"]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4645,7 +5863,7 @@ pub mod policy_configurations {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -4785,6 +6003,20 @@ pub mod policy_configurations {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::PolicyConfiguration> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -4969,87 +6201,336 @@ pub mod pull_requests {
             }
         }
     }
-    pub mod get_pull_requests_by_project {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_requests_by_project(
+            &self,
+            project: impl Into<String>,
+        ) -> get_pull_requests_by_project::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_pull_requests_by_project(self.0.organization.clone(), project)
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_by_id(
+            &self,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_by_id(
+                self.0.organization.clone(),
+                pull_request_id,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_requests(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_pull_requests::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_requests(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) project: String,
-            pub(crate) search_criteria_creator_id: Option<String>,
-            pub(crate) search_criteria_include_links: Option<bool>,
-            pub(crate) search_criteria_max_time: Option<time::OffsetDateTime>,
-            pub(crate) search_criteria_min_time: Option<time::OffsetDateTime>,
-            pub(crate) search_criteria_query_time_range_type: Option<String>,
-            pub(crate) search_criteria_repository_id: Option<String>,
-            pub(crate) search_criteria_reviewer_id: Option<String>,
-            pub(crate) search_criteria_source_ref_name: Option<String>,
-            pub(crate) search_criteria_source_repository_id: Option<String>,
-            pub(crate) search_criteria_status: Option<String>,
-            pub(crate) search_criteria_target_ref_name: Option<String>,
-            pub(crate) max_comment_length: Option<i32>,
-            pub(crate) skip: Option<i32>,
-            pub(crate) top: Option<i32>,
+        #[doc = "Create a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `create_options`: The pull request to create."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+            create_options: impl Into<models::GitPullRequestCreateOptions>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+                create_options,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "If set, search for pull requests that were created by this identity."]
-            pub fn search_criteria_creator_id(
-                mut self,
-                search_criteria_creator_id: impl Into<String>,
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Update a pull request\n\nThese are the properties that can be updated with the API:\n - Status\n - Title\n - Description (up to 4000 characters)\n - CompletionOptions\n - MergeOptions\n - AutoCompleteSetBy.Id\n - TargetRefName (when the PR retargeting feature is enabled)\n Attempting to update other properties outside of this list will either cause the server to throw an `InvalidArgumentValueException`,\n or to silently ignore the update."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `update_options`: The pull request content to update."]
+        pub fn update(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+            pull_request_id: i32,
+            update_options: impl Into<models::GitPullRequestUpdateOptions>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                repository_id,
+                project,
+                pull_request_id,
+                update_options,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        pub fn get_pull_requests_by_project(&self) -> get_pull_requests_by_project::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_pull_requests_by_project(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        pub fn get_pull_request_by_id(
+            &self,
+            pull_request_id: i32,
+        ) -> get_pull_request_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_by_id(
+                self.0.organization.clone(),
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve all pull requests matching a specified criteria.\n\nPlease note that description field will be truncated up to 400 symbols in the result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        pub fn get_pull_requests(
+            &self,
+            repository_id: impl Into<String>,
+        ) -> get_pull_requests::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_requests(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `create_options`: The pull request to create."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            create_options: impl Into<models::GitPullRequestCreateOptions>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+                create_options,
+            )
+        }
+        #[doc = "Retrieve a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        pub fn get_pull_request(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update a pull request\n\nThese are the properties that can be updated with the API:\n - Status\n - Title\n - Description (up to 4000 characters)\n - CompletionOptions\n - MergeOptions\n - AutoCompleteSetBy.Id\n - TargetRefName (when the PR retargeting feature is enabled)\n Attempting to update other properties outside of this list will either cause the server to throw an `InvalidArgumentValueException`,\n or to silently ignore the update."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: The ID of the pull request to retrieve."]
+        #[doc = "* `update_options`: The pull request content to update."]
+        pub fn update(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            update_options: impl Into<models::GitPullRequestUpdateOptions>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+                pull_request_id,
+                update_options,
+            )
+        }
+    }
+    pub mod get_pull_requests_by_project {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaStatus {
+            NotSet,
+            Active,
+            Abandoned,
+            Completed,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaStatus {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::NotSet => "notSet",
+                    Self::Active => "active",
+                    Self::Abandoned => "abandoned",
+                    Self::Completed => "completed",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaStatus {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "The type of time range which should be used for minTime and maxTime. Defaults to Created if unset."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaQueryTimeRangeType {
+            Created,
+            Closed,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaQueryTimeRangeType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Created => "created",
+                    Self::Closed => "closed",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaQueryTimeRangeType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) project: String,
+            pub(crate) search_criteria_creator_id: Option<String>,
+            pub(crate) search_criteria_include_links: Option<bool>,
+            pub(crate) search_criteria_max_time: Option<time::OffsetDateTime>,
+            pub(crate) search_criteria_min_time: Option<time::OffsetDateTime>,
+            pub(crate) search_criteria_query_time_range_type:
+                Option<SearchCriteriaQueryTimeRangeType>,
+            pub(crate) search_criteria_repository_id: Option<String>,
+            pub(crate) search_criteria_reviewer_id: Option<String>,
+            pub(crate) search_criteria_source_ref_name: Option<String>,
+            pub(crate) search_criteria_source_repository_id: Option<String>,
+            pub(crate) search_criteria_status: Option<SearchCriteriaStatus>,
+            pub(crate) search_criteria_target_ref_name: Option<String>,
+            pub(crate) max_comment_length: Option<i32>,
+            pub(crate) skip: Option<i32>,
+            pub(crate) top: Option<i32>,
+        }
+        impl RequestBuilder {
+            #[doc = "If set, search for pull requests that were created by this identity."]
+            pub fn search_criteria_creator_id(
+                mut self,
+                search_criteria_creator_id: impl Into<String>,
             ) -> Self {
                 self.search_criteria_creator_id = Some(search_criteria_creator_id.into());
                 self
@@ -5081,7 +6562,7 @@ pub mod pull_requests {
             #[doc = "The type of time range which should be used for minTime and maxTime. Defaults to Created if unset."]
             pub fn search_criteria_query_time_range_type(
                 mut self,
-                search_criteria_query_time_range_type: impl Into<String>,
+                search_criteria_query_time_range_type: impl Into<SearchCriteriaQueryTimeRangeType>,
             ) -> Self {
                 self.search_criteria_query_time_range_type =
                     Some(search_criteria_query_time_range_type.into());
@@ -5123,7 +6604,7 @@ pub mod pull_requests {
             #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
             pub fn search_criteria_status(
                 mut self,
-                search_criteria_status: impl Into<String>,
+                search_criteria_status: impl Into<SearchCriteriaStatus>,
             ) -> Self {
                 self.search_criteria_status = Some(search_criteria_status.into());
                 self
@@ -5186,13 +6667,13 @@ pub mod pull_requests {
                         if let Some(search_criteria_max_time) = &this.search_criteria_max_time {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.maxTime",
-                                &search_criteria_max_time.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_max_time)?,
                             );
                         }
                         if let Some(search_criteria_min_time) = &this.search_criteria_min_time {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.minTime",
-                                &search_criteria_min_time.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_min_time)?,
                             );
                         }
                         if let Some(search_criteria_query_time_range_type) =
@@ -5200,7 +6681,7 @@ pub mod pull_requests {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.queryTimeRangeType",
-                                search_criteria_query_time_range_type,
+                                search_criteria_query_time_range_type.as_str(),
                             );
                         }
                         if let Some(search_criteria_repository_id) =
@@ -5235,9 +6716,10 @@ pub mod pull_requests {
                             );
                         }
                         if let Some(search_criteria_status) = &this.search_criteria_status {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("searchCriteria.status", search_criteria_status);
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "searchCriteria.status",
+                                search_criteria_status.as_str(),
+                            );
                         }
                         if let Some(search_criteria_target_ref_name) =
                             &this.search_criteria_target_ref_name
@@ -5284,6 +6766,18 @@ pub mod pull_requests {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitPullRequest> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPullRequestList>;
@@ -5457,6 +6951,58 @@ pub mod pull_requests {
                 self.as_raw_response()
             }
         }
+        #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaStatus {
+            NotSet,
+            Active,
+            Abandoned,
+            Completed,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaStatus {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::NotSet => "notSet",
+                    Self::Active => "active",
+                    Self::Abandoned => "abandoned",
+                    Self::Completed => "completed",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaStatus {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "The type of time range which should be used for minTime and maxTime. Defaults to Created if unset."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum SearchCriteriaQueryTimeRangeType {
+            Created,
+            Closed,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl SearchCriteriaQueryTimeRangeType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Created => "created",
+                    Self::Closed => "closed",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for SearchCriteriaQueryTimeRangeType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -5482,12 +7028,13 @@ pub mod pull_requests {
             pub(crate) search_criteria_include_links: Option<bool>,
             pub(crate) search_criteria_max_time: Option<time::OffsetDateTime>,
             pub(crate) search_criteria_min_time: Option<time::OffsetDateTime>,
-            pub(crate) search_criteria_query_time_range_type: Option<String>,
+            pub(crate) search_criteria_query_time_range_type:
+                Option<SearchCriteriaQueryTimeRangeType>,
             pub(crate) search_criteria_repository_id: Option<String>,
             pub(crate) search_criteria_reviewer_id: Option<String>,
             pub(crate) search_criteria_source_ref_name: Option<String>,
             pub(crate) search_criteria_source_repository_id: Option<String>,
-            pub(crate) search_criteria_status: Option<String>,
+            pub(crate) search_criteria_status: Option<SearchCriteriaStatus>,
             pub(crate) search_criteria_target_ref_name: Option<String>,
             pub(crate) max_comment_length: Option<i32>,
             pub(crate) skip: Option<i32>,
@@ -5529,7 +7076,7 @@ pub mod pull_requests {
             #[doc = "The type of time range which should be used for minTime and maxTime. Defaults to Created if unset."]
             pub fn search_criteria_query_time_range_type(
                 mut self,
-                search_criteria_query_time_range_type: impl Into<String>,
+                search_criteria_query_time_range_type: impl Into<SearchCriteriaQueryTimeRangeType>,
             ) -> Self {
                 self.search_criteria_query_time_range_type =
                     Some(search_criteria_query_time_range_type.into());
@@ -5571,7 +7118,7 @@ pub mod pull_requests {
             #[doc = "If set, search for pull requests that are in this state. Defaults to Active if unset."]
             pub fn search_criteria_status(
                 mut self,
-                search_criteria_status: impl Into<String>,
+                search_criteria_status: impl Into<SearchCriteriaStatus>,
             ) -> Self {
                 self.search_criteria_status = Some(search_criteria_status.into());
                 self
@@ -5634,13 +7181,13 @@ pub mod pull_requests {
                         if let Some(search_criteria_max_time) = &this.search_criteria_max_time {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.maxTime",
-                                &search_criteria_max_time.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_max_time)?,
                             );
                         }
                         if let Some(search_criteria_min_time) = &this.search_criteria_min_time {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.minTime",
-                                &search_criteria_min_time.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_min_time)?,
                             );
                         }
                         if let Some(search_criteria_query_time_range_type) =
@@ -5648,7 +7195,7 @@ pub mod pull_requests {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.queryTimeRangeType",
-                                search_criteria_query_time_range_type,
+                                search_criteria_query_time_range_type.as_str(),
                             );
                         }
                         if let Some(search_criteria_repository_id) =
@@ -5683,9 +7230,10 @@ pub mod pull_requests {
                             );
                         }
                         if let Some(search_criteria_status) = &this.search_criteria_status {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("searchCriteria.status", search_criteria_status);
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "searchCriteria.status",
+                                search_criteria_status.as_str(),
+                            );
                         }
                         if let Some(search_criteria_target_ref_name) =
                             &this.search_criteria_target_ref_name
@@ -5733,6 +7281,18 @@ pub mod pull_requests {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitPullRequest> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPullRequestList>;
@@ -6254,45 +7814,123 @@ pub mod annotated_tags {
             }
         }
     }
-    pub mod create {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitAnnotatedTag> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitAnnotatedTag =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Create an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Object containing details of tag to be created."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAnnotatedTag>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
+        #[doc = "Get an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `object_id`: ObjectId (Sha1Id) of tag to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            object_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                repository_id,
+                object_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Create an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Object containing details of tag to be created."]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAnnotatedTag>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Get an annotated tag.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects\nmay contain a repository of the same name. You don't need to include the project if you specify a\nrepository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `object_id`: ObjectId (Sha1Id) of tag to get."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            object_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+                object_id,
+            )
+        }
+    }
+    pub mod create {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitAnnotatedTag> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitAnnotatedTag =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
             }
         }
         #[derive(Clone)]
@@ -6526,6 +8164,7 @@ pub mod blobs {
                 repository_id: repository_id.into(),
                 project: project.into(),
                 filename: None,
+                accept: None,
             }
         }
         #[doc = "Get a single blob.\n\nRepositories have both a name and an identifier. Identifiers are globally unique,\nbut several projects may contain a repository of the same name. You don't need to include\nthe project if you specify a repository by ID. However, if you specify a repository by name,\nyou must also specify the project (by name or ID)."]
@@ -6552,9 +8191,88 @@ pub mod blobs {
                 file_name: None,
                 format: None,
                 resolve_lfs: None,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets one or more blobs in a zip file download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Blob IDs (SHA1 hashes) to be returned in the zip file."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_blobs_zip(
+            &self,
+            body: Vec<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_blobs_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_blobs_zip(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Get a single blob.\n\nRepositories have both a name and an identifier. Identifiers are globally unique,\nbut several projects may contain a repository of the same name. You don't need to include\nthe project if you specify a repository by ID. However, if you specify a repository by name,\nyou must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `sha1`: SHA1 hash of the file. You can get the SHA1 of a file using the \"Git/Items/Get Item\" endpoint."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_blob(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_blob::RequestBuilder {
+            Client(self.0.client.clone()).get_blob(
+                self.0.organization.clone(),
+                repository_id,
+                sha1,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets one or more blobs in a zip file download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Blob IDs (SHA1 hashes) to be returned in the zip file."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get_blobs_zip(
+            &self,
+            body: Vec<String>,
+            repository_id: impl Into<String>,
+        ) -> get_blobs_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_blobs_zip(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a single blob.\n\nRepositories have both a name and an identifier. Identifiers are globally unique,\nbut several projects may contain a repository of the same name. You don't need to include\nthe project if you specify a repository by ID. However, if you specify a repository by name,\nyou must also specify the project (by name or ID)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `sha1`: SHA1 hash of the file. You can get the SHA1 of a file using the \"Git/Items/Get Item\" endpoint."]
+        pub fn get_blob(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+        ) -> get_blob::RequestBuilder {
+            Client(self.0.client.clone()).get_blob(
+                self.0.organization.clone(),
+                repository_id,
+                sha1,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_blobs_zip {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6564,19 +8282,29 @@ pub mod blobs {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -6618,12 +8346,18 @@ pub mod blobs {
             pub(crate) repository_id: String,
             pub(crate) project: String,
             pub(crate) filename: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             pub fn filename(mut self, filename: impl Into<String>) -> Self {
                 self.filename = Some(filename.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -6642,6 +8376,7 @@ pub mod blobs {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/zip");
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
                         if let Some(filename) = &this.filename {
@@ -6649,6 +8384,9 @@ pub mod blobs {
                                 .query_pairs_mut()
                                 .append_pair("filename", filename);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -6673,8 +8411,8 @@ pub mod blobs {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -6708,6 +8446,27 @@ pub mod blobs {
                 })?;
                 Ok(body)
             }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
@@ -6751,6 +8510,7 @@ pub mod blobs {
             pub(crate) file_name: Option<String>,
             pub(crate) format: Option<String>,
             pub(crate) resolve_lfs: Option<bool>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "If true, prompt for a download rather than rendering in a browser. Note: this value defaults to true if format is zip"]
@@ -6773,6 +8533,11 @@ pub mod blobs {
                 self.resolve_lfs = Some(resolve_lfs);
                 self
             }
+            #[doc = "The content type of the response: one of `application/json`, `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -6811,6 +8576,9 @@ pub mod blobs {
                                 .query_pairs_mut()
                                 .append_pair("resolveLfs", &resolve_lfs.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6924,6 +8692,118 @@ pub mod cherry_picks {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve information about a cherry pick operation for a specific branch. This operation is expensive due to the underlying object structure, so this API only looks at the 1000 most recent cherry pick operations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the cherry pick operation."]
+        pub fn get_cherry_pick_for_ref_name(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_cherry_pick_for_ref_name::RequestBuilder {
+            Client(self.0.client.clone()).get_cherry_pick_for_ref_name(
+                self.0.organization.clone(),
+                project,
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Cherry pick a specific commit or commits that are associated to a pull request into a new branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve information about a cherry pick operation by cherry pick Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `cherry_pick_id`: ID of the cherry pick."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_cherry_pick(
+            &self,
+            project: impl Into<String>,
+            cherry_pick_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_cherry_pick::RequestBuilder {
+            Client(self.0.client.clone()).get_cherry_pick(
+                self.0.organization.clone(),
+                project,
+                cherry_pick_id,
+                repository_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve information about a cherry pick operation for a specific branch. This operation is expensive due to the underlying object structure, so this API only looks at the 1000 most recent cherry pick operations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the cherry pick operation."]
+        pub fn get_cherry_pick_for_ref_name(
+            &self,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_cherry_pick_for_ref_name::RequestBuilder {
+            Client(self.0.client.clone()).get_cherry_pick_for_ref_name(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Cherry pick a specific commit or commits that are associated to a pull request into a new branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve information about a cherry pick operation by cherry pick Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `cherry_pick_id`: ID of the cherry pick."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_cherry_pick(
+            &self,
+            cherry_pick_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_cherry_pick::RequestBuilder {
+            Client(self.0.client.clone()).get_cherry_pick(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                cherry_pick_id,
+                repository_id,
+            )
+        }
+    }
     pub mod get_cherry_pick_for_ref_name {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7347,30 +9227,114 @@ pub mod statuses {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitStatusList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitStatusList = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get statuses associated with the Git commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Create Git commit status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Git commit status object to create."]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitStatus>,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                commit_id,
+                repository_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get statuses associated with the Git commit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn list(
+            &self,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                commit_id,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create Git commit status."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Git commit status object to create."]
+        #[doc = "* `commit_id`: ID of the Git commit."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitStatus>,
+            commit_id: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                commit_id,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitStatusList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitStatusList = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
             pub fn as_raw_response(&self) -> &azure_core::Response {
@@ -7660,6 +9624,35 @@ pub mod diffs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Find the closest common commit (the merge base) between base and target commits, and get the diff between either the base and target commits or common and target commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), repository_id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Find the closest common commit (the merge base) between base and target commits, and get the diff between either the base and target commits or common and target commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn get(&self, repository_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7700,6 +9693,106 @@ pub mod diffs {
                 self.as_raw_response()
             }
         }
+        #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum BaseVersionOptions {
+            None,
+            PreviousChange,
+            FirstParent,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl BaseVersionOptions {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::PreviousChange => "previousChange",
+                    Self::FirstParent => "firstParent",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for BaseVersionOptions {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum BaseVersionType {
+            Branch,
+            Tag,
+            Commit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl BaseVersionType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Branch => "branch",
+                    Self::Tag => "tag",
+                    Self::Commit => "commit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for BaseVersionType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum TargetVersionOptions {
+            None,
+            PreviousChange,
+            FirstParent,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl TargetVersionOptions {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::PreviousChange => "previousChange",
+                    Self::FirstParent => "firstParent",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for TargetVersionOptions {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum TargetVersionType {
+            Branch,
+            Tag,
+            Commit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl TargetVersionType {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Branch => "branch",
+                    Self::Tag => "tag",
+                    Self::Commit => "commit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for TargetVersionType {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -7725,11 +9818,11 @@ pub mod diffs {
             pub(crate) top: Option<i32>,
             pub(crate) skip: Option<i32>,
             pub(crate) base_version: Option<String>,
-            pub(crate) base_version_options: Option<String>,
-            pub(crate) base_version_type: Option<String>,
+            pub(crate) base_version_options: Option<BaseVersionOptions>,
+            pub(crate) base_version_type: Option<BaseVersionType>,
             pub(crate) target_version: Option<String>,
-            pub(crate) target_version_options: Option<String>,
-            pub(crate) target_version_type: Option<String>,
+            pub(crate) target_version_options: Option<TargetVersionOptions>,
+            pub(crate) target_version_type: Option<TargetVersionType>,
         }
         impl RequestBuilder {
             #[doc = "If true, diff between common and target commits. If false, diff between base and target commits."]
@@ -7753,12 +9846,18 @@ pub mod diffs {
                 self
             }
             #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
-            pub fn base_version_options(mut self, base_version_options: impl Into<String>) -> Self {
+            pub fn base_version_options(
+                mut self,
+                base_version_options: impl Into<BaseVersionOptions>,
+            ) -> Self {
                 self.base_version_options = Some(base_version_options.into());
                 self
             }
             #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
-            pub fn base_version_type(mut self, base_version_type: impl Into<String>) -> Self {
+            pub fn base_version_type(
+                mut self,
+                base_version_type: impl Into<BaseVersionType>,
+            ) -> Self {
                 self.base_version_type = Some(base_version_type.into());
                 self
             }
@@ -7770,13 +9869,16 @@ pub mod diffs {
             #[doc = "Version options - Specify additional modifiers to version (e.g Previous)"]
             pub fn target_version_options(
                 mut self,
-                target_version_options: impl Into<String>,
+                target_version_options: impl Into<TargetVersionOptions>,
             ) -> Self {
                 self.target_version_options = Some(target_version_options.into());
                 self
             }
             #[doc = "Version type (branch, tag, or commit). Determines how Id is interpreted"]
-            pub fn target_version_type(mut self, target_version_type: impl Into<String>) -> Self {
+            pub fn target_version_type(
+                mut self,
+                target_version_type: impl Into<TargetVersionType>,
+            ) -> Self {
                 self.target_version_type = Some(target_version_type.into());
                 self
             }
@@ -7821,12 +9923,12 @@ pub mod diffs {
                         if let Some(base_version_options) = &this.base_version_options {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("baseVersionOptions", base_version_options);
+                                .append_pair("baseVersionOptions", base_version_options.as_str());
                         }
                         if let Some(base_version_type) = &this.base_version_type {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("baseVersionType", base_version_type);
+                                .append_pair("baseVersionType", base_version_type.as_str());
                         }
                         if let Some(target_version) = &this.target_version {
                             req.url_mut()
@@ -7834,14 +9936,15 @@ pub mod diffs {
                                 .append_pair("targetVersion", target_version);
                         }
                         if let Some(target_version_options) = &this.target_version_options {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("targetVersionOptions", target_version_options);
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "targetVersionOptions",
+                                target_version_options.as_str(),
+                            );
                         }
                         if let Some(target_version_type) = &this.target_version_type {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("targetVersionType", target_version_type);
+                                .append_pair("targetVersionType", target_version_type.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -7979,6 +10082,149 @@ pub mod import_requests {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve import requests for a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn query(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), project, repository_id)
+        }
+        #[doc = "Create an import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The import request to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve a particular import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                repository_id,
+                import_request_id,
+            )
+        }
+        #[doc = "Retry or abandon a failed import request.\n\nThere can only be one active import request associated with a repository. Marking a failed import request abandoned makes it inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated version of the import request. Currently, the only change allowed is setting the Status to Queued or Abandoned."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+                import_request_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve import requests for a repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn query(&self, repository_id: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Create an import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The import request to create."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve a particular import request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+                import_request_id,
+            )
+        }
+        #[doc = "Retry or abandon a failed import request.\n\nThere can only be one active import request associated with a repository. Marking a failed import request abandoned makes it inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated version of the import request. Currently, the only change allowed is setting the Status to Queued or Abandoned."]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `import_request_id`: The unique identifier for the import request to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitImportRequest>,
+            repository_id: impl Into<String>,
+            import_request_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+                import_request_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8506,6 +10752,48 @@ pub mod pull_request_query {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "This API is used to find what pull requests are related to a given commit.  It can be used to either find the pull request that created a particular merge commit or it can be used to find all pull requests that have ever merged a particular commit.  The input is a list of queries which each contain a list of commits. For each commit that you search against, you will get back a dictionary of commit -> pull requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of queries to perform."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            body: impl Into<models::GitPullRequestQuery>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "This API is used to find what pull requests are related to a given commit.  It can be used to either find the pull request that created a particular merge commit or it can be used to find all pull requests that have ever merged a particular commit.  The input is a list of queries which each contain a list of commits. For each commit that you search against, you will get back a dictionary of commit -> pull requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of queries to perform."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get(
+            &self,
+            body: impl Into<models::GitPullRequestQuery>,
+            repository_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8681,6 +10969,7 @@ pub mod pull_request_attachments {
                 repository_id: repository_id.into(),
                 pull_request_id,
                 project: project.into(),
+                accept: None,
             }
         }
         #[doc = "Attach a new file to a pull request."]
@@ -8737,10 +11026,184 @@ pub mod pull_request_attachments {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of files attached to a given pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the file content of a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Attach a new file to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `file_name`: The name of the file."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Delete a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment to delete."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of files attached to a given pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the file content of a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn get(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Attach a new file to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `file_name`: The name of the file."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a pull request attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `file_name`: The name of the attachment to delete."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn delete(
+            &self,
+            file_name: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                file_name,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
         #[cfg(target_arch = "wasm32")]
         use futures::future::LocalBoxFuture as BoxFuture;
         #[derive(Debug)]
@@ -8866,19 +11329,29 @@ pub mod pull_request_attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -8920,8 +11393,14 @@ pub mod pull_request_attachments {
             pub(crate) repository_id: String,
             pub(crate) pull_request_id: i32,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -8940,6 +11419,10 @@ pub mod pull_request_attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8967,8 +11450,8 @@ pub mod pull_request_attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -9275,6 +11758,90 @@ pub mod pull_request_commits {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the commits for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_commits(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the commits for the specified iteration of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the iteration from which to get the commits."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_pull_request_iteration_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get_pull_request_iteration_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_iteration_commits(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the commits for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn get_pull_request_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> get_pull_request_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_commits(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the commits for the specified iteration of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the iteration from which to get the commits."]
+        pub fn get_pull_request_iteration_commits(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> get_pull_request_iteration_commits::RequestBuilder {
+            Client(self.0.client.clone()).get_pull_request_iteration_commits(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_pull_request_commits {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9404,6 +11971,20 @@ pub mod pull_request_commits {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitCommitRef> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitCommitRefList>;
@@ -9621,38 +12202,122 @@ pub mod pull_request_iterations {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::GitPullRequestIterationList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestIterationList = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the list of iterations for the specified pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the specified iteration for a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration to return."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::GitPullRequestIterationList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestIterationList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -9918,6 +12583,54 @@ pub mod pull_request_iteration_changes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve the changes made in a pull request between two iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve the changes made in a pull request between two iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration. <br /> Iteration one is the head of the source branch at the time the pull request is created and subsequent iterations are created when there are pushes to the source branch. Allowed values are between 1 and the maximum iteration on this pull request."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10224,80 +12937,320 @@ pub mod pull_request_iteration_statuses {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatusList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::GitPullRequestStatusList = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the statuses associated with a pull request iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
-            pub(crate) organization: String,
-            pub(crate) repository_id: String,
-            pub(crate) pull_request_id: i32,
-            pub(crate) iteration_id: i32,
-            pub(crate) project: String,
+        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project,
+            )
         }
-        impl RequestBuilder {
-            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
-            #[doc = ""]
-            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
-            #[doc = "However, this function can provide more flexibility when required."]
-            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
+        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the statuses associated with a pull request iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create a pull request status on the iteration. This operation will have the same result as Create status on pull request with specified iteration ID in the request body.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that `iterationId` in the request body is optional since `iterationId` can be specified in the URL.\nA conflict between `iterationId` in the URL and `iterationId` in the request body will result in status code 400."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update pull request iteration statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the specific pull request iteration status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete pull request iteration status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `iteration_id`: ID of the pull request iteration."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            iteration_id: i32,
+            status_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                iteration_id,
+                status_id,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::GitPullRequestStatusList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::GitPullRequestStatusList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) repository_id: String,
+            pub(crate) pull_request_id: i32,
+            pub(crate) iteration_id: i32,
+            pub(crate) project: String,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
                         let url = this.url()?;
                         let mut req = azure_core::Request::new(url, azure_core::Method::Get);
                         if let Some(auth_header) = this
@@ -10935,52 +13888,220 @@ pub mod pull_request_labels {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinitionList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::WebApiTagDefinitionList = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the labels (tags) assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Create a tag (if that does not exists yet) and add that as a label (tag) for a specified pull request. The only required field is the name of the new label (tag)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Label to assign to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiCreateTagRequestData>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
+        #[doc = "Retrieves a single label (tag) that has been assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                project,
+            )
+        }
+        #[doc = "Removes a label (tag) from the set of those assigned to the pull request. The tag itself will not be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the labels (tags) assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create a tag (if that does not exists yet) and add that as a label (tag) for a specified pull request. The only required field is the name of the new label (tag)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Label to assign to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WebApiCreateTagRequestData>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieves a single label (tag) that has been assigned to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Removes a label (tag) from the set of those assigned to the pull request. The tag itself will not be deleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `label_id_or_name`: The name or ID of the label requested."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            label_id_or_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                label_id_or_name,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::WebApiTagDefinitionList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::WebApiTagDefinitionList = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
         #[doc = r""]
         #[doc = r" To finalize and submit the request, invoke `.await`, which"]
         #[doc = r" converts the [`RequestBuilder`] into a future,"]
@@ -11515,6 +14636,90 @@ pub mod pull_request_properties {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get external properties of the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create or update pull request external properties. The patch operation can be `add`, `replace` or `remove`. For `add` operation, the path can be empty. If the path is empty, the value must be a list of key value pairs. For `replace` operation, the path cannot be empty. If the path does not exist, the property will be added to the collection. For `remove` operation, the path cannot be empty. If the path does not exist, no action will be performed."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties to add, replace or remove in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get external properties of the pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create or update pull request external properties. The patch operation can be `add`, `replace` or `remove`. For `add` operation, the path can be empty. If the path is empty, the value must be a list of key value pairs. For `replace` operation, the path cannot be empty. If the path does not exist, the property will be added to the collection. For `remove` operation, the path cannot be empty. If the path does not exist, no action will be performed."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties to add, replace or remove in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11921,55 +15126,403 @@ pub mod pull_request_reviewers {
         #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pull_request_reviewer(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_pull_request_reviewer::RequestBuilder {
+            update_pull_request_reviewer::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                reviewer_id: reviewer_id.into(),
+                project: project.into(),
+            }
+        }
+        #[doc = "Remove a reviewer from a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                reviewer_id: reviewer_id.into(),
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve the reviewers for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Add reviewers to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRef>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_pull_request_reviewers::RequestBuilder {
+            Client(self.0.client.clone()).create_pull_request_reviewers(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_unmaterialized_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
+            Client(self.0.client.clone()).create_unmaterialized_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Reset the votes of multiple reviewers on a pull request.  NOTE: This endpoint only supports updating votes, but does not support updating required reviewers (use policy) or display names."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: IDs of the reviewers whose votes will be reset to zero"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update_pull_request_reviewers::RequestBuilder {
+            Client(self.0.client.clone()).update_pull_request_reviewers(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve information about a particular reviewer on a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+        #[doc = "Add a reviewer to a pull request or cast a vote."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer's vote.<br />If the reviewer's ID is included here, it must match the reviewerID parameter.<br />Reviewers can set their own vote with this method.  When adding other reviewers, vote must be set to zero."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_pull_request_reviewer::RequestBuilder {
+            Client(self.0.client.clone()).create_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+        #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_pull_request_reviewer::RequestBuilder {
+            Client(self.0.client.clone()).update_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+        #[doc = "Remove a reviewer from a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve the reviewers for a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Add reviewers to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewers to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRef>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create_pull_request_reviewers::RequestBuilder {
+            Client(self.0.client.clone()).create_pull_request_reviewers(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Add an unmaterialized identity to the reviewers of a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer to add to the pull request."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create_unmaterialized_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create_unmaterialized_pull_request_reviewer::RequestBuilder {
+            Client(self.0.client.clone()).create_unmaterialized_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Reset the votes of multiple reviewers on a pull request.  NOTE: This endpoint only supports updating votes, but does not support updating required reviewers (use policy) or display names."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: IDs of the reviewers whose votes will be reset to zero"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request"]
+        pub fn update_pull_request_reviewers(
+            &self,
+            body: Vec<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> update_pull_request_reviewers::RequestBuilder {
+            Client(self.0.client.clone()).update_pull_request_reviewers(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve information about a particular reviewer on a pull request"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Add a reviewer to a pull request or cast a vote."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Reviewer's vote.<br />If the reviewer's ID is included here, it must match the reviewerID parameter.<br />Reviewers can set their own vote with this method.  When adding other reviewers, vote must be set to zero."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `reviewer_id`: ID of the reviewer."]
+        pub fn create_pull_request_reviewer(
+            &self,
+            body: impl Into<models::IdentityRefWithVote>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            reviewer_id: impl Into<String>,
+        ) -> create_pull_request_reviewer::RequestBuilder {
+            Client(self.0.client.clone()).create_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                reviewer_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Edit a reviewer entry. These fields are patchable: isFlagged, hasDeclined"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `body`: Reviewer data.<br />If the reviewer's ID is included here, it must match the reviewerID parameter."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `reviewer_id`: ID of the reviewer."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update_pull_request_reviewer(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::IdentityRefWithVote>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> update_pull_request_reviewer::RequestBuilder {
-            update_pull_request_reviewer::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).update_pull_request_reviewer(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                self.0.project.clone(),
+            )
         }
         #[doc = "Remove a reviewer from a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `reviewer_id`: ID of the reviewer to remove."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             reviewer_id: impl Into<String>,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
-                reviewer_id: reviewer_id.into(),
-                project: project.into(),
-            }
+                reviewer_id,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -12970,6 +16523,52 @@ pub mod pull_request_share {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Sends an e-mail notification about a specific pull request to a set of recipients"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn share_pull_request(
+            &self,
+            body: impl Into<models::ShareNotificationContext>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> share_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).share_pull_request(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Sends an e-mail notification about a specific pull request to a set of recipients"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn share_pull_request(
+            &self,
+            body: impl Into<models::ShareNotificationContext>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> share_pull_request::RequestBuilder {
+            Client(self.0.client.clone()).share_pull_request(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod share_pull_request {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13065,152 +16664,362 @@ pub mod pull_request_share {
                 Ok(url)
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<()>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
-            #[doc = "Returns a future that sends the request and waits for the response."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move {
-                    let _rsp = self.send().await?;
-                    Ok(())
-                })
-            }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<()>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<()>>;
+            #[doc = "Returns a future that sends the request and waits for the response."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move {
+                    let _rsp = self.send().await?;
+                    Ok(())
+                })
+            }
+        }
+    }
+}
+pub mod pull_request_statuses {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get all the statuses associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                status_id,
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the statuses associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Pull request status to create."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestStatus>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                status_id,
+                project,
+            )
+        }
+        #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `status_id`: ID of the pull request status."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            status_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                status_id,
+                project,
+            )
         }
     }
-}
-pub mod pull_request_statuses {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
         #[doc = "Get all the statuses associated with a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Create a pull request status.\n\nThe only required field for the status is `Context.Name` that uniquely identifies the status.\nNote that you can specify iterationId in the request body to post the status on the iteration."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Pull request status to create."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitPullRequestStatus>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Update pull request statuses collection. The only supported operation type is `remove`.\n\nThis operation allows to delete multiple statuses in one call.\nThe path of the `remove` operation should refer to the ID of the pull request status.\nFor example `path=\"/1\"` refers to the pull request status with ID 1."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Operations to apply to the pull request statuses in JSON Patch format."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::JsonPatchDocument>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Get the specific pull request status by ID. The status ID is unique within the pull request across all iterations."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             status_id: i32,
-            project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 status_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete pull request status.\n\nYou can remove multiple statuses in one call by using Update operation."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request’s target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `status_id`: ID of the pull request status."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             status_id: i32,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 status_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -13877,44 +17686,218 @@ pub mod pull_request_threads {
             thread_id: i32,
             project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+                iteration: None,
+                base_iteration: None,
+            }
+        }
+        #[doc = "Update a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The thread content that should be updated."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all threads in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Create a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread to create. Thread must contain at least one comment."]
+        #[doc = "* `repository_id`: Repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Update a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread content that should be updated."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve all threads in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Create a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The thread to create. Thread must contain at least one comment."]
+        #[doc = "* `repository_id`: Repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPullRequestCommentThread>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-                iteration: None,
-                base_iteration: None,
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Update a thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The thread content that should be updated."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitPullRequestCommentThread>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -14451,168 +18434,414 @@ pub mod pull_request_threads {
                 Ok(url)
             }
         }
-        impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<models::GitPullRequestCommentThread>;
-            type IntoFuture =
-                BoxFuture<'static, azure_core::Result<models::GitPullRequestCommentThread>>;
-            #[doc = "Returns a future that sends the request and returns the parsed response body."]
-            #[doc = ""]
-            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
-            #[doc = ""]
-            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
-            fn into_future(self) -> Self::IntoFuture {
-                Box::pin(async move { self.send().await?.into_body().await })
-            }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::GitPullRequestCommentThread>;
+            type IntoFuture =
+                BoxFuture<'static, azure_core::Result<models::GitPullRequestCommentThread>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
+}
+pub mod pull_request_thread_comments {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Retrieve all comments associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Create a comment on a specific thread in a pull request (up to 500 comments can be created per thread)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The comment to create. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Update a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+        #[doc = "Delete a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all comments associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Create a comment on a specific thread in a pull request (up to 500 comments can be created per thread)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The comment to create. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Update a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Comment>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Delete a comment associated with a specific thread in a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
+        #[doc = "* `comment_id`: ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
         }
     }
-}
-pub mod pull_request_thread_comments {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
         #[doc = "Retrieve all comments associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
-            project: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Create a comment on a specific thread in a pull request (up to 500 comments can be created per thread)."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The comment to create. Comments can be up to 150,000 characters."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Comment>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
-            project: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Retrieve a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Update a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: The comment content that should be updated. Comments can be up to 150,000 characters."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment to update."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Comment>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
         #[doc = "Delete a comment associated with a specific thread in a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
         #[doc = "* `thread_id`: ID of the thread that the desired comment is in."]
         #[doc = "* `comment_id`: ID of the comment."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
             thread_id: i32,
             comment_id: i32,
-            project: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
                 thread_id,
                 comment_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -15318,6 +19547,156 @@ pub mod pull_request_comment_likes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get likes for a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Add a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+        #[doc = "Delete a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get likes for a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Add a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn create(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Delete a like on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The repository ID of the pull request's target branch."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `thread_id`: The ID of the thread that contains the comment."]
+        #[doc = "* `comment_id`: The ID of the comment."]
+        pub fn delete(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            thread_id: i32,
+            comment_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                thread_id,
+                comment_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -15655,24 +20034,66 @@ pub mod pull_request_work_items {
         #[doc = "Retrieve a list of work items associated with a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                repository_id: repository_id.into(),
+                pull_request_id,
+                project: project.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve a list of work items associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID or name of the repository."]
+        #[doc = "* `pull_request_id`: ID of the pull request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            pull_request_id: i32,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                pull_request_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve a list of work items associated with a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `repository_id`: ID or name of the repository."]
         #[doc = "* `pull_request_id`: ID of the pull request."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             repository_id: impl Into<String>,
             pull_request_id: i32,
-            project: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                repository_id: repository_id.into(),
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
                 pull_request_id,
-                project: project.into(),
-            }
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -15878,6 +20299,101 @@ pub mod pushes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieves pushes associated with the specified repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), repository_id, project)
+        }
+        #[doc = "Push changes to the repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPush>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Retrieves a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `push_id`: ID of the push."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            push_id: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                push_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieves pushes associated with the specified repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Push changes to the repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitPush>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieves a particular push."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `push_id`: ID of the push."]
+        pub fn get(&self, repository_id: impl Into<String>, push_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                push_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -16039,7 +20555,7 @@ pub mod pushes {
                         if let Some(search_criteria_from_date) = &this.search_criteria_from_date {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.fromDate",
-                                &search_criteria_from_date.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_from_date)?,
                             );
                         }
                         if let Some(search_criteria_include_links) =
@@ -16071,7 +20587,7 @@ pub mod pushes {
                         if let Some(search_criteria_to_date) = &this.search_criteria_to_date {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "searchCriteria.toDate",
-                                &search_criteria_to_date.to_string(),
+                                &crate::date_time::format_date_time(search_criteria_to_date)?,
                             );
                         }
                         let req_body = azure_core::EMPTY_BODY;
@@ -16097,6 +20613,18 @@ pub mod pushes {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitPush> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitPushList>;
@@ -16460,6 +20988,113 @@ pub mod refs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Queries the provided repository for its refs and returns them."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), repository_id, project)
+        }
+        #[doc = "Creating, updating, or deleting refs(branches).\n\nUpdating a ref means making it point at a different commit than it used to. You must specify both the old and new commit to avoid race conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of ref updates to attempt to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_refs(
+            &self,
+            body: Vec<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_refs::RequestBuilder {
+            Client(self.0.client.clone()).update_refs(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                project,
+            )
+        }
+        #[doc = "Lock or Unlock a branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref update action (lock/unlock) to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `filter`: The name of the branch to lock/unlock"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update_ref(
+            &self,
+            body: impl Into<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            filter: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update_ref::RequestBuilder {
+            Client(self.0.client.clone()).update_ref(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                filter,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Queries the provided repository for its refs and returns them."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Creating, updating, or deleting refs(branches).\n\nUpdating a ref means making it point at a different commit than it used to. You must specify both the old and new commit to avoid race conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of ref updates to attempt to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        pub fn update_refs(
+            &self,
+            body: Vec<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+        ) -> update_refs::RequestBuilder {
+            Client(self.0.client.clone()).update_refs(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Lock or Unlock a branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The ref update action (lock/unlock) to perform"]
+        #[doc = "* `repository_id`: The name or ID of the repository."]
+        #[doc = "* `filter`: The name of the branch to lock/unlock"]
+        pub fn update_ref(
+            &self,
+            body: impl Into<models::GitRefUpdate>,
+            repository_id: impl Into<String>,
+            filter: impl Into<String>,
+        ) -> update_ref::RequestBuilder {
+            Client(self.0.client.clone()).update_ref(
+                self.0.organization.clone(),
+                body,
+                repository_id,
+                filter,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -16664,6 +21299,20 @@ pub mod refs {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GitRef> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GitRefList>;
@@ -16970,56 +21619,168 @@ pub mod reverts {
             repository_id: impl Into<String>,
             ref_name: impl Into<String>,
         ) -> get_revert_for_ref_name::RequestBuilder {
-            get_revert_for_ref_name::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                repository_id: repository_id.into(),
-                ref_name: ref_name.into(),
-            }
+            get_revert_for_ref_name::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                repository_id: repository_id.into(),
+                ref_name: ref_name.into(),
+            }
+        }
+        #[doc = "Starts the operation to create a new branch which reverts changes introduced by either a specific commit or commits that are associated to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                repository_id: repository_id.into(),
+            }
+        }
+        #[doc = "Retrieve information about a revert operation by revert Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `revert_id`: ID of the revert operation."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_revert(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            revert_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_revert::RequestBuilder {
+            get_revert::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                revert_id,
+                repository_id: repository_id.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve information about a revert operation for a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the revert operation."]
+        pub fn get_revert_for_ref_name(
+            &self,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_revert_for_ref_name::RequestBuilder {
+            Client(self.0.client.clone()).get_revert_for_ref_name(
+                self.0.organization.clone(),
+                project,
+                repository_id,
+                ref_name,
+            )
+        }
+        #[doc = "Starts the operation to create a new branch which reverts changes introduced by either a specific commit or commits that are associated to a pull request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitAsyncRefOperationParameters>,
+            project: impl Into<String>,
+            repository_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_id,
+            )
+        }
+        #[doc = "Retrieve information about a revert operation by revert Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `revert_id`: ID of the revert operation."]
+        #[doc = "* `repository_id`: ID of the repository."]
+        pub fn get_revert(
+            &self,
+            project: impl Into<String>,
+            revert_id: i32,
+            repository_id: impl Into<String>,
+        ) -> get_revert::RequestBuilder {
+            Client(self.0.client.clone()).get_revert(
+                self.0.organization.clone(),
+                project,
+                revert_id,
+                repository_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve information about a revert operation for a specific branch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the repository."]
+        #[doc = "* `ref_name`: The GitAsyncRefOperationParameters generatedRefName used for the revert operation."]
+        pub fn get_revert_for_ref_name(
+            &self,
+            repository_id: impl Into<String>,
+            ref_name: impl Into<String>,
+        ) -> get_revert_for_ref_name::RequestBuilder {
+            Client(self.0.client.clone()).get_revert_for_ref_name(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_id,
+                ref_name,
+            )
         }
         #[doc = "Starts the operation to create a new branch which reverts changes introduced by either a specific commit or commits that are associated to a pull request."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `repository_id`: ID of the repository."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::GitAsyncRefOperationParameters>,
-            project: impl Into<String>,
             repository_id: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                repository_id: repository_id.into(),
-            }
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_id,
+            )
         }
         #[doc = "Retrieve information about a revert operation by revert Id."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `revert_id`: ID of the revert operation."]
         #[doc = "* `repository_id`: ID of the repository."]
         pub fn get_revert(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             revert_id: i32,
             repository_id: impl Into<String>,
         ) -> get_revert::RequestBuilder {
-            get_revert::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_revert(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 revert_id,
-                repository_id: repository_id.into(),
-            }
+                repository_id,
+            )
         }
     }
     pub mod get_revert_for_ref_name {
@@ -17414,6 +22175,35 @@ pub mod suggestions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve a pull request suggestion for a particular repository or team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), repository_id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve a pull request suggestion for a particular repository or team project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: ID of the git repository."]
+        pub fn list(&self, repository_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -17569,6 +22359,48 @@ pub mod trees {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "The Tree endpoint returns the collection of objects underneath the specified tree. Trees are folders in a Git repository.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects may contain a repository of the same name. You don't need to include the project if you specify a repository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: Repository Id."]
+        #[doc = "* `sha1`: SHA1 hash of the tree object."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                sha1,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "The Tree endpoint returns the collection of objects underneath the specified tree. Trees are folders in a Git repository.\n\nRepositories have both a name and an identifier. Identifiers are globally unique, but several projects may contain a repository of the same name. You don't need to include the project if you specify a repository by ID. However, if you specify a repository by name, you must also specify the project (by name or ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_id`: Repository Id."]
+        #[doc = "* `sha1`: SHA1 hash of the tree object."]
+        pub fn get(
+            &self,
+            repository_id: impl Into<String>,
+            sha1: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                repository_id,
+                sha1,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -17770,6 +22602,54 @@ pub mod merge_bases {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Find the merge bases of two commits, optionally across forks. If otherRepositoryId is not specified, the merge bases will only be calculated within the context of the local repositoryNameOrId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: ID or name of the local repository."]
+        #[doc = "* `commit_id`: First commit, usually the tip of the target branch of the potential merge."]
+        #[doc = "* `other_commit_id`: Other commit, usually the tip of the source branch of the potential merge."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            commit_id: impl Into<String>,
+            other_commit_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                commit_id,
+                other_commit_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Find the merge bases of two commits, optionally across forks. If otherRepositoryId is not specified, the merge bases will only be calculated within the context of the local repositoryNameOrId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: ID or name of the local repository."]
+        #[doc = "* `commit_id`: First commit, usually the tip of the target branch of the potential merge."]
+        #[doc = "* `other_commit_id`: Other commit, usually the tip of the source branch of the potential merge."]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            commit_id: impl Into<String>,
+            other_commit_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                commit_id,
+                other_commit_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -18018,6 +22898,150 @@ pub mod forks {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all forks of a repository in the collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `collection_id`: Team project collection ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            collection_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                collection_id,
+                project,
+            )
+        }
+        #[doc = "Retrieve all requested fork sync operations on this repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_fork_sync_requests(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_fork_sync_requests::RequestBuilder {
+            Client(self.0.client.clone()).get_fork_sync_requests(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                project,
+            )
+        }
+        #[doc = "Request that another repository's refs be fetched into this one. It syncs two existing forks. To create a fork, please see the <a href=\"https://docs.microsoft.com/en-us/rest/api/vsts/git/repositories/create?view=azure-devops-rest-5.1\"> repositories endpoint</a>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Source repository and ref mapping."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_fork_sync_request(
+            &self,
+            body: impl Into<models::GitForkSyncRequestParameters>,
+            repository_name_or_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create_fork_sync_request::RequestBuilder {
+            Client(self.0.client.clone()).create_fork_sync_request(
+                self.0.organization.clone(),
+                body,
+                repository_name_or_id,
+                project,
+            )
+        }
+        #[doc = "Get a specific fork sync operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `fork_sync_operation_id`: OperationId of the sync request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_fork_sync_request(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            fork_sync_operation_id: i32,
+            project: impl Into<String>,
+        ) -> get_fork_sync_request::RequestBuilder {
+            Client(self.0.client.clone()).get_fork_sync_request(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                fork_sync_operation_id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve all forks of a repository in the collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `collection_id`: Team project collection ID."]
+        pub fn list(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            collection_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                collection_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve all requested fork sync operations on this repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn get_fork_sync_requests(
+            &self,
+            repository_name_or_id: impl Into<String>,
+        ) -> get_fork_sync_requests::RequestBuilder {
+            Client(self.0.client.clone()).get_fork_sync_requests(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Request that another repository's refs be fetched into this one. It syncs two existing forks. To create a fork, please see the <a href=\"https://docs.microsoft.com/en-us/rest/api/vsts/git/repositories/create?view=azure-devops-rest-5.1\"> repositories endpoint</a>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Source repository and ref mapping."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create_fork_sync_request(
+            &self,
+            body: impl Into<models::GitForkSyncRequestParameters>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create_fork_sync_request::RequestBuilder {
+            Client(self.0.client.clone()).create_fork_sync_request(
+                self.0.organization.clone(),
+                body,
+                repository_name_or_id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a specific fork sync operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `fork_sync_operation_id`: OperationId of the sync request."]
+        pub fn get_fork_sync_request(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            fork_sync_operation_id: i32,
+        ) -> get_fork_sync_request::RequestBuilder {
+            Client(self.0.client.clone()).get_fork_sync_request(
+                self.0.organization.clone(),
+                repository_name_or_id,
+                fork_sync_operation_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -18612,6 +23636,84 @@ pub mod merges {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Request a git merge operation. Currently we support merging only 2 commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parents commitIds and merge commit messsage."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitMergeParameters>,
+            project: impl Into<String>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                repository_name_or_id,
+            )
+        }
+        #[doc = "Get a specific merge operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `merge_operation_id`: OperationId of the merge request."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository_name_or_id: impl Into<String>,
+            merge_operation_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                repository_name_or_id,
+                merge_operation_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Request a git merge operation. Currently we support merging only 2 commits."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parents commitIds and merge commit messsage."]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GitMergeParameters>,
+            repository_name_or_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                repository_name_or_id,
+            )
+        }
+        #[doc = "Get a specific merge operation's details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository_name_or_id`: The name or ID of the repository."]
+        #[doc = "* `merge_operation_id`: OperationId of the merge request."]
+        pub fn get(
+            &self,
+            repository_name_or_id: impl Into<String>,
+            merge_operation_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository_name_or_id,
+                merge_operation_id,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -353,7 +353,8 @@ pub struct Change {
     #[doc = "The type of change that was made to the item."]
     #[serde(rename = "changeType")]
     pub change_type: change::ChangeType,
-    pub item: serde_json::Value,
+    #[doc = "Current version."]
+    pub item: GitItem,
     #[serde(
         rename = "newContent",
         default,
@@ -372,7 +373,7 @@ pub struct Change {
     pub url: Option<String>,
 }
 impl Change {
-    pub fn new(change_type: change::ChangeType, item: serde_json::Value) -> Self {
+    pub fn new(change_type: change::ChangeType, item: GitItem) -> Self {
         Self {
             change_type,
             item,
@@ -4041,6 +4042,20 @@ pub struct GitPullRequestUpdateOptions {
         skip_serializing_if = "Option::is_none"
     )]
     pub auto_complete_set_by: Option<IdentityRef>,
+    #[doc = "Preferences about how the pull request should be completed."]
+    #[serde(
+        rename = "completionOptions",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub completion_options: Option<GitPullRequestCompletionOptions>,
+    #[doc = "The commit at the head of the source branch. Required when completing the pull request."]
+    #[serde(
+        rename = "lastMergeSourceCommit",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_merge_source_commit: Option<GitCommitRef>,
 }
 impl GitPullRequestUpdateOptions {
     pub fn new() -> Self {
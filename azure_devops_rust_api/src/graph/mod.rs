@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn avatars_client(&self) -> avatars::Client {
         avatars::Client(self.clone())
     }
@@ -166,6 +193,118 @@ impl Client {
         users::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn avatars_client(&self) -> avatars::OrganizationClient {
+        avatars::OrganizationClient(self.clone())
+    }
+    pub fn descriptors_client(&self) -> descriptors::OrganizationClient {
+        descriptors::OrganizationClient(self.clone())
+    }
+    pub fn groups_client(&self) -> groups::OrganizationClient {
+        groups::OrganizationClient(self.clone())
+    }
+    pub fn membership_states_client(&self) -> membership_states::OrganizationClient {
+        membership_states::OrganizationClient(self.clone())
+    }
+    pub fn memberships_client(&self) -> memberships::OrganizationClient {
+        memberships::OrganizationClient(self.clone())
+    }
+    pub fn provider_info_client(&self) -> provider_info::OrganizationClient {
+        provider_info::OrganizationClient(self.clone())
+    }
+    pub fn request_access_client(&self) -> request_access::OrganizationClient {
+        request_access::OrganizationClient(self.clone())
+    }
+    pub fn service_principals_client(&self) -> service_principals::OrganizationClient {
+        service_principals::OrganizationClient(self.clone())
+    }
+    pub fn storage_keys_client(&self) -> storage_keys::OrganizationClient {
+        storage_keys::OrganizationClient(self.clone())
+    }
+    pub fn subject_lookup_client(&self) -> subject_lookup::OrganizationClient {
+        subject_lookup::OrganizationClient(self.clone())
+    }
+    pub fn subject_query_client(&self) -> subject_query::OrganizationClient {
+        subject_query::OrganizationClient(self.clone())
+    }
+    pub fn users_client(&self) -> users::OrganizationClient {
+        users::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn avatars_client(&self) -> avatars::ProjectClient {
+        avatars::ProjectClient(self.clone())
+    }
+    pub fn descriptors_client(&self) -> descriptors::ProjectClient {
+        descriptors::ProjectClient(self.clone())
+    }
+    pub fn groups_client(&self) -> groups::ProjectClient {
+        groups::ProjectClient(self.clone())
+    }
+    pub fn membership_states_client(&self) -> membership_states::ProjectClient {
+        membership_states::ProjectClient(self.clone())
+    }
+    pub fn memberships_client(&self) -> memberships::ProjectClient {
+        memberships::ProjectClient(self.clone())
+    }
+    pub fn provider_info_client(&self) -> provider_info::ProjectClient {
+        provider_info::ProjectClient(self.clone())
+    }
+    pub fn request_access_client(&self) -> request_access::ProjectClient {
+        request_access::ProjectClient(self.clone())
+    }
+    pub fn service_principals_client(&self) -> service_principals::ProjectClient {
+        service_principals::ProjectClient(self.clone())
+    }
+    pub fn storage_keys_client(&self) -> storage_keys::ProjectClient {
+        storage_keys::ProjectClient(self.clone())
+    }
+    pub fn subject_lookup_client(&self) -> subject_lookup::ProjectClient {
+        subject_lookup::ProjectClient(self.clone())
+    }
+    pub fn subject_query_client(&self) -> subject_query::ProjectClient {
+        subject_query::ProjectClient(self.clone())
+    }
+    pub fn users_client(&self) -> users::ProjectClient {
+        users::ProjectClient(self.clone())
+    }
+}
 pub mod descriptors {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -191,6 +330,26 @@ pub mod descriptors {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Resolve a storage key to a descriptor"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `storage_key`: Storage key of the subject (user, group, scope, etc.) to resolve"]
+        pub fn get(&self, storage_key: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(storage_key, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Resolve a storage key to a descriptor"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `storage_key`: Storage key of the subject (user, group, scope, etc.) to resolve"]
+        pub fn get(&self, storage_key: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(storage_key, self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -400,6 +559,100 @@ pub mod groups {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a list of all groups in the current scope (usually organization or account).\n\nThe optional parameters are used to filter down the returned results. Returned results are in no guaranteed order.\n\n Since the list of groups may be large, results are returned in pages of groups.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a new Azure DevOps group or materialize an existing AAD group.\n\nThe body of the request must be a derived type of GraphGroupCreationContext:\n  * GraphGroupVstsCreationContext - Create a new Azure DevOps group that is not backed by an external provider.\n  * GraphGroupMailAddressCreationContext - Create a new group using the mail address as a reference to an existing group from an external AD or AAD backed provider.\n  * GraphGroupOriginIdCreationContext - Create a new group using the OriginID as a reference to a group from an external AD or AAD backed provider.\n\n Optionally, you can add the newly created group as a member of an existing Azure DevOps group and/or specify a custom storage key for the group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph group used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphGroupCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a group by its descriptor.\n\nThe group will be returned even if it has been deleted from the account or has had all its memberships\ndeleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the desired graph group."]
+        pub fn get(&self, group_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_descriptor)
+        }
+        #[doc = "Update the properties of an Azure DevOps group.\n\nCurrently limited to only changing the description and account name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to modify."]
+        #[doc = "* `body`: The JSON+Patch document containing the fields to alter."]
+        pub fn update(
+            &self,
+            group_descriptor: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                group_descriptor,
+                body,
+            )
+        }
+        #[doc = "Removes an Azure DevOps group from all of its parent groups.\n\nThe group will still be visible, but membership\n checks for the group, and all descendants which derive membership through it, will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to delete."]
+        pub fn delete(&self, group_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_descriptor)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a list of all groups in the current scope (usually organization or account).\n\nThe optional parameters are used to filter down the returned results. Returned results are in no guaranteed order.\n\n Since the list of groups may be large, results are returned in pages of groups.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a new Azure DevOps group or materialize an existing AAD group.\n\nThe body of the request must be a derived type of GraphGroupCreationContext:\n  * GraphGroupVstsCreationContext - Create a new Azure DevOps group that is not backed by an external provider.\n  * GraphGroupMailAddressCreationContext - Create a new group using the mail address as a reference to an existing group from an external AD or AAD backed provider.\n  * GraphGroupOriginIdCreationContext - Create a new group using the OriginID as a reference to a group from an external AD or AAD backed provider.\n\n Optionally, you can add the newly created group as a member of an existing Azure DevOps group and/or specify a custom storage key for the group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph group used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphGroupCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a group by its descriptor.\n\nThe group will be returned even if it has been deleted from the account or has had all its memberships\ndeleted."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the desired graph group."]
+        pub fn get(&self, group_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_descriptor)
+        }
+        #[doc = "Update the properties of an Azure DevOps group.\n\nCurrently limited to only changing the description and account name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to modify."]
+        #[doc = "* `body`: The JSON+Patch document containing the fields to alter."]
+        pub fn update(
+            &self,
+            group_descriptor: impl Into<String>,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                group_descriptor,
+                body,
+            )
+        }
+        #[doc = "Removes an Azure DevOps group from all of its parent groups.\n\nThe group will still be visible, but membership\n checks for the group, and all descendants which derive membership through it, will return false.”"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_descriptor`: The descriptor of the group to delete."]
+        pub fn delete(&self, group_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -429,7 +682,7 @@ pub mod groups {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -545,6 +798,20 @@ pub mod groups {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GraphGroup> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GraphGroupList>;
@@ -1140,6 +1407,154 @@ pub mod memberships {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the memberships where this descriptor is a member in the relationship.\n\nThe default value for direction is 'up' meaning return all memberships where the subject is a member (e.g. all groups the subject is a member of).\n Alternatively, passing the direction as 'down' will return all memberships where the subject is a container (e.g. all members of the subject group)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Fetch all direct memberships of this descriptor."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subject_descriptor)
+        }
+        #[doc = "Get a membership relationship between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to the container in the relationship."]
+        pub fn get(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Create a new membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that can be the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that can be the container in the relationship."]
+        pub fn add(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Deletes a membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that is the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that is the container in the relationship."]
+        pub fn remove_membership(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> remove_membership::RequestBuilder {
+            Client(self.0.client.clone()).remove_membership(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Check to see if a membership relationship between a container and subject exists."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The group or user that is a child subject of the relationship."]
+        #[doc = "* `container_descriptor`: The group that is the container in the relationship."]
+        pub fn check_membership_existence(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> check_membership_existence::RequestBuilder {
+            Client(self.0.client.clone()).check_membership_existence(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the memberships where this descriptor is a member in the relationship.\n\nThe default value for direction is 'up' meaning return all memberships where the subject is a member (e.g. all groups the subject is a member of).\n Alternatively, passing the direction as 'down' will return all memberships where the subject is a container (e.g. all members of the subject group)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Fetch all direct memberships of this descriptor."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subject_descriptor)
+        }
+        #[doc = "Get a membership relationship between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to the container in the relationship."]
+        pub fn get(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Create a new membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that can be the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that can be the container in the relationship."]
+        pub fn add(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Deletes a membership between a container and subject."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: A descriptor to a group or user that is the child subject in the relationship."]
+        #[doc = "* `container_descriptor`: A descriptor to a group that is the container in the relationship."]
+        pub fn remove_membership(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> remove_membership::RequestBuilder {
+            Client(self.0.client.clone()).remove_membership(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+        #[doc = "Check to see if a membership relationship between a container and subject exists."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The group or user that is a child subject of the relationship."]
+        #[doc = "* `container_descriptor`: The group that is the container in the relationship."]
+        pub fn check_membership_existence(
+            &self,
+            subject_descriptor: impl Into<String>,
+            container_descriptor: impl Into<String>,
+        ) -> check_membership_existence::RequestBuilder {
+            Client(self.0.client.clone()).check_membership_existence(
+                self.0.organization.clone(),
+                subject_descriptor,
+                container_descriptor,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1757,6 +2172,26 @@ pub mod membership_states {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Check whether a subject is active or inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Descriptor of the subject (user, group, scope, etc.) to check state of"]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subject_descriptor)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Check whether a subject is active or inactive."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: Descriptor of the subject (user, group, scope, etc.) to check state of"]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subject_descriptor)
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1897,6 +2332,24 @@ pub mod request_access {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn request_access(
+            &self,
+            body: impl Into<models::JToken>,
+        ) -> request_access::RequestBuilder {
+            Client(self.0.client.clone()).request_access(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn request_access(
+            &self,
+            body: impl Into<models::JToken>,
+        ) -> request_access::RequestBuilder {
+            Client(self.0.client.clone()).request_access(self.0.organization.clone(), body)
+        }
+    }
     pub mod request_access {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2073,6 +2526,78 @@ pub mod service_principals {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of all service principals in a given scope.\n\nSince the list of service principals may be large, results are returned in pages of service principals. If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results. The only reliable way to know if there is no more service principals left is the lack of a continuation token."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD service principal into the ADO account.\n\nNOTE: Created service principals are not active in an account.\n\n Adding a service principal to an account is required before the service principal can be added to ADO groups or assigned an asset.\n\n The body of the request must be a derived type of GraphServicePrincipalCreationContext:\n  * GraphServicePrincipalOriginIdCreationContext - Create a new service principal using the OriginID as a reference to an existing service principal from AAD backed provider.\n\n If the service principal to be added corresponds to a service principal that was previously deleted, then that service principal will be restored.\n\n Optionally, you can add the newly created service principal as a member of an existing ADO group and/or specify a custom storage key for the service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph service principal used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphServicePrincipalCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a service principal by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_descriptor`: The descriptor of the desired service principal."]
+        pub fn get(&self, service_principal_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone())
+                .get(self.0.organization.clone(), service_principal_descriptor)
+        }
+        #[doc = "Disables a service principal.\n\nThe service principal will still be visible, but membership checks for the service principal will return false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_descriptor`: The descriptor of the service principal to delete."]
+        pub fn delete(
+            &self,
+            service_principal_descriptor: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete(self.0.organization.clone(), service_principal_descriptor)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of all service principals in a given scope.\n\nSince the list of service principals may be large, results are returned in pages of service principals. If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results. The only reliable way to know if there is no more service principals left is the lack of a continuation token."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD service principal into the ADO account.\n\nNOTE: Created service principals are not active in an account.\n\n Adding a service principal to an account is required before the service principal can be added to ADO groups or assigned an asset.\n\n The body of the request must be a derived type of GraphServicePrincipalCreationContext:\n  * GraphServicePrincipalOriginIdCreationContext - Create a new service principal using the OriginID as a reference to an existing service principal from AAD backed provider.\n\n If the service principal to be added corresponds to a service principal that was previously deleted, then that service principal will be restored.\n\n Optionally, you can add the newly created service principal as a member of an existing ADO group and/or specify a custom storage key for the service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph service principal used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphServicePrincipalCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a service principal by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_descriptor`: The descriptor of the desired service principal."]
+        pub fn get(&self, service_principal_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone())
+                .get(self.0.organization.clone(), service_principal_descriptor)
+        }
+        #[doc = "Disables a service principal.\n\nThe service principal will still be visible, but membership checks for the service principal will return false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_descriptor`: The descriptor of the service principal to delete."]
+        pub fn delete(
+            &self,
+            service_principal_descriptor: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete(self.0.organization.clone(), service_principal_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2103,7 +2628,7 @@ pub mod service_principals {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -2208,6 +2733,20 @@ pub mod service_principals {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GraphServicePrincipal> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GraphServicePrincipalList>;
@@ -2598,6 +3137,20 @@ pub mod storage_keys {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Resolve a descriptor to a storage key."]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(subject_descriptor, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Resolve a descriptor to a storage key."]
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(subject_descriptor, self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2741,6 +3294,32 @@ pub mod subject_lookup {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Resolve descriptors to users, groups or scopes (Subjects) in a batch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of descriptors that specifies a subset of subjects to retrieve. Each descriptor uniquely identifies the subject across all instance scopes, but only at a single point in time."]
+        pub fn lookup_subjects(
+            &self,
+            body: impl Into<models::GraphSubjectLookup>,
+        ) -> lookup_subjects::RequestBuilder {
+            Client(self.0.client.clone()).lookup_subjects(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Resolve descriptors to users, groups or scopes (Subjects) in a batch."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of descriptors that specifies a subset of subjects to retrieve. Each descriptor uniquely identifies the subject across all instance scopes, but only at a single point in time."]
+        pub fn lookup_subjects(
+            &self,
+            body: impl Into<models::GraphSubjectLookup>,
+        ) -> lookup_subjects::RequestBuilder {
+            Client(self.0.client.clone()).lookup_subjects(self.0.organization.clone(), body)
+        }
+    }
     pub mod lookup_subjects {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2883,6 +3462,26 @@ pub mod subject_query {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Search for Azure Devops users, or/and groups. Results will be returned in a batch with no more than 100 graph subjects."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query that we'll be using to search includes the following: Query: the search term. The search will be prefix matching only. SubjectKind: \"User\" or \"Group\" can be specified, both or either ScopeDescriptor: Non-default scope can be specified, i.e. project scope descriptor"]
+        pub fn query(&self, body: impl Into<models::GraphSubjectQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Search for Azure Devops users, or/and groups. Results will be returned in a batch with no more than 100 graph subjects."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query that we'll be using to search includes the following: Query: the search term. The search will be prefix matching only. SubjectKind: \"User\" or \"Group\" can be specified, both or either ScopeDescriptor: Non-default scope can be specified, i.e. project scope descriptor"]
+        pub fn query(&self, body: impl Into<models::GraphSubjectQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body)
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3053,6 +3652,46 @@ pub mod avatars {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(subject_descriptor, self.0.organization.clone())
+        }
+        pub fn set_avatar(
+            &self,
+            body: impl Into<models::Avatar>,
+            subject_descriptor: impl Into<String>,
+        ) -> set_avatar::RequestBuilder {
+            Client(self.0.client.clone()).set_avatar(
+                body,
+                subject_descriptor,
+                self.0.organization.clone(),
+            )
+        }
+        pub fn delete(&self, subject_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(subject_descriptor, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, subject_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(subject_descriptor, self.0.organization.clone())
+        }
+        pub fn set_avatar(
+            &self,
+            body: impl Into<models::Avatar>,
+            subject_descriptor: impl Into<String>,
+        ) -> set_avatar::RequestBuilder {
+            Client(self.0.client.clone()).set_avatar(
+                body,
+                subject_descriptor,
+                self.0.organization.clone(),
+            )
+        }
+        pub fn delete(&self, subject_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(subject_descriptor, self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3490,6 +4129,92 @@ pub mod users {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of all users in a given scope.\n\nSince the list of users may be large, results are returned in pages of users.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD or MSA user into the ADO account.\n\nNOTE: Created users are not active in an account unless they have been explicitly assigned a parent group at creation time or have signed in\n  and been autolicensed through AAD group memberships.\n\n Adding a user to an account is required before the user can be added to ADO groups or assigned an asset.\n\n The body of the request must be a derived type of GraphUserCreationContext:\n  * GraphUserMailAddressCreationContext - Create a new user using the mail address as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserOriginIdCreationContext - Create a new user using the OriginID as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserPrincipalNameCreationContext - Create a new user using the principal name as a reference to an existing user from an external AD or AAD backed provider.\n\n If the user to be added corresponds to a user that was previously deleted, then that user will be restored.\n\n Optionally, you can add the newly created user as a member of an existing ADO group and/or specify a custom storage key for the user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphUserCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a user by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the desired user."]
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), user_descriptor)
+        }
+        #[doc = "Map an existing user to a different user.\n\nThe body of the request must be a derived type of GraphUserUpdateContext:\n * GraphUserOriginIdUpdateContext - Map an existing user in an account, to an existing user from an external AD or AAD backed provider using the OriginId as a reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        #[doc = "* `user_descriptor`: The descriptor of the user to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GraphUserUpdateContext>,
+            user_descriptor: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, user_descriptor)
+        }
+        #[doc = "Disables a user.\n\nThe user will still be visible, but membership checks for the user will return false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the user to delete."]
+        pub fn delete(&self, user_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), user_descriptor)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of all users in a given scope.\n\nSince the list of users may be large, results are returned in pages of users.  If there are more results\n than can be returned in a single page, the result set will contain a continuation token for retrieval of the\n next set of results."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Materialize an existing AAD or MSA user into the ADO account.\n\nNOTE: Created users are not active in an account unless they have been explicitly assigned a parent group at creation time or have signed in\n  and been autolicensed through AAD group memberships.\n\n Adding a user to an account is required before the user can be added to ADO groups or assigned an asset.\n\n The body of the request must be a derived type of GraphUserCreationContext:\n  * GraphUserMailAddressCreationContext - Create a new user using the mail address as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserOriginIdCreationContext - Create a new user using the OriginID as a reference to an existing user from an external AD or AAD backed provider.\n  * GraphUserPrincipalNameCreationContext - Create a new user using the principal name as a reference to an existing user from an external AD or AAD backed provider.\n\n If the user to be added corresponds to a user that was previously deleted, then that user will be restored.\n\n Optionally, you can add the newly created user as a member of an existing ADO group and/or specify a custom storage key for the user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        pub fn create(
+            &self,
+            body: impl Into<models::GraphUserCreationContext>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a user by its descriptor."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the desired user."]
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), user_descriptor)
+        }
+        #[doc = "Map an existing user to a different user.\n\nThe body of the request must be a derived type of GraphUserUpdateContext:\n * GraphUserOriginIdUpdateContext - Map an existing user in an account, to an existing user from an external AD or AAD backed provider using the OriginId as a reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The subset of the full graph user used to uniquely find the graph subject in an external provider."]
+        #[doc = "* `user_descriptor`: The descriptor of the user to update"]
+        pub fn update(
+            &self,
+            body: impl Into<models::GraphUserUpdateContext>,
+            user_descriptor: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, user_descriptor)
+        }
+        #[doc = "Disables a user.\n\nThe user will still be visible, but membership checks for the user will return false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_descriptor`: The descriptor of the user to delete."]
+        pub fn delete(&self, user_descriptor: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), user_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3519,7 +4244,7 @@ pub mod users {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -3635,6 +4360,20 @@ pub mod users {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::GraphUser> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::GraphUserList>;
@@ -4138,6 +4877,18 @@ pub mod provider_info {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(user_descriptor, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, user_descriptor: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(user_descriptor, self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn consumers_client(&self) -> consumers::Client {
         consumers::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         subscriptions::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn consumers_client(&self) -> consumers::OrganizationClient {
+        consumers::OrganizationClient(self.clone())
+    }
+    pub fn diagnostics_client(&self) -> diagnostics::OrganizationClient {
+        diagnostics::OrganizationClient(self.clone())
+    }
+    pub fn notifications_client(&self) -> notifications::OrganizationClient {
+        notifications::OrganizationClient(self.clone())
+    }
+    pub fn publishers_client(&self) -> publishers::OrganizationClient {
+        publishers::OrganizationClient(self.clone())
+    }
+    pub fn subscriptions_client(&self) -> subscriptions::OrganizationClient {
+        subscriptions::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn consumers_client(&self) -> consumers::ProjectClient {
+        consumers::ProjectClient(self.clone())
+    }
+    pub fn diagnostics_client(&self) -> diagnostics::ProjectClient {
+        diagnostics::ProjectClient(self.clone())
+    }
+    pub fn notifications_client(&self) -> notifications::ProjectClient {
+        notifications::ProjectClient(self.clone())
+    }
+    pub fn publishers_client(&self) -> publishers::ProjectClient {
+        publishers::ProjectClient(self.clone())
+    }
+    pub fn subscriptions_client(&self) -> subscriptions::ProjectClient {
+        subscriptions::ProjectClient(self.clone())
+    }
+}
 pub mod consumers {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -219,6 +316,88 @@ pub mod consumers {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of available service hook consumer services. Optionally filter by consumers that support at least one event type from the specific publisher."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a specific consumer service. Optionally filter out consumer actions that do not support any event types for the specified publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn get(&self, consumer_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), consumer_id)
+        }
+        #[doc = "Get a list of consumer actions for a specific consumer."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn list_consumer_actions(
+            &self,
+            consumer_id: impl Into<String>,
+        ) -> list_consumer_actions::RequestBuilder {
+            Client(self.0.client.clone())
+                .list_consumer_actions(self.0.organization.clone(), consumer_id)
+        }
+        #[doc = "Get details about a specific consumer action."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        #[doc = "* `consumer_action_id`: ID for a consumerActionId."]
+        pub fn get_consumer_action(
+            &self,
+            consumer_id: impl Into<String>,
+            consumer_action_id: impl Into<String>,
+        ) -> get_consumer_action::RequestBuilder {
+            Client(self.0.client.clone()).get_consumer_action(
+                self.0.organization.clone(),
+                consumer_id,
+                consumer_action_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of available service hook consumer services. Optionally filter by consumers that support at least one event type from the specific publisher."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a specific consumer service. Optionally filter out consumer actions that do not support any event types for the specified publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn get(&self, consumer_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), consumer_id)
+        }
+        #[doc = "Get a list of consumer actions for a specific consumer."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        pub fn list_consumer_actions(
+            &self,
+            consumer_id: impl Into<String>,
+        ) -> list_consumer_actions::RequestBuilder {
+            Client(self.0.client.clone())
+                .list_consumer_actions(self.0.organization.clone(), consumer_id)
+        }
+        #[doc = "Get details about a specific consumer action."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `consumer_id`: ID for a consumer."]
+        #[doc = "* `consumer_action_id`: ID for a consumerActionId."]
+        pub fn get_consumer_action(
+            &self,
+            consumer_id: impl Into<String>,
+            consumer_action_id: impl Into<String>,
+        ) -> get_consumer_action::RequestBuilder {
+            Client(self.0.client.clone()).get_consumer_action(
+                self.0.organization.clone(),
+                consumer_id,
+                consumer_action_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -802,6 +981,72 @@ pub mod notifications {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query for notifications. A notification includes details about the event, the request to and the response from the consumer service."]
+        pub fn query(&self, body: impl Into<models::NotificationsQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a list of notifications for a specific subscription. A notification includes details about the event, the request to and the response from the consumer service."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn list(&self, subscription_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Get a specific notification for a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(
+            &self,
+            subscription_id: impl Into<String>,
+            notification_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                subscription_id,
+                notification_id,
+            )
+        }
+        #[doc = "Sends a test notification. This is useful for verifying the configuration of an updated or new service hooks subscription."]
+        pub fn create(&self, body: impl Into<models::Notification>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query for notifications. A notification includes details about the event, the request to and the response from the consumer service."]
+        pub fn query(&self, body: impl Into<models::NotificationsQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a list of notifications for a specific subscription. A notification includes details about the event, the request to and the response from the consumer service."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn list(&self, subscription_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Get a specific notification for a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(
+            &self,
+            subscription_id: impl Into<String>,
+            notification_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                subscription_id,
+                notification_id,
+            )
+        }
+        #[doc = "Sends a test notification. This is useful for verifying the configuration of an updated or new service hooks subscription."]
+        pub fn create(&self, body: impl Into<models::Notification>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1414,6 +1659,122 @@ pub mod publishers {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of publishers."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a specific service hooks publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get(&self, publisher_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), publisher_id)
+        }
+        #[doc = "Get the event types for a specific publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn list_event_types(
+            &self,
+            publisher_id: impl Into<String>,
+        ) -> list_event_types::RequestBuilder {
+            Client(self.0.client.clone())
+                .list_event_types(self.0.organization.clone(), publisher_id)
+        }
+        #[doc = "Get a specific event type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get_event_type(
+            &self,
+            publisher_id: impl Into<String>,
+            event_type_id: impl Into<String>,
+        ) -> get_event_type::RequestBuilder {
+            Client(self.0.client.clone()).get_event_type(
+                self.0.organization.clone(),
+                publisher_id,
+                event_type_id,
+            )
+        }
+        pub fn query_input_values(
+            &self,
+            body: impl Into<models::InputValuesQuery>,
+            publisher_id: impl Into<String>,
+        ) -> query_input_values::RequestBuilder {
+            Client(self.0.client.clone()).query_input_values(
+                self.0.organization.clone(),
+                body,
+                publisher_id,
+            )
+        }
+        #[doc = "Query for service hook publishers."]
+        pub fn query_publishers(
+            &self,
+            body: impl Into<models::PublishersQuery>,
+        ) -> query_publishers::RequestBuilder {
+            Client(self.0.client.clone()).query_publishers(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of publishers."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a specific service hooks publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get(&self, publisher_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), publisher_id)
+        }
+        #[doc = "Get the event types for a specific publisher."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn list_event_types(
+            &self,
+            publisher_id: impl Into<String>,
+        ) -> list_event_types::RequestBuilder {
+            Client(self.0.client.clone())
+                .list_event_types(self.0.organization.clone(), publisher_id)
+        }
+        #[doc = "Get a specific event type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `publisher_id`: ID for a publisher."]
+        pub fn get_event_type(
+            &self,
+            publisher_id: impl Into<String>,
+            event_type_id: impl Into<String>,
+        ) -> get_event_type::RequestBuilder {
+            Client(self.0.client.clone()).get_event_type(
+                self.0.organization.clone(),
+                publisher_id,
+                event_type_id,
+            )
+        }
+        pub fn query_input_values(
+            &self,
+            body: impl Into<models::InputValuesQuery>,
+            publisher_id: impl Into<String>,
+        ) -> query_input_values::RequestBuilder {
+            Client(self.0.client.clone()).query_input_values(
+                self.0.organization.clone(),
+                body,
+                publisher_id,
+            )
+        }
+        #[doc = "Query for service hook publishers."]
+        pub fn query_publishers(
+            &self,
+            body: impl Into<models::PublishersQuery>,
+        ) -> query_publishers::RequestBuilder {
+            Client(self.0.client.clone()).query_publishers(self.0.organization.clone(), body)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2221,6 +2582,102 @@ pub mod subscriptions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of subscriptions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Subscription to be created."]
+        pub fn create(&self, body: impl Into<models::Subscription>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Update a subscription. <param name=\"subscriptionId\">ID for a subscription that you wish to update.</param>"]
+        pub fn replace_subscription(
+            &self,
+            body: impl Into<models::Subscription>,
+            subscription_id: impl Into<String>,
+        ) -> replace_subscription::RequestBuilder {
+            Client(self.0.client.clone()).replace_subscription(
+                self.0.organization.clone(),
+                body,
+                subscription_id,
+            )
+        }
+        #[doc = "Delete a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn delete(&self, subscription_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Query for service hook subscriptions."]
+        pub fn create_subscriptions_query(
+            &self,
+            body: impl Into<models::SubscriptionsQuery>,
+        ) -> create_subscriptions_query::RequestBuilder {
+            Client(self.0.client.clone())
+                .create_subscriptions_query(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of subscriptions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Subscription to be created."]
+        pub fn create(&self, body: impl Into<models::Subscription>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Update a subscription. <param name=\"subscriptionId\">ID for a subscription that you wish to update.</param>"]
+        pub fn replace_subscription(
+            &self,
+            body: impl Into<models::Subscription>,
+            subscription_id: impl Into<String>,
+        ) -> replace_subscription::RequestBuilder {
+            Client(self.0.client.clone()).replace_subscription(
+                self.0.organization.clone(),
+                body,
+                subscription_id,
+            )
+        }
+        #[doc = "Delete a specific service hooks subscription."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subscription_id`: ID for a subscription."]
+        pub fn delete(&self, subscription_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), subscription_id)
+        }
+        #[doc = "Query for service hook subscriptions."]
+        pub fn create_subscriptions_query(
+            &self,
+            body: impl Into<models::SubscriptionsQuery>,
+        ) -> create_subscriptions_query::RequestBuilder {
+            Client(self.0.client.clone())
+                .create_subscriptions_query(self.0.organization.clone(), body)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2990,6 +3447,32 @@ pub mod diagnostics {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subscription_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateSubscripitonDiagnosticsParameters>,
+            subscription_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, subscription_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, subscription_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), subscription_id)
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateSubscripitonDiagnosticsParameters>,
+            subscription_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, subscription_id)
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -132,12 +132,19 @@ pub mod wit;
 pub mod work;
 
 mod auth;
-pub use auth::Credential;
+pub use auth::{CachingTokenCredential, Credential, TokenProvider};
+mod client;
+pub use client::{AdoClient, AdoClientBuilder};
 
 pub mod date_time;
+pub mod error;
 pub mod headers;
+pub mod paging;
+pub mod server;
 pub mod telemetry;
+pub mod throttling;
 
+pub(crate) mod response_body;
 pub(crate) mod serde;
 
 /// The token scope for Azure DevOps
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn group_entitlements_client(&self) -> group_entitlements::Client {
         group_entitlements::Client(self.clone())
     }
@@ -148,6 +175,86 @@ impl Client {
         user_entitlements::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn group_entitlements_client(&self) -> group_entitlements::OrganizationClient {
+        group_entitlements::OrganizationClient(self.clone())
+    }
+    pub fn member_entitlements_client(&self) -> member_entitlements::OrganizationClient {
+        member_entitlements::OrganizationClient(self.clone())
+    }
+    pub fn members_client(&self) -> members::OrganizationClient {
+        members::OrganizationClient(self.clone())
+    }
+    pub fn service_principal_entitlements_client(
+        &self,
+    ) -> service_principal_entitlements::OrganizationClient {
+        service_principal_entitlements::OrganizationClient(self.clone())
+    }
+    pub fn user_entitlement_summary_client(&self) -> user_entitlement_summary::OrganizationClient {
+        user_entitlement_summary::OrganizationClient(self.clone())
+    }
+    pub fn user_entitlements_client(&self) -> user_entitlements::OrganizationClient {
+        user_entitlements::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn group_entitlements_client(&self) -> group_entitlements::ProjectClient {
+        group_entitlements::ProjectClient(self.clone())
+    }
+    pub fn member_entitlements_client(&self) -> member_entitlements::ProjectClient {
+        member_entitlements::ProjectClient(self.clone())
+    }
+    pub fn members_client(&self) -> members::ProjectClient {
+        members::ProjectClient(self.clone())
+    }
+    pub fn service_principal_entitlements_client(
+        &self,
+    ) -> service_principal_entitlements::ProjectClient {
+        service_principal_entitlements::ProjectClient(self.clone())
+    }
+    pub fn user_entitlement_summary_client(&self) -> user_entitlement_summary::ProjectClient {
+        user_entitlement_summary::ProjectClient(self.clone())
+    }
+    pub fn user_entitlements_client(&self) -> user_entitlements::ProjectClient {
+        user_entitlements::ProjectClient(self.clone())
+    }
+}
 pub mod group_entitlements {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -238,6 +345,86 @@ pub mod group_entitlements {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the group entitlements for an account."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a group entitlement with license rule, extension rule."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: GroupEntitlement object specifying License Rule, Extensions Rule for the group. Based on the rules the members of the group will be given licenses and extensions. The Group Entitlement can be used to add the group to another project level groups"]
+        pub fn add(&self, body: impl Into<models::GroupEntitlement>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a group entitlement.\n\nIf the group entitlement does not exist, returns null."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_id)
+        }
+        #[doc = "Update entitlements (License Rule, Extensions Rule, Project memberships etc.) for a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the group."]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a group entitlement."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group to delete."]
+        pub fn delete(&self, group_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the group entitlements for an account."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Create a group entitlement with license rule, extension rule."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: GroupEntitlement object specifying License Rule, Extensions Rule for the group. Based on the rules the members of the group will be given licenses and extensions. The Group Entitlement can be used to add the group to another project level groups"]
+        pub fn add(&self, body: impl Into<models::GroupEntitlement>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a group entitlement.\n\nIf the group entitlement does not exist, returns null."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_id)
+        }
+        #[doc = "Update entitlements (License Rule, Extensions Rule, Project memberships etc.) for a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the group."]
+        #[doc = "* `group_id`: ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, group_id)
+        }
+        #[doc = "Delete a group entitlement."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: ID of the group to delete."]
+        pub fn delete(&self, group_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), group_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -943,6 +1130,82 @@ pub mod members {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get direct members of a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_id)
+        }
+        #[doc = "Add a member to a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        #[doc = "* `member_id`: Id of the member to add."]
+        pub fn add(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), group_id, member_id)
+        }
+        #[doc = "Remove a member from a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the group."]
+        #[doc = "* `member_id`: Id of the member to remove."]
+        pub fn remove_member_from_group(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> remove_member_from_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_member_from_group(
+                self.0.organization.clone(),
+                group_id,
+                member_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get direct members of a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        pub fn get(&self, group_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), group_id)
+        }
+        #[doc = "Add a member to a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the Group."]
+        #[doc = "* `member_id`: Id of the member to add."]
+        pub fn add(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), group_id, member_id)
+        }
+        #[doc = "Remove a member from a Group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `group_id`: Id of the group."]
+        #[doc = "* `member_id`: Id of the member to remove."]
+        pub fn remove_member_from_group(
+            &self,
+            group_id: impl Into<String>,
+            member_id: impl Into<String>,
+        ) -> remove_member_from_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_member_from_group(
+                self.0.organization.clone(),
+                group_id,
+                member_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1321,6 +1584,18 @@ pub mod member_entitlements {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn search_member_entitlements(&self) -> search_member_entitlements::RequestBuilder {
+            Client(self.0.client.clone()).search_member_entitlements(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn search_member_entitlements(&self) -> search_member_entitlements::RequestBuilder {
+            Client(self.0.client.clone()).search_member_entitlements(self.0.organization.clone())
+        }
+    }
     pub mod search_member_entitlements {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1568,6 +1843,114 @@ pub mod service_principal_entitlements {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Add a service principal, assign license and extensions and make them a member of a project group in an account.\n\nNOTE: If you are working with AAD app registration, you can find service principal of your app\nin enterprise applications, and make sure to use service principal's object id as originId parameter\nin the request body"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: ServicePrincipalEntitlement object specifying License, Extensions and Project/Team groups the service principal should be added to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::ServicePrincipalEntitlement>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more service principals."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_service_principal_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_service_principal_entitlements::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_service_principal_entitlements(self.0.organization.clone(), body)
+        }
+        #[doc = "Get Service principal Entitlement for a service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn get(&self, service_principal_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), service_principal_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the service principal."]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn update_service_principal_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            service_principal_id: impl Into<String>,
+        ) -> update_service_principal_entitlement::RequestBuilder {
+            Client(self.0.client.clone()).update_service_principal_entitlement(
+                self.0.organization.clone(),
+                body,
+                service_principal_id,
+            )
+        }
+        #[doc = "Delete a service principal from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the service principal from all project memberships.\nThe service principal would continue to have access to the account if it is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn delete(&self, service_principal_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), service_principal_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Add a service principal, assign license and extensions and make them a member of a project group in an account.\n\nNOTE: If you are working with AAD app registration, you can find service principal of your app\nin enterprise applications, and make sure to use service principal's object id as originId parameter\nin the request body"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: ServicePrincipalEntitlement object specifying License, Extensions and Project/Team groups the service principal should be added to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::ServicePrincipalEntitlement>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more service principals."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_service_principal_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_service_principal_entitlements::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_service_principal_entitlements(self.0.organization.clone(), body)
+        }
+        #[doc = "Get Service principal Entitlement for a service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn get(&self, service_principal_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), service_principal_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a service principal."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the service principal."]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn update_service_principal_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            service_principal_id: impl Into<String>,
+        ) -> update_service_principal_entitlement::RequestBuilder {
+            Client(self.0.client.clone()).update_service_principal_entitlement(
+                self.0.organization.clone(),
+                body,
+                service_principal_id,
+            )
+        }
+        #[doc = "Delete a service principal from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the service principal from all project memberships.\nThe service principal would continue to have access to the account if it is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `service_principal_id`: ID of the service principal."]
+        pub fn delete(&self, service_principal_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), service_principal_id)
+        }
+    }
     pub mod add {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2273,6 +2656,116 @@ pub mod user_entitlements {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a paged set of user entitlements matching the filter and sort criteria built with properties that match the select input."]
+        pub fn search_user_entitlements(&self) -> search_user_entitlements::RequestBuilder {
+            Client(self.0.client.clone()).search_user_entitlements(self.0.organization.clone())
+        }
+        #[doc = "Add a user, assign license and extensions and make them a member of a project group in an account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: UserEntitlement object specifying License, Extensions and Project/Team groups the user should be added to."]
+        pub fn add(&self, body: impl Into<models::UserEntitlement>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more users."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_user_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_user_entitlements::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_user_entitlements(self.0.organization.clone(), body)
+        }
+        #[doc = "Get User Entitlement for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn get(&self, user_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), user_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the user."]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn update_user_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            user_id: impl Into<String>,
+        ) -> update_user_entitlement::RequestBuilder {
+            Client(self.0.client.clone()).update_user_entitlement(
+                self.0.organization.clone(),
+                body,
+                user_id,
+            )
+        }
+        #[doc = "Delete a user from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the user from all project memberships.\nThe user would continue to have access to the account if she is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn delete(&self, user_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), user_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a paged set of user entitlements matching the filter and sort criteria built with properties that match the select input."]
+        pub fn search_user_entitlements(&self) -> search_user_entitlements::RequestBuilder {
+            Client(self.0.client.clone()).search_user_entitlements(self.0.organization.clone())
+        }
+        #[doc = "Add a user, assign license and extensions and make them a member of a project group in an account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: UserEntitlement object specifying License, Extensions and Project/Team groups the user should be added to."]
+        pub fn add(&self, body: impl Into<models::UserEntitlement>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for one or more users."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform."]
+        pub fn update_user_entitlements(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+        ) -> update_user_entitlements::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_user_entitlements(self.0.organization.clone(), body)
+        }
+        #[doc = "Get User Entitlement for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn get(&self, user_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), user_id)
+        }
+        #[doc = "Edit the entitlements (License, Extensions, Projects, Teams etc) for a user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: JsonPatchDocument containing the operations to perform on the user."]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn update_user_entitlement(
+            &self,
+            body: impl Into<models::JsonPatchDocument>,
+            user_id: impl Into<String>,
+        ) -> update_user_entitlement::RequestBuilder {
+            Client(self.0.client.clone()).update_user_entitlement(
+                self.0.organization.clone(),
+                body,
+                user_id,
+            )
+        }
+        #[doc = "Delete a user from the account.\n\nThe delete operation includes unassigning Extensions and Licenses and removing the user from all project memberships.\nThe user would continue to have access to the account if she is member of an AAD group, that is added directly to the account."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `user_id`: ID of the user."]
+        pub fn delete(&self, user_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), user_id)
+        }
+    }
     pub mod search_user_entitlements {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3055,6 +3548,20 @@ pub mod user_entitlement_summary {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get summary of Licenses, Extension, Projects, Groups and their assignments in the collection."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get summary of Licenses, Extension, Projects, Groups and their assignments in the collection."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,372 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps paging support.
+//!
+//! List operations return a single page of results per request. The next page is requested either
+//! by passing the `x-ms-continuationtoken` header value from the previous response as the
+//! `continuationToken` query parameter, or by advancing the `$skip` query parameter.
+//!
+//! Request builders for these operations provide an `into_stream()` method that returns a
+//! [`Pageable`], which fetches each page on demand and follows the continuation automatically.
+//!
+//! ```rust
+//!     let mut builds = build_client
+//!         .builds_client()
+//!         .list(organization, project)
+//!         .into_stream()
+//!         .items();
+//!     while let Some(build) = builds.next().await {
+//!         println!("{}", build?.id);
+//!     }
+//! ```
+use azure_core::headers::HeaderName;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures::stream::LocalBoxStream as BoxStream;
+
+/// Response header containing the token used to request the next page of results.
+pub const X_MS_CONTINUATIONTOKEN: HeaderName = HeaderName::from_static("x-ms-continuationtoken");
+
+/// `Send` on native targets, no bound on `wasm32` (where futures are not `Send`).
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// A single page of results from a list operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The items returned in this page.
+    pub items: Vec<T>,
+    /// The continuation token returned with this page, if any.
+    ///
+    /// This can be saved and passed to the request builder's `continuation_token()`
+    /// method to resume listing from the following page.
+    pub continuation_token: Option<String>,
+}
+
+// List responses are `{ "count": n, "value": [...] }`.
+#[derive(Deserialize)]
+struct ValueList<T> {
+    #[serde(default = "Vec::new")]
+    value: Vec<T>,
+}
+
+/// A stream of [`Page`]s returned by a list operation's `into_stream()` method.
+///
+/// Pages are only requested as the stream is polled. The stream ends after the last page,
+/// or after the first error.
+pub struct Pageable<T> {
+    stream: BoxStream<'static, azure_core::Result<Page<T>>>,
+}
+
+impl<T> Pageable<T>
+where
+    T: DeserializeOwned + MaybeSend + 'static,
+{
+    /// Creates a `Pageable` for an operation that is paged using continuation tokens.
+    ///
+    /// `send` is called with the continuation token for the page to fetch (`None` for the first page).
+    pub(crate) fn by_continuation_token<F>(send: F) -> Self
+    where
+        F: Fn(Option<String>) -> BoxFuture<'static, azure_core::Result<azure_core::Response>>
+            + MaybeSend
+            + 'static,
+    {
+        // The continuation token of the next page, `None` once there are no more pages
+        let first: Option<Option<String>> = Some(None);
+        let stream = futures::stream::unfold(first, move |next| {
+            let request = next.map(&send);
+            async move {
+                let page = Self::read_page(request?).await.map(|(page, _)| page);
+                let next = match &page {
+                    Ok(page) => page.continuation_token.clone().map(Some),
+                    Err(_) => None,
+                };
+                Some((page, next))
+            }
+        });
+        Self::new(stream)
+    }
+
+    /// Creates a `Pageable` for an operation that is paged using `$top` and `$skip`.
+    ///
+    /// `send` is called with the `$skip` value for the page to fetch. Paging stops when a page
+    /// returns no items, or fewer than `top` items (if specified). It also stops if a page is the
+    /// same as the previous one, so that an endpoint that ignores `$skip` is not paged forever.
+    pub(crate) fn by_skip<F>(skip: Option<i32>, top: Option<i32>, send: F) -> Self
+    where
+        F: Fn(i32) -> BoxFuture<'static, azure_core::Result<azure_core::Response>>
+            + MaybeSend
+            + 'static,
+    {
+        // The `$skip` of the next page and the body of the previous page, `None` once there are
+        // no more pages
+        let first: Option<(i32, Option<Bytes>)> = Some((skip.unwrap_or(0), None));
+        let stream = futures::stream::unfold(first, move |next| {
+            let request = next.as_ref().map(|(skip, _)| send(*skip));
+            async move {
+                let (skip, previous) = next?;
+                let (page, body) = match Self::read_page(request?).await {
+                    Ok(page) => page,
+                    Err(err) => return Some((Err(err), None)),
+                };
+                if previous.as_ref() == Some(&body) {
+                    return None;
+                }
+                let count = page.items.len() as i32;
+                let next = if count > 0 && top.map_or(true, |top| count >= top) {
+                    Some((skip + count, Some(body)))
+                } else {
+                    None
+                };
+                Some((Ok(page), next))
+            }
+        });
+        Self::new(stream)
+    }
+
+    fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = azure_core::Result<Page<T>>> + MaybeSend + 'static,
+    {
+        #[cfg(not(target_arch = "wasm32"))]
+        let stream = stream.boxed();
+        #[cfg(target_arch = "wasm32")]
+        let stream = stream.boxed_local();
+        Self { stream }
+    }
+
+    /// Reads a page, along with the response body it was read from.
+    async fn read_page(
+        request: BoxFuture<'static, azure_core::Result<azure_core::Response>>,
+    ) -> azure_core::Result<(Page<T>, Bytes)> {
+        let rsp = request.await?;
+        let continuation_token = rsp
+            .headers()
+            .get_optional_string(&X_MS_CONTINUATIONTOKEN)
+            .filter(|token| !token.is_empty());
+        let bytes = rsp.into_body().collect().await?;
+        let list: ValueList<T> = serde_json::from_slice(&bytes).map_err(|e| {
+            azure_core::error::Error::full(
+                azure_core::error::ErrorKind::DataConversion,
+                e,
+                format!(
+                    "Failed to deserialize response:\n{}",
+                    String::from_utf8_lossy(&bytes)
+                ),
+            )
+        })?;
+        let page = Page {
+            items: list.value,
+            continuation_token,
+        };
+        Ok((page, bytes))
+    }
+
+    /// Converts the stream of pages into a stream of the individual items.
+    pub fn items(self) -> BoxStream<'static, azure_core::Result<T>> {
+        let stream = self.flat_map(|page| {
+            let items: Vec<azure_core::Result<T>> = match page {
+                Ok(page) => page.items.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            futures::stream::iter(items)
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let stream = stream.boxed();
+        #[cfg(target_arch = "wasm32")]
+        let stream = stream.boxed_local();
+        stream
+    }
+
+    /// Fetches all remaining pages and returns all of their items.
+    pub async fn collect_items(self) -> azure_core::Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut pages = self;
+        while let Some(page) = pages.next().await {
+            items.extend(page?.items);
+        }
+        Ok(items)
+    }
+}
+
+impl<T> Stream for Pageable<T> {
+    type Item = azure_core::Result<Page<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for Pageable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pageable").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::error::{Error, ErrorKind};
+    use azure_core::headers::Headers;
+    use azure_core::{Response, StatusCode};
+    use std::sync::{Arc, Mutex};
+
+    fn response(items: &[i32], continuation_token: Option<&str>) -> Response {
+        let mut headers = Headers::new();
+        if let Some(continuation_token) = continuation_token {
+            headers.insert(X_MS_CONTINUATIONTOKEN, continuation_token.to_string());
+        }
+        let body = serde_json::json!({ "count": items.len(), "value": items }).to_string();
+        Response::new(
+            StatusCode::Ok,
+            headers,
+            Box::pin(futures::stream::once(futures::future::ready(Ok(
+                bytes::Bytes::from(body),
+            )))),
+        )
+    }
+
+    /// Pages of `0..total`, `page_size` items at a time, as returned for each `$skip`.
+    fn by_skip(
+        skip: Option<i32>,
+        top: Option<i32>,
+        page_size: i32,
+        total: i32,
+    ) -> (Pageable<i32>, Arc<Mutex<Vec<i32>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let sent = requests.clone();
+        let pageable = Pageable::by_skip(skip, top, move |skip| {
+            sent.lock().unwrap().push(skip);
+            let items: Vec<i32> = (skip..(skip + page_size).min(total)).collect();
+            Box::pin(futures::future::ready(Ok(response(&items, None))))
+        });
+        (pageable, requests)
+    }
+
+    #[test]
+    fn test_by_continuation_token() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let sent = requests.clone();
+        let pageable = Pageable::<i32>::by_continuation_token(move |continuation_token| {
+            sent.lock().unwrap().push(continuation_token.clone());
+            let rsp = match continuation_token.as_deref() {
+                None => response(&[1, 2], Some("a")),
+                Some("a") => response(&[3], Some("b")),
+                // An empty token ends the paging
+                _ => response(&[4], Some("")),
+            };
+            Box::pin(futures::future::ready(Ok(rsp)))
+        });
+        let pages: Vec<_> = futures::executor::block_on(pageable.collect());
+        let pages: Vec<Page<i32>> = pages.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            pages,
+            vec![
+                Page {
+                    items: vec![1, 2],
+                    continuation_token: Some("a".to_string())
+                },
+                Page {
+                    items: vec![3],
+                    continuation_token: Some("b".to_string())
+                },
+                Page {
+                    items: vec![4],
+                    continuation_token: None
+                },
+            ]
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![None, Some("a".to_string()), Some("b".to_string())]
+        );
+
+        // No continuation token: a single page
+        let pageable = Pageable::<i32>::by_continuation_token(|_| {
+            Box::pin(futures::future::ready(Ok(response(&[1], None))))
+        });
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![1]);
+    }
+
+    #[test]
+    fn test_by_skip() {
+        // Stops on a page with fewer than `top` items
+        let (pageable, requests) = by_skip(None, Some(2), 2, 5);
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        assert_eq!(*requests.lock().unwrap(), vec![0, 2, 4]);
+
+        // Without `top`, stops on an empty page
+        let (pageable, requests) = by_skip(None, None, 2, 4);
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        assert_eq!(*requests.lock().unwrap(), vec![0, 2, 4]);
+
+        // Starts at `skip`, and advances by the number of items returned
+        let (pageable, requests) = by_skip(Some(3), Some(4), 3, 10);
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![3, 4, 5]);
+        assert_eq!(*requests.lock().unwrap(), vec![3]);
+
+        let (pageable, requests) = by_skip(Some(6), None, 3, 10);
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![6, 7, 8, 9]);
+        assert_eq!(*requests.lock().unwrap(), vec![6, 9, 10]);
+
+        // An endpoint that ignores `$skip` returns the same page again, which ends the paging
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let sent = requests.clone();
+        let pageable = Pageable::<i32>::by_skip(None, None, move |skip| {
+            sent.lock().unwrap().push(skip);
+            Box::pin(futures::future::ready(Ok(response(&[1, 2], None))))
+        });
+        let items = futures::executor::block_on(pageable.collect_items()).unwrap();
+        assert_eq!(items, vec![1, 2]);
+        assert_eq!(*requests.lock().unwrap(), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_items() {
+        let pageable = Pageable::<i32>::by_continuation_token(|continuation_token| {
+            let rsp = match continuation_token {
+                None => Ok(response(&[1, 2], Some("a"))),
+                Some(_) => Err(Error::message(ErrorKind::Other, "failed")),
+            };
+            Box::pin(futures::future::ready(rsp))
+        });
+        let items: Vec<_> = futures::executor::block_on(pageable.items().collect());
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap(), &1);
+        assert_eq!(items[1].as_ref().unwrap(), &2);
+        assert!(items[2].is_err());
+
+        let pageable = Pageable::<i32>::by_skip(None, None, |skip| {
+            let rsp = match skip {
+                0 => Ok(response(&[1, 2], None)),
+                _ => Err(Error::message(ErrorKind::Other, "failed")),
+            };
+            Box::pin(futures::future::ready(rsp))
+        });
+        assert!(futures::executor::block_on(pageable.collect_items()).is_err());
+    }
+}
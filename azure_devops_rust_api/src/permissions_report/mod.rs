@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn permissions_report_client(&self) -> permissions_report::Client {
         permissions_report::Client(self.clone())
     }
@@ -136,6 +163,60 @@ impl Client {
         permissions_report_download::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn permissions_report_client(&self) -> permissions_report::OrganizationClient {
+        permissions_report::OrganizationClient(self.clone())
+    }
+    pub fn permissions_report_download_client(
+        &self,
+    ) -> permissions_report_download::OrganizationClient {
+        permissions_report_download::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn permissions_report_client(&self) -> permissions_report::ProjectClient {
+        permissions_report::ProjectClient(self.clone())
+    }
+    pub fn permissions_report_download_client(&self) -> permissions_report_download::ProjectClient {
+        permissions_report_download::ProjectClient(self.clone())
+    }
+}
 pub mod permissions_report {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -187,6 +268,54 @@ pub mod permissions_report {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of permissions reports"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Request a permissions report to be created asyncronously"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request configuration to be included in the permissions report"]
+        pub fn create(
+            &self,
+            body: impl Into<models::PermissionsReportRequest>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a specific permissions report"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The ID (GUID) of the permissions report"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of permissions reports"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Request a permissions report to be created asyncronously"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Request configuration to be included in the permissions report"]
+        pub fn create(
+            &self,
+            body: impl Into<models::PermissionsReportRequest>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a specific permissions report"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The ID (GUID) of the permissions report"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -558,9 +687,30 @@ pub mod permissions_report_download {
                 client: self.0.clone(),
                 organization: organization.into(),
                 id: id.into(),
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Download the json results of a permissions report"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The ID (GUID) of the permissions report"]
+        pub fn download(&self, id: impl Into<String>) -> download::RequestBuilder {
+            Client(self.0.client.clone()).download(self.0.organization.clone(), id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Download the json results of a permissions report"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The ID (GUID) of the permissions report"]
+        pub fn download(&self, id: impl Into<String>) -> download::RequestBuilder {
+            Client(self.0.client.clone()).download(self.0.organization.clone(), id)
+        }
+    }
     pub mod download {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -570,19 +720,29 @@ pub mod permissions_report_download {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -621,8 +781,14 @@ pub mod permissions_report_download {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) id: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -641,6 +807,10 @@ pub mod permissions_report_download {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -665,8 +835,8 @@ pub mod permissions_report_download {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
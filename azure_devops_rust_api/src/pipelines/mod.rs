@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn artifacts_client(&self) -> artifacts::Client {
         artifacts::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         runs::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn artifacts_client(&self) -> artifacts::OrganizationClient {
+        artifacts::OrganizationClient(self.clone())
+    }
+    pub fn logs_client(&self) -> logs::OrganizationClient {
+        logs::OrganizationClient(self.clone())
+    }
+    pub fn pipelines_client(&self) -> pipelines::OrganizationClient {
+        pipelines::OrganizationClient(self.clone())
+    }
+    pub fn preview_client(&self) -> preview::OrganizationClient {
+        preview::OrganizationClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::OrganizationClient {
+        runs::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn artifacts_client(&self) -> artifacts::ProjectClient {
+        artifacts::ProjectClient(self.clone())
+    }
+    pub fn logs_client(&self) -> logs::ProjectClient {
+        logs::ProjectClient(self.clone())
+    }
+    pub fn pipelines_client(&self) -> pipelines::ProjectClient {
+        pipelines::ProjectClient(self.clone())
+    }
+    pub fn preview_client(&self) -> preview::ProjectClient {
+        preview::ProjectClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::ProjectClient {
+        runs::ProjectClient(self.clone())
+    }
+}
 pub mod pipelines {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -212,6 +309,68 @@ pub mod pipelines {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of pipelines."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Input parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePipelineParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets a pipeline, optionally at the specified version"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID"]
+        pub fn get(&self, project: impl Into<String>, pipeline_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, pipeline_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of pipelines."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Input parameters."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePipelineParameters>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets a pipeline, optionally at the specified version"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline ID"]
+        pub fn get(&self, pipeline_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -347,6 +506,20 @@ pub mod pipelines {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::Pipeline> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PipelineList>;
@@ -641,6 +814,48 @@ pub mod preview {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Queues a dry run of the pipeline and returns an object containing the final yaml."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn preview(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            project: impl Into<String>,
+            pipeline_id: i32,
+        ) -> preview::RequestBuilder {
+            Client(self.0.client.clone()).preview(
+                self.0.organization.clone(),
+                body,
+                project,
+                pipeline_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Queues a dry run of the pipeline and returns an object containing the final yaml."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn preview(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            pipeline_id: i32,
+        ) -> preview::RequestBuilder {
+            Client(self.0.client.clone()).preview(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod preview {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -846,6 +1061,99 @@ pub mod runs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets top 10000 runs for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        pub fn list(&self, project: impl Into<String>, pipeline_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, pipeline_id)
+        }
+        #[doc = "Runs a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn run_pipeline(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            project: impl Into<String>,
+            pipeline_id: i32,
+        ) -> run_pipeline::RequestBuilder {
+            Client(self.0.client.clone()).run_pipeline(
+                self.0.organization.clone(),
+                body,
+                project,
+                pipeline_id,
+            )
+        }
+        #[doc = "Gets a run for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        #[doc = "* `run_id`: The run id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets top 10000 runs for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        pub fn list(&self, pipeline_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+        #[doc = "Runs a pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Optional additional parameters for this run."]
+        #[doc = "* `pipeline_id`: The pipeline ID."]
+        pub fn run_pipeline(
+            &self,
+            body: impl Into<models::RunPipelineParameters>,
+            pipeline_id: i32,
+        ) -> run_pipeline::RequestBuilder {
+            Client(self.0.client.clone()).run_pipeline(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+        #[doc = "Gets a run for a particular pipeline."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: The pipeline id"]
+        #[doc = "* `run_id`: The run id"]
+        pub fn get(&self, pipeline_id: i32, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+                run_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1251,6 +1559,54 @@ pub mod artifacts {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a specific artifact from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `artifact_name`: Name of the artifact."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+                artifact_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a specific artifact from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `artifact_name`: Name of the artifact."]
+        pub fn get(
+            &self,
+            pipeline_id: i32,
+            run_id: i32,
+            artifact_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+                run_id,
+                artifact_name,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1446,6 +1802,81 @@ pub mod logs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of logs from a pipeline run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+            )
+        }
+        #[doc = "Get a specific log from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `log_id`: ID of the log."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+            run_id: i32,
+            log_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                pipeline_id,
+                run_id,
+                log_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of logs from a pipeline run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        pub fn list(&self, pipeline_id: i32, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+                run_id,
+            )
+        }
+        #[doc = "Get a specific log from a pipeline run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: ID of the pipeline."]
+        #[doc = "* `run_id`: ID of the run of that pipeline."]
+        #[doc = "* `log_id`: ID of the log."]
+        pub fn get(&self, pipeline_id: i32, run_id: i32, log_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+                run_id,
+                log_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
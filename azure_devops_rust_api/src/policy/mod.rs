@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn configurations_client(&self) -> configurations::Client {
         configurations::Client(self.clone())
     }
@@ -142,6 +169,70 @@ impl Client {
         types::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn configurations_client(&self) -> configurations::OrganizationClient {
+        configurations::OrganizationClient(self.clone())
+    }
+    pub fn evaluations_client(&self) -> evaluations::OrganizationClient {
+        evaluations::OrganizationClient(self.clone())
+    }
+    pub fn revisions_client(&self) -> revisions::OrganizationClient {
+        revisions::OrganizationClient(self.clone())
+    }
+    pub fn types_client(&self) -> types::OrganizationClient {
+        types::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn configurations_client(&self) -> configurations::ProjectClient {
+        configurations::ProjectClient(self.clone())
+    }
+    pub fn evaluations_client(&self) -> evaluations::ProjectClient {
+        evaluations::ProjectClient(self.clone())
+    }
+    pub fn revisions_client(&self) -> revisions::ProjectClient {
+        revisions::ProjectClient(self.clone())
+    }
+    pub fn types_client(&self) -> types::ProjectClient {
+        types::ProjectClient(self.clone())
+    }
+}
 pub mod configurations {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -250,6 +341,139 @@ pub mod configurations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of policy configurations in a project.\n\nThe 'scope' parameter for this API should not be used, except for legacy compatability reasons. It returns specifically\nscoped policies and does not support heirarchical nesting. Instead, use the /_apis/git/policy/configurations API, which provides\nfirst class scope filtering support.\n\nThe optional `policyType` parameter can be used to filter the set of policies returned from this method."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a policy configuration of a given policy type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the policy configuration"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                configuration_id,
+            )
+        }
+        #[doc = "Update a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the existing policy configuration to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                configuration_id,
+            )
+        }
+        #[doc = "Delete a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: ID of the policy configuration to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                configuration_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of policy configurations in a project.\n\nThe 'scope' parameter for this API should not be used, except for legacy compatability reasons. It returns specifically\nscoped policies and does not support heirarchical nesting. Instead, use the /_apis/git/policy/configurations API, which provides\nfirst class scope filtering support.\n\nThe optional `policyType` parameter can be used to filter the set of policies returned from this method."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a policy configuration of a given policy type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to create."]
+        pub fn create(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: ID of the policy configuration"]
+        pub fn get(&self, configuration_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Update a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The policy configuration to update."]
+        #[doc = "* `configuration_id`: ID of the existing policy configuration to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PolicyConfiguration>,
+            configuration_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Delete a policy configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: ID of the policy configuration to delete."]
+        pub fn delete(&self, configuration_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                configuration_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -395,6 +619,20 @@ pub mod configurations {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::PolicyConfiguration> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -929,6 +1167,71 @@ pub mod revisions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all revisions for a given policy."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                configuration_id,
+            )
+        }
+        #[doc = "Retrieve a specific revision of a given policy by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        #[doc = "* `revision_id`: The revision ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            configuration_id: i32,
+            revision_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                configuration_id,
+                revision_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve all revisions for a given policy."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        pub fn list(&self, configuration_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                configuration_id,
+            )
+        }
+        #[doc = "Retrieve a specific revision of a given policy by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `configuration_id`: The policy configuration ID."]
+        #[doc = "* `revision_id`: The revision ID."]
+        pub fn get(&self, configuration_id: i32, revision_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                configuration_id,
+                revision_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1056,6 +1359,18 @@ pub mod revisions {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::PolicyConfiguration> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyConfigurationList>;
@@ -1262,6 +1577,88 @@ pub mod evaluations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieves a list of all the policy evaluation statuses for a specific pull request.\n\nEvaluations are retrieved using an artifact ID which uniquely identifies the pull request.\nTo generate an artifact ID for a pull request, use this template:\n```\nvstfs:///CodeReview/CodeReviewId/{projectId}/{pullRequestId}\n```"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `artifact_id`: A string which uniquely identifies the target of a policy evaluation."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            artifact_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, artifact_id)
+        }
+        #[doc = "Gets the present evaluation state of a policy.\n\nEach policy which applies to a pull request will have an evaluation state which is specific to that policy running\nin the context of that pull request. Each evaluation is uniquely identified via a Guid. You can find all the policy\nevaluations for a specific pull request using the List operation of this controller."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            evaluation_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, evaluation_id)
+        }
+        #[doc = "Requeue the policy evaluation.\n\nSome policies define a \"requeue\" action which performs some policy-specific operation.\nYou can trigger this operation by updating an existing policy evaluation and setting the\nPolicyEvaluationRecord.Status field to Queued.\nAlthough any policy evaluation can be requeued, at present only build policies perform any action\nin response. Requeueing a build policy will queue a new build to run (cancelling any existing build which\nis running)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn requeue_policy_evaluation(
+            &self,
+            project: impl Into<String>,
+            evaluation_id: impl Into<String>,
+        ) -> requeue_policy_evaluation::RequestBuilder {
+            Client(self.0.client.clone()).requeue_policy_evaluation(
+                self.0.organization.clone(),
+                project,
+                evaluation_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieves a list of all the policy evaluation statuses for a specific pull request.\n\nEvaluations are retrieved using an artifact ID which uniquely identifies the pull request.\nTo generate an artifact ID for a pull request, use this template:\n```\nvstfs:///CodeReview/CodeReviewId/{projectId}/{pullRequestId}\n```"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `artifact_id`: A string which uniquely identifies the target of a policy evaluation."]
+        pub fn list(&self, artifact_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                artifact_id,
+            )
+        }
+        #[doc = "Gets the present evaluation state of a policy.\n\nEach policy which applies to a pull request will have an evaluation state which is specific to that policy running\nin the context of that pull request. Each evaluation is uniquely identified via a Guid. You can find all the policy\nevaluations for a specific pull request using the List operation of this controller."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn get(&self, evaluation_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                evaluation_id,
+            )
+        }
+        #[doc = "Requeue the policy evaluation.\n\nSome policies define a \"requeue\" action which performs some policy-specific operation.\nYou can trigger this operation by updating an existing policy evaluation and setting the\nPolicyEvaluationRecord.Status field to Queued.\nAlthough any policy evaluation can be requeued, at present only build policies perform any action\nin response. Requeueing a build policy will queue a new build to run (cancelling any existing build which\nis running)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `evaluation_id`: ID of the policy evaluation to be retrieved."]
+        pub fn requeue_policy_evaluation(
+            &self,
+            evaluation_id: impl Into<String>,
+        ) -> requeue_policy_evaluation::RequestBuilder {
+            Client(self.0.client.clone()).requeue_policy_evaluation(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                evaluation_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1404,6 +1801,18 @@ pub mod evaluations {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::PolicyEvaluationRecord> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::PolicyEvaluationRecordList>;
@@ -1704,6 +2113,46 @@ pub mod types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve all available policy types."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Retrieve a specific policy type by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_id`: The policy ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            type_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, type_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve all available policy types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Retrieve a specific policy type by ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_id`: The policy ID."]
+        pub fn get(&self, type_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn behaviors_client(&self) -> behaviors::Client {
         behaviors::Client(self.clone())
     }
@@ -169,6 +196,126 @@ impl Client {
         work_item_types_behaviors::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn behaviors_client(&self) -> behaviors::OrganizationClient {
+        behaviors::OrganizationClient(self.clone())
+    }
+    pub fn controls_client(&self) -> controls::OrganizationClient {
+        controls::OrganizationClient(self.clone())
+    }
+    pub fn fields_client(&self) -> fields::OrganizationClient {
+        fields::OrganizationClient(self.clone())
+    }
+    pub fn groups_client(&self) -> groups::OrganizationClient {
+        groups::OrganizationClient(self.clone())
+    }
+    pub fn layout_client(&self) -> layout::OrganizationClient {
+        layout::OrganizationClient(self.clone())
+    }
+    pub fn lists_client(&self) -> lists::OrganizationClient {
+        lists::OrganizationClient(self.clone())
+    }
+    pub fn pages_client(&self) -> pages::OrganizationClient {
+        pages::OrganizationClient(self.clone())
+    }
+    pub fn processes_client(&self) -> processes::OrganizationClient {
+        processes::OrganizationClient(self.clone())
+    }
+    pub fn rules_client(&self) -> rules::OrganizationClient {
+        rules::OrganizationClient(self.clone())
+    }
+    pub fn states_client(&self) -> states::OrganizationClient {
+        states::OrganizationClient(self.clone())
+    }
+    pub fn system_controls_client(&self) -> system_controls::OrganizationClient {
+        system_controls::OrganizationClient(self.clone())
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::OrganizationClient {
+        work_item_types::OrganizationClient(self.clone())
+    }
+    pub fn work_item_types_behaviors_client(
+        &self,
+    ) -> work_item_types_behaviors::OrganizationClient {
+        work_item_types_behaviors::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn behaviors_client(&self) -> behaviors::ProjectClient {
+        behaviors::ProjectClient(self.clone())
+    }
+    pub fn controls_client(&self) -> controls::ProjectClient {
+        controls::ProjectClient(self.clone())
+    }
+    pub fn fields_client(&self) -> fields::ProjectClient {
+        fields::ProjectClient(self.clone())
+    }
+    pub fn groups_client(&self) -> groups::ProjectClient {
+        groups::ProjectClient(self.clone())
+    }
+    pub fn layout_client(&self) -> layout::ProjectClient {
+        layout::ProjectClient(self.clone())
+    }
+    pub fn lists_client(&self) -> lists::ProjectClient {
+        lists::ProjectClient(self.clone())
+    }
+    pub fn pages_client(&self) -> pages::ProjectClient {
+        pages::ProjectClient(self.clone())
+    }
+    pub fn processes_client(&self) -> processes::ProjectClient {
+        processes::ProjectClient(self.clone())
+    }
+    pub fn rules_client(&self) -> rules::ProjectClient {
+        rules::ProjectClient(self.clone())
+    }
+    pub fn states_client(&self) -> states::ProjectClient {
+        states::ProjectClient(self.clone())
+    }
+    pub fn system_controls_client(&self) -> system_controls::ProjectClient {
+        system_controls::ProjectClient(self.clone())
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::ProjectClient {
+        work_item_types::ProjectClient(self.clone())
+    }
+    pub fn work_item_types_behaviors_client(&self) -> work_item_types_behaviors::ProjectClient {
+        work_item_types_behaviors::ProjectClient(self.clone())
+    }
+}
 pub mod groups {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -336,6 +483,298 @@ pub mod groups {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Moves a group to a different page and section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is i.n"]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_page_id`: ID of the page to remove the group from."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_page(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_page_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_page::RequestBuilder {
+            Client(self.0.client.clone()).move_group_to_page(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_page_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Adds a group to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page to add the group to."]
+        #[doc = "* `section_id`: The ID of the section to add the group to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+            )
+        }
+        #[doc = "Moves a group to a different section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_section(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_section::RequestBuilder {
+            Client(self.0.client.clone()).move_group_to_section(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Updates a group in the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+        #[doc = "Removes a group from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page the group is in"]
+        #[doc = "* `section_id`: The ID of the section to the group is in"]
+        #[doc = "* `group_id`: The ID of the group"]
+        pub fn remove_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> remove_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_group(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Moves a group to a different page and section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is i.n"]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_page_id`: ID of the page to remove the group from."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_page(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_page_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_page::RequestBuilder {
+            Client(self.0.client.clone()).move_group_to_page(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_page_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Adds a group to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page to add the group to."]
+        #[doc = "* `section_id`: The ID of the section to add the group to."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+            )
+        }
+        #[doc = "Moves a group to a different section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `remove_from_section_id`: ID of the section to remove the group from."]
+        pub fn move_group_to_section(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+            remove_from_section_id: impl Into<String>,
+        ) -> move_group_to_section::RequestBuilder {
+            Client(self.0.client.clone()).move_group_to_section(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+                remove_from_section_id,
+            )
+        }
+        #[doc = "Updates a group in the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated group."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `page_id`: The ID of the page the group is in."]
+        #[doc = "* `section_id`: The ID of the section the group is in."]
+        #[doc = "* `group_id`: The ID of the group."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Group>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+        #[doc = "Removes a group from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page the group is in"]
+        #[doc = "* `section_id`: The ID of the section to the group is in"]
+        #[doc = "* `group_id`: The ID of the group"]
+        pub fn remove_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+            section_id: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> remove_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_group(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+                section_id,
+                group_id,
+            )
+        }
+    }
     pub mod move_group_to_page {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1007,13 +1446,87 @@ pub mod processes {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get list of all processes including system and inherited."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Creates a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: CreateProcessModel."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessModel>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a single process of a specified ID."]
+        pub fn get(&self, process_type_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_type_id)
+        }
+        #[doc = "Edit a process of a specific ID."]
+        pub fn edit_process(
+            &self,
+            body: impl Into<models::UpdateProcessModel>,
+            process_type_id: impl Into<String>,
+        ) -> edit_process::RequestBuilder {
+            Client(self.0.client.clone()).edit_process(
+                self.0.organization.clone(),
+                body,
+                process_type_id,
+            )
+        }
+        #[doc = "Removes a process of a specific ID."]
+        pub fn delete(&self, process_type_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), process_type_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get list of all processes including system and inherited."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Creates a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: CreateProcessModel."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessModel>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Get a single process of a specified ID."]
+        pub fn get(&self, process_type_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_type_id)
+        }
+        #[doc = "Edit a process of a specific ID."]
+        pub fn edit_process(
+            &self,
+            body: impl Into<models::UpdateProcessModel>,
+            process_type_id: impl Into<String>,
+        ) -> edit_process::RequestBuilder {
+            Client(self.0.client.clone()).edit_process(
+                self.0.organization.clone(),
+                body,
+                process_type_id,
+            )
+        }
+        #[doc = "Removes a process of a specific ID."]
+        pub fn delete(&self, process_type_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), process_type_id)
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ProcessInfoList> {
@@ -1702,6 +2215,148 @@ pub mod behaviors {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all behaviors in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), process_id)
+        }
+        #[doc = "Creates a single behavior in the given process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ProcessBehaviorCreateRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a behavior of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Replaces a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ProcessBehaviorUpdateRequest>,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                behavior_ref_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of all behaviors in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), process_id)
+        }
+        #[doc = "Creates a single behavior in the given process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ProcessBehaviorCreateRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a behavior of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Replaces a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ProcessBehaviorUpdateRequest>,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                behavior_ref_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2410,6 +3065,140 @@ pub mod work_item_types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all work item types in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), process_id)
+        }
+        #[doc = "Creates a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process on which to create work item type."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a single work item type in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Updates a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Removes a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of all work item types in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        pub fn list(&self, process_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), process_id)
+        }
+        #[doc = "Creates a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process on which to create work item type."]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, process_id)
+        }
+        #[doc = "Returns a single work item type in a process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id, wit_ref_name)
+        }
+        #[doc = "Updates a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessWorkItemTypeRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Removes a work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3135,9 +3924,201 @@ pub mod fields {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all fields in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Adds a field to a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::AddProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Returns a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Updates a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Removes a field from a work item type. Does not permanently delete the field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn remove_work_item_type_field(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> remove_work_item_type_field::RequestBuilder {
+            Client(self.0.client.clone()).remove_work_item_type_field(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of all fields in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Adds a field to a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::AddProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Returns a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Updates a field in a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessWorkItemTypeFieldRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+        #[doc = "Removes a field from a work item type. Does not permanently delete the field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `field_ref_name`: The reference name of the field."]
+        pub fn remove_work_item_type_field(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            field_ref_name: impl Into<String>,
+        ) -> remove_work_item_type_field::RequestBuilder {
+            Client(self.0.client.clone()).remove_work_item_type_field(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                field_ref_name,
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
         use futures::future::BoxFuture;
         #[cfg(target_arch = "wasm32")]
         use futures::future::LocalBoxFuture as BoxFuture;
@@ -3773,6 +4754,36 @@ pub mod layout {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the form layout."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id, wit_ref_name)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the form layout."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), process_id, wit_ref_name)
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4008,64 +5019,258 @@ pub mod controls {
             }
         }
     }
-    pub mod create {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::Control> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Control = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates a control in a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to add the control to."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Moves a control to a specified group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to move the control to."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn move_control_to_group(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> move_control_to_group::RequestBuilder {
+            Client(self.0.client.clone()).move_control_to_group(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Updates a control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
-        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
-        #[doc = r" that resolves to a lower-level [`Response`] value."]
-        pub struct RequestBuilder {
-            pub(crate) client: super::super::Client,
+        #[doc = "Removes a control from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control to remove."]
+        pub fn remove_control_from_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> remove_control_from_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_control_from_group(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates a control in a group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to add the control to."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+            )
+        }
+        #[doc = "Moves a control to a specified group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group to move the control to."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn move_control_to_group(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> move_control_to_group::RequestBuilder {
+            Client(self.0.client.clone()).move_control_to_group(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+        #[doc = "Updates a control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The updated control."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+        #[doc = "Removes a control from the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `group_id`: The ID of the group."]
+        #[doc = "* `control_id`: The ID of the control to remove."]
+        pub fn remove_control_from_group(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            group_id: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> remove_control_from_group::RequestBuilder {
+            Client(self.0.client.clone()).remove_control_from_group(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                group_id,
+                control_id,
+            )
+        }
+    }
+    pub mod create {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::Control> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::Control = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) body: models::Control,
             pub(crate) process_id: String,
@@ -4576,6 +5781,126 @@ pub mod pages {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Adds a page to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Updates a page on the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Removes a page from the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page"]
+        pub fn remove_page(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+        ) -> remove_page::RequestBuilder {
+            Client(self.0.client.clone()).remove_page(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Adds a page to the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page."]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn add(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Updates a page on the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The page"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn update(
+            &self,
+            body: impl Into<models::Page>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Removes a page from the work item form"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `page_id`: The ID of the page"]
+        pub fn remove_page(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            page_id: impl Into<String>,
+        ) -> remove_page::RequestBuilder {
+            Client(self.0.client.clone()).remove_page(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                page_id,
+            )
+        }
+    }
     pub mod add {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5000,43 +6325,161 @@ pub mod system_controls {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::ControlList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ControlList = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets edited system controls for a work item type in a process. To get all system controls (base + edited) use layout API(s)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Updates/adds a system control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+        #[doc = "Deletes a system control modification on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets edited system controls for a work item type in a process. To get all system controls (base + edited) use layout API(s)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Updates/adds a system control on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Control>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+        #[doc = "Deletes a system control modification on the work item form."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process."]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type."]
+        #[doc = "* `control_id`: The ID of the control."]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            control_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                control_id,
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::ControlList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::ControlList = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
+                        ),
+                    )
+                })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
                 self.as_raw_response()
             }
         }
@@ -5478,6 +6921,198 @@ pub mod rules {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all rules in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Adds a rule to work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn add(
+            &self,
+            body: impl Into<models::CreateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Returns a single rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+        #[doc = "Updates a rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+        #[doc = "Removes a rule from the work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of all rules in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Adds a rule to work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn add(
+            &self,
+            body: impl Into<models::CreateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Returns a single rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+        #[doc = "Updates a rule in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateProcessRuleRequest>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+        #[doc = "Removes a rule from the work item type in the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `rule_id`: The ID of the rule"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            rule_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                rule_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6068,146 +7703,380 @@ pub mod rules {
             }
         }
     }
-}
-pub mod states {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod states {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Returns a list of all state definitions in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Creates a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+            }
+        }
+        #[doc = "Returns a single state definition in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn hide_state_definition(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::HideStateModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> hide_state_definition::RequestBuilder {
+            hide_state_definition::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Updates a given state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+        #[doc = "Removes a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                process_id: process_id.into(),
+                wit_ref_name: wit_ref_name.into(),
+                state_id: state_id.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all state definitions in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Creates a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
+        }
+        #[doc = "Returns a single state definition in a work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: The ID of the state"]
+        pub fn hide_state_definition(
+            &self,
+            body: impl Into<models::HideStateModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> hide_state_definition::RequestBuilder {
+            Client(self.0.client.clone()).hide_state_definition(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Updates a given state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemStateInputModel>,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+        #[doc = "Removes a state definition in the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: ID of the process"]
+        #[doc = "* `wit_ref_name`: The reference name of the work item type"]
+        #[doc = "* `state_id`: ID of the state"]
+        pub fn delete(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name: impl Into<String>,
+            state_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
         #[doc = "Returns a list of all state definitions in a work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+            )
         }
         #[doc = "Creates a state definition in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemStateInputModel>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-            }
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+            )
         }
         #[doc = "Returns a single state definition in a work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: The ID of the state"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Hides a state definition in the work item type of the process.Only states with customizationType:System can be hidden."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: The ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: The ID of the state"]
         pub fn hide_state_definition(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::HideStateModel>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> hide_state_definition::RequestBuilder {
-            hide_state_definition::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            Client(self.0.client.clone()).hide_state_definition(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Updates a given state definition in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: ID of the state"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::WorkItemStateInputModel>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
         #[doc = "Removes a state definition in the work item type of the process."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `process_id`: ID of the process"]
         #[doc = "* `wit_ref_name`: The reference name of the work item type"]
         #[doc = "* `state_id`: ID of the state"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             process_id: impl Into<String>,
             wit_ref_name: impl Into<String>,
             state_id: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                process_id: process_id.into(),
-                wit_ref_name: wit_ref_name.into(),
-                state_id: state_id.into(),
-            }
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name,
+                state_id,
+            )
         }
     }
     pub mod list {
@@ -7048,6 +8917,192 @@ pub mod work_item_types_behaviors {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of all behaviors for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Adds a behavior to the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn add(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Updates a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Returns a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn remove_behavior_from_work_item_type(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> remove_behavior_from_work_item_type::RequestBuilder {
+            Client(self.0.client.clone()).remove_behavior_from_work_item_type(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of all behaviors for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn list(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Adds a behavior to the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn add(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Updates a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTypeBehavior>,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                process_id,
+                wit_ref_name_for_behaviors,
+            )
+        }
+        #[doc = "Returns a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn get(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+        #[doc = "Removes a behavior for the work item type of the process."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `process_id`: The ID of the process"]
+        #[doc = "* `wit_ref_name_for_behaviors`: Work item type reference name for the behavior"]
+        #[doc = "* `behavior_ref_name`: The reference name of the behavior"]
+        pub fn remove_behavior_from_work_item_type(
+            &self,
+            process_id: impl Into<String>,
+            wit_ref_name_for_behaviors: impl Into<String>,
+            behavior_ref_name: impl Into<String>,
+        ) -> remove_behavior_from_work_item_type::RequestBuilder {
+            Client(self.0.client.clone()).remove_behavior_from_work_item_type(
+                self.0.organization.clone(),
+                process_id,
+                wit_ref_name_for_behaviors,
+                behavior_ref_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7726,6 +9781,84 @@ pub mod lists {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns meta data of the picklist."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Creates a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Picklist"]
+        pub fn create(&self, body: impl Into<models::PickList>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Returns a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn get(&self, list_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), list_id)
+        }
+        #[doc = "Updates a list."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PickList>,
+            list_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, list_id)
+        }
+        #[doc = "Removes a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn delete(&self, list_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), list_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns meta data of the picklist."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Creates a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Picklist"]
+        pub fn create(&self, body: impl Into<models::PickList>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Returns a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn get(&self, list_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), list_id)
+        }
+        #[doc = "Updates a list."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn update(
+            &self,
+            body: impl Into<models::PickList>,
+            list_id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, list_id)
+        }
+        #[doc = "Removes a picklist."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `list_id`: The ID of the list"]
+        pub fn delete(&self, list_id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), list_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn approvals_client(&self) -> approvals::Client {
         approvals::Client(self.clone())
     }
@@ -154,6 +181,94 @@ impl Client {
         releases::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn approvals_client(&self) -> approvals::OrganizationClient {
+        approvals::OrganizationClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn definitions_client(&self) -> definitions::OrganizationClient {
+        definitions::OrganizationClient(self.clone())
+    }
+    pub fn deployments_client(&self) -> deployments::OrganizationClient {
+        deployments::OrganizationClient(self.clone())
+    }
+    pub fn folders_client(&self) -> folders::OrganizationClient {
+        folders::OrganizationClient(self.clone())
+    }
+    pub fn gates_client(&self) -> gates::OrganizationClient {
+        gates::OrganizationClient(self.clone())
+    }
+    pub fn manual_interventions_client(&self) -> manual_interventions::OrganizationClient {
+        manual_interventions::OrganizationClient(self.clone())
+    }
+    pub fn releases_client(&self) -> releases::OrganizationClient {
+        releases::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn approvals_client(&self) -> approvals::ProjectClient {
+        approvals::ProjectClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn definitions_client(&self) -> definitions::ProjectClient {
+        definitions::ProjectClient(self.clone())
+    }
+    pub fn deployments_client(&self) -> deployments::ProjectClient {
+        deployments::ProjectClient(self.clone())
+    }
+    pub fn folders_client(&self) -> folders::ProjectClient {
+        folders::ProjectClient(self.clone())
+    }
+    pub fn gates_client(&self) -> gates::ProjectClient {
+        gates::ProjectClient(self.clone())
+    }
+    pub fn manual_interventions_client(&self) -> manual_interventions::ProjectClient {
+        manual_interventions::ProjectClient(self.clone())
+    }
+    pub fn releases_client(&self) -> releases::ProjectClient {
+        releases::ProjectClient(self.clone())
+    }
+}
 pub mod releases {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -261,6 +376,7 @@ pub mod releases {
                 project: project.into(),
                 release_id,
                 definition_snapshot_revision,
+                accept: None,
             }
         }
         #[doc = "Update a complete release object."]
@@ -383,6 +499,7 @@ pub mod releases {
                 task_id,
                 start_line: None,
                 end_line: None,
+                accept: None,
             }
         }
         #[doc = "Get logs for a release Id."]
@@ -402,9 +519,337 @@ pub mod releases {
                 organization: organization.into(),
                 project: project.into(),
                 release_id,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a Release"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_release(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> get_release::RequestBuilder {
+            Client(self.0.client.clone()).get_release(
+                self.0.organization.clone(),
+                project,
+                release_id,
+            )
+        }
+        #[doc = "Get a list of releases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to create a release."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseStartMetadata>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get release for a given revision number."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `definition_snapshot_revision`: Definition snapshot revision number."]
+        pub fn get_release_revision(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            definition_snapshot_revision: i32,
+        ) -> get_release_revision::RequestBuilder {
+            Client(self.0.client.clone()).get_release_revision(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                definition_snapshot_revision,
+            )
+        }
+        #[doc = "Update a complete release object."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release object for update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release(
+            &self,
+            body: impl Into<models::Release>,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> update_release::RequestBuilder {
+            Client(self.0.client.clone()).update_release(
+                self.0.organization.clone(),
+                body,
+                project,
+                release_id,
+            )
+        }
+        #[doc = "Update few properties of a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties of release to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release_resource(
+            &self,
+            body: impl Into<models::ReleaseUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> update_release_resource::RequestBuilder {
+            Client(self.0.client.clone()).update_release_resource(
+                self.0.organization.clone(),
+                body,
+                project,
+                release_id,
+            )
+        }
+        #[doc = "Get a release environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        pub fn get_release_environment(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> get_release_environment::RequestBuilder {
+            Client(self.0.client.clone()).get_release_environment(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Update the status of a release environment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment update meta data."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        pub fn update_release_environment(
+            &self,
+            body: impl Into<models::ReleaseEnvironmentUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> update_release_environment::RequestBuilder {
+            Client(self.0.client.clone()).update_release_environment(
+                self.0.organization.clone(),
+                body,
+                project,
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Gets the task log of a release as a plain text file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        #[doc = "* `release_deploy_phase_id`: Release deploy phase Id."]
+        #[doc = "* `task_id`: ReleaseTask Id for the log."]
+        pub fn get_task_log(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            release_deploy_phase_id: i32,
+            task_id: i32,
+        ) -> get_task_log::RequestBuilder {
+            Client(self.0.client.clone()).get_task_log(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                environment_id,
+                release_deploy_phase_id,
+                task_id,
+            )
+        }
+        #[doc = "Get logs for a release Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_logs(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+        ) -> get_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_logs(self.0.organization.clone(), project, release_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a Release"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_release(&self, release_id: i32) -> get_release::RequestBuilder {
+            Client(self.0.client.clone()).get_release(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Get a list of releases"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to create a release."]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseStartMetadata>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get release for a given revision number."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `definition_snapshot_revision`: Definition snapshot revision number."]
+        pub fn get_release_revision(
+            &self,
+            release_id: i32,
+            definition_snapshot_revision: i32,
+        ) -> get_release_revision::RequestBuilder {
+            Client(self.0.client.clone()).get_release_revision(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                definition_snapshot_revision,
+            )
+        }
+        #[doc = "Update a complete release object."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release object for update."]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release(
+            &self,
+            body: impl Into<models::Release>,
+            release_id: i32,
+        ) -> update_release::RequestBuilder {
+            Client(self.0.client.clone()).update_release(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Update few properties of a release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Properties of release to update."]
+        #[doc = "* `release_id`: Id of the release to update."]
+        pub fn update_release_resource(
+            &self,
+            body: impl Into<models::ReleaseUpdateMetadata>,
+            release_id: i32,
+        ) -> update_release_resource::RequestBuilder {
+            Client(self.0.client.clone()).update_release_resource(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Get a release environment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        pub fn get_release_environment(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+        ) -> get_release_environment::RequestBuilder {
+            Client(self.0.client.clone()).get_release_environment(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Update the status of a release environment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Environment update meta data."]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        pub fn update_release_environment(
+            &self,
+            body: impl Into<models::ReleaseEnvironmentUpdateMetadata>,
+            release_id: i32,
+            environment_id: i32,
+        ) -> update_release_environment::RequestBuilder {
+            Client(self.0.client.clone()).update_release_environment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+            )
+        }
+        #[doc = "Gets the task log of a release as a plain text file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of release environment."]
+        #[doc = "* `release_deploy_phase_id`: Release deploy phase Id."]
+        #[doc = "* `task_id`: ReleaseTask Id for the log."]
+        pub fn get_task_log(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            release_deploy_phase_id: i32,
+            task_id: i32,
+        ) -> get_task_log::RequestBuilder {
+            Client(self.0.client.clone()).get_task_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+                release_deploy_phase_id,
+                task_id,
+            )
+        }
+        #[doc = "Get logs for a release Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn get_logs(&self, release_id: i32) -> get_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_logs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+    }
     pub mod get_release {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -810,14 +1255,16 @@ pub mod releases {
                             );
                         }
                         if let Some(min_created_time) = &this.min_created_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minCreatedTime", &min_created_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minCreatedTime",
+                                &crate::date_time::format_date_time(min_created_time)?,
+                            );
                         }
                         if let Some(max_created_time) = &this.max_created_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("maxCreatedTime", &max_created_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "maxCreatedTime",
+                                &crate::date_time::format_date_time(max_created_time)?,
+                            );
                         }
                         if let Some(query_order) = &this.query_order {
                             req.url_mut()
@@ -1046,18 +1493,28 @@ pub mod releases {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -1098,8 +1555,14 @@ pub mod releases {
             pub(crate) project: String,
             pub(crate) release_id: i32,
             pub(crate) definition_snapshot_revision: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1118,11 +1581,15 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
                         let definition_snapshot_revision = &this.definition_snapshot_revision;
                         req.url_mut().query_pairs_mut().append_pair(
                             "definitionSnapshotRevision",
                             &definition_snapshot_revision.to_string(),
                         );
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1665,18 +2132,28 @@ pub mod releases {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -1721,6 +2198,7 @@ pub mod releases {
             pub(crate) task_id: i32,
             pub(crate) start_line: Option<i64>,
             pub(crate) end_line: Option<i64>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Starting line number for logs"]
@@ -1733,6 +2211,11 @@ pub mod releases {
                 self.end_line = Some(end_line);
                 self
             }
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1751,6 +2234,7 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
                         if let Some(start_line) = &this.start_line {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -1761,6 +2245,9 @@ pub mod releases {
                                 .query_pairs_mut()
                                 .append_pair("endLine", &end_line.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1801,19 +2288,29 @@ pub mod releases {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -1853,8 +2350,14 @@ pub mod releases {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) release_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1873,6 +2376,10 @@ pub mod releases {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/zip");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1898,8 +2405,8 @@ pub mod releases {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -1946,24 +2453,77 @@ pub mod approvals {
         #[doc = "Update status of an approval"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: ReleaseApproval object having status, approver and comments."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::ReleaseApproval>,
+            project: impl Into<String>,
+            approval_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                approval_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of approvals"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Update status of an approval"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `body`: ReleaseApproval object having status, approver and comments."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `approval_id`: Id of the approval."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::ReleaseApproval>,
             project: impl Into<String>,
             approval_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
                 approval_id,
-            }
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of approvals"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Update status of an approval"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: ReleaseApproval object having status, approver and comments."]
+        #[doc = "* `approval_id`: Id of the approval."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ReleaseApproval>,
+            approval_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                approval_id,
+            )
         }
     }
     pub mod list {
@@ -2450,9 +3010,185 @@ pub mod definitions {
                 project: project.into(),
                 definition_id,
                 revision,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of release definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: release definition object to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::ReleaseDefinition>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Update a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release definition object to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ReleaseDefinition>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn get(&self, project: impl Into<String>, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition_id)
+        }
+        #[doc = "Delete a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Get revision history for a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        pub fn get_release_definition_history(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+        ) -> get_release_definition_history::RequestBuilder {
+            Client(self.0.client.clone()).get_release_definition_history(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+            )
+        }
+        #[doc = "Get release definition for a given definition_id and revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        #[doc = "* `revision`: Id of the revision."]
+        pub fn get_definition_revision(
+            &self,
+            project: impl Into<String>,
+            definition_id: i32,
+            revision: i32,
+        ) -> get_definition_revision::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_revision(
+                self.0.organization.clone(),
+                project,
+                definition_id,
+                revision,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of release definitions."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: release definition object to create."]
+        pub fn create(&self, body: impl Into<models::ReleaseDefinition>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Release definition object to update."]
+        pub fn update(&self, body: impl Into<models::ReleaseDefinition>) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn get(&self, definition_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Delete a release definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the release definition."]
+        pub fn delete(&self, definition_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Get revision history for a release definition"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        pub fn get_release_definition_history(
+            &self,
+            definition_id: i32,
+        ) -> get_release_definition_history::RequestBuilder {
+            Client(self.0.client.clone()).get_release_definition_history(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+            )
+        }
+        #[doc = "Get release definition for a given definition_id and revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition_id`: Id of the definition."]
+        #[doc = "* `revision`: Id of the revision."]
+        pub fn get_definition_revision(
+            &self,
+            definition_id: i32,
+            revision: i32,
+        ) -> get_definition_revision::RequestBuilder {
+            Client(self.0.client.clone()).get_definition_revision(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition_id,
+                revision,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2714,6 +3450,20 @@ pub mod definitions {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::ReleaseDefinition> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ReleaseDefinitionList>;
@@ -3357,18 +4107,28 @@ pub mod definitions {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3409,8 +4169,14 @@ pub mod definitions {
             pub(crate) project: String,
             pub(crate) definition_id: i32,
             pub(crate) revision: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -3429,6 +4195,10 @@ pub mod definitions {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -3506,6 +4276,20 @@ pub mod deployments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3690,14 +4474,16 @@ pub mod deployments {
                                 .append_pair("createdBy", created_by);
                         }
                         if let Some(min_modified_time) = &this.min_modified_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minModifiedTime", &min_modified_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minModifiedTime",
+                                &crate::date_time::format_date_time(min_modified_time)?,
+                            );
                         }
                         if let Some(max_modified_time) = &this.max_modified_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("maxModifiedTime", &max_modified_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "maxModifiedTime",
+                                &crate::date_time::format_date_time(max_modified_time)?,
+                            );
                         }
                         if let Some(deployment_status) = &this.deployment_status {
                             req.url_mut()
@@ -3736,14 +4522,16 @@ pub mod deployments {
                                 .append_pair("createdFor", created_for);
                         }
                         if let Some(min_started_time) = &this.min_started_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("minStartedTime", &min_started_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "minStartedTime",
+                                &crate::date_time::format_date_time(min_started_time)?,
+                            );
                         }
                         if let Some(max_started_time) = &this.max_started_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("maxStartedTime", &max_started_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "maxStartedTime",
+                                &crate::date_time::format_date_time(max_started_time)?,
+                            );
                         }
                         if let Some(source_branch) = &this.source_branch {
                             req.url_mut()
@@ -3800,83 +4588,197 @@ pub mod folders {
         #[doc = "Arguments:"]
         #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
-        #[doc = "* `path`: Path of the folder."]
-        pub fn list(
+        #[doc = "* `path`: Path of the folder."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                path: path.into(),
+                query_order: None,
+            }
+        }
+        #[doc = "This method is no longer supported. Use CreateFolder with folder parameter API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                path: path.into(),
+            }
+        }
+        #[doc = "Updates an existing folder at given existing path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder to update."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::Folder>,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                path: path.into(),
+            }
+        }
+        #[doc = "Deletes a definition folder for given folder name and path and all it's existing definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder to delete."]
+        pub fn delete(
             &self,
             organization: impl Into<String>,
             project: impl Into<String>,
             path: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
                 project: project.into(),
                 path: path.into(),
-                query_order: None,
             }
         }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            path: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, path)
+        }
         #[doc = "This method is no longer supported. Use CreateFolder with folder parameter API."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: folder."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `path`: Path of the folder."]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Folder>,
             project: impl Into<String>,
             path: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                path: path.into(),
-            }
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, path)
         }
         #[doc = "Updates an existing folder at given existing path."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: folder."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `path`: Path of the folder to update."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::Folder>,
             project: impl Into<String>,
             path: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                path: path.into(),
-            }
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, path)
         }
         #[doc = "Deletes a definition folder for given folder name and path and all it's existing definitions."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `path`: Path of the folder to delete."]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             path: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                path: path.into(),
-            }
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, path)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn list(&self, path: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "This method is no longer supported. Use CreateFolder with folder parameter API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `path`: Path of the folder."]
+        pub fn create(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "Updates an existing folder at given existing path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: folder."]
+        #[doc = "* `path`: Path of the folder to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::Folder>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                path,
+            )
+        }
+        #[doc = "Deletes a definition folder for given folder name and path and all it's existing definitions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Path of the folder to delete."]
+        pub fn delete(&self, path: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                path,
+            )
         }
     }
     pub mod list {
@@ -4387,6 +5289,48 @@ pub mod gates {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Updates the gate for a deployment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to patch the Release Gates."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `gate_step_id`: Gate step Id."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GateUpdateMetadata>,
+            project: impl Into<String>,
+            gate_step_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                gate_step_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Updates the gate for a deployment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Metadata to patch the Release Gates."]
+        #[doc = "* `gate_step_id`: Gate step Id."]
+        pub fn update(
+            &self,
+            body: impl Into<models::GateUpdateMetadata>,
+            gate_step_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                gate_step_id,
+            )
+        }
+    }
     pub mod update {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4507,19 +5451,162 @@ pub mod gates {
             }
         }
     }
-}
-pub mod attachments {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod attachments {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get the release task attachments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_release_task_attachments(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_release_task_attachments::RequestBuilder {
+            get_release_task_attachments::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id: plan_id.into(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "Get a release task attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_release_task_attachment_content(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_release_task_attachment_content::RequestBuilder {
+            get_release_task_attachment_content::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id: plan_id.into(),
+                timeline_id: timeline_id.into(),
+                record_id: record_id.into(),
+                type_: type_.into(),
+                name: name.into(),
+                accept: None,
+            }
+        }
+        #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_task_attachments(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_task_attachments::RequestBuilder {
+            get_task_attachments::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id: timeline_id.into(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_task_attachment_content(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_task_attachment_content::RequestBuilder {
+            get_task_attachment_content::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id: timeline_id.into(),
+                record_id: record_id.into(),
+                type_: type_.into(),
+                name: name.into(),
+                accept: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
         #[doc = "Get the release task attachments."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4528,7 +5615,6 @@ pub mod attachments {
         #[doc = "* `type_`: Type of the attachment."]
         pub fn get_release_task_attachments(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4536,21 +5622,19 @@ pub mod attachments {
             plan_id: impl Into<String>,
             type_: impl Into<String>,
         ) -> get_release_task_attachments::RequestBuilder {
-            get_release_task_attachments::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_release_task_attachments(
+                self.0.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                plan_id: plan_id.into(),
-                type_: type_.into(),
-            }
+                plan_id,
+                type_,
+            )
         }
         #[doc = "Get a release task attachment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4562,7 +5646,6 @@ pub mod attachments {
         #[doc = "* `name`: Name of the attachment."]
         pub fn get_release_task_attachment_content(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4573,24 +5656,22 @@ pub mod attachments {
             type_: impl Into<String>,
             name: impl Into<String>,
         ) -> get_release_task_attachment_content::RequestBuilder {
-            get_release_task_attachment_content::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_release_task_attachment_content(
+                self.0.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                plan_id: plan_id.into(),
-                timeline_id: timeline_id.into(),
-                record_id: record_id.into(),
-                type_: type_.into(),
-                name: name.into(),
-            }
+                plan_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
         }
         #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4599,7 +5680,6 @@ pub mod attachments {
         #[doc = "* `type_`: Type of the attachment."]
         pub fn get_task_attachments(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4607,21 +5687,19 @@ pub mod attachments {
             timeline_id: impl Into<String>,
             type_: impl Into<String>,
         ) -> get_task_attachments::RequestBuilder {
-            get_task_attachments::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_task_attachments(
+                self.0.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                timeline_id: timeline_id.into(),
-                type_: type_.into(),
-            }
+                timeline_id,
+                type_,
+            )
         }
         #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `release_id`: Id of the release."]
         #[doc = "* `environment_id`: Id of the release environment."]
@@ -4632,7 +5710,6 @@ pub mod attachments {
         #[doc = "* `name`: Name of the attachment."]
         pub fn get_task_attachment_content(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             release_id: i32,
             environment_id: i32,
@@ -4642,18 +5719,139 @@ pub mod attachments {
             type_: impl Into<String>,
             name: impl Into<String>,
         ) -> get_task_attachment_content::RequestBuilder {
-            get_task_attachment_content::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_task_attachment_content(
+                self.0.organization.clone(),
+                project,
                 release_id,
                 environment_id,
                 attempt_id,
-                timeline_id: timeline_id.into(),
-                record_id: record_id.into(),
-                type_: type_.into(),
-                name: name.into(),
-            }
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the release task attachments."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_release_task_attachments(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_release_task_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_release_task_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id,
+                type_,
+            )
+        }
+        #[doc = "Get a release task attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `plan_id`: Plan Id of the deploy phase."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_release_task_attachment_content(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            plan_id: impl Into<String>,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_release_task_attachment_content::RequestBuilder {
+            Client(self.0.client.clone()).get_release_task_attachment_content(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                plan_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
+        }
+        #[doc = "GetTaskAttachments API is deprecated. Use GetReleaseTaskAttachments API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `type_`: Type of the attachment."]
+        pub fn get_task_attachments(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_task_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_task_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id,
+                type_,
+            )
+        }
+        #[doc = "GetTaskAttachmentContent API is deprecated. Use GetReleaseTaskAttachmentContent API instead."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `environment_id`: Id of the release environment."]
+        #[doc = "* `attempt_id`: Attempt number of deployment."]
+        #[doc = "* `timeline_id`: Timeline Id of the task."]
+        #[doc = "* `record_id`: Record Id of attachment."]
+        #[doc = "* `type_`: Type of the attachment."]
+        #[doc = "* `name`: Name of the attachment."]
+        pub fn get_task_attachment_content(
+            &self,
+            release_id: i32,
+            environment_id: i32,
+            attempt_id: i32,
+            timeline_id: impl Into<String>,
+            record_id: impl Into<String>,
+            type_: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_task_attachment_content::RequestBuilder {
+            Client(self.0.client.clone()).get_task_attachment_content(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                environment_id,
+                attempt_id,
+                timeline_id,
+                record_id,
+                type_,
+                name,
+            )
         }
     }
     pub mod get_release_task_attachments {
@@ -4783,19 +5981,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4842,8 +6050,14 @@ pub mod attachments {
             pub(crate) record_id: String,
             pub(crate) type_: String,
             pub(crate) name: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4862,6 +6076,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4881,8 +6099,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5020,19 +6238,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5078,8 +6306,14 @@ pub mod attachments {
             pub(crate) record_id: String,
             pub(crate) type_: String,
             pub(crate) name: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5098,6 +6332,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5117,8 +6355,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5205,6 +6443,105 @@ pub mod manual_interventions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "List all manual interventions for a given release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn list(&self, project: impl Into<String>, release_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, release_id)
+        }
+        #[doc = "Get manual intervention for a given release and manual intervention id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                manual_intervention_id,
+            )
+        }
+        #[doc = "Update manual intervention."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Meta data to update manual intervention."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ManualInterventionUpdateMetadata>,
+            project: impl Into<String>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                release_id,
+                manual_intervention_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "List all manual interventions for a given release."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        pub fn list(&self, release_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+        #[doc = "Get manual intervention for a given release and manual intervention id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn get(&self, release_id: i32, manual_intervention_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                manual_intervention_id,
+            )
+        }
+        #[doc = "Update manual intervention."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Meta data to update manual intervention."]
+        #[doc = "* `release_id`: Id of the release."]
+        #[doc = "* `manual_intervention_id`: Id of the manual intervention."]
+        pub fn update(
+            &self,
+            body: impl Into<models::ManualInterventionUpdateMetadata>,
+            release_id: i32,
+            manual_intervention_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                release_id,
+                manual_intervention_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
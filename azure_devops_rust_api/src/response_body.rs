@@ -0,0 +1,60 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Support for operations that return raw (non-JSON) response bodies, such as file downloads.
+
+use azure_core::error::{ErrorKind, ResultExt};
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::{Stream, TryStreamExt};
+
+/// Writes a response body to `writer` as it is received, returning the number of bytes written.
+pub(crate) async fn write_to<W>(
+    rsp: azure_core::Response,
+    writer: &mut W,
+) -> azure_core::Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    write_stream(rsp.into_body(), writer).await
+}
+
+async fn write_stream<S, W>(mut body: S, writer: &mut W) -> azure_core::Result<u64>
+where
+    S: Stream<Item = azure_core::Result<bytes::Bytes>> + Unpin,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written = 0;
+    while let Some(bytes) = body.try_next().await? {
+        writer
+            .write_all(&bytes)
+            .await
+            .context(ErrorKind::Io, "failed to write response body")?;
+        written += bytes.len() as u64;
+    }
+    writer
+        .flush()
+        .await
+        .context(ErrorKind::Io, "failed to write response body")?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_stream() {
+        futures::executor::block_on(async {
+            let chunks: Vec<azure_core::Result<bytes::Bytes>> = vec![
+                Ok(bytes::Bytes::from_static(b"PK\x03\x04")),
+                Ok(bytes::Bytes::from_static(b"\xff\x00")),
+            ];
+            let mut writer = futures::io::Cursor::new(Vec::new());
+            let written = write_stream(futures::stream::iter(chunks), &mut writer)
+                .await
+                .unwrap();
+            assert_eq!(written, 6);
+            assert_eq!(writer.into_inner(), b"PK\x03\x04\xff\x00");
+        });
+    }
+}
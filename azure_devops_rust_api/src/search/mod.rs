@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn code_search_results_client(&self) -> code_search_results::Client {
         code_search_results::Client(self.clone())
     }
@@ -148,6 +175,82 @@ impl Client {
         work_item_search_results::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn code_search_results_client(&self) -> code_search_results::OrganizationClient {
+        code_search_results::OrganizationClient(self.clone())
+    }
+    pub fn package_search_results_client(&self) -> package_search_results::OrganizationClient {
+        package_search_results::OrganizationClient(self.clone())
+    }
+    pub fn repositories_client(&self) -> repositories::OrganizationClient {
+        repositories::OrganizationClient(self.clone())
+    }
+    pub fn tfvc_client(&self) -> tfvc::OrganizationClient {
+        tfvc::OrganizationClient(self.clone())
+    }
+    pub fn wiki_search_results_client(&self) -> wiki_search_results::OrganizationClient {
+        wiki_search_results::OrganizationClient(self.clone())
+    }
+    pub fn work_item_search_results_client(&self) -> work_item_search_results::OrganizationClient {
+        work_item_search_results::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn code_search_results_client(&self) -> code_search_results::ProjectClient {
+        code_search_results::ProjectClient(self.clone())
+    }
+    pub fn package_search_results_client(&self) -> package_search_results::ProjectClient {
+        package_search_results::ProjectClient(self.clone())
+    }
+    pub fn repositories_client(&self) -> repositories::ProjectClient {
+        repositories::ProjectClient(self.clone())
+    }
+    pub fn tfvc_client(&self) -> tfvc::ProjectClient {
+        tfvc::ProjectClient(self.clone())
+    }
+    pub fn wiki_search_results_client(&self) -> wiki_search_results::ProjectClient {
+        wiki_search_results::ProjectClient(self.clone())
+    }
+    pub fn work_item_search_results_client(&self) -> work_item_search_results::ProjectClient {
+        work_item_search_results::ProjectClient(self.clone())
+    }
+}
 pub mod package_search_results {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -173,6 +276,34 @@ pub mod package_search_results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Package Search Request."]
+        pub fn fetch_package_search_results(
+            &self,
+            body: impl Into<models::PackageSearchRequest>,
+        ) -> fetch_package_search_results::RequestBuilder {
+            Client(self.0.client.clone())
+                .fetch_package_search_results(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Package Search Request."]
+        pub fn fetch_package_search_results(
+            &self,
+            body: impl Into<models::PackageSearchRequest>,
+        ) -> fetch_package_search_results::RequestBuilder {
+            Client(self.0.client.clone())
+                .fetch_package_search_results(self.0.organization.clone(), body)
+        }
+    }
     pub mod fetch_package_search_results {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -205,7 +336,7 @@ pub mod package_search_results {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -332,6 +463,42 @@ pub mod code_search_results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Code Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_code_search_results(
+            &self,
+            body: impl Into<models::CodeSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_code_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_code_search_results(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Code Search Request."]
+        pub fn fetch_code_search_results(
+            &self,
+            body: impl Into<models::CodeSearchRequest>,
+        ) -> fetch_code_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_code_search_results(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod fetch_code_search_results {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -480,6 +647,35 @@ pub mod repositories {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides status of Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `repository`: Repository ID or repository name."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            repository: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, repository)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides status of Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `repository`: Repository ID or repository name."]
+        pub fn get(&self, repository: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                repository,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -626,6 +822,23 @@ pub mod tfvc {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides status of TFVC Repository."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides status of TFVC Repository."]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -775,6 +988,42 @@ pub mod wiki_search_results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Wiki Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_wiki_search_results(
+            &self,
+            body: impl Into<models::WikiSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_wiki_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_wiki_search_results(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Wiki Search Request."]
+        pub fn fetch_wiki_search_results(
+            &self,
+            body: impl Into<models::WikiSearchRequest>,
+        ) -> fetch_wiki_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_wiki_search_results(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod fetch_wiki_search_results {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -923,6 +1172,42 @@ pub mod work_item_search_results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Work Item Search Request."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn fetch_work_item_search_results(
+            &self,
+            body: impl Into<models::WorkItemSearchRequest>,
+            project: impl Into<String>,
+        ) -> fetch_work_item_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_work_item_search_results(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Provides a set of results for the search text."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The Work Item Search Request."]
+        pub fn fetch_work_item_search_results(
+            &self,
+            body: impl Into<models::WorkItemSearchRequest>,
+        ) -> fetch_work_item_search_results::RequestBuilder {
+            Client(self.0.client.clone()).fetch_work_item_search_results(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod fetch_work_item_search_results {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
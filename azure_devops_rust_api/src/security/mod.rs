@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn access_control_entries_client(&self) -> access_control_entries::Client {
         access_control_entries::Client(self.clone())
     }
@@ -142,6 +169,70 @@ impl Client {
         security_namespaces::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn access_control_entries_client(&self) -> access_control_entries::OrganizationClient {
+        access_control_entries::OrganizationClient(self.clone())
+    }
+    pub fn access_control_lists_client(&self) -> access_control_lists::OrganizationClient {
+        access_control_lists::OrganizationClient(self.clone())
+    }
+    pub fn permissions_client(&self) -> permissions::OrganizationClient {
+        permissions::OrganizationClient(self.clone())
+    }
+    pub fn security_namespaces_client(&self) -> security_namespaces::OrganizationClient {
+        security_namespaces::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn access_control_entries_client(&self) -> access_control_entries::ProjectClient {
+        access_control_entries::ProjectClient(self.clone())
+    }
+    pub fn access_control_lists_client(&self) -> access_control_lists::ProjectClient {
+        access_control_lists::ProjectClient(self.clone())
+    }
+    pub fn permissions_client(&self) -> permissions::ProjectClient {
+        permissions::ProjectClient(self.clone())
+    }
+    pub fn security_namespaces_client(&self) -> security_namespaces::ProjectClient {
+        security_namespaces::ProjectClient(self.clone())
+    }
+}
 pub mod access_control_entries {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -187,6 +278,64 @@ pub mod access_control_entries {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Add or update ACEs in the ACL for the provided token. The request body contains the target token, a list of [ACEs](https://docs.microsoft.com/en-us/rest/api/azure/devops/security/access%20control%20entries/set%20access%20control%20entries?#accesscontrolentry) and a optional merge parameter. In the case of a collision (by identity descriptor) with an existing ACE in the ACL, the \"merge\" parameter determines the behavior. If set, the existing ACE has its allow and deny merged with the incoming ACE's allow and deny. If unset, the existing ACE is displaced."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_entries(
+            &self,
+            body: impl Into<serde_json::Value>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_entries::RequestBuilder {
+            Client(self.0.client.clone()).set_access_control_entries(
+                body,
+                security_namespace_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove the specified ACEs from the ACL belonging to the specified token."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_entries(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_entries::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_access_control_entries(security_namespace_id, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Add or update ACEs in the ACL for the provided token. The request body contains the target token, a list of [ACEs](https://docs.microsoft.com/en-us/rest/api/azure/devops/security/access%20control%20entries/set%20access%20control%20entries?#accesscontrolentry) and a optional merge parameter. In the case of a collision (by identity descriptor) with an existing ACE in the ACL, the \"merge\" parameter determines the behavior. If set, the existing ACE has its allow and deny merged with the incoming ACE's allow and deny. If unset, the existing ACE is displaced."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_entries(
+            &self,
+            body: impl Into<serde_json::Value>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_entries::RequestBuilder {
+            Client(self.0.client.clone()).set_access_control_entries(
+                body,
+                security_namespace_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove the specified ACEs from the ACL belonging to the specified token."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_entries(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_entries::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_access_control_entries(security_namespace_id, self.0.organization.clone())
+        }
+    }
     pub mod set_access_control_entries {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -510,6 +659,80 @@ pub mod access_control_lists {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Return a list of access control lists for the specified security namespace and token. All ACLs in the security namespace will be retrieved if no optional parameters are provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(security_namespace_id, self.0.organization.clone())
+        }
+        #[doc = "Create or update one or more access control lists. All data that currently exists for the ACLs supplied will be overwritten."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of ACLs to create or update."]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_lists(
+            &self,
+            body: impl Into<models::AccessControlListBody>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_lists::RequestBuilder {
+            Client(self.0.client.clone()).set_access_control_lists(
+                body,
+                security_namespace_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove access control lists under the specfied security namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_lists(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_lists::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_access_control_lists(security_namespace_id, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Return a list of access control lists for the specified security namespace and token. All ACLs in the security namespace will be retrieved if no optional parameters are provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(security_namespace_id, self.0.organization.clone())
+        }
+        #[doc = "Create or update one or more access control lists. All data that currently exists for the ACLs supplied will be overwritten."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of ACLs to create or update."]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn set_access_control_lists(
+            &self,
+            body: impl Into<models::AccessControlListBody>,
+            security_namespace_id: impl Into<String>,
+        ) -> set_access_control_lists::RequestBuilder {
+            Client(self.0.client.clone()).set_access_control_lists(
+                body,
+                security_namespace_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove access control lists under the specfied security namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn remove_access_control_lists(
+            &self,
+            security_namespace_id: impl Into<String>,
+        ) -> remove_access_control_lists::RequestBuilder {
+            Client(self.0.client.clone())
+                .remove_access_control_lists(security_namespace_id, self.0.organization.clone())
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -986,6 +1209,102 @@ pub mod permissions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Evaluates whether the caller has the specified permissions on the specified set of security tokens."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `permissions`: Permissions to evaluate."]
+        pub fn has_permissions(
+            &self,
+            security_namespace_id: impl Into<String>,
+            permissions: i32,
+        ) -> has_permissions::RequestBuilder {
+            Client(self.0.client.clone()).has_permissions(
+                security_namespace_id,
+                self.0.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Removes the specified permissions on a security token for a user or group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `descriptor`: Identity descriptor of the user to remove permissions for."]
+        #[doc = "* `permissions`: Permissions to remove."]
+        pub fn remove_permission(
+            &self,
+            security_namespace_id: impl Into<String>,
+            descriptor: impl Into<String>,
+            permissions: i32,
+        ) -> remove_permission::RequestBuilder {
+            Client(self.0.client.clone()).remove_permission(
+                security_namespace_id,
+                descriptor,
+                self.0.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Evaluates multiple permissions for the calling user.  Note: This method does not aggregate the results, nor does it short-circuit if one of the permissions evaluates to false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of evaluation requests."]
+        pub fn has_permissions_batch(
+            &self,
+            body: impl Into<models::PermissionEvaluationBatch>,
+        ) -> has_permissions_batch::RequestBuilder {
+            Client(self.0.client.clone()).has_permissions_batch(body, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Evaluates whether the caller has the specified permissions on the specified set of security tokens."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `permissions`: Permissions to evaluate."]
+        pub fn has_permissions(
+            &self,
+            security_namespace_id: impl Into<String>,
+            permissions: i32,
+        ) -> has_permissions::RequestBuilder {
+            Client(self.0.client.clone()).has_permissions(
+                security_namespace_id,
+                self.0.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Removes the specified permissions on a security token for a user or group."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        #[doc = "* `descriptor`: Identity descriptor of the user to remove permissions for."]
+        #[doc = "* `permissions`: Permissions to remove."]
+        pub fn remove_permission(
+            &self,
+            security_namespace_id: impl Into<String>,
+            descriptor: impl Into<String>,
+            permissions: i32,
+        ) -> remove_permission::RequestBuilder {
+            Client(self.0.client.clone()).remove_permission(
+                security_namespace_id,
+                descriptor,
+                self.0.organization.clone(),
+                permissions,
+            )
+        }
+        #[doc = "Evaluates multiple permissions for the calling user.  Note: This method does not aggregate the results, nor does it short-circuit if one of the permissions evaluates to false."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The set of evaluation requests."]
+        pub fn has_permissions_batch(
+            &self,
+            body: impl Into<models::PermissionEvaluationBatch>,
+        ) -> has_permissions_batch::RequestBuilder {
+            Client(self.0.client.clone()).has_permissions_batch(body, self.0.organization.clone())
+        }
+    }
     pub mod has_permissions {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1420,6 +1739,26 @@ pub mod security_namespaces {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "List all security namespaces or just the specified namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), security_namespace_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "List all security namespaces or just the specified namespace."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `security_namespace_id`: Security namespace identifier."]
+        pub fn query(&self, security_namespace_id: impl Into<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), security_namespace_id)
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn roleassignments_client(&self) -> roleassignments::Client {
         roleassignments::Client(self.clone())
     }
@@ -136,6 +163,58 @@ impl Client {
         roledefinitions::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn roleassignments_client(&self) -> roleassignments::OrganizationClient {
+        roleassignments::OrganizationClient(self.clone())
+    }
+    pub fn roledefinitions_client(&self) -> roledefinitions::OrganizationClient {
+        roledefinitions::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn roleassignments_client(&self) -> roleassignments::ProjectClient {
+        roleassignments::ProjectClient(self.clone())
+    }
+    pub fn roledefinitions_client(&self) -> roledefinitions::ProjectClient {
+        roledefinitions::ProjectClient(self.clone())
+    }
+}
 pub mod roleassignments {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -267,6 +346,206 @@ pub mod roleassignments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn change_inheritance(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            inherit_permissions: bool,
+        ) -> change_inheritance::RequestBuilder {
+            Client(self.0.client.clone()).change_inheritance(
+                scope_id,
+                resource_id,
+                inherit_permissions,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Get role assignments for the resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource that is assigned the scope"]
+        pub fn list(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(scope_id, resource_id, self.0.organization.clone())
+        }
+        #[doc = "Set role assignments on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Roles to be assigned"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be assigned"]
+        pub fn set_role_assignments(
+            &self,
+            body: Vec<models::UserRoleAssignmentRef>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> set_role_assignments::RequestBuilder {
+            Client(self.0.client.clone()).set_role_assignments(
+                body,
+                scope_id,
+                resource_id,
+                self.0.organization.clone(),
+            )
+        }
+        pub fn remove_role_assignments(
+            &self,
+            body: Vec<String>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> remove_role_assignments::RequestBuilder {
+            Client(self.0.client.clone()).remove_role_assignments(
+                body,
+                scope_id,
+                resource_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Set role assignment on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Roles to be assigned"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be assigned"]
+        pub fn set_role_assignment(
+            &self,
+            body: impl Into<models::UserRoleAssignmentRef>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            identity_id: impl Into<String>,
+        ) -> set_role_assignment::RequestBuilder {
+            Client(self.0.client.clone()).set_role_assignment(
+                body,
+                scope_id,
+                resource_id,
+                identity_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove the role assignment on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be removed"]
+        #[doc = "* `identity_id`: Identity on which the assignment is to be removed"]
+        pub fn remove_role_assignment(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            identity_id: impl Into<String>,
+        ) -> remove_role_assignment::RequestBuilder {
+            Client(self.0.client.clone()).remove_role_assignment(
+                scope_id,
+                resource_id,
+                identity_id,
+                self.0.organization.clone(),
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn change_inheritance(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            inherit_permissions: bool,
+        ) -> change_inheritance::RequestBuilder {
+            Client(self.0.client.clone()).change_inheritance(
+                scope_id,
+                resource_id,
+                inherit_permissions,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Get role assignments for the resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource that is assigned the scope"]
+        pub fn list(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(scope_id, resource_id, self.0.organization.clone())
+        }
+        #[doc = "Set role assignments on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Roles to be assigned"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be assigned"]
+        pub fn set_role_assignments(
+            &self,
+            body: Vec<models::UserRoleAssignmentRef>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> set_role_assignments::RequestBuilder {
+            Client(self.0.client.clone()).set_role_assignments(
+                body,
+                scope_id,
+                resource_id,
+                self.0.organization.clone(),
+            )
+        }
+        pub fn remove_role_assignments(
+            &self,
+            body: Vec<String>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+        ) -> remove_role_assignments::RequestBuilder {
+            Client(self.0.client.clone()).remove_role_assignments(
+                body,
+                scope_id,
+                resource_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Set role assignment on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Roles to be assigned"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be assigned"]
+        pub fn set_role_assignment(
+            &self,
+            body: impl Into<models::UserRoleAssignmentRef>,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            identity_id: impl Into<String>,
+        ) -> set_role_assignment::RequestBuilder {
+            Client(self.0.client.clone()).set_role_assignment(
+                body,
+                scope_id,
+                resource_id,
+                identity_id,
+                self.0.organization.clone(),
+            )
+        }
+        #[doc = "Remove the role assignment on a resource"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_id`: Id of the assigned scope"]
+        #[doc = "* `resource_id`: Id of the resource on which the role is to be removed"]
+        #[doc = "* `identity_id`: Identity on which the assignment is to be removed"]
+        pub fn remove_role_assignment(
+            &self,
+            scope_id: impl Into<String>,
+            resource_id: impl Into<String>,
+            identity_id: impl Into<String>,
+        ) -> remove_role_assignment::RequestBuilder {
+            Client(self.0.client.clone()).remove_role_assignment(
+                scope_id,
+                resource_id,
+                identity_id,
+                self.0.organization.clone(),
+            )
+        }
+    }
     pub mod change_inheritance {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -992,6 +1271,18 @@ pub mod roledefinitions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        pub fn list(&self, scope_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(scope_id, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, scope_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(scope_id, self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,360 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps Server (on-premises) support.
+//!
+//! Azure DevOps Services URLs are of the form `https://dev.azure.com/{organization}/{project}/_apis/...`,
+//! with some services hosted on their own sub-host (e.g. `https://vssps.dev.azure.com`,
+//! `https://vsrm.dev.azure.com`). Azure DevOps Server hosts all services under a single collection URL,
+//! e.g. `https://tfs.corp/tfs/DefaultCollection/{project}/_apis/...`.
+//!
+//! A [`CollectionUrl`] splits a collection URL into the endpoint and the collection name. The
+//! collection name takes the place of the organization, and the endpoint replaces all of the
+//! Azure DevOps Services hosts.
+//!
+//! Older servers do not support the latest API version. Use [`ApiVersion`] to select the
+//! `api-version` sent with each request.
+//!
+//! The [`AdoClientBuilder`](crate::AdoClientBuilder) supports both:
+//! ```rust
+//!     let collection = CollectionUrl::parse("https://tfs.corp/tfs/DefaultCollection")?;
+//!     let client = AdoClient::server_builder(credential, collection)
+//!         .api_version(ApiVersion::V6_0)
+//!         .build();
+//! ```
+//!
+//! Alternatively, configure a service client directly:
+//! ```rust
+//!     let collection = CollectionUrl::parse("https://tfs.corp/tfs/DefaultCollection")?;
+//!     let git_client = git::ClientBuilder::new(credential)
+//!         .endpoint(collection.endpoint().clone())
+//!         .per_call_policies(vec![server::api_version_policy(ApiVersion::V6_0)])
+//!         .build()
+//!         .for_organization(collection.collection());
+//! ```
+use async_trait::async_trait;
+use azure_core::error::{Error, ErrorKind};
+use azure_core::{Body, Context, Policy, PolicyResult, Request, Url};
+use std::sync::Arc;
+
+/// An Azure DevOps Server collection URL, e.g. `https://tfs.corp/tfs/DefaultCollection`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionUrl {
+    endpoint: Url,
+    collection: String,
+}
+
+impl CollectionUrl {
+    /// Parse a collection URL.
+    ///
+    /// The last path segment is the collection name, and the rest of the URL is the endpoint.
+    pub fn parse(url: &str) -> azure_core::Result<Self> {
+        let url = Url::parse(url.trim_end_matches('/'))?;
+        let mut segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+            .unwrap_or_default();
+        let collection = segments.pop().ok_or_else(|| {
+            Error::message(
+                ErrorKind::DataConversion,
+                format!("collection URL has no collection name: {url}"),
+            )
+        })?;
+        let collection = collection.to_string();
+        let mut endpoint = url.clone();
+        endpoint.set_path(&segments.join("/"));
+        endpoint.set_query(None);
+        endpoint.set_fragment(None);
+        Ok(Self {
+            endpoint,
+            collection,
+        })
+    }
+
+    /// The endpoint, i.e. the collection URL without the collection name.
+    ///
+    /// This is used as the endpoint for all services.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// The collection name, which is used in place of the organization.
+    pub fn collection(&self) -> &str {
+        &self.collection
+    }
+}
+
+impl std::fmt::Display for CollectionUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            self.endpoint.as_str().trim_end_matches('/'),
+            self.collection
+        )
+    }
+}
+
+/// Azure DevOps REST API version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    /// Azure DevOps Server 2020.
+    V6_0,
+    /// Azure DevOps Server 2022.
+    V7_0,
+    /// Azure DevOps Services, Azure DevOps Server 2022.1 and later.
+    #[default]
+    V7_1,
+}
+
+impl ApiVersion {
+    /// The version number, e.g. `7.1`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiVersion::V6_0 => "6.0",
+            ApiVersion::V7_0 => "7.0",
+            ApiVersion::V7_1 => "7.1",
+        }
+    }
+
+    /// The major and minor version numbers, e.g. `(7, 1)`.
+    fn number(&self) -> (u32, u32) {
+        match self {
+            ApiVersion::V6_0 => (6, 0),
+            ApiVersion::V7_0 => (7, 0),
+            ApiVersion::V7_1 => (7, 1),
+        }
+    }
+
+    /// Apply this version to an operation's `api-version` value, keeping any preview suffix.
+    ///
+    /// Versions that are not newer than this version are kept, so `5.1-preview.1` is unchanged for
+    /// [`ApiVersion::V6_0`]. Newer versions are downgraded: `7.1-preview.2` becomes
+    /// `6.0-preview.2`. The preview revision is not adjusted, so it may not exist in the older
+    /// version.
+    fn apply(&self, api_version: &str) -> String {
+        let (number, suffix) = match api_version.split_once('-') {
+            Some((number, suffix)) => (number, Some(suffix)),
+            None => (api_version, None),
+        };
+        let version = number
+            .split_once('.')
+            .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)));
+        match (version, suffix) {
+            (Some(version), _) if version <= self.number() => api_version.to_string(),
+            (_, Some(suffix)) => format!("{}-{suffix}", self.as_str()),
+            (_, None) => self.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Policy that sets the `api-version` query parameter of each request to the selected version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiVersionPolicy {
+    api_version: ApiVersion,
+}
+
+impl ApiVersionPolicy {
+    /// Create a new policy that selects the given API version.
+    pub fn new(api_version: ApiVersion) -> Self {
+        Self { api_version }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Policy for ApiVersionPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        set_api_version(request.url_mut(), self.api_version);
+        set_batch_api_version(request);
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+/// Rewrite the `api-version` query parameter in place, leaving the other parameters as they were
+/// encoded.
+fn set_api_version(url: &mut Url, api_version: ApiVersion) {
+    let query = url
+        .query()
+        .and_then(|query| replace_api_version(query, |value| api_version.apply(value)));
+    if let Some(query) = query {
+        url.set_query(Some(&query));
+    }
+}
+
+/// Set the `api-version` of the requests in the body of a `$batch` request to the `api-version`
+/// of the `$batch` request itself, so that they use the selected version too.
+fn set_batch_api_version(request: &mut Request) {
+    let is_batch = request
+        .url()
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        == Some("$batch");
+    if !is_batch {
+        return;
+    }
+    let api_version = match request
+        .url()
+        .query_pairs()
+        .find(|(k, _)| k == azure_core::query_param::API_VERSION)
+    {
+        Some((_, api_version)) => api_version.into_owned(),
+        None => return,
+    };
+    let mut requests: Vec<serde_json::Value> = match request.body() {
+        Body::Bytes(bytes) => match serde_json::from_slice(bytes) {
+            Ok(requests) => requests,
+            Err(_) => return,
+        },
+        #[cfg(not(target_arch = "wasm32"))]
+        Body::SeekableStream(_) => return,
+    };
+    for uri in requests
+        .iter_mut()
+        .filter_map(|request| request.get_mut("uri"))
+    {
+        let replaced =
+            uri.as_str()
+                .and_then(|uri| uri.split_once('?'))
+                .and_then(|(path, query)| {
+                    let query = replace_api_version(query, |_| api_version.clone())?;
+                    Some(format!("{path}?{query}"))
+                });
+        if let Some(replaced) = replaced {
+            *uri = serde_json::Value::String(replaced);
+        }
+    }
+    if let Ok(body) = serde_json::to_vec(&requests) {
+        request.set_body(body);
+    }
+}
+
+/// Replace the value of the `api-version` parameter of a query, leaving the other parameters as
+/// they were encoded. `None` if the query has no `api-version`.
+fn replace_api_version(query: &str, replace: impl Fn(&str) -> String) -> Option<String> {
+    let prefix = format!("{}=", azure_core::query_param::API_VERSION);
+    let mut replaced = false;
+    let pairs: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.strip_prefix(&prefix) {
+            Some(value) => {
+                replaced = true;
+                format!("{prefix}{}", replace(value))
+            }
+            None => pair.to_string(),
+        })
+        .collect();
+    if replaced {
+        Some(pairs.join("&"))
+    } else {
+        None
+    }
+}
+
+/// Create a new [`ApiVersionPolicy`].
+pub fn api_version_policy(api_version: ApiVersion) -> Arc<dyn Policy> {
+    Arc::new(ApiVersionPolicy::new(api_version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_url() {
+        let collection = CollectionUrl::parse("https://tfs.corp/tfs/DefaultCollection/").unwrap();
+        assert_eq!(collection.endpoint().as_str(), "https://tfs.corp/tfs");
+        assert_eq!(collection.collection(), "DefaultCollection");
+        assert_eq!(
+            collection.to_string(),
+            "https://tfs.corp/tfs/DefaultCollection"
+        );
+
+        let collection = CollectionUrl::parse("https://tfs.corp/DefaultCollection").unwrap();
+        assert_eq!(collection.endpoint().as_str(), "https://tfs.corp/");
+        assert_eq!(collection.collection(), "DefaultCollection");
+
+        assert!(CollectionUrl::parse("https://tfs.corp/").is_err());
+    }
+
+    #[test]
+    fn test_apply_api_version() {
+        assert_eq!(ApiVersion::V6_0.apply("7.1"), "6.0");
+        assert_eq!(ApiVersion::V6_0.apply("7.1-preview.2"), "6.0-preview.2");
+        assert_eq!(ApiVersion::V6_0.apply("5.1-preview.1"), "5.1-preview.1");
+        assert_eq!(ApiVersion::V7_0.apply("6.0"), "6.0");
+        assert_eq!(ApiVersion::V7_0.apply("7.0-preview"), "7.0-preview");
+        assert_eq!(ApiVersion::V7_0.apply("7.1-preview.1"), "7.0-preview.1");
+        assert_eq!(ApiVersion::V7_1.apply("7.1-preview.3"), "7.1-preview.3");
+    }
+
+    #[test]
+    fn test_set_api_version() {
+        let mut url = Url::parse(
+            "https://tfs.corp/tfs/DefaultCollection/_apis/git/repositories?api-version=7.1-preview.1&$top=10",
+        )
+        .unwrap();
+        set_api_version(&mut url, ApiVersion::V6_0);
+        assert_eq!(
+            url.as_str(),
+            "https://tfs.corp/tfs/DefaultCollection/_apis/git/repositories?api-version=6.0-preview.1&$top=10"
+        );
+
+        let mut url = Url::parse(
+            "https://tfs.corp/tfs/DefaultCollection/_apis/wit/wiql?$skip=5&api-version=5.1&searchCriteria.path=a%20b",
+        )
+        .unwrap();
+        set_api_version(&mut url, ApiVersion::V6_0);
+        assert_eq!(
+            url.as_str(),
+            "https://tfs.corp/tfs/DefaultCollection/_apis/wit/wiql?$skip=5&api-version=5.1&searchCriteria.path=a%20b"
+        );
+
+        let mut url = Url::parse("https://tfs.corp/tfs/DefaultCollection/_apis/projects").unwrap();
+        set_api_version(&mut url, ApiVersion::V7_0);
+        assert_eq!(
+            url.as_str(),
+            "https://tfs.corp/tfs/DefaultCollection/_apis/projects"
+        );
+    }
+
+    #[test]
+    fn test_set_batch_api_version() {
+        let mut request = Request::new(
+            Url::parse(
+                "https://tfs.corp/tfs/DefaultCollection/_apis/wit/$batch?api-version=7.1-preview",
+            )
+            .unwrap(),
+            azure_core::Method::Post,
+        );
+        let body = serde_json::json!([
+            { "method": "PATCH", "uri": "/_apis/wit/workitems/1?api-version=7.1-preview&bypassRules=true" },
+            { "method": "PATCH", "uri": "/_apis/wit/workitems/2" },
+        ]);
+        request.set_body(serde_json::to_vec(&body).unwrap());
+        set_api_version(request.url_mut(), ApiVersion::V6_0);
+        set_batch_api_version(&mut request);
+        let body: serde_json::Value = match request.body() {
+            Body::Bytes(bytes) => serde_json::from_slice(bytes).unwrap(),
+            _ => panic!("expected a bytes body"),
+        };
+        assert_eq!(
+            body,
+            serde_json::json!([
+                { "method": "PATCH", "uri": "/_apis/wit/workitems/1?api-version=6.0-preview&bypassRules=true" },
+                { "method": "PATCH", "uri": "/_apis/wit/workitems/2" },
+            ])
+        );
+    }
+}
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn endpointproxy_client(&self) -> endpointproxy::Client {
         endpointproxy::Client(self.clone())
     }
@@ -142,6 +169,70 @@ impl Client {
         types::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn endpointproxy_client(&self) -> endpointproxy::OrganizationClient {
+        endpointproxy::OrganizationClient(self.clone())
+    }
+    pub fn endpoints_client(&self) -> endpoints::OrganizationClient {
+        endpoints::OrganizationClient(self.clone())
+    }
+    pub fn executionhistory_client(&self) -> executionhistory::OrganizationClient {
+        executionhistory::OrganizationClient(self.clone())
+    }
+    pub fn types_client(&self) -> types::OrganizationClient {
+        types::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn endpointproxy_client(&self) -> endpointproxy::ProjectClient {
+        endpointproxy::ProjectClient(self.clone())
+    }
+    pub fn endpoints_client(&self) -> endpoints::ProjectClient {
+        endpoints::ProjectClient(self.clone())
+    }
+    pub fn executionhistory_client(&self) -> executionhistory::ProjectClient {
+        executionhistory::ProjectClient(self.clone())
+    }
+    pub fn types_client(&self) -> types::ProjectClient {
+        types::ProjectClient(self.clone())
+    }
+}
 pub mod endpointproxy {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -192,6 +283,71 @@ pub mod endpointproxy {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Proxy for a GET request defined by a service endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn execute_service_endpoint_request(
+            &self,
+            body: impl Into<models::ServiceEndpointRequest>,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> execute_service_endpoint_request::RequestBuilder {
+            Client(self.0.client.clone()).execute_service_endpoint_request(
+                self.0.organization.clone(),
+                body,
+                project,
+                endpoint_id,
+            )
+        }
+        #[doc = "Use ExecuteServiceEndpointRequest API Instead"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Describes the data source to fetch."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::DataSourceBinding>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Proxy for a GET request defined by a service endpoint."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint request."]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn execute_service_endpoint_request(
+            &self,
+            body: impl Into<models::ServiceEndpointRequest>,
+            endpoint_id: impl Into<String>,
+        ) -> execute_service_endpoint_request::RequestBuilder {
+            Client(self.0.client.clone()).execute_service_endpoint_request(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                endpoint_id,
+            )
+        }
+        #[doc = "Use ExecuteServiceEndpointRequest API Instead"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Describes the data source to fetch."]
+        pub fn query(&self, body: impl Into<models::DataSourceBinding>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod execute_service_endpoint_request {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -628,6 +784,249 @@ pub mod endpoints {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_service_endpoints(
+            &self,
+            project: impl Into<String>,
+        ) -> get_service_endpoints::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_service_endpoints(self.0.organization.clone(), project)
+        }
+        #[doc = "Creates a new service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint to create"]
+        pub fn create(&self, body: impl Into<models::ServiceEndpoint>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Names of the service endpoints to update."]
+        pub fn update_service_endpoints(
+            &self,
+            body: Vec<models::ServiceEndpoint>,
+        ) -> update_service_endpoints::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_service_endpoints(self.0.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated data for the endpoint"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to update"]
+        pub fn update_service_endpoint(
+            &self,
+            body: impl Into<models::ServiceEndpoint>,
+            endpoint_id: impl Into<String>,
+        ) -> update_service_endpoint::RequestBuilder {
+            Client(self.0.client.clone()).update_service_endpoint(
+                self.0.organization.clone(),
+                body,
+                endpoint_id,
+            )
+        }
+        #[doc = "Share service endpoint across projects"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Project reference details of the target project"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to share"]
+        pub fn share_service_endpoint(
+            &self,
+            body: Vec<models::ServiceEndpointProjectReference>,
+            endpoint_id: impl Into<String>,
+        ) -> share_service_endpoint::RequestBuilder {
+            Client(self.0.client.clone()).share_service_endpoint(
+                self.0.organization.clone(),
+                body,
+                endpoint_id,
+            )
+        }
+        #[doc = "Delete a service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Endpoint Id of endpoint to delete"]
+        #[doc = "* `project_ids`: project Ids from which endpoint needs to be deleted"]
+        pub fn delete(
+            &self,
+            endpoint_id: impl Into<String>,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                endpoint_id,
+                project_ids,
+            )
+        }
+        #[doc = "Get the service endpoints by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_names`: Names of the service endpoints."]
+        pub fn get_service_endpoints_by_names(
+            &self,
+            project: impl Into<String>,
+            endpoint_names: impl Into<String>,
+        ) -> get_service_endpoints_by_names::RequestBuilder {
+            Client(self.0.client.clone()).get_service_endpoints_by_names(
+                self.0.organization.clone(),
+                project,
+                endpoint_names,
+            )
+        }
+        #[doc = "Gets the service endpoints and patch new authorization parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Scope, Validity of Token requested."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_ids`: Ids of the service endpoints."]
+        pub fn get_service_endpoints_with_refreshed_authentication(
+            &self,
+            body: Vec<models::RefreshAuthenticationParameters>,
+            project: impl Into<String>,
+            endpoint_ids: impl Into<String>,
+        ) -> get_service_endpoints_with_refreshed_authentication::RequestBuilder {
+            Client(self.0.client.clone()).get_service_endpoints_with_refreshed_authentication(
+                self.0.organization.clone(),
+                body,
+                project,
+                endpoint_ids,
+            )
+        }
+        #[doc = "Get the service endpoint details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, endpoint_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the service endpoints."]
+        pub fn get_service_endpoints(&self) -> get_service_endpoints::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_service_endpoints(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Creates a new service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Service endpoint to create"]
+        pub fn create(&self, body: impl Into<models::ServiceEndpoint>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoints."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Names of the service endpoints to update."]
+        pub fn update_service_endpoints(
+            &self,
+            body: Vec<models::ServiceEndpoint>,
+        ) -> update_service_endpoints::RequestBuilder {
+            Client(self.0.client.clone())
+                .update_service_endpoints(self.0.organization.clone(), body)
+        }
+        #[doc = "Update the service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated data for the endpoint"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to update"]
+        pub fn update_service_endpoint(
+            &self,
+            body: impl Into<models::ServiceEndpoint>,
+            endpoint_id: impl Into<String>,
+        ) -> update_service_endpoint::RequestBuilder {
+            Client(self.0.client.clone()).update_service_endpoint(
+                self.0.organization.clone(),
+                body,
+                endpoint_id,
+            )
+        }
+        #[doc = "Share service endpoint across projects"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Project reference details of the target project"]
+        #[doc = "* `endpoint_id`: Endpoint Id of the endpoint to share"]
+        pub fn share_service_endpoint(
+            &self,
+            body: Vec<models::ServiceEndpointProjectReference>,
+            endpoint_id: impl Into<String>,
+        ) -> share_service_endpoint::RequestBuilder {
+            Client(self.0.client.clone()).share_service_endpoint(
+                self.0.organization.clone(),
+                body,
+                endpoint_id,
+            )
+        }
+        #[doc = "Delete a service endpoint"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Endpoint Id of endpoint to delete"]
+        #[doc = "* `project_ids`: project Ids from which endpoint needs to be deleted"]
+        pub fn delete(
+            &self,
+            endpoint_id: impl Into<String>,
+            project_ids: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                endpoint_id,
+                project_ids,
+            )
+        }
+        #[doc = "Get the service endpoints by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_names`: Names of the service endpoints."]
+        pub fn get_service_endpoints_by_names(
+            &self,
+            endpoint_names: impl Into<String>,
+        ) -> get_service_endpoints_by_names::RequestBuilder {
+            Client(self.0.client.clone()).get_service_endpoints_by_names(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                endpoint_names,
+            )
+        }
+        #[doc = "Gets the service endpoints and patch new authorization parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Scope, Validity of Token requested."]
+        #[doc = "* `endpoint_ids`: Ids of the service endpoints."]
+        pub fn get_service_endpoints_with_refreshed_authentication(
+            &self,
+            body: Vec<models::RefreshAuthenticationParameters>,
+            endpoint_ids: impl Into<String>,
+        ) -> get_service_endpoints_with_refreshed_authentication::RequestBuilder {
+            Client(self.0.client.clone()).get_service_endpoints_with_refreshed_authentication(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                endpoint_ids,
+            )
+        }
+        #[doc = "Get the service endpoint details."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn get(&self, endpoint_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                endpoint_id,
+            )
+        }
+    }
     pub mod get_service_endpoints {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1859,6 +2258,20 @@ pub mod types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get service endpoint types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get service endpoint types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2026,6 +2439,35 @@ pub mod executionhistory {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get service endpoint execution records."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            endpoint_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, endpoint_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get service endpoint execution records."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `endpoint_id`: Id of the service endpoint."]
+        pub fn list(&self, endpoint_id: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                endpoint_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn availability_client(&self) -> availability::Client {
         availability::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         symsrv::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn availability_client(&self) -> availability::OrganizationClient {
+        availability::OrganizationClient(self.clone())
+    }
+    pub fn client_client(&self) -> client::OrganizationClient {
+        client::OrganizationClient(self.clone())
+    }
+    pub fn contents_client(&self) -> contents::OrganizationClient {
+        contents::OrganizationClient(self.clone())
+    }
+    pub fn requests_client(&self) -> requests::OrganizationClient {
+        requests::OrganizationClient(self.clone())
+    }
+    pub fn symsrv_client(&self) -> symsrv::OrganizationClient {
+        symsrv::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn availability_client(&self) -> availability::ProjectClient {
+        availability::ProjectClient(self.clone())
+    }
+    pub fn client_client(&self) -> client::ProjectClient {
+        client::ProjectClient(self.clone())
+    }
+    pub fn contents_client(&self) -> contents::ProjectClient {
+        contents::ProjectClient(self.clone())
+    }
+    pub fn requests_client(&self) -> requests::ProjectClient {
+        requests::ProjectClient(self.clone())
+    }
+    pub fn symsrv_client(&self) -> symsrv::ProjectClient {
+        symsrv::ProjectClient(self.clone())
+    }
+}
 pub mod requests {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -318,6 +415,260 @@ pub mod requests {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Create debug entries for a symbol request as specified by its name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_name`: The symbol request name."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_name_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_name: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_name_debug_entries::RequestBuilder {
+            Client(self.0.client.clone()).create_requests_request_name_debug_entries(
+                self.0.organization.clone(),
+                body,
+                request_name,
+                collection,
+            )
+        }
+        #[doc = "Get a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn get_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> get_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_requests_request_name(self.0.organization.clone(), request_name)
+        }
+        #[doc = "Create a new symbol request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request to create."]
+        pub fn create_requests(
+            &self,
+            body: impl Into<models::Request>,
+        ) -> create_requests::RequestBuilder {
+            Client(self.0.client.clone()).create_requests(self.0.organization.clone(), body)
+        }
+        #[doc = "Update a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn update_requests_request_name(
+            &self,
+            body: impl Into<models::Request>,
+            request_name: impl Into<String>,
+        ) -> update_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone()).update_requests_request_name(
+                self.0.organization.clone(),
+                body,
+                request_name,
+            )
+        }
+        #[doc = "Delete a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn delete_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> delete_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete_requests_request_name(self.0.organization.clone(), request_name)
+        }
+        #[doc = "Get a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn get_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> get_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_requests_request_id(self.0.organization.clone(), request_id)
+        }
+        #[doc = "Create debug entries for a symbol request as specified by its identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_id_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_id: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_id_debug_entries::RequestBuilder {
+            Client(self.0.client.clone()).create_requests_request_id_debug_entries(
+                self.0.organization.clone(),
+                body,
+                request_id,
+                collection,
+            )
+        }
+        #[doc = "Update a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn update_requests_request_id(
+            &self,
+            body: impl Into<models::Request>,
+            request_id: impl Into<String>,
+        ) -> update_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone()).update_requests_request_id(
+                self.0.organization.clone(),
+                body,
+                request_id,
+            )
+        }
+        #[doc = "Delete a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn delete_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> delete_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete_requests_request_id(self.0.organization.clone(), request_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Create debug entries for a symbol request as specified by its name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_name`: The symbol request name."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_name_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_name: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_name_debug_entries::RequestBuilder {
+            Client(self.0.client.clone()).create_requests_request_name_debug_entries(
+                self.0.organization.clone(),
+                body,
+                request_name,
+                collection,
+            )
+        }
+        #[doc = "Get a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn get_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> get_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_requests_request_name(self.0.organization.clone(), request_name)
+        }
+        #[doc = "Create a new symbol request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request to create."]
+        pub fn create_requests(
+            &self,
+            body: impl Into<models::Request>,
+        ) -> create_requests::RequestBuilder {
+            Client(self.0.client.clone()).create_requests(self.0.organization.clone(), body)
+        }
+        #[doc = "Update a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn update_requests_request_name(
+            &self,
+            body: impl Into<models::Request>,
+            request_name: impl Into<String>,
+        ) -> update_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone()).update_requests_request_name(
+                self.0.organization.clone(),
+                body,
+                request_name,
+            )
+        }
+        #[doc = "Delete a symbol request by request name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_name`: The symbol request name."]
+        pub fn delete_requests_request_name(
+            &self,
+            request_name: impl Into<String>,
+        ) -> delete_requests_request_name::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete_requests_request_name(self.0.organization.clone(), request_name)
+        }
+        #[doc = "Get a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn get_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> get_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_requests_request_id(self.0.organization.clone(), request_id)
+        }
+        #[doc = "Create debug entries for a symbol request as specified by its identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A batch that contains debug entries to create."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `collection`: A valid debug entry collection name. Must be \"debugentries\"."]
+        pub fn create_requests_request_id_debug_entries(
+            &self,
+            body: impl Into<models::DebugEntryCreateBatch>,
+            request_id: impl Into<String>,
+            collection: impl Into<String>,
+        ) -> create_requests_request_id_debug_entries::RequestBuilder {
+            Client(self.0.client.clone()).create_requests_request_id_debug_entries(
+                self.0.organization.clone(),
+                body,
+                request_id,
+                collection,
+            )
+        }
+        #[doc = "Update a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The symbol request."]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn update_requests_request_id(
+            &self,
+            body: impl Into<models::Request>,
+            request_id: impl Into<String>,
+        ) -> update_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone()).update_requests_request_id(
+                self.0.organization.clone(),
+                body,
+                request_id,
+            )
+        }
+        #[doc = "Delete a symbol request by request identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        pub fn delete_requests_request_id(
+            &self,
+            request_id: impl Into<String>,
+        ) -> delete_requests_request_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .delete_requests_request_id(self.0.organization.clone(), request_id)
+        }
+    }
     pub mod create_requests_request_name_debug_entries {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1417,6 +1768,20 @@ pub mod availability {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Check the availability of symbol service. This includes checking for feature flag, and possibly license in future. Note this is NOT an anonymous endpoint, and the caller will be redirected to authentication before hitting it."]
+        pub fn check_availability(&self) -> check_availability::RequestBuilder {
+            Client(self.0.client.clone()).check_availability(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Check the availability of symbol service. This includes checking for feature flag, and possibly license in future. Note this is NOT an anonymous endpoint, and the caller will be redirected to authentication before hitting it."]
+        pub fn check_availability(&self) -> check_availability::RequestBuilder {
+            Client(self.0.client.clone()).check_availability(self.0.organization.clone())
+        }
+    }
     pub mod check_availability {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1553,9 +1918,38 @@ pub mod client {
                 client: self.0.clone(),
                 organization: organization.into(),
                 client_type: client_type.into(),
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get client version information."]
+        pub fn head_client(&self) -> head_client::RequestBuilder {
+            Client(self.0.client.clone()).head_client(self.0.organization.clone())
+        }
+        #[doc = "Get the client package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `client_type`: Either \"EXE\" for a zip file containing a Windows symbol client (a.k.a. symbol.exe) along with dependencies, or \"TASK\" for a VSTS task that can be run on a VSTS build agent. All the other values are invalid. The parameter is case-insensitive."]
+        pub fn get(&self, client_type: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), client_type)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get client version information."]
+        pub fn head_client(&self) -> head_client::RequestBuilder {
+            Client(self.0.client.clone()).head_client(self.0.organization.clone())
+        }
+        #[doc = "Get the client package."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `client_type`: Either \"EXE\" for a zip file containing a Windows symbol client (a.k.a. symbol.exe) along with dependencies, or \"TASK\" for a VSTS task that can be run on a VSTS build agent. All the other values are invalid. The parameter is case-insensitive."]
+        pub fn get(&self, client_type: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), client_type)
+        }
+    }
     pub mod head_client {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1668,19 +2062,29 @@ pub mod client {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -1719,8 +2123,14 @@ pub mod client {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) client_type: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1739,6 +2149,10 @@ pub mod client {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1763,8 +2177,8 @@ pub mod client {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -1804,6 +2218,44 @@ pub mod contents {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a stitched debug entry for a symbol request as specified by symbol request identifier and debug entry identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `debug_entry_id`: The debug entry identifier."]
+        pub fn get(
+            &self,
+            request_id: impl Into<String>,
+            debug_entry_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                request_id,
+                debug_entry_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a stitched debug entry for a symbol request as specified by symbol request identifier and debug entry identifier."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `request_id`: The symbol request identifier."]
+        #[doc = "* `debug_entry_id`: The debug entry identifier."]
+        pub fn get(
+            &self,
+            request_id: impl Into<String>,
+            debug_entry_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                request_id,
+                debug_entry_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1937,6 +2389,26 @@ pub mod symsrv {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Given a client key, returns the best matched debug entry."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `debug_entry_client_key`: A \"client key\" used by both ends of Microsoft's symbol protocol to identify a debug entry. The semantics of client key is governed by symsrv and is beyond the scope of this documentation."]
+        pub fn get(&self, debug_entry_client_key: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), debug_entry_client_key)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Given a client key, returns the best matched debug entry."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `debug_entry_client_key`: A \"client key\" used by both ends of Microsoft's symbol protocol to identify a debug entry. The semantics of client key is governed by symsrv and is beyond the scope of this documentation."]
+        pub fn get(&self, debug_entry_client_key: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), debug_entry_client_key)
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
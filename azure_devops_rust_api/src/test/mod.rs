@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn attachments_client(&self) -> attachments::Client {
         attachments::Client(self.clone())
     }
@@ -163,6 +190,114 @@ impl Client {
         test_suites::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn code_coverage_client(&self) -> code_coverage::OrganizationClient {
+        code_coverage::OrganizationClient(self.clone())
+    }
+    pub fn iterations_client(&self) -> iterations::OrganizationClient {
+        iterations::OrganizationClient(self.clone())
+    }
+    pub fn points_client(&self) -> points::OrganizationClient {
+        points::OrganizationClient(self.clone())
+    }
+    pub fn result_retention_settings_client(
+        &self,
+    ) -> result_retention_settings::OrganizationClient {
+        result_retention_settings::OrganizationClient(self.clone())
+    }
+    pub fn results_client(&self) -> results::OrganizationClient {
+        results::OrganizationClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::OrganizationClient {
+        runs::OrganizationClient(self.clone())
+    }
+    pub fn session_client(&self) -> session::OrganizationClient {
+        session::OrganizationClient(self.clone())
+    }
+    pub fn test_cases_client(&self) -> test_cases::OrganizationClient {
+        test_cases::OrganizationClient(self.clone())
+    }
+    pub fn test_history_client(&self) -> test_history::OrganizationClient {
+        test_history::OrganizationClient(self.clone())
+    }
+    pub fn test_suites_client(&self) -> test_suites::OrganizationClient {
+        test_suites::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn code_coverage_client(&self) -> code_coverage::ProjectClient {
+        code_coverage::ProjectClient(self.clone())
+    }
+    pub fn iterations_client(&self) -> iterations::ProjectClient {
+        iterations::ProjectClient(self.clone())
+    }
+    pub fn points_client(&self) -> points::ProjectClient {
+        points::ProjectClient(self.clone())
+    }
+    pub fn result_retention_settings_client(&self) -> result_retention_settings::ProjectClient {
+        result_retention_settings::ProjectClient(self.clone())
+    }
+    pub fn results_client(&self) -> results::ProjectClient {
+        results::ProjectClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::ProjectClient {
+        runs::ProjectClient(self.clone())
+    }
+    pub fn session_client(&self) -> session::ProjectClient {
+        session::ProjectClient(self.clone())
+    }
+    pub fn test_cases_client(&self) -> test_cases::ProjectClient {
+        test_cases::ProjectClient(self.clone())
+    }
+    pub fn test_history_client(&self) -> test_history::ProjectClient {
+        test_history::ProjectClient(self.clone())
+    }
+    pub fn test_suites_client(&self) -> test_suites::ProjectClient {
+        test_suites::ProjectClient(self.clone())
+    }
+}
 pub mod runs {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -331,6 +466,189 @@ pub mod runs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            project: impl Into<String>,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                project,
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        #[doc = "Get a list of test runs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create new test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunCreateModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::RunCreateModel>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_by_id(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_by_id(
+                self.0.organization.clone(),
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Update test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunUpdateModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Delete a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to delete."]
+        pub fn delete(&self, project: impl Into<String>, run_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, run_id)
+        }
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_statistics(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_statistics::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_statistics(
+                self.0.organization.clone(),
+                project,
+                run_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        #[doc = "Get a list of test runs."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create new test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunCreateModel"]
+        pub fn create(&self, body: impl Into<models::RunCreateModel>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_by_id(&self, run_id: i32) -> get_test_run_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_by_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Update test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Run details RunUpdateModel"]
+        #[doc = "* `run_id`: ID of the run to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Delete a test run by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to delete."]
+        pub fn delete(&self, run_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get_test_run_statistics(
+            &self,
+            run_id: i32,
+        ) -> get_test_run_statistics::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_statistics(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -498,13 +816,15 @@ pub mod runs {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
                         let min_last_updated_date = &this.min_last_updated_date;
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair("minLastUpdatedDate", &min_last_updated_date.to_string());
+                        req.url_mut().query_pairs_mut().append_pair(
+                            "minLastUpdatedDate",
+                            &crate::date_time::format_date_time(min_last_updated_date)?,
+                        );
                         let max_last_updated_date = &this.max_last_updated_date;
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair("maxLastUpdatedDate", &max_last_updated_date.to_string());
+                        req.url_mut().query_pairs_mut().append_pair(
+                            "maxLastUpdatedDate",
+                            &crate::date_time::format_date_time(max_last_updated_date)?,
+                        );
                         if let Some(state) = &this.state {
                             req.url_mut().query_pairs_mut().append_pair("state", state);
                         }
@@ -585,8 +905,8 @@ pub mod runs {
                     self.client.endpoint(),
                     &self.organization,
                     &self.project,
-                    &self.min_last_updated_date,
-                    &self.max_last_updated_date
+                    &crate::date_time::format_date_time(&self.min_last_updated_date)?,
+                    &crate::date_time::format_date_time(&self.max_last_updated_date)?
                 ))?;
                 let has_api_version_already = url
                     .query_pairs()
@@ -597,6 +917,20 @@ pub mod runs {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestRun> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -798,6 +1132,18 @@ pub mod runs {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestRun> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -1493,6 +1839,7 @@ pub mod attachments {
                 test_case_result_id,
                 attachment_id,
                 test_sub_result_id,
+                accept: None,
             }
         }
         #[doc = "Get list of test run attachments reference."]
@@ -1556,6 +1903,7 @@ pub mod attachments {
                 project: project.into(),
                 run_id,
                 attachment_id,
+                accept: None,
             }
         }
         #[doc = "Get list of test result attachments reference."]
@@ -1628,61 +1976,428 @@ pub mod attachments {
                 run_id,
                 test_case_result_id,
                 attachment_id,
+                accept: None,
             }
         }
     }
-    pub mod get_test_sub_result_attachments {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::TestAttachmentList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::TestAttachmentList =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
-        }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
-        }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get list of test sub result attachments"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
+        #[doc = "Attach a file to a test result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment Request Model."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub results against which attachment has to be uploaded."]
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_sub_result_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Download a test sub result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded"]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Get list of test run attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run."]
+        pub fn get_test_run_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Attach a file to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run against which attachment has to be uploaded."]
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_run_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Download a test run attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test run attachment to be downloaded."]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Get list of test result attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result."]
+        pub fn get_test_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result against which attachment has to be uploaded."]
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_result_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Download a test result attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the testCaseResultId."]
+        #[doc = "* `test_case_result_id`: ID of the test result whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded."]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get list of test sub result attachments"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment Request Model."]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `test_sub_result_id`: ID of the test sub results against which attachment has to be uploaded."]
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_sub_result_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Download a test sub result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test results that contains sub result."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded"]
+        #[doc = "* `test_sub_result_id`: ID of the test sub result whose attachment has to be downloaded"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Get list of test run attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run."]
+        pub fn get_test_run_attachments(
+            &self,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Attach a file to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `run_id`: ID of the test run against which attachment has to be uploaded."]
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_run_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Download a test run attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test run attachment to be downloaded."]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Get list of test result attachments reference."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result."]
+        pub fn get_test_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Attach a file to a test result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Attachment details TestAttachmentRequestModel"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result against which attachment has to be uploaded."]
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_result_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Download a test result attachment by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the testCaseResultId."]
+        #[doc = "* `test_case_result_id`: ID of the test result whose attachment has to be downloaded."]
+        #[doc = "* `attachment_id`: ID of the test result attachment to be downloaded."]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+    }
+    pub mod get_test_sub_result_attachments {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::TestAttachmentList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::TestAttachmentList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
         #[doc = r" If you need lower-level access to the raw response details"]
         #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
         #[doc = r" can finalize the request using the"]
@@ -1897,19 +2612,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -1952,8 +2677,14 @@ pub mod attachments {
             pub(crate) test_case_result_id: i32,
             pub(crate) attachment_id: i32,
             pub(crate) test_sub_result_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1972,10 +2703,14 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let test_sub_result_id = &this.test_sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("testSubResultId", &test_sub_result_id.to_string());
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2004,8 +2739,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -2266,19 +3001,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -2319,8 +3064,14 @@ pub mod attachments {
             pub(crate) project: String,
             pub(crate) run_id: i32,
             pub(crate) attachment_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -2339,6 +3090,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2365,8 +3120,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -2622,28 +3377,38 @@ pub mod attachments {
             }
         }
     }
-    pub mod get_test_result_attachment_zip {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+    pub mod get_test_result_attachment_zip {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -2685,8 +3450,14 @@ pub mod attachments {
             pub(crate) run_id: i32,
             pub(crate) test_case_result_id: i32,
             pub(crate) attachment_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -2705,6 +3476,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2732,8 +3507,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -2798,6 +3573,84 @@ pub mod code_coverage {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get code coverage data for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: ID of the build for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_build_code_coverage(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_build_code_coverage(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                flags,
+            )
+        }
+        #[doc = "Get code coverage data for a test run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_test_run_code_coverage(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_code_coverage(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                flags,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get code coverage data for a build."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: ID of the build for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_build_code_coverage(
+            &self,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_build_code_coverage(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                flags,
+            )
+        }
+        #[doc = "Get code coverage data for a test run"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run for which code coverage data needs to be fetched."]
+        #[doc = "* `flags`: Value of flags determine the level of code coverage details to be fetched. Flags are additive. Expected Values are 1 for Modules, 2 for Functions, 4 for BlockData."]
+        pub fn get_test_run_code_coverage(
+            &self,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_code_coverage(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                flags,
+            )
+        }
+    }
     pub mod get_build_code_coverage {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3049,118 +3902,276 @@ pub mod code_coverage {
             }
         }
     }
-}
-pub mod points {
-    use super::models;
-    #[cfg(not(target_arch = "wasm32"))]
-    use futures::future::BoxFuture;
-    #[cfg(target_arch = "wasm32")]
-    use futures::future::LocalBoxFuture as BoxFuture;
-    pub struct Client(pub(crate) super::Client);
-    impl Client {
+}
+pub mod points {
+    use super::models;
+    #[cfg(not(target_arch = "wasm32"))]
+    use futures::future::BoxFuture;
+    #[cfg(target_arch = "wasm32")]
+    use futures::future::LocalBoxFuture as BoxFuture;
+    pub struct Client(pub(crate) super::Client);
+    impl Client {
+        #[doc = "Get a list of test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                wit_fields: None,
+                configuration_id: None,
+                test_case_id: None,
+                test_point_ids: None,
+                include_point_details: None,
+                skip: None,
+                top: None,
+            }
+        }
+        #[doc = "Get a test point."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the point."]
+        #[doc = "* `point_ids`: ID of the test point to get."]
+        pub fn get_point(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: i32,
+        ) -> get_point::RequestBuilder {
+            get_point::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                point_ids,
+                wit_fields: None,
+            }
+        }
+        #[doc = "Update test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Data to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::PointUpdateModel>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+                point_ids: point_ids.into(),
+            }
+        }
+        #[doc = "Get test points using query."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: TestPointsQuery to get test points."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_points_by_query(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestPointsQuery>,
+            project: impl Into<String>,
+        ) -> get_points_by_query::RequestBuilder {
+            get_points_by_query::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                skip: None,
+                top: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a test point."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the point."]
+        #[doc = "* `point_ids`: ID of the test point to get."]
+        pub fn get_point(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: i32,
+        ) -> get_point::RequestBuilder {
+            Client(self.0.client.clone()).get_point(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                point_ids,
+            )
+        }
+        #[doc = "Update test points."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Data to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan."]
+        #[doc = "* `suite_id`: ID of the suite that contains the points."]
+        #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PointUpdateModel>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+                point_ids,
+            )
+        }
+        #[doc = "Get test points using query."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestPointsQuery to get test points."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_points_by_query(
+            &self,
+            body: impl Into<models::TestPointsQuery>,
+            project: impl Into<String>,
+        ) -> get_points_by_query::RequestBuilder {
+            Client(self.0.client.clone()).get_points_by_query(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
         #[doc = "Get a list of test points."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
         #[doc = "* `suite_id`: ID of the suite that contains the points."]
-        pub fn list(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            plan_id: i32,
-            suite_id: i32,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn list(&self, plan_id: i32, suite_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 plan_id,
                 suite_id,
-                wit_fields: None,
-                configuration_id: None,
-                test_case_id: None,
-                test_point_ids: None,
-                include_point_details: None,
-                skip: None,
-                top: None,
-            }
+            )
         }
         #[doc = "Get a test point."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
         #[doc = "* `suite_id`: ID of the suite that contains the point."]
         #[doc = "* `point_ids`: ID of the test point to get."]
         pub fn get_point(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             point_ids: i32,
         ) -> get_point::RequestBuilder {
-            get_point::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_point(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 plan_id,
                 suite_id,
                 point_ids,
-                wit_fields: None,
-            }
+            )
         }
         #[doc = "Update test points."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Data to update."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan."]
         #[doc = "* `suite_id`: ID of the suite that contains the points."]
         #[doc = "* `point_ids`: ID of the test point to get. Use a comma-separated list of IDs to update multiple test points."]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::PointUpdateModel>,
-            project: impl Into<String>,
             plan_id: i32,
             suite_id: i32,
             point_ids: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
                 plan_id,
                 suite_id,
-                point_ids: point_ids.into(),
-            }
+                point_ids,
+            )
         }
         #[doc = "Get test points using query."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: TestPointsQuery to get test points."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_points_by_query(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestPointsQuery>,
-            project: impl Into<String>,
         ) -> get_points_by_query::RequestBuilder {
-            get_points_by_query::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                skip: None,
-                top: None,
-            }
+            Client(self.0.client.clone()).get_points_by_query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
         }
     }
     pub mod list {
@@ -3347,6 +4358,18 @@ pub mod points {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestPoint> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPointList>;
@@ -3894,6 +4917,213 @@ pub mod test_suites {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all test cases in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a specific test case in a test suite with test case id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite that contains the test case."]
+        #[doc = "* `test_case_ids`: ID of the test case to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Add test cases to suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn add(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Updates the properties of the test case association in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Model for updation of the properties of test case suite association."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn update(
+            &self,
+            body: impl Into<models::SuiteTestCaseUpdateModel>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "The test points associated with the test cases are removed from the test suite. The test case work item is not deleted from the system. See test cases resource to delete a test case permanently."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to remove from the suite."]
+        pub fn remove_test_cases_from_suite_url(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite_url::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_from_suite_url(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all test cases in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn list(&self, plan_id: i32, suite_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a specific test case in a test suite with test case id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite that contains the test case."]
+        #[doc = "* `test_case_ids`: ID of the test case to get."]
+        pub fn get(&self, plan_id: i32, suite_id: i32, test_case_ids: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Add test cases to suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn add(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Updates the properties of the test case association in a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Model for updation of the properties of test case suite association."]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to which the test cases must be added."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to add to the suite. Ids are specified in comma separated format."]
+        pub fn update(
+            &self,
+            body: impl Into<models::SuiteTestCaseUpdateModel>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "The test points associated with the test cases are removed from the test suite. The test case work item is not deleted from the system. See test cases resource to delete a test case permanently."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        #[doc = "* `test_case_ids`: IDs of the test cases to remove from the suite."]
+        pub fn remove_test_cases_from_suite_url(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite_url::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_from_suite_url(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4542,6 +5772,49 @@ pub mod result_retention_settings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+        #[doc = "Update test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test result retention settings details to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ResultRetentionSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get test result retention settings"]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Update test result retention settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test result retention settings details to be updated"]
+        pub fn update(
+            &self,
+            body: impl Into<models::ResultRetentionSettings>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4809,6 +6082,35 @@ pub mod test_history {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestHistoryQuery>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        pub fn query(&self, body: impl Into<models::TestHistoryQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5028,6 +6330,121 @@ pub mod results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get test results for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID of test results to fetch."]
+        pub fn list(&self, project: impl Into<String>, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, run_id)
+        }
+        #[doc = "Add test results to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to add."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID into which test results to add."]
+        pub fn add(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Update test results in a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID whose test results to update."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Get a test result for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test run ID of a test result to fetch."]
+        #[doc = "* `test_case_result_id`: Test result ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get test results for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Test run ID of test results to fetch."]
+        pub fn list(&self, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Add test results to a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to add."]
+        #[doc = "* `run_id`: Test run ID into which test results to add."]
+        pub fn add(&self, body: Vec<models::TestCaseResult>, run_id: i32) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Update test results in a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of test results to update."]
+        #[doc = "* `run_id`: Test run ID whose test results to update."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Get a test result for a test run."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Test run ID of a test result to fetch."]
+        #[doc = "* `test_case_result_id`: Test result ID."]
+        pub fn get(&self, run_id: i32, test_case_result_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5177,6 +6594,18 @@ pub mod results {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestCaseResult> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseResultList>;
@@ -5623,6 +7052,86 @@ pub mod iterations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get iterations for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Get iteration for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        #[doc = "* `iteration_id`: Id of the test results Iteration."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get iterations for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        pub fn list(&self, run_id: i32, test_case_result_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Get iteration for a result"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the test run that contains the result."]
+        #[doc = "* `test_case_result_id`: ID of the test result that contains the iterations."]
+        #[doc = "* `iteration_id`: Id of the test results Iteration."]
+        pub fn get(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5924,6 +7433,35 @@ pub mod test_cases {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_id`: Id of test case to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_case_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, test_case_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: Id of test case to delete."]
+        pub fn delete(&self, test_case_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_case_id,
+            )
+        }
+    }
     pub mod delete {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6109,6 +7647,97 @@ pub mod session {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test sessions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Create a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for creation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSession>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, team)
+        }
+        #[doc = "Update a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSession>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of test sessions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Create a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for creation"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSession>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Update a test session"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Test session details for update"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSession>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
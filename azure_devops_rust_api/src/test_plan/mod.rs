@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn configurations_client(&self) -> configurations::Client {
         configurations::Client(self.clone())
     }
@@ -163,6 +190,112 @@ impl Client {
         variables::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn configurations_client(&self) -> configurations::OrganizationClient {
+        configurations::OrganizationClient(self.clone())
+    }
+    pub fn suite_test_case_client(&self) -> suite_test_case::OrganizationClient {
+        suite_test_case::OrganizationClient(self.clone())
+    }
+    pub fn test_case_clone_client(&self) -> test_case_clone::OrganizationClient {
+        test_case_clone::OrganizationClient(self.clone())
+    }
+    pub fn test_cases_client(&self) -> test_cases::OrganizationClient {
+        test_cases::OrganizationClient(self.clone())
+    }
+    pub fn test_plan_clone_client(&self) -> test_plan_clone::OrganizationClient {
+        test_plan_clone::OrganizationClient(self.clone())
+    }
+    pub fn test_plans_client(&self) -> test_plans::OrganizationClient {
+        test_plans::OrganizationClient(self.clone())
+    }
+    pub fn test_point_client(&self) -> test_point::OrganizationClient {
+        test_point::OrganizationClient(self.clone())
+    }
+    pub fn test_suite_clone_client(&self) -> test_suite_clone::OrganizationClient {
+        test_suite_clone::OrganizationClient(self.clone())
+    }
+    pub fn test_suite_entry_client(&self) -> test_suite_entry::OrganizationClient {
+        test_suite_entry::OrganizationClient(self.clone())
+    }
+    pub fn test_suites_client(&self) -> test_suites::OrganizationClient {
+        test_suites::OrganizationClient(self.clone())
+    }
+    pub fn variables_client(&self) -> variables::OrganizationClient {
+        variables::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn configurations_client(&self) -> configurations::ProjectClient {
+        configurations::ProjectClient(self.clone())
+    }
+    pub fn suite_test_case_client(&self) -> suite_test_case::ProjectClient {
+        suite_test_case::ProjectClient(self.clone())
+    }
+    pub fn test_case_clone_client(&self) -> test_case_clone::ProjectClient {
+        test_case_clone::ProjectClient(self.clone())
+    }
+    pub fn test_cases_client(&self) -> test_cases::ProjectClient {
+        test_cases::ProjectClient(self.clone())
+    }
+    pub fn test_plan_clone_client(&self) -> test_plan_clone::ProjectClient {
+        test_plan_clone::ProjectClient(self.clone())
+    }
+    pub fn test_plans_client(&self) -> test_plans::ProjectClient {
+        test_plans::ProjectClient(self.clone())
+    }
+    pub fn test_point_client(&self) -> test_point::ProjectClient {
+        test_point::ProjectClient(self.clone())
+    }
+    pub fn test_suite_clone_client(&self) -> test_suite_clone::ProjectClient {
+        test_suite_clone::ProjectClient(self.clone())
+    }
+    pub fn test_suite_entry_client(&self) -> test_suite_entry::ProjectClient {
+        test_suite_entry::ProjectClient(self.clone())
+    }
+    pub fn test_suites_client(&self) -> test_suites::ProjectClient {
+        test_suites::ProjectClient(self.clone())
+    }
+    pub fn variables_client(&self) -> variables::ProjectClient {
+        variables::ProjectClient(self.clone())
+    }
+}
 pub mod suite_test_case {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -329,6 +462,258 @@ pub mod suite_test_case {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_case_ids`: Test Case Ids to be removed."]
+        pub fn remove_test_cases_from_suite(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_from_suite(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Get Test Case List return those test cases which have all the configuration Ids as mentioned in the optional parameter. If configuration Ids is null, it return all the test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        pub fn get_test_case_list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_test_case_list::RequestBuilder {
+            Client(self.0.client.clone()).get_test_case_list(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Add test cases to a suite with specified configurations"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be added."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be added."]
+        pub fn add(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Update the configurations for test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be updated."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided. This API can be used to remove a larger number of test cases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_ids`: Comma separated string of Test Case Ids to be removed."]
+        pub fn remove_test_cases_list_from_suite(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_ids: impl Into<String>,
+        ) -> remove_test_cases_list_from_suite::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_list_from_suite(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_ids,
+            )
+        }
+        #[doc = "Get a particular Test Case from a Suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        #[doc = "* `test_case_id`: Test Case Id to be fetched."]
+        pub fn get_test_case(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_id: impl Into<String>,
+        ) -> get_test_case::RequestBuilder {
+            Client(self.0.client.clone()).get_test_case(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                test_case_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_case_ids`: Test Case Ids to be removed."]
+        pub fn remove_test_cases_from_suite(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_ids: impl Into<String>,
+        ) -> remove_test_cases_from_suite::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_from_suite(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_ids,
+            )
+        }
+        #[doc = "Get Test Case List return those test cases which have all the configuration Ids as mentioned in the optional parameter. If configuration Ids is null, it return all the test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        pub fn get_test_case_list(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_test_case_list::RequestBuilder {
+            Client(self.0.client.clone()).get_test_case_list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Add test cases to a suite with specified configurations"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be added."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be added."]
+        pub fn add(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Update the configurations for test cases"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A SuiteTestCaseCreateUpdateParameters object."]
+        #[doc = "* `plan_id`: ID of the test plan to which test cases are to be updated."]
+        #[doc = "* `suite_id`: ID of the test suite to which test cases are to be updated."]
+        pub fn update(
+            &self,
+            body: Vec<models::SuiteTestCaseCreateUpdateParameters>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Removes test cases from a suite based on the list of test case Ids provided. This API can be used to remove a larger number of test cases."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan from which test cases are to be removed."]
+        #[doc = "* `suite_id`: ID of the test suite from which test cases are to be removed."]
+        #[doc = "* `test_ids`: Comma separated string of Test Case Ids to be removed."]
+        pub fn remove_test_cases_list_from_suite(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_ids: impl Into<String>,
+        ) -> remove_test_cases_list_from_suite::RequestBuilder {
+            Client(self.0.client.clone()).remove_test_cases_list_from_suite(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_ids,
+            )
+        }
+        #[doc = "Get a particular Test Case from a Suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test cases are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test cases are requested."]
+        #[doc = "* `test_case_id`: Test Case Id to be fetched."]
+        pub fn get_test_case(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            test_case_id: impl Into<String>,
+        ) -> get_test_case::RequestBuilder {
+            Client(self.0.client.clone()).get_test_case(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                test_case_id,
+            )
+        }
+    }
     pub mod remove_test_cases_from_suite {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -637,6 +1022,20 @@ pub mod suite_test_case {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestCase> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseList>;
@@ -1245,6 +1644,132 @@ pub mod test_point {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the points inside a suite based on some filters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested"]
+        pub fn get_points_list(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_points_list::RequestBuilder {
+            Client(self.0.client.clone()).get_points_list(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a particular Test Point from a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        #[doc = "* `point_id`: ID of test point to be fetched."]
+        pub fn get_points(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+            point_id: impl Into<String>,
+        ) -> get_points::RequestBuilder {
+            Client(self.0.client.clone()).get_points(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+                point_id,
+            )
+        }
+        #[doc = "Update Test Points. This is used to Reset test point to active, update the outcome of a test point or update the tester of a test point"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A TestPointUpdateParams Object."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestPointUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the points inside a suite based on some filters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested"]
+        pub fn get_points_list(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get_points_list::RequestBuilder {
+            Client(self.0.client.clone()).get_points_list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Get a particular Test Point from a suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        #[doc = "* `point_id`: ID of test point to be fetched."]
+        pub fn get_points(
+            &self,
+            plan_id: i32,
+            suite_id: i32,
+            point_id: impl Into<String>,
+        ) -> get_points::RequestBuilder {
+            Client(self.0.client.clone()).get_points(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+                point_id,
+            )
+        }
+        #[doc = "Update Test Points. This is used to Reset test point to active, update the outcome of a test point or update the tester of a test point"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A TestPointUpdateParams Object."]
+        #[doc = "* `plan_id`: ID of the test plan for which test points are requested."]
+        #[doc = "* `suite_id`: ID of the test suite for which test points are requested."]
+        pub fn update(
+            &self,
+            body: Vec<models::TestPointUpdateParams>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+    }
     pub mod get_points_list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1418,6 +1943,20 @@ pub mod test_point {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestPoint> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPointList>;
@@ -1840,29 +2379,229 @@ pub mod test_suites {
                 project: project.into(),
                 plan_id,
                 suite_id,
-            }
+            }
+        }
+        #[doc = "Delete test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to delete."]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                plan_id,
+                suite_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Find the list of all test suites in which a given test case is present. This is helpful if you need to find out which test suites are using a test case, when you need to make changes to a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: ID of the test case for which suites need to be fetched."]
+        pub fn get_suites_by_test_case_id(
+            &self,
+            test_case_id: i32,
+        ) -> get_suites_by_test_case_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_suites_by_test_case_id(self.0.organization.clone(), test_case_id)
+        }
+        #[doc = "Get test suites for plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan for which suites are requested."]
+        pub fn get_test_suites_for_plan(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> get_test_suites_for_plan::RequestBuilder {
+            Client(self.0.client.clone()).get_test_suites_for_plan(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+            )
+        }
+        #[doc = "Create test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite creation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSuiteCreateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+            )
+        }
+        #[doc = "Get test suite by suite id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Update test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite updation"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the parent suite."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSuiteUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Delete test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
+        #[doc = "* `suite_id`: ID of the test suite to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                plan_id,
+                suite_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Find the list of all test suites in which a given test case is present. This is helpful if you need to find out which test suites are using a test case, when you need to make changes to a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: ID of the test case for which suites need to be fetched."]
+        pub fn get_suites_by_test_case_id(
+            &self,
+            test_case_id: i32,
+        ) -> get_suites_by_test_case_id::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_suites_by_test_case_id(self.0.organization.clone(), test_case_id)
+        }
+        #[doc = "Get test suites for plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan for which suites are requested."]
+        pub fn get_test_suites_for_plan(
+            &self,
+            plan_id: i32,
+        ) -> get_test_suites_for_plan::RequestBuilder {
+            Client(self.0.client.clone()).get_test_suites_for_plan(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Create test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite creation"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSuiteCreateParams>,
+            plan_id: i32,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Get test suite by suite id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the suite to get."]
+        pub fn get(&self, plan_id: i32, suite_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
+        }
+        #[doc = "Update test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for suite updation"]
+        #[doc = "* `plan_id`: ID of the test plan that contains the suites."]
+        #[doc = "* `suite_id`: ID of the parent suite."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestSuiteUpdateParams>,
+            plan_id: i32,
+            suite_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+                suite_id,
+            )
         }
         #[doc = "Delete test suite."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `plan_id`: ID of the test plan that contains the suite."]
         #[doc = "* `suite_id`: ID of the test suite to delete."]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            plan_id: i32,
-            suite_id: i32,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn delete(&self, plan_id: i32, suite_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 plan_id,
                 suite_id,
-            }
+            )
         }
     }
     pub mod get_suites_by_test_case_id {
@@ -2121,6 +2860,20 @@ pub mod test_suites {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestSuite> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestSuiteList>;
@@ -2723,6 +3476,139 @@ pub mod configurations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test configurations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a test configuration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Update a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            project: impl Into<String>,
+            test_configuartion_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Delete a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_configuartion_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Get a test configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_configuration_id`: ID of the test configuration to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            test_configuration_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                test_configuration_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of test configurations."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a test configuration."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestConfigurationCreateUpdateParameters"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestConfigurationCreateUpdateParameters>,
+            test_configuartion_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Delete a test configuration by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_configuartion_id`: ID of the test configuration to delete."]
+        pub fn delete(&self, test_configuartion_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_configuartion_id,
+            )
+        }
+        #[doc = "Get a test configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_configuration_id`: ID of the test configuration to get."]
+        pub fn get(&self, test_configuration_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_configuration_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2837,6 +3723,20 @@ pub mod configurations {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestConfiguration> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestConfigurationList>;
@@ -3432,6 +4332,123 @@ pub mod test_plans {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test plans"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanCreateParams object.TestPlanCreateParams"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestPlanCreateParams>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a test plan by Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to get."]
+        pub fn get(&self, project: impl Into<String>, plan_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, plan_id)
+        }
+        #[doc = "Update a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanUpdateParams object.TestPlanUpdateParams"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestPlanUpdateParams>,
+            project: impl Into<String>,
+            plan_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                plan_id,
+            )
+        }
+        #[doc = "Delete a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `plan_id`: ID of the test plan to be deleted."]
+        pub fn delete(&self, project: impl Into<String>, plan_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, plan_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of test plans"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanCreateParams object.TestPlanCreateParams"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestPlanCreateParams>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a test plan by Id."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan to get."]
+        pub fn get(&self, plan_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Update a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A testPlanUpdateParams object.TestPlanUpdateParams"]
+        #[doc = "* `plan_id`: ID of the test plan to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestPlanUpdateParams>,
+            plan_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                plan_id,
+            )
+        }
+        #[doc = "Delete a test plan."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `plan_id`: ID of the test plan to be deleted."]
+        pub fn delete(&self, plan_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                plan_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3577,6 +4594,20 @@ pub mod test_plans {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestPlan> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestPlanList>;
@@ -4086,21 +5117,84 @@ pub mod test_plan_clone {
         #[doc = "Get clone information."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                clone_operation_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Clone test plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan Clone Request Body detail TestPlanCloneRequest"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_plan(
+            &self,
+            body: impl Into<models::CloneTestPlanParams>,
+            project: impl Into<String>,
+        ) -> clone_test_plan::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_plan(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                clone_operation_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Clone test plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan Clone Request Body detail TestPlanCloneRequest"]
+        pub fn clone_test_plan(
+            &self,
+            body: impl Into<models::CloneTestPlanParams>,
+        ) -> clone_test_plan::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_plan(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            clone_operation_id: i32,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 clone_operation_id,
-            }
+            )
         }
     }
     pub mod clone_test_plan {
@@ -4410,6 +5504,67 @@ pub mod test_suite_entry {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent suite."]
+        pub fn list(&self, project: impl Into<String>, suite_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, suite_id)
+        }
+        #[doc = "Reorder test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of SuiteEntry to reorder."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `suite_id`: Id of the parent test suite."]
+        pub fn reorder_suite_entries(
+            &self,
+            body: Vec<models::SuiteEntryUpdateParams>,
+            project: impl Into<String>,
+            suite_id: i32,
+        ) -> reorder_suite_entries::RequestBuilder {
+            Client(self.0.client.clone()).reorder_suite_entries(
+                self.0.organization.clone(),
+                body,
+                project,
+                suite_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `suite_id`: Id of the parent suite."]
+        pub fn list(&self, suite_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                suite_id,
+            )
+        }
+        #[doc = "Reorder test suite entries in the test suite."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of SuiteEntry to reorder."]
+        #[doc = "* `suite_id`: Id of the parent test suite."]
+        pub fn reorder_suite_entries(
+            &self,
+            body: Vec<models::SuiteEntryUpdateParams>,
+            suite_id: i32,
+        ) -> reorder_suite_entries::RequestBuilder {
+            Client(self.0.client.clone()).reorder_suite_entries(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                suite_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4707,6 +5862,69 @@ pub mod test_suite_clone {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Clone test suite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Suite Clone Request Body detail TestSuiteCloneRequest"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_suite(
+            &self,
+            body: impl Into<models::CloneTestSuiteParams>,
+            project: impl Into<String>,
+        ) -> clone_test_suite::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_suite(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                clone_operation_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Clone test suite"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Suite Clone Request Body detail TestSuiteCloneRequest"]
+        pub fn clone_test_suite(
+            &self,
+            body: impl Into<models::CloneTestSuiteParams>,
+        ) -> clone_test_suite::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_suite(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                clone_operation_id,
+            )
+        }
+    }
     pub mod clone_test_suite {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4991,6 +6209,35 @@ pub mod test_cases {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_id`: Id of test case to be deleted."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_case_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, test_case_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Delete a test case."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_case_id`: Id of test case to be deleted."]
+        pub fn delete(&self, test_case_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_case_id,
+            )
+        }
+    }
     pub mod delete {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5143,6 +6390,62 @@ pub mod test_case_clone {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn clone_test_case(
+            &self,
+            body: impl Into<models::CloneTestCaseParams>,
+            project: impl Into<String>,
+        ) -> clone_test_case::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_case(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            clone_operation_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                clone_operation_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn clone_test_case(
+            &self,
+            body: impl Into<models::CloneTestCaseParams>,
+        ) -> clone_test_case::RequestBuilder {
+            Client(self.0.client.clone()).clone_test_case(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get clone information."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `clone_operation_id`: Operation ID returned when we queue a clone operation"]
+        pub fn get(&self, clone_operation_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                clone_operation_id,
+            )
+        }
+    }
     pub mod clone_test_case {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5493,6 +6796,139 @@ pub mod variables {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of test variables."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a test variable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to get."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                test_variable_id,
+            )
+        }
+        #[doc = "Update a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                test_variable_id,
+            )
+        }
+        #[doc = "Delete a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_variable_id`: ID of the test variable to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_variable_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                test_variable_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of test variables."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a test variable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_variable_id`: ID of the test variable to get."]
+        pub fn get(&self, test_variable_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_variable_id,
+            )
+        }
+        #[doc = "Update a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestVariableCreateUpdateParameters"]
+        #[doc = "* `test_variable_id`: ID of the test variable to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestVariableCreateUpdateParameters>,
+            test_variable_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                test_variable_id,
+            )
+        }
+        #[doc = "Delete a test variable by its ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `test_variable_id`: ID of the test variable to delete."]
+        pub fn delete(&self, test_variable_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_variable_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5607,6 +7043,20 @@ pub mod variables {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestVariable> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestVariableList>;
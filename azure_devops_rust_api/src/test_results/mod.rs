@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn attachments_client(&self) -> attachments::Client {
         attachments::Client(self.clone())
     }
@@ -247,6 +274,282 @@ impl Client {
         workitems::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn bugs_client(&self) -> bugs::OrganizationClient {
+        bugs::OrganizationClient(self.clone())
+    }
+    pub fn codecoverage_client(&self) -> codecoverage::OrganizationClient {
+        codecoverage::OrganizationClient(self.clone())
+    }
+    pub fn filecoverage_client(&self) -> filecoverage::OrganizationClient {
+        filecoverage::OrganizationClient(self.clone())
+    }
+    pub fn history_client(&self) -> history::OrganizationClient {
+        history::OrganizationClient(self.clone())
+    }
+    pub fn message_logs_client(&self) -> message_logs::OrganizationClient {
+        message_logs::OrganizationClient(self.clone())
+    }
+    pub fn metrics_client(&self) -> metrics::OrganizationClient {
+        metrics::OrganizationClient(self.clone())
+    }
+    pub fn result_document_client(&self) -> result_document::OrganizationClient {
+        result_document::OrganizationClient(self.clone())
+    }
+    pub fn result_meta_data_client(&self) -> result_meta_data::OrganizationClient {
+        result_meta_data::OrganizationClient(self.clone())
+    }
+    pub fn result_trend_by_build_client(&self) -> result_trend_by_build::OrganizationClient {
+        result_trend_by_build::OrganizationClient(self.clone())
+    }
+    pub fn result_trend_by_release_client(&self) -> result_trend_by_release::OrganizationClient {
+        result_trend_by_release::OrganizationClient(self.clone())
+    }
+    pub fn resultdetailsbybuild_client(&self) -> resultdetailsbybuild::OrganizationClient {
+        resultdetailsbybuild::OrganizationClient(self.clone())
+    }
+    pub fn resultdetailsbyrelease_client(&self) -> resultdetailsbyrelease::OrganizationClient {
+        resultdetailsbyrelease::OrganizationClient(self.clone())
+    }
+    pub fn resultgroupsbybuild_client(&self) -> resultgroupsbybuild::OrganizationClient {
+        resultgroupsbybuild::OrganizationClient(self.clone())
+    }
+    pub fn resultgroupsbyrelease_client(&self) -> resultgroupsbyrelease::OrganizationClient {
+        resultgroupsbyrelease::OrganizationClient(self.clone())
+    }
+    pub fn results_client(&self) -> results::OrganizationClient {
+        results::OrganizationClient(self.clone())
+    }
+    pub fn resultsbybuild_client(&self) -> resultsbybuild::OrganizationClient {
+        resultsbybuild::OrganizationClient(self.clone())
+    }
+    pub fn resultsbypipeline_client(&self) -> resultsbypipeline::OrganizationClient {
+        resultsbypipeline::OrganizationClient(self.clone())
+    }
+    pub fn resultsbyrelease_client(&self) -> resultsbyrelease::OrganizationClient {
+        resultsbyrelease::OrganizationClient(self.clone())
+    }
+    pub fn resultsgroup_details_client(&self) -> resultsgroup_details::OrganizationClient {
+        resultsgroup_details::OrganizationClient(self.clone())
+    }
+    pub fn resultsummarybybuild_client(&self) -> resultsummarybybuild::OrganizationClient {
+        resultsummarybybuild::OrganizationClient(self.clone())
+    }
+    pub fn resultsummarybypipeline_client(&self) -> resultsummarybypipeline::OrganizationClient {
+        resultsummarybypipeline::OrganizationClient(self.clone())
+    }
+    pub fn resultsummarybyrelease_client(&self) -> resultsummarybyrelease::OrganizationClient {
+        resultsummarybyrelease::OrganizationClient(self.clone())
+    }
+    pub fn resultsummarybyrequirement_client(
+        &self,
+    ) -> resultsummarybyrequirement::OrganizationClient {
+        resultsummarybyrequirement::OrganizationClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::OrganizationClient {
+        runs::OrganizationClient(self.clone())
+    }
+    pub fn runsummary_client(&self) -> runsummary::OrganizationClient {
+        runsummary::OrganizationClient(self.clone())
+    }
+    pub fn settings_client(&self) -> settings::OrganizationClient {
+        settings::OrganizationClient(self.clone())
+    }
+    pub fn similar_test_results_client(&self) -> similar_test_results::OrganizationClient {
+        similar_test_results::OrganizationClient(self.clone())
+    }
+    pub fn statistics_client(&self) -> statistics::OrganizationClient {
+        statistics::OrganizationClient(self.clone())
+    }
+    pub fn status_client(&self) -> status::OrganizationClient {
+        status::OrganizationClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::OrganizationClient {
+        tags::OrganizationClient(self.clone())
+    }
+    pub fn tagsummary_client(&self) -> tagsummary::OrganizationClient {
+        tagsummary::OrganizationClient(self.clone())
+    }
+    pub fn test_history_client(&self) -> test_history::OrganizationClient {
+        test_history::OrganizationClient(self.clone())
+    }
+    pub fn testattachments_client(&self) -> testattachments::OrganizationClient {
+        testattachments::OrganizationClient(self.clone())
+    }
+    pub fn testfailuretype_client(&self) -> testfailuretype::OrganizationClient {
+        testfailuretype::OrganizationClient(self.clone())
+    }
+    pub fn testlog_client(&self) -> testlog::OrganizationClient {
+        testlog::OrganizationClient(self.clone())
+    }
+    pub fn testlogstoreendpoint_client(&self) -> testlogstoreendpoint::OrganizationClient {
+        testlogstoreendpoint::OrganizationClient(self.clone())
+    }
+    pub fn testsettings_client(&self) -> testsettings::OrganizationClient {
+        testsettings::OrganizationClient(self.clone())
+    }
+    pub fn workitems_client(&self) -> workitems::OrganizationClient {
+        workitems::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn bugs_client(&self) -> bugs::ProjectClient {
+        bugs::ProjectClient(self.clone())
+    }
+    pub fn codecoverage_client(&self) -> codecoverage::ProjectClient {
+        codecoverage::ProjectClient(self.clone())
+    }
+    pub fn filecoverage_client(&self) -> filecoverage::ProjectClient {
+        filecoverage::ProjectClient(self.clone())
+    }
+    pub fn history_client(&self) -> history::ProjectClient {
+        history::ProjectClient(self.clone())
+    }
+    pub fn message_logs_client(&self) -> message_logs::ProjectClient {
+        message_logs::ProjectClient(self.clone())
+    }
+    pub fn metrics_client(&self) -> metrics::ProjectClient {
+        metrics::ProjectClient(self.clone())
+    }
+    pub fn result_document_client(&self) -> result_document::ProjectClient {
+        result_document::ProjectClient(self.clone())
+    }
+    pub fn result_meta_data_client(&self) -> result_meta_data::ProjectClient {
+        result_meta_data::ProjectClient(self.clone())
+    }
+    pub fn result_trend_by_build_client(&self) -> result_trend_by_build::ProjectClient {
+        result_trend_by_build::ProjectClient(self.clone())
+    }
+    pub fn result_trend_by_release_client(&self) -> result_trend_by_release::ProjectClient {
+        result_trend_by_release::ProjectClient(self.clone())
+    }
+    pub fn resultdetailsbybuild_client(&self) -> resultdetailsbybuild::ProjectClient {
+        resultdetailsbybuild::ProjectClient(self.clone())
+    }
+    pub fn resultdetailsbyrelease_client(&self) -> resultdetailsbyrelease::ProjectClient {
+        resultdetailsbyrelease::ProjectClient(self.clone())
+    }
+    pub fn resultgroupsbybuild_client(&self) -> resultgroupsbybuild::ProjectClient {
+        resultgroupsbybuild::ProjectClient(self.clone())
+    }
+    pub fn resultgroupsbyrelease_client(&self) -> resultgroupsbyrelease::ProjectClient {
+        resultgroupsbyrelease::ProjectClient(self.clone())
+    }
+    pub fn results_client(&self) -> results::ProjectClient {
+        results::ProjectClient(self.clone())
+    }
+    pub fn resultsbybuild_client(&self) -> resultsbybuild::ProjectClient {
+        resultsbybuild::ProjectClient(self.clone())
+    }
+    pub fn resultsbypipeline_client(&self) -> resultsbypipeline::ProjectClient {
+        resultsbypipeline::ProjectClient(self.clone())
+    }
+    pub fn resultsbyrelease_client(&self) -> resultsbyrelease::ProjectClient {
+        resultsbyrelease::ProjectClient(self.clone())
+    }
+    pub fn resultsgroup_details_client(&self) -> resultsgroup_details::ProjectClient {
+        resultsgroup_details::ProjectClient(self.clone())
+    }
+    pub fn resultsummarybybuild_client(&self) -> resultsummarybybuild::ProjectClient {
+        resultsummarybybuild::ProjectClient(self.clone())
+    }
+    pub fn resultsummarybypipeline_client(&self) -> resultsummarybypipeline::ProjectClient {
+        resultsummarybypipeline::ProjectClient(self.clone())
+    }
+    pub fn resultsummarybyrelease_client(&self) -> resultsummarybyrelease::ProjectClient {
+        resultsummarybyrelease::ProjectClient(self.clone())
+    }
+    pub fn resultsummarybyrequirement_client(&self) -> resultsummarybyrequirement::ProjectClient {
+        resultsummarybyrequirement::ProjectClient(self.clone())
+    }
+    pub fn runs_client(&self) -> runs::ProjectClient {
+        runs::ProjectClient(self.clone())
+    }
+    pub fn runsummary_client(&self) -> runsummary::ProjectClient {
+        runsummary::ProjectClient(self.clone())
+    }
+    pub fn settings_client(&self) -> settings::ProjectClient {
+        settings::ProjectClient(self.clone())
+    }
+    pub fn similar_test_results_client(&self) -> similar_test_results::ProjectClient {
+        similar_test_results::ProjectClient(self.clone())
+    }
+    pub fn statistics_client(&self) -> statistics::ProjectClient {
+        statistics::ProjectClient(self.clone())
+    }
+    pub fn status_client(&self) -> status::ProjectClient {
+        status::ProjectClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::ProjectClient {
+        tags::ProjectClient(self.clone())
+    }
+    pub fn tagsummary_client(&self) -> tagsummary::ProjectClient {
+        tagsummary::ProjectClient(self.clone())
+    }
+    pub fn test_history_client(&self) -> test_history::ProjectClient {
+        test_history::ProjectClient(self.clone())
+    }
+    pub fn testattachments_client(&self) -> testattachments::ProjectClient {
+        testattachments::ProjectClient(self.clone())
+    }
+    pub fn testfailuretype_client(&self) -> testfailuretype::ProjectClient {
+        testfailuretype::ProjectClient(self.clone())
+    }
+    pub fn testlog_client(&self) -> testlog::ProjectClient {
+        testlog::ProjectClient(self.clone())
+    }
+    pub fn testlogstoreendpoint_client(&self) -> testlogstoreendpoint::ProjectClient {
+        testlogstoreendpoint::ProjectClient(self.clone())
+    }
+    pub fn testsettings_client(&self) -> testsettings::ProjectClient {
+        testsettings::ProjectClient(self.clone())
+    }
+    pub fn workitems_client(&self) -> workitems::ProjectClient {
+        workitems::ProjectClient(self.clone())
+    }
+}
 pub mod codecoverage {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -328,6 +631,93 @@ pub mod codecoverage {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_build_code_coverage(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_build_code_coverage(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                flags,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, build_id)
+        }
+        #[doc = "http://(tfsserver):8080/tfs/DefaultCollection/_apis/test/CodeCoverage?buildId=10 Request: Json of code coverage summary"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(&self, project: impl Into<String>, build_id: i32) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), project, build_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_run_code_coverage(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_code_coverage(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                flags,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get_build_code_coverage(
+            &self,
+            build_id: i32,
+            flags: i32,
+        ) -> get_build_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_build_code_coverage(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                flags,
+            )
+        }
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        #[doc = "http://(tfsserver):8080/tfs/DefaultCollection/_apis/test/CodeCoverage?buildId=10 Request: Json of code coverage summary"]
+        pub fn update(&self, build_id: i32) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+        pub fn get_test_run_code_coverage(
+            &self,
+            run_id: i32,
+            flags: i32,
+        ) -> get_test_run_code_coverage::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_code_coverage(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                flags,
+            )
+        }
+    }
     pub mod get_build_code_coverage {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -969,6 +1359,118 @@ pub mod runs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            project: impl Into<String>,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                project,
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::RunCreateModel>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, run_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(&self, project: impl Into<String>, run_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, run_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Query Test Runs based on filters. Mandatory fields are minLastUpdatedDate and maxLastUpdatedDate."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `min_last_updated_date`: Minimum Last Modified Date of run to be queried (Mandatory)."]
+        #[doc = "* `max_last_updated_date`: Maximum Last Modified Date of run to be queried (Mandatory, difference between min and max date can be atmost 7 days)."]
+        pub fn query(
+            &self,
+            min_last_updated_date: impl Into<time::OffsetDateTime>,
+            max_last_updated_date: impl Into<time::OffsetDateTime>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                min_last_updated_date,
+                max_last_updated_date,
+            )
+        }
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        pub fn create(&self, body: impl Into<models::RunCreateModel>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        pub fn get(&self, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::RunUpdateModel>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        pub fn delete(&self, run_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1136,13 +1638,15 @@ pub mod runs {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
                         let min_last_updated_date = &this.min_last_updated_date;
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair("minLastUpdatedDate", &min_last_updated_date.to_string());
+                        req.url_mut().query_pairs_mut().append_pair(
+                            "minLastUpdatedDate",
+                            &crate::date_time::format_date_time(min_last_updated_date)?,
+                        );
                         let max_last_updated_date = &this.max_last_updated_date;
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair("maxLastUpdatedDate", &max_last_updated_date.to_string());
+                        req.url_mut().query_pairs_mut().append_pair(
+                            "maxLastUpdatedDate",
+                            &crate::date_time::format_date_time(max_last_updated_date)?,
+                        );
                         if let Some(state) = &this.state {
                             req.url_mut().query_pairs_mut().append_pair("state", state);
                         }
@@ -1223,8 +1727,8 @@ pub mod runs {
                     self.client.endpoint(),
                     &self.organization,
                     &self.project,
-                    &self.min_last_updated_date,
-                    &self.max_last_updated_date
+                    &crate::date_time::format_date_time(&self.min_last_updated_date)?,
+                    &crate::date_time::format_date_time(&self.max_last_updated_date)?
                 ))?;
                 let has_api_version_already = url
                     .query_pairs()
@@ -1235,6 +1739,20 @@ pub mod runs {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestRun> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -1429,6 +1947,18 @@ pub mod runs {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestRun> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestRunList>;
@@ -2053,24 +2583,192 @@ pub mod testlog {
             }
         }
     }
-    pub mod get_test_sub_result_logs {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::TestLogList> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::TestLogList = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get list of test subresult attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the results"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_sub_result_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_sub_result_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_logs(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test result attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test result"]
+        #[doc = "* `type_`: type of attachments to get"]
+        pub fn get_test_result_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_result_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_logs(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test run attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_run_logs(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_run_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_logs(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of build attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: Id of the build to get"]
+        #[doc = "* `type_`: type of the attachment to get"]
+        pub fn get_test_logs_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_logs_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_logs_for_build(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                type_,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get list of test subresult attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the results"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_sub_result_logs(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_sub_result_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_logs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test result attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test result"]
+        #[doc = "* `type_`: type of attachments to get"]
+        pub fn get_test_result_logs(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_result_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_logs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                result_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of test run attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run"]
+        #[doc = "* `type_`: type of the attachments to get"]
+        pub fn get_test_run_logs(
+            &self,
+            run_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_run_logs::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_logs(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                type_,
+            )
+        }
+        #[doc = "Get list of build attachments reference"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: Id of the build to get"]
+        #[doc = "* `type_`: type of the attachment to get"]
+        pub fn get_test_logs_for_build(
+            &self,
+            build_id: i32,
+            type_: impl Into<String>,
+        ) -> get_test_logs_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_logs_for_build(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                type_,
+            )
+        }
+    }
+    pub mod get_test_sub_result_logs {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::TestLogList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::TestLogList = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
+                        azure_core::error::ErrorKind::DataConversion,
+                        e,
+                        format!(
+                            "Failed to deserialize response:\n{}",
+                            String::from_utf8_lossy(&bytes)
                         ),
                     )
                 })?;
@@ -2959,63 +3657,381 @@ pub mod testlogstoreendpoint {
             }
         }
     }
-    pub mod get_test_log_store_endpoint_details_for_sub_result_log {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::TestLogStoreEndpointDetails> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::TestLogStoreEndpointDetails = serde_json::from_slice(&bytes)
-                    .map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get SAS Uri of a test subresults attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult whose file sas uri is needed"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_sub_result_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_sub_result_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_sub_result_log(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+                file_path,
+            )
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Get SAS Uri of a test results attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result whose files need to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_result_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_result_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_result_log(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                type_,
+                file_path,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
-            }
+        #[doc = "Create empty file for a result and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test results that contains sub result"]
+        #[doc = "* `sub_result_id`: Id of the test sub result whose file sas uri is needed"]
+        #[doc = "* `file_path`: file path inside the sub result for which sas uri is needed"]
+        #[doc = "* `type_`: Type of the file for download"]
+        pub fn test_log_store_endpoint_details_for_result(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            file_path: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_result::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_result(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                result_id,
+                sub_result_id,
+                file_path,
+                type_,
+            )
         }
-        #[derive(Clone)]
-        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
-        #[doc = r""]
-        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
-        #[doc = r" parameters can be chained."]
-        #[doc = r""]
-        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
-        #[doc = r" converts the [`RequestBuilder`] into a future,"]
-        #[doc = r" executes the request and returns a `Result` with the parsed"]
-        #[doc = r" response."]
-        #[doc = r""]
-        #[doc = r" If you need lower-level access to the raw response details"]
-        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
-        #[doc = r" can finalize the request using the"]
+        #[doc = "Get SAS Uri of a test run attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the test run whose file has to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_run_log(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_run_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_run_log(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create empty file for a run and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Id of the run to get endpoint details"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_run(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_run::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_run(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_log_store_operation_type,
+            )
+        }
+        #[doc = "Get SAS Uri of a build attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build`: Id of the build to get"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_build_log(
+            &self,
+            project: impl Into<String>,
+            build: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_build_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_build_log(
+                self.0.organization.clone(),
+                project,
+                build,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create and Get sas uri of the build container"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: Id of the build to get"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_build::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_build(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                test_log_store_operation_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get SAS Uri of a test subresults attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result that contains subresult"]
+        #[doc = "* `sub_result_id`: Id of the test subresult whose file sas uri is needed"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_sub_result_log(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_sub_result_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_sub_result_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                result_id,
+                sub_result_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Get SAS Uri of a test results attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains result"]
+        #[doc = "* `result_id`: Id of the test result whose files need to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_result_log(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_result_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_result_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                result_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create empty file for a result and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run that contains the result"]
+        #[doc = "* `result_id`: Id of the test results that contains sub result"]
+        #[doc = "* `sub_result_id`: Id of the test sub result whose file sas uri is needed"]
+        #[doc = "* `file_path`: file path inside the sub result for which sas uri is needed"]
+        #[doc = "* `type_`: Type of the file for download"]
+        pub fn test_log_store_endpoint_details_for_result(
+            &self,
+            run_id: i32,
+            result_id: i32,
+            sub_result_id: i32,
+            file_path: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_result::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_result(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                result_id,
+                sub_result_id,
+                file_path,
+                type_,
+            )
+        }
+        #[doc = "Get SAS Uri of a test run attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the test run whose file has to be downloaded"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_run_log(
+            &self,
+            run_id: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_run_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_run_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create empty file for a run and Get Sas uri for the file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Id of the run to get endpoint details"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_run(
+            &self,
+            run_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_run::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_run(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_log_store_operation_type,
+            )
+        }
+        #[doc = "Get SAS Uri of a build attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build`: Id of the build to get"]
+        #[doc = "* `type_`: type of the file"]
+        #[doc = "* `file_path`: filePath for which sas uri is needed"]
+        pub fn get_test_log_store_endpoint_details_for_build_log(
+            &self,
+            build: i32,
+            type_: impl Into<String>,
+            file_path: impl Into<String>,
+        ) -> get_test_log_store_endpoint_details_for_build_log::RequestBuilder {
+            Client(self.0.client.clone()).get_test_log_store_endpoint_details_for_build_log(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build,
+                type_,
+                file_path,
+            )
+        }
+        #[doc = "Create and Get sas uri of the build container"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `build_id`: Id of the build to get"]
+        #[doc = "* `test_log_store_operation_type`: Type of operation to perform using sas uri"]
+        pub fn test_log_store_endpoint_details_for_build(
+            &self,
+            build_id: i32,
+            test_log_store_operation_type: impl Into<String>,
+        ) -> test_log_store_endpoint_details_for_build::RequestBuilder {
+            Client(self.0.client.clone()).test_log_store_endpoint_details_for_build(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                test_log_store_operation_type,
+            )
+        }
+    }
+    pub mod get_test_log_store_endpoint_details_for_sub_result_log {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::TestLogStoreEndpointDetails> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::TestLogStoreEndpointDetails = serde_json::from_slice(&bytes)
+                    .map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
         #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
         #[doc = r" that resolves to a lower-level [`Response`] value."]
         pub struct RequestBuilder {
@@ -4001,6 +5017,7 @@ pub mod attachments {
                 test_case_result_id,
                 attachment_id,
                 test_sub_result_id,
+                accept: None,
             }
         }
         #[doc = "Returns a test iteration attachment"]
@@ -4025,6 +5042,7 @@ pub mod attachments {
                 test_case_result_id,
                 attachment_id,
                 iteration_id,
+                accept: None,
             }
         }
         #[doc = "Arguments:"]
@@ -4079,6 +5097,7 @@ pub mod attachments {
                 project: project.into(),
                 run_id,
                 attachment_id,
+                accept: None,
             }
         }
         #[doc = "Arguments:"]
@@ -4157,27 +5176,444 @@ pub mod attachments {
                 run_id,
                 test_case_result_id,
                 attachment_id,
-            }
+                accept: None,
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_test_result_attachment(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> delete_test_result_attachment::RequestBuilder {
+            delete_test_result_attachment::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns attachment references for test sub result."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_sub_result_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_test_iteration_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> create_test_iteration_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_iteration_result_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+        #[doc = "Returns a test sub result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Returns a test iteration attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_iteration_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            iteration_id: i32,
+        ) -> get_test_iteration_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_iteration_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                iteration_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_run_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_run_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Returns a test run attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_test_run_attachment(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> delete_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).delete_test_run_attachment(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_result_attachments(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachments(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_result_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Returns a test result attachment"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachment_zip(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_test_result_attachment(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> delete_test_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).delete_test_result_attachment(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns attachment references for test sub result."]
+        pub fn get_test_sub_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        pub fn create_test_sub_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> create_test_sub_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_sub_result_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                test_sub_result_id,
+            )
+        }
+        pub fn create_test_iteration_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+            iteration_id: i32,
+        ) -> create_test_iteration_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_iteration_result_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                iteration_id,
+            )
+        }
+        #[doc = "Returns a test sub result attachment"]
+        pub fn get_test_sub_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            test_sub_result_id: i32,
+        ) -> get_test_sub_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_sub_result_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                test_sub_result_id,
+            )
+        }
+        #[doc = "Returns a test iteration attachment"]
+        pub fn get_test_iteration_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+            iteration_id: i32,
+        ) -> get_test_iteration_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_iteration_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+                iteration_id,
+            )
+        }
+        pub fn get_test_run_attachments(
+            &self,
+            run_id: i32,
+        ) -> get_test_run_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        pub fn create_test_run_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+        ) -> create_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_run_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        #[doc = "Returns a test run attachment"]
+        pub fn get_test_run_attachment_zip(
+            &self,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> get_test_run_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_run_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                attachment_id,
+            )
+        }
+        pub fn delete_test_run_attachment(
+            &self,
+            run_id: i32,
+            attachment_id: i32,
+        ) -> delete_test_run_attachment::RequestBuilder {
+            Client(self.0.client.clone()).delete_test_run_attachment(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                attachment_id,
+            )
+        }
+        pub fn get_test_result_attachments(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> get_test_result_attachments::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        pub fn create_test_result_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> create_test_result_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_result_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Returns a test result attachment"]
+        pub fn get_test_result_attachment_zip(
+            &self,
+            run_id: i32,
+            test_case_result_id: i32,
+            attachment_id: i32,
+        ) -> get_test_result_attachment_zip::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_attachment_zip(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+                attachment_id,
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn delete_test_result_attachment(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             run_id: i32,
             test_case_result_id: i32,
             attachment_id: i32,
         ) -> delete_test_result_attachment::RequestBuilder {
-            delete_test_result_attachment::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).delete_test_result_attachment(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 run_id,
                 test_case_result_id,
                 attachment_id,
-            }
+            )
         }
     }
     pub mod get_test_sub_result_attachments {
@@ -4586,19 +6022,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4641,8 +6087,14 @@ pub mod attachments {
             pub(crate) test_case_result_id: i32,
             pub(crate) attachment_id: i32,
             pub(crate) test_sub_result_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4661,10 +6113,14 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let test_sub_result_id = &this.test_sub_result_id;
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("testSubResultId", &test_sub_result_id.to_string());
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4684,8 +6140,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -4705,19 +6161,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4760,8 +6226,14 @@ pub mod attachments {
             pub(crate) test_case_result_id: i32,
             pub(crate) attachment_id: i32,
             pub(crate) iteration_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4780,10 +6252,14 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let iteration_id = &this.iteration_id;
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("iterationId", &iteration_id.to_string());
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4812,8 +6288,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5074,19 +6550,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5127,8 +6613,14 @@ pub mod attachments {
             pub(crate) project: String,
             pub(crate) run_id: i32,
             pub(crate) attachment_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5147,6 +6639,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5173,8 +6669,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5548,19 +7044,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5602,8 +7108,14 @@ pub mod attachments {
             pub(crate) run_id: i32,
             pub(crate) test_case_result_id: i32,
             pub(crate) attachment_id: i32,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5622,6 +7134,10 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5649,8 +7165,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5800,93 +7316,255 @@ pub mod testattachments {
                 organization: organization.into(),
                 project: project.into(),
                 run_id,
-                filename: filename.into(),
-            }
+                filename: filename.into(),
+                accept: None,
+            }
+        }
+        #[doc = "Returns a list of attachments for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Creates an attachment in the LogStore for the specified runId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        pub fn create_test_run_log_store_attachment(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> create_test_run_log_store_attachment::RequestBuilder {
+            create_test_run_log_store_attachment::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Deletes the attachment with the specified filename for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        #[doc = "* `filename`: Attachment FileName"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            run_id: i32,
+            filename: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                run_id,
+                filename: filename.into(),
+            }
+        }
+        #[doc = "Creates an attachment in the LogStore for the specified buildId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: BuildId"]
+        pub fn create_build_attachment_in_log_store(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> create_build_attachment_in_log_store::RequestBuilder {
+            create_build_attachment_in_log_store::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                build_id,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the attachment with the specified filename for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        #[doc = "* `filename`: Attachment FileName"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            filename: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                filename,
+            )
+        }
+        #[doc = "Returns a list of attachments for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        pub fn list(&self, project: impl Into<String>, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, run_id)
+        }
+        #[doc = "Creates an attachment in the LogStore for the specified runId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        pub fn create_test_run_log_store_attachment(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> create_test_run_log_store_attachment::RequestBuilder {
+            Client(self.0.client.clone()).create_test_run_log_store_attachment(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Deletes the attachment with the specified filename for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: Test RunId"]
+        #[doc = "* `filename`: Attachment FileName"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            filename: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                filename,
+            )
+        }
+        #[doc = "Creates an attachment in the LogStore for the specified buildId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `build_id`: BuildId"]
+        pub fn create_build_attachment_in_log_store(
+            &self,
+            body: impl Into<models::TestAttachmentRequestModel>,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> create_build_attachment_in_log_store::RequestBuilder {
+            Client(self.0.client.clone()).create_build_attachment_in_log_store(
+                self.0.organization.clone(),
+                body,
+                project,
+                build_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the attachment with the specified filename for the specified runId from the LogStore."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: Test RunId"]
+        #[doc = "* `filename`: Attachment FileName"]
+        pub fn get(&self, run_id: i32, filename: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                filename,
+            )
         }
         #[doc = "Returns a list of attachments for the specified runId from the LogStore."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test RunId"]
-        pub fn list(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            run_id: i32,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn list(&self, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 run_id,
-            }
+            )
         }
         #[doc = "Creates an attachment in the LogStore for the specified runId."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test RunId"]
         pub fn create_test_run_log_store_attachment(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestAttachmentRequestModel>,
-            project: impl Into<String>,
             run_id: i32,
         ) -> create_test_run_log_store_attachment::RequestBuilder {
-            create_test_run_log_store_attachment::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).create_test_run_log_store_attachment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
                 run_id,
-            }
+            )
         }
         #[doc = "Deletes the attachment with the specified filename for the specified runId from the LogStore."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `run_id`: Test RunId"]
         #[doc = "* `filename`: Attachment FileName"]
-        pub fn delete(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            run_id: i32,
-            filename: impl Into<String>,
-        ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+        pub fn delete(&self, run_id: i32, filename: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 run_id,
-                filename: filename.into(),
-            }
+                filename,
+            )
         }
         #[doc = "Creates an attachment in the LogStore for the specified buildId."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `body`: Contains attachment info like stream, filename, comment, attachmentType"]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `build_id`: BuildId"]
         pub fn create_build_attachment_in_log_store(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::TestAttachmentRequestModel>,
-            project: impl Into<String>,
             build_id: i32,
         ) -> create_build_attachment_in_log_store::RequestBuilder {
-            create_build_attachment_in_log_store::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).create_build_attachment_in_log_store(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
                 build_id,
-            }
+            )
         }
     }
     pub mod get {
@@ -5898,19 +7576,29 @@ pub mod testattachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5951,8 +7639,14 @@ pub mod testattachments {
             pub(crate) project: String,
             pub(crate) run_id: i32,
             pub(crate) filename: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5971,10 +7665,14 @@ pub mod testattachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         let filename = &this.filename;
                         req.url_mut()
                             .query_pairs_mut()
                             .append_pair("filename", filename);
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -6001,8 +7699,8 @@ pub mod testattachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -6540,6 +8238,84 @@ pub mod tags {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_tags_for_release(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> get_test_tags_for_release::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tags_for_release(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                release_env_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestTagsUpdateModel>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_tags_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_test_tags_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tags_for_build(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get_test_tags_for_release(
+            &self,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> get_test_tags_for_release::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tags_for_release(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                release_env_id,
+            )
+        }
+        pub fn update(
+            &self,
+            body: impl Into<models::TestTagsUpdateModel>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+        pub fn get_test_tags_for_build(
+            &self,
+            build_id: i32,
+        ) -> get_test_tags_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tags_for_build(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get_test_tags_for_release {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6953,6 +8729,62 @@ pub mod tagsummary {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_tag_summary_for_release(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> get_test_tag_summary_for_release::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tag_summary_for_release(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                release_env_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_tag_summary_for_build(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+        ) -> get_test_tag_summary_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tag_summary_for_build(
+                self.0.organization.clone(),
+                project,
+                build_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get_test_tag_summary_for_release(
+            &self,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> get_test_tag_summary_for_release::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tag_summary_for_release(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                release_env_id,
+            )
+        }
+        pub fn get_test_tag_summary_for_build(
+            &self,
+            build_id: i32,
+        ) -> get_test_tag_summary_for_build::RequestBuilder {
+            Client(self.0.client.clone()).get_test_tag_summary_for_build(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get_test_tag_summary_for_release {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7304,6 +9136,131 @@ pub mod workitems {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_test_method_linked_work_items(
+            &self,
+            project: impl Into<String>,
+            test_name: impl Into<String>,
+        ) -> query_test_method_linked_work_items::RequestBuilder {
+            Client(self.0.client.clone()).query_test_method_linked_work_items(
+                self.0.organization.clone(),
+                project,
+                test_name,
+            )
+        }
+        #[doc = "Query Test Result WorkItems based on filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_category`: can take values Microsoft.BugCategory or all(for getting all workitems)"]
+        pub fn query_test_result_work_items(
+            &self,
+            project: impl Into<String>,
+            work_item_category: impl Into<String>,
+        ) -> query_test_result_work_items::RequestBuilder {
+            Client(self.0.client.clone()).query_test_result_work_items(
+                self.0.organization.clone(),
+                project,
+                work_item_category,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: impl Into<models::WorkItemToTestLinks>,
+            project: impl Into<String>,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_name: impl Into<String>,
+            work_item_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                test_name,
+                work_item_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query_test_method_linked_work_items(
+            &self,
+            test_name: impl Into<String>,
+        ) -> query_test_method_linked_work_items::RequestBuilder {
+            Client(self.0.client.clone()).query_test_method_linked_work_items(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_name,
+            )
+        }
+        #[doc = "Query Test Result WorkItems based on filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_category`: can take values Microsoft.BugCategory or all(for getting all workitems)"]
+        pub fn query_test_result_work_items(
+            &self,
+            work_item_category: impl Into<String>,
+        ) -> query_test_result_work_items::RequestBuilder {
+            Client(self.0.client.clone()).query_test_result_work_items(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_category,
+            )
+        }
+        pub fn list(&self, run_id: i32, test_case_result_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+        pub fn add(&self, body: impl Into<models::WorkItemToTestLinks>) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        pub fn delete(
+            &self,
+            test_name: impl Into<String>,
+            work_item_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_name,
+                work_item_id,
+            )
+        }
+    }
     pub mod query_test_method_linked_work_items {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7553,9 +9510,10 @@ pub mod workitems {
                                 .append_pair("testCaseId", &test_case_id.to_string());
                         }
                         if let Some(max_complete_date) = &this.max_complete_date {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("maxCompleteDate", &max_complete_date.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "maxCompleteDate",
+                                &crate::date_time::format_date_time(max_complete_date)?,
+                            );
                         }
                         if let Some(days) = &this.days {
                             req.url_mut()
@@ -7995,9 +9953,39 @@ pub mod filecoverage {
                 organization: organization.into(),
                 body: body.into(),
                 project: project.into(),
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get file coverage for the specified file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: File details with pull request iteration context"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            body: impl Into<models::FileCoverageRequest>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get file coverage for the specified file"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: File details with pull request iteration context"]
+        pub fn get(&self, body: impl Into<models::FileCoverageRequest>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8008,18 +9996,28 @@ pub mod filecoverage {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+                self.into_text().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -8059,8 +10057,14 @@ pub mod filecoverage {
             pub(crate) organization: String,
             pub(crate) body: models::FileCoverageRequest,
             pub(crate) project: String,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
+            #[doc = "The content type of the response: one of `text/plain`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -8079,8 +10083,12 @@ pub mod filecoverage {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "text/plain");
                         req.insert_header("content-type", "application/json");
                         let req_body = azure_core::to_json(&this.body)?;
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
                     }
@@ -8144,9 +10152,39 @@ pub mod status {
                 definition: definition.into(),
                 branch_name: None,
                 label: None,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "<p>Gets the coverage status for the last successful build of a definition, optionally scoped to a specific branch</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `definition`: The ID or name of the definition."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            definition: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, definition)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "<p>Gets the coverage status for the last successful build of a definition, optionally scoped to a specific branch</p>"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `definition`: The ID or name of the definition."]
+        pub fn get(&self, definition: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                definition,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8156,19 +10194,29 @@ pub mod status {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -8210,6 +10258,7 @@ pub mod status {
             pub(crate) definition: String,
             pub(crate) branch_name: Option<String>,
             pub(crate) label: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The branch name."]
@@ -8222,6 +10271,11 @@ pub mod status {
                 self.label = Some(label.into());
                 self
             }
+            #[doc = "The content type of the response: one of `image/svg+xml`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -8240,6 +10294,7 @@ pub mod status {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "image/svg+xml");
                         if let Some(branch_name) = &this.branch_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -8248,6 +10303,9 @@ pub mod status {
                         if let Some(label) = &this.label {
                             req.url_mut().query_pairs_mut().append_pair("label", label);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -8273,8 +10331,8 @@ pub mod status {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -8319,6 +10377,31 @@ pub mod metrics {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get summary of test results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn get(&self, project: impl Into<String>, pipeline_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, pipeline_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get summary of test results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn get(&self, pipeline_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8527,6 +10610,24 @@ pub mod resultdetailsbybuild {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, build_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, build_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8750,6 +10851,35 @@ pub mod resultdetailsbyrelease {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                release_env_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, release_id: i32, release_env_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                release_env_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8974,6 +11104,39 @@ pub mod resultgroupsbybuild {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            build_id: i32,
+            publish_context: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                build_id,
+                publish_context,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(
+            &self,
+            build_id: i32,
+            publish_context: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+                publish_context,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9109,6 +11272,22 @@ pub mod resultgroupsbybuild {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(
+                self,
+            ) -> crate::paging::Pageable<models::FieldDetailsForTestResults> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::FieldDetailsForTestResultsList>;
@@ -9155,6 +11334,39 @@ pub mod resultgroupsbyrelease {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            publish_context: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                publish_context,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(
+            &self,
+            release_id: i32,
+            publish_context: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                publish_context,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9300,6 +11512,22 @@ pub mod resultgroupsbyrelease {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(
+                self,
+            ) -> crate::paging::Pageable<models::FieldDetailsForTestResults> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::FieldDetailsForTestResultsList>;
@@ -9382,59 +11610,198 @@ pub mod results {
             }
         }
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> add::RequestBuilder {
+            add::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                run_id,
+            }
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_result_by_id(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            run_id: i32,
+            test_result_id: i32,
+        ) -> get_test_result_by_id::RequestBuilder {
+            get_test_result_by_id::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                run_id,
+                test_result_id,
+                details_to_include: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_results_by_query(
+            &self,
+            body: impl Into<models::TestResultsQuery>,
+            project: impl Into<String>,
+        ) -> get_test_results_by_query::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results_by_query(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_results_by_query_wiql(
+            &self,
+            body: impl Into<models::QueryModel>,
+            project: impl Into<String>,
+        ) -> get_test_results_by_query_wiql::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results_by_query_wiql(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_test_results(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> get_test_results::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results(
+                self.0.organization.clone(),
+                project,
+                run_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn add(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: Vec<models::TestCaseResult>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, run_id)
+        }
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
-        pub fn add(
+        pub fn get_test_result_by_id(
             &self,
-            organization: impl Into<String>,
-            body: Vec<models::TestCaseResult>,
             project: impl Into<String>,
             run_id: i32,
-        ) -> add::RequestBuilder {
-            add::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            test_result_id: i32,
+        ) -> get_test_result_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_test_result_by_id(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_result_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get_test_results_by_query(
+            &self,
+            body: impl Into<models::TestResultsQuery>,
+        ) -> get_test_results_by_query::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results_by_query(
+                self.0.organization.clone(),
                 body,
-                project: project.into(),
+                self.0.project.clone(),
+            )
+        }
+        pub fn get_test_results_by_query_wiql(
+            &self,
+            body: impl Into<models::QueryModel>,
+        ) -> get_test_results_by_query_wiql::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results_by_query_wiql(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        pub fn get_test_results(&self, run_id: i32) -> get_test_results::RequestBuilder {
+            Client(self.0.client.clone()).get_test_results(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 run_id,
-            }
+            )
+        }
+        pub fn add(&self, body: Vec<models::TestCaseResult>, run_id: i32) -> add::RequestBuilder {
+            Client(self.0.client.clone()).add(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::TestCaseResult>,
-            project: impl Into<String>,
             run_id: i32,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
                 body,
-                project: project.into(),
+                self.0.project.clone(),
                 run_id,
-            }
+            )
         }
-        #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         pub fn get_test_result_by_id(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             run_id: i32,
             test_result_id: i32,
         ) -> get_test_result_by_id::RequestBuilder {
-            get_test_result_by_id::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).get_test_result_by_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
                 run_id,
                 test_result_id,
-                details_to_include: None,
-            }
+            )
         }
     }
     pub mod get_test_results_by_query {
@@ -9703,6 +12070,18 @@ pub mod results {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestCaseResult> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseResultList>;
@@ -9872,6 +12251,18 @@ pub mod results {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TestCaseResult> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TestCaseResultList>;
@@ -10284,6 +12675,28 @@ pub mod history {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::ResultsFilter>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query(&self, body: impl Into<models::ResultsFilter>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10455,6 +12868,71 @@ pub mod result_meta_data {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get list of test Result meta data details for corresponding testcasereferenceId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestCaseReference Ids of the test Result to be queried, comma separated list of valid ids (limit no. of ids 200)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: Vec<String>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Update properties of test result meta data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestResultMetaData update input TestResultMetaDataUpdateInput"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `test_case_reference_id`: TestCaseReference Id of Test Result to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultMetaDataUpdateInput>,
+            project: impl Into<String>,
+            test_case_reference_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                test_case_reference_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get list of test Result meta data details for corresponding testcasereferenceId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestCaseReference Ids of the test Result to be queried, comma separated list of valid ids (limit no. of ids 200)."]
+        pub fn query(&self, body: Vec<String>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update properties of test result meta data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestResultMetaData update input TestResultMetaDataUpdateInput"]
+        #[doc = "* `test_case_reference_id`: TestCaseReference Id of Test Result to be updated."]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultMetaDataUpdateInput>,
+            test_case_reference_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                test_case_reference_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10736,6 +13214,35 @@ pub mod test_history {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestHistoryQuery>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get history of a test method using TestHistoryQuery"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TestHistoryQuery to get history"]
+        pub fn query(&self, body: impl Into<models::TestHistoryQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10885,6 +13392,24 @@ pub mod resultsbybuild {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>, build_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, build_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, build_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11033,6 +13558,20 @@ pub mod resultsbybuild {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::ShallowTestCaseResult> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ShallowTestCaseResultList>;
@@ -11083,6 +13622,31 @@ pub mod resultsbypipeline {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn list(&self, project: impl Into<String>, pipeline_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, pipeline_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn list(&self, pipeline_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11301,6 +13865,24 @@ pub mod resultsbyrelease {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>, release_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, release_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, release_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11459,6 +14041,20 @@ pub mod resultsbyrelease {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::ShallowTestCaseResult> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::ShallowTestCaseResultList>;
@@ -11508,6 +14104,42 @@ pub mod resultsgroup_details {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the available groups details and for these groups get failed and aborted results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn test_results_group_details(
+            &self,
+            project: impl Into<String>,
+            pipeline_id: i32,
+        ) -> test_results_group_details::RequestBuilder {
+            Client(self.0.client.clone()).test_results_group_details(
+                self.0.organization.clone(),
+                project,
+                pipeline_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the available groups details and for these groups get failed and aborted results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn test_results_group_details(
+            &self,
+            pipeline_id: i32,
+        ) -> test_results_group_details::RequestBuilder {
+            Client(self.0.client.clone()).test_results_group_details(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod test_results_group_details {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11734,6 +14366,24 @@ pub mod resultsummarybybuild {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(&self, project: impl Into<String>, build_id: i32) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), project, build_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query(&self, build_id: i32) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                build_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12014,6 +14664,31 @@ pub mod resultsummarybypipeline {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get summary of test results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn query(&self, project: impl Into<String>, pipeline_id: i32) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), project, pipeline_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get summary of test results."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `pipeline_id`: Pipeline Id. This is same as build Id."]
+        pub fn query(&self, pipeline_id: i32) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                pipeline_id,
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12237,6 +14912,62 @@ pub mod resultsummarybyrelease {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_test_results_report_for_release(
+            &self,
+            project: impl Into<String>,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> query_test_results_report_for_release::RequestBuilder {
+            Client(self.0.client.clone()).query_test_results_report_for_release(
+                self.0.organization.clone(),
+                project,
+                release_id,
+                release_env_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query_test_results_summary_for_releases(
+            &self,
+            body: Vec<models::ReleaseReference>,
+            project: impl Into<String>,
+        ) -> query_test_results_summary_for_releases::RequestBuilder {
+            Client(self.0.client.clone()).query_test_results_summary_for_releases(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query_test_results_report_for_release(
+            &self,
+            release_id: i32,
+            release_env_id: i32,
+        ) -> query_test_results_report_for_release::RequestBuilder {
+            Client(self.0.client.clone()).query_test_results_report_for_release(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                release_id,
+                release_env_id,
+            )
+        }
+        pub fn query_test_results_summary_for_releases(
+            &self,
+            body: Vec<models::ReleaseReference>,
+        ) -> query_test_results_summary_for_releases::RequestBuilder {
+            Client(self.0.client.clone()).query_test_results_summary_for_releases(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query_test_results_report_for_release {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12449,7 +15180,9 @@ pub mod resultsummarybyrelease {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "releaseToCompare.creationDate",
-                                &release_to_compare_creation_date.to_string(),
+                                &crate::date_time::format_date_time(
+                                    release_to_compare_creation_date,
+                                )?,
                             );
                         }
                         if let Some(release_to_compare_definition_id) =
@@ -12465,7 +15198,9 @@ pub mod resultsummarybyrelease {
                         {
                             req.url_mut().query_pairs_mut().append_pair(
                                 "releaseToCompare.environmentCreationDate",
-                                &release_to_compare_environment_creation_date.to_string(),
+                                &crate::date_time::format_date_time(
+                                    release_to_compare_environment_creation_date,
+                                )?,
                             );
                         }
                         if let Some(release_to_compare_environment_definition_id) =
@@ -12693,6 +15428,28 @@ pub mod resultsummarybyrequirement {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestResultsContext>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query(&self, body: impl Into<models::TestResultsContext>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12849,6 +15606,31 @@ pub mod result_trend_by_build {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestResultTrendFilter>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query(
+            &self,
+            body: impl Into<models::TestResultTrendFilter>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12997,6 +15779,31 @@ pub mod result_trend_by_release {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::TestResultTrendFilter>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn query(
+            &self,
+            body: impl Into<models::TestResultTrendFilter>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13148,6 +15955,31 @@ pub mod message_logs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get test run message logs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn list(&self, project: impl Into<String>, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, run_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get test run message logs"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn list(&self, run_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13296,6 +16128,39 @@ pub mod result_document {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn publish_test_result_document(
+            &self,
+            body: impl Into<models::TestResultDocument>,
+            project: impl Into<String>,
+            run_id: i32,
+        ) -> publish_test_result_document::RequestBuilder {
+            Client(self.0.client.clone()).publish_test_result_document(
+                self.0.organization.clone(),
+                body,
+                project,
+                run_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn publish_test_result_document(
+            &self,
+            body: impl Into<models::TestResultDocument>,
+            run_id: i32,
+        ) -> publish_test_result_document::RequestBuilder {
+            Client(self.0.client.clone()).publish_test_result_document(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod publish_test_result_document {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13445,6 +16310,35 @@ pub mod bugs {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_case_result_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_case_result_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, run_id: i32, test_case_result_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_case_result_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13603,6 +16497,54 @@ pub mod similar_test_results {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the list of results whose failure matches with the provided one."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: id of test run"]
+        #[doc = "* `test_result_id`: id of test result inside a test run"]
+        #[doc = "* `test_sub_result_id`: id of subresult inside a test result"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            run_id: i32,
+            test_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                run_id,
+                test_result_id,
+                test_sub_result_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the list of results whose failure matches with the provided one."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: id of test run"]
+        #[doc = "* `test_result_id`: id of test result inside a test run"]
+        #[doc = "* `test_sub_result_id`: id of subresult inside a test result"]
+        pub fn list(
+            &self,
+            run_id: i32,
+            test_result_id: i32,
+            test_sub_result_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+                test_result_id,
+                test_sub_result_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13778,6 +16720,31 @@ pub mod runsummary {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get test run summary, used when we want to get summary of a run by outcome. Test run should be in completed state."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get(&self, project: impl Into<String>, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, run_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get test run summary, used when we want to get summary of a run by outcome. Test run should be in completed state."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get(&self, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13926,6 +16893,31 @@ pub mod statistics {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get(&self, project: impl Into<String>, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, run_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get test run statistics , used when we want to get summary of a run by outcome."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `run_id`: ID of the run to get."]
+        pub fn get(&self, run_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                run_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -14090,6 +17082,45 @@ pub mod settings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get TestResultsSettings data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+        #[doc = "Update project settings of test results"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultsUpdateSettings>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get TestResultsSettings data"]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Update project settings of test results"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TestResultsUpdateSettings>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -14398,6 +17429,68 @@ pub mod testfailuretype {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the list of test failure types."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Creates a new test failure type"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestResultFailureTypeRequestModel>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Deletes a test failure type with specified failureTypeId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            failure_type_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                failure_type_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the list of test failure types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Creates a new test failure type"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestResultFailureTypeRequestModel>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Deletes a test failure type with specified failureTypeId"]
+        pub fn delete(&self, failure_type_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                failure_type_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -14800,6 +17893,68 @@ pub mod testsettings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            test_settings_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                test_settings_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TestSettings>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            test_settings_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                test_settings_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn get(&self, test_settings_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_settings_id,
+            )
+        }
+        pub fn create(&self, body: impl Into<models::TestSettings>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        pub fn delete(&self, test_settings_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                test_settings_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
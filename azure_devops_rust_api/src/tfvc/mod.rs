@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn branches_client(&self) -> branches::Client {
         branches::Client(self.clone())
     }
@@ -145,6 +172,76 @@ impl Client {
         shelvesets::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn branches_client(&self) -> branches::OrganizationClient {
+        branches::OrganizationClient(self.clone())
+    }
+    pub fn changesets_client(&self) -> changesets::OrganizationClient {
+        changesets::OrganizationClient(self.clone())
+    }
+    pub fn items_client(&self) -> items::OrganizationClient {
+        items::OrganizationClient(self.clone())
+    }
+    pub fn labels_client(&self) -> labels::OrganizationClient {
+        labels::OrganizationClient(self.clone())
+    }
+    pub fn shelvesets_client(&self) -> shelvesets::OrganizationClient {
+        shelvesets::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn branches_client(&self) -> branches::ProjectClient {
+        branches::ProjectClient(self.clone())
+    }
+    pub fn changesets_client(&self) -> changesets::ProjectClient {
+        changesets::ProjectClient(self.clone())
+    }
+    pub fn items_client(&self) -> items::ProjectClient {
+        items::ProjectClient(self.clone())
+    }
+    pub fn labels_client(&self) -> labels::ProjectClient {
+        labels::ProjectClient(self.clone())
+    }
+    pub fn shelvesets_client(&self) -> shelvesets::ProjectClient {
+        shelvesets::ProjectClient(self.clone())
+    }
+}
 pub mod shelvesets {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -230,6 +327,78 @@ pub mod shelvesets {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Return a collection of shallow shelveset references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a single deep shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get(&self, shelveset_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get changes included in a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_changes(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_changes::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_shelveset_changes(self.0.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get work items associated with a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_work_items(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_work_items::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_shelveset_work_items(self.0.organization.clone(), shelveset_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Return a collection of shallow shelveset references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a single deep shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get(&self, shelveset_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get changes included in a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_changes(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_changes::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_shelveset_changes(self.0.organization.clone(), shelveset_id)
+        }
+        #[doc = "Get work items associated with a shelveset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `shelveset_id`: Shelveset's unique ID"]
+        pub fn get_shelveset_work_items(
+            &self,
+            shelveset_id: impl Into<String>,
+        ) -> get_shelveset_work_items::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_shelveset_work_items(self.0.organization.clone(), shelveset_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -455,6 +624,18 @@ pub mod shelvesets {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcShelvesetRef> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcShelvesetRefList>;
@@ -817,6 +998,18 @@ pub mod shelvesets {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcChange> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangeList>;
@@ -1024,6 +1217,77 @@ pub mod branches {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a single branch hierarchy at the given path with parents or children as specified."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), path, project)
+        }
+        #[doc = "Get a collection of branch roots -- first-level children, branches with no parents."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_branches(&self, project: impl Into<String>) -> get_branches::RequestBuilder {
+            Client(self.0.client.clone()).get_branches(self.0.organization.clone(), project)
+        }
+        #[doc = "Get branch hierarchies below the specified scopePath"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_branch_refs(
+            &self,
+            scope_path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_branch_refs::RequestBuilder {
+            Client(self.0.client.clone()).get_branch_refs(
+                self.0.organization.clone(),
+                scope_path,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a single branch hierarchy at the given path with parents or children as specified."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        pub fn get(&self, path: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                path,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a collection of branch roots -- first-level children, branches with no parents."]
+        pub fn get_branches(&self) -> get_branches::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_branches(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get branch hierarchies below the specified scopePath"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `scope_path`: Full path to the branch.  Default: $/ Examples: $/, $/MyProject, $/MyProject/SomeFolder."]
+        pub fn get_branch_refs(
+            &self,
+            scope_path: impl Into<String>,
+        ) -> get_branch_refs::RequestBuilder {
+            Client(self.0.client.clone()).get_branch_refs(
+                self.0.organization.clone(),
+                scope_path,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1547,6 +1811,72 @@ pub mod items {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Version control path of an individual item to return."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            path: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), path, project)
+        }
+        #[doc = "Post for retrieving a set of items given a list of paths or a long path. Allows for specifying the recursionLevel and version descriptors for each path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::TfvcItemRequestData>,
+            project: impl Into<String>,
+        ) -> get_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_items_batch(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Get a list of Tfvc items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get Item Metadata and/or Content for a single item. The download parameter is to indicate whether the content should be available as a download or just sent as a stream in the response. Doesn't apply to zipped content which is always returned as a download."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `path`: Version control path of an individual item to return."]
+        pub fn get(&self, path: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                path,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Post for retrieving a set of items given a list of paths or a long path. Allows for specifying the recursionLevel and version descriptors for each path."]
+        pub fn get_items_batch(
+            &self,
+            body: impl Into<models::TfvcItemRequestData>,
+        ) -> get_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_items_batch(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get a list of Tfvc items"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2231,6 +2561,116 @@ pub mod changesets {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Retrieve Tfvc changes for a given changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset. Default: null"]
+        pub fn get_changeset_changes(&self, id: i32) -> get_changeset_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_changeset_changes(self.0.organization.clone(), id)
+        }
+        #[doc = "Retrieves the work items associated with a particular changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset."]
+        pub fn get_changeset_work_items(
+            &self,
+            id: i32,
+        ) -> get_changeset_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_changeset_work_items(self.0.organization.clone(), id)
+        }
+        #[doc = "Returns changesets for a given list of changeset Ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of changeset IDs."]
+        pub fn get_batched_changesets(
+            &self,
+            body: impl Into<models::TfvcChangesetsRequestData>,
+        ) -> get_batched_changesets::RequestBuilder {
+            Client(self.0.client.clone()).get_batched_changesets(self.0.organization.clone(), body)
+        }
+        #[doc = "Retrieve Tfvc Changesets\n\nNote: This is a new version of the GetChangesets API that doesn't expose the unneeded queryParams\npresent in the 1.0 version of the API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_changesets(&self, project: impl Into<String>) -> get_changesets::RequestBuilder {
+            Client(self.0.client.clone()).get_changesets(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a new changeset.\n\nAccepts TfvcChangeset as JSON body"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TfvcChangeset>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Retrieve a Tfvc Changeset"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Changeset Id to retrieve."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Retrieve Tfvc changes for a given changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset. Default: null"]
+        pub fn get_changeset_changes(&self, id: i32) -> get_changeset_changes::RequestBuilder {
+            Client(self.0.client.clone()).get_changeset_changes(self.0.organization.clone(), id)
+        }
+        #[doc = "Retrieves the work items associated with a particular changeset."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the changeset."]
+        pub fn get_changeset_work_items(
+            &self,
+            id: i32,
+        ) -> get_changeset_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_changeset_work_items(self.0.organization.clone(), id)
+        }
+        #[doc = "Returns changesets for a given list of changeset Ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of changeset IDs."]
+        pub fn get_batched_changesets(
+            &self,
+            body: impl Into<models::TfvcChangesetsRequestData>,
+        ) -> get_batched_changesets::RequestBuilder {
+            Client(self.0.client.clone()).get_batched_changesets(self.0.organization.clone(), body)
+        }
+        #[doc = "Retrieve Tfvc Changesets\n\nNote: This is a new version of the GetChangesets API that doesn't expose the unneeded queryParams\npresent in the 1.0 version of the API."]
+        pub fn get_changesets(&self) -> get_changesets::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_changesets(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a new changeset.\n\nAccepts TfvcChangeset as JSON body"]
+        pub fn create(&self, body: impl Into<models::TfvcChangeset>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Retrieve a Tfvc Changeset"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Changeset Id to retrieve."]
+        pub fn get(&self, id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_changeset_changes {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2366,6 +2806,20 @@ pub mod changesets {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcChange> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangeList>;
@@ -2885,6 +3339,18 @@ pub mod changesets {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcChangesetRef> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcChangesetRefList>;
@@ -3425,6 +3891,66 @@ pub mod labels {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get items under a label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get_label_items(
+            &self,
+            label_id: impl Into<String>,
+        ) -> get_label_items::RequestBuilder {
+            Client(self.0.client.clone()).get_label_items(self.0.organization.clone(), label_id)
+        }
+        #[doc = "Get a collection of shallow label references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Get a single deep label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            label_id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), label_id, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get items under a label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get_label_items(
+            &self,
+            label_id: impl Into<String>,
+        ) -> get_label_items::RequestBuilder {
+            Client(self.0.client.clone()).get_label_items(self.0.organization.clone(), label_id)
+        }
+        #[doc = "Get a collection of shallow label references."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get a single deep label."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `label_id`: Unique identifier of label"]
+        pub fn get(&self, label_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                label_id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_label_items {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3549,6 +4075,18 @@ pub mod labels {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcItem> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcItemList>;
@@ -3766,6 +4304,18 @@ pub mod labels {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::TfvcLabelRef> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TfvcLabelRefList>;
@@ -64,7 +64,7 @@ pub struct Change {
     pub change_type: Option<change::ChangeType>,
     #[doc = "Current version."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub item: Option<String>,
+    pub item: Option<TfvcItem>,
     #[serde(
         rename = "newContent",
         default,
@@ -0,0 +1,342 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Azure DevOps rate limit aware throttling.
+//!
+//! Azure DevOps limits the resources a user can consume in a sliding five minute window,
+//! measured in Azure DevOps throughput units (TSTUs). As the limit is approached, responses
+//! include the [`headers`](crate::headers) `X-RateLimit-*` headers, and once it is exceeded
+//! requests are delayed or blocked with `429 Too Many Requests`.
+//!
+//! [`RateLimitPolicy`] tracks these headers and slows down requests before the budget runs out,
+//! rather than waiting to be blocked. The current budget can be read from the shared
+//! [`RateLimitBudget`] handle, which can also be shared between several clients (and policies)
+//! so that they pace themselves against the same budget.
+//!
+//! To use the policy, add it to the client builder:
+//! ```rust
+//!     let budget = throttling::RateLimitBudget::new();
+//!     let git_client = git::ClientBuilder::new(credential)
+//!            .per_retry_policies(vec![throttling::rate_limit_policy(budget.clone())])
+//!            .build();
+//!     ...
+//!     println!("Remaining TSTUs: {:?}", budget.status().remaining);
+//! ```
+//!
+//! For more information see [Azure DevOps Rate and usage limits](https://learn.microsoft.com/en-us/azure/devops/integrate/concepts/rate-limits?view=azure-devops).
+use crate::headers::{
+    X_RATELIMIT_DELAY, X_RATELIMIT_LIMIT, X_RATELIMIT_REMAINING, X_RATELIMIT_RESET,
+};
+use async_trait::async_trait;
+use azure_core::headers::{Headers, RETRY_AFTER};
+use azure_core::{Context, Policy, PolicyResult, Request, StatusCode};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+use tracing::debug;
+
+/// A snapshot of the rate limit budget, as reported by the most recent response.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimitStatus {
+    /// Total number of TSTUs allowed before delays are imposed.
+    pub limit: Option<f64>,
+    /// Number of TSTUs remaining before requests are delayed.
+    pub remaining: Option<f64>,
+    /// Time at which tracked usage will have returned to 0 TSTUs.
+    pub reset: Option<OffsetDateTime>,
+    /// How long the service delayed the most recent request.
+    pub delay: Option<Duration>,
+    /// Requests are paused until this time, following a `Retry-After` response.
+    pub blocked_until: Option<OffsetDateTime>,
+}
+
+impl RateLimitStatus {
+    /// Returns `true` if the service is delaying or blocking requests.
+    pub fn is_throttled(&self) -> bool {
+        self.remaining == Some(0.0)
+            || self.delay.map_or(false, |delay| !delay.is_zero())
+            || self.blocked_until.map_or(false, |blocked_until| {
+                blocked_until > OffsetDateTime::now_utc()
+            })
+    }
+
+    fn update(&mut self, status: StatusCode, headers: &Headers, now: OffsetDateTime) {
+        // The rate limit headers are only returned once usage is significant, so a response
+        // without them means the budget has recovered.
+        let limit = get_f64(headers, &X_RATELIMIT_LIMIT);
+        let remaining = get_f64(headers, &X_RATELIMIT_REMAINING);
+        if limit.is_some() || remaining.is_some() {
+            self.limit = limit.or(self.limit);
+            self.remaining = remaining;
+            self.reset = get_f64(headers, &X_RATELIMIT_RESET)
+                .and_then(|reset| OffsetDateTime::from_unix_timestamp(reset as i64).ok());
+        } else if self.reset.map_or(true, |reset| reset <= now) {
+            self.remaining = None;
+            self.reset = None;
+        }
+        self.delay = get_f64(headers, &X_RATELIMIT_DELAY)
+            .filter(|delay| delay.is_finite() && *delay >= 0.0)
+            .map(Duration::from_secs_f64);
+        if status == StatusCode::TooManyRequests || status == StatusCode::ServiceUnavailable {
+            if let Some(retry_after) = retry_after(headers, now) {
+                self.blocked_until = Some(now + retry_after);
+            }
+        }
+    }
+
+    /// How long to wait before sending the next request.
+    fn throttle_delay(
+        &self,
+        low_watermark: f64,
+        max_delay: Duration,
+        now: OffsetDateTime,
+    ) -> Duration {
+        if let Some(blocked_until) = self.blocked_until {
+            if blocked_until > now {
+                return to_std_duration(blocked_until - now).min(max_delay);
+            }
+        }
+        let (remaining, reset) = match (self.remaining, self.reset) {
+            (Some(remaining), Some(reset)) => (remaining, reset),
+            _ => return Duration::ZERO,
+        };
+        if reset <= now {
+            return Duration::ZERO;
+        }
+        let threshold = low_watermark * self.limit.unwrap_or(0.0);
+        if remaining > threshold {
+            return Duration::ZERO;
+        }
+        // Spread the remaining requests across the time left until the budget resets:
+        // the closer to empty, the longer the pause, up to waiting for the full reset.
+        let until_reset = to_std_duration(reset - now);
+        let fraction = if threshold > 0.0 {
+            1.0 - (remaining / threshold).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        until_reset.mul_f64(fraction).min(max_delay)
+    }
+}
+
+fn get_f64(headers: &Headers, name: &azure_core::headers::HeaderName) -> Option<f64> {
+    headers
+        .get_optional_str(name)
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// Parse a `Retry-After` header, which is either a number of seconds or an HTTP date.
+fn retry_after(headers: &Headers, now: OffsetDateTime) -> Option<Duration> {
+    let value = headers.get_optional_str(&RETRY_AFTER)?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = azure_core::date::parse_rfc1123(value).ok()?;
+    Some(to_std_duration(date - now))
+}
+
+fn to_std_duration(duration: time::Duration) -> Duration {
+    Duration::try_from(duration).unwrap_or(Duration::ZERO)
+}
+
+/// Shared handle to the rate limit budget tracked by one or more [`RateLimitPolicy`]s.
+///
+/// Cloning the handle shares the same budget.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitBudget {
+    status: Arc<Mutex<RateLimitStatus>>,
+}
+
+impl RateLimitBudget {
+    /// Create a new budget, with no rate limit information.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a snapshot of the current budget.
+    pub fn status(&self) -> RateLimitStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    fn update(&self, status: StatusCode, headers: &Headers) {
+        let mut current = self.status.lock().unwrap();
+        current.update(status, headers, OffsetDateTime::now_utc());
+        debug!(status = ?*current, "Rate limit status");
+    }
+
+    fn throttle_delay(&self, low_watermark: f64, max_delay: Duration) -> Duration {
+        self.status.lock().unwrap().throttle_delay(
+            low_watermark,
+            max_delay,
+            OffsetDateTime::now_utc(),
+        )
+    }
+}
+
+/// Rate limit aware throttling policy.
+///
+/// Before each request is sent, this policy pauses if a previous `429`/`503` response asked
+/// for requests to be retried later (`Retry-After`), or if the remaining TSTU budget has fallen
+/// below the low watermark. The pause grows as the budget approaches zero, up to waiting until
+/// the budget resets, and is capped at `max_delay`.
+///
+/// Add this as a per-retry policy so that it applies to retried requests as well.
+#[derive(Debug, Clone)]
+pub struct RateLimitPolicy {
+    budget: RateLimitBudget,
+    low_watermark: f64,
+    max_delay: Duration,
+}
+
+impl RateLimitPolicy {
+    /// Default fraction of the limit below which requests are slowed down.
+    pub const DEFAULT_LOW_WATERMARK: f64 = 0.2;
+    /// Default maximum time to pause before sending a request.
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+    /// Create a new policy that tracks the given budget.
+    pub fn new(budget: RateLimitBudget) -> Self {
+        Self {
+            budget,
+            low_watermark: Self::DEFAULT_LOW_WATERMARK,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+        }
+    }
+
+    /// Set the fraction of the limit (0.0 to 1.0) below which requests are slowed down.
+    pub fn low_watermark(mut self, low_watermark: f64) -> Self {
+        self.low_watermark = low_watermark.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the maximum time to pause before sending a request.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The budget tracked by this policy.
+    pub fn budget(&self) -> &RateLimitBudget {
+        &self.budget
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Policy for RateLimitPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let delay = self
+            .budget
+            .throttle_delay(self.low_watermark, self.max_delay);
+        if !delay.is_zero() {
+            debug!(delay = ?delay, "Throttling request");
+            azure_core::sleep::sleep(delay).await;
+        }
+        let rsp = next[0].send(ctx, request, &next[1..]).await?;
+        self.budget.update(rsp.status(), rsp.headers());
+        Ok(rsp)
+    }
+}
+
+/// Create a new [`RateLimitPolicy`] that tracks the given budget.
+pub fn rate_limit_policy(budget: RateLimitBudget) -> Arc<dyn Policy> {
+    Arc::new(RateLimitPolicy::new(budget))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(values: &[(&'static str, &str)]) -> Headers {
+        let mut headers = Headers::new();
+        for (name, value) in values {
+            headers.insert(
+                azure_core::headers::HeaderName::from_static(name),
+                value.to_string(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn test_throttle_delay() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let mut status = RateLimitStatus::default();
+        let max_delay = Duration::from_secs(600);
+
+        // No rate limit headers: no delay
+        status.update(StatusCode::Ok, &Headers::new(), now);
+        assert_eq!(status.throttle_delay(0.2, max_delay, now), Duration::ZERO);
+
+        // Plenty of budget left: no delay
+        status.update(
+            StatusCode::Ok,
+            &headers(&[
+                ("x-ratelimit-limit", "200"),
+                ("x-ratelimit-remaining", "150"),
+                ("x-ratelimit-reset", "1700000100"),
+            ]),
+            now,
+        );
+        assert_eq!(status.throttle_delay(0.2, max_delay, now), Duration::ZERO);
+
+        // Half way through the low watermark: wait half the time until reset
+        status.update(
+            StatusCode::Ok,
+            &headers(&[
+                ("x-ratelimit-limit", "200"),
+                ("x-ratelimit-remaining", "20"),
+                ("x-ratelimit-reset", "1700000100"),
+            ]),
+            now,
+        );
+        assert_eq!(
+            status.throttle_delay(0.2, max_delay, now),
+            Duration::from_secs(50)
+        );
+
+        // Budget exhausted: wait until reset, capped at max delay
+        status.update(
+            StatusCode::Ok,
+            &headers(&[
+                ("x-ratelimit-limit", "200"),
+                ("x-ratelimit-remaining", "0"),
+                ("x-ratelimit-reset", "1700000100"),
+            ]),
+            now,
+        );
+        assert!(status.is_throttled());
+        assert_eq!(
+            status.throttle_delay(0.2, max_delay, now),
+            Duration::from_secs(100)
+        );
+        assert_eq!(
+            status.throttle_delay(0.2, Duration::from_secs(30), now),
+            Duration::from_secs(30)
+        );
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let mut status = RateLimitStatus::default();
+        status.update(
+            StatusCode::TooManyRequests,
+            &headers(&[("retry-after", "30")]),
+            now,
+        );
+        assert_eq!(
+            status.throttle_delay(0.2, Duration::from_secs(600), now),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            status.throttle_delay(0.2, Duration::from_secs(600), now + Duration::from_secs(30)),
+            Duration::ZERO
+        );
+    }
+}
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn personal_access_tokens_client(&self) -> personal_access_tokens::Client {
         personal_access_tokens::Client(self.clone())
     }
@@ -139,6 +166,64 @@ impl Client {
         revocations::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn personal_access_tokens_client(&self) -> personal_access_tokens::OrganizationClient {
+        personal_access_tokens::OrganizationClient(self.clone())
+    }
+    pub fn revocation_rules_client(&self) -> revocation_rules::OrganizationClient {
+        revocation_rules::OrganizationClient(self.clone())
+    }
+    pub fn revocations_client(&self) -> revocations::OrganizationClient {
+        revocations::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn personal_access_tokens_client(&self) -> personal_access_tokens::ProjectClient {
+        personal_access_tokens::ProjectClient(self.clone())
+    }
+    pub fn revocation_rules_client(&self) -> revocation_rules::ProjectClient {
+        revocation_rules::ProjectClient(self.clone())
+    }
+    pub fn revocations_client(&self) -> revocations::ProjectClient {
+        revocations::ProjectClient(self.clone())
+    }
+}
 pub mod personal_access_tokens {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -167,6 +252,26 @@ pub mod personal_access_tokens {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Lists of all the session token details of the personal access tokens (PATs) for a particular user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The descriptor of the target user."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subject_descriptor)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Lists of all the session token details of the personal access tokens (PATs) for a particular user."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `subject_descriptor`: The descriptor of the target user."]
+        pub fn list(&self, subject_descriptor: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), subject_descriptor)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -305,6 +410,20 @@ pub mod personal_access_tokens {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, passing the continuation token returned"]
+            #[doc = "with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::SessionToken> {
+                crate::paging::Pageable::by_continuation_token(move |continuation_token| {
+                    let mut this = self.clone();
+                    if let Some(continuation_token) = continuation_token {
+                        this.continuation_token = Some(continuation_token);
+                    }
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::TokenAdminPagedSessionTokens>;
@@ -346,6 +465,32 @@ pub mod revocation_rules {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates a revocation rule to prevent the further usage of any OAuth authorizations that were created before the current point in time and which match the conditions in the rule.\n\nNot all kinds of OAuth authorizations can be revoked directly.\nSome, such as self-describing session tokens, must instead by revoked by creating a rule\nwhich will be evaluated and used to reject matching OAuth credentials at authentication time.\nRevocation rules created through this endpoint will apply to all credentials that were issued\nbefore the datetime at which the rule was created and which match one or more additional conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The revocation rule to create. The rule must specify a space-separated list of scopes, after which preexisting OAuth authorizations that match that any of the scopes will be rejected. For a list of all OAuth scopes supported by VSTS, see:<https://docs>.microsoft.com/en-us/vsts/integrate/get-started/authentication/oauth?view=vsts#scopes The rule may also specify the time before which to revoke tokens."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TokenAdminRevocationRule>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates a revocation rule to prevent the further usage of any OAuth authorizations that were created before the current point in time and which match the conditions in the rule.\n\nNot all kinds of OAuth authorizations can be revoked directly.\nSome, such as self-describing session tokens, must instead by revoked by creating a rule\nwhich will be evaluated and used to reject matching OAuth credentials at authentication time.\nRevocation rules created through this endpoint will apply to all credentials that were issued\nbefore the datetime at which the rule was created and which match one or more additional conditions."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The revocation rule to create. The rule must specify a space-separated list of scopes, after which preexisting OAuth authorizations that match that any of the scopes will be rejected. For a list of all OAuth scopes supported by VSTS, see:<https://docs>.microsoft.com/en-us/vsts/integrate/get-started/authentication/oauth?view=vsts#scopes The rule may also specify the time before which to revoke tokens."]
+        pub fn create(
+            &self,
+            body: impl Into<models::TokenAdminRevocationRule>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -478,6 +623,32 @@ pub mod revocations {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Revokes the listed OAuth authorizations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of objects containing the authorization IDs of the OAuth authorizations, such as session tokens retrieved by listed a users PATs, that should be revoked."]
+        pub fn revoke_authorizations(
+            &self,
+            body: Vec<models::TokenAdminRevocation>,
+        ) -> revoke_authorizations::RequestBuilder {
+            Client(self.0.client.clone()).revoke_authorizations(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Revokes the listed OAuth authorizations."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The list of objects containing the authorization IDs of the OAuth authorizations, such as session tokens retrieved by listed a users PATs, that should be revoked."]
+        pub fn revoke_authorizations(
+            &self,
+            body: Vec<models::TokenAdminRevocation>,
+        ) -> revoke_authorizations::RequestBuilder {
+            Client(self.0.client.clone()).revoke_authorizations(self.0.organization.clone(), body)
+        }
+    }
     pub mod revoke_authorizations {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,10 +129,83 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn pats_client(&self) -> pats::Client {
         pats::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn pats_client(&self) -> pats::OrganizationClient {
+        pats::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn pats_client(&self) -> pats::ProjectClient {
+        pats::ProjectClient(self.clone())
+    }
+}
 pub mod pats {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -219,6 +292,76 @@ pub mod pats {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets a paged list of personal access tokens (PATs) created in this organization. Subsequent calls to the API require the same filtering options to be supplied."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets a single personal access token (PAT)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `authorization_id`: The authorizationId identifying a single, unique personal access token (PAT)"]
+        pub fn get(&self, authorization_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), authorization_id)
+        }
+        #[doc = "Creates a new personal access token (PAT) for the requesting user."]
+        pub fn create(
+            &self,
+            body: impl Into<models::PatTokenCreateRequest>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Updates an existing personal access token (PAT) with the new parameters. To update a token, it must be valid (has not been revoked)."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PatTokenUpdateRequest>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body)
+        }
+        #[doc = "Revokes a personal access token (PAT) by authorizationId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `authorization_id`: The authorizationId identifying a single, unique personal access token (PAT)"]
+        pub fn revoke(&self, authorization_id: impl Into<String>) -> revoke::RequestBuilder {
+            Client(self.0.client.clone()).revoke(self.0.organization.clone(), authorization_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets a paged list of personal access tokens (PATs) created in this organization. Subsequent calls to the API require the same filtering options to be supplied."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets a single personal access token (PAT)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `authorization_id`: The authorizationId identifying a single, unique personal access token (PAT)"]
+        pub fn get(&self, authorization_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), authorization_id)
+        }
+        #[doc = "Creates a new personal access token (PAT) for the requesting user."]
+        pub fn create(
+            &self,
+            body: impl Into<models::PatTokenCreateRequest>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body)
+        }
+        #[doc = "Updates an existing personal access token (PAT) with the new parameters. To update a token, it must be valid (has not been revoked)."]
+        pub fn update(
+            &self,
+            body: impl Into<models::PatTokenUpdateRequest>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body)
+        }
+        #[doc = "Revokes a personal access token (PAT) by authorizationId."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `authorization_id`: The authorizationId identifying a single, unique personal access token (PAT)"]
+        pub fn revoke(&self, authorization_id: impl Into<String>) -> revoke::RequestBuilder {
+            Client(self.0.client.clone()).revoke(self.0.organization.clone(), authorization_id)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn attachments_client(&self) -> attachments::Client {
         attachments::Client(self.clone())
     }
@@ -148,6 +175,82 @@ impl Client {
         wikis::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn page_moves_client(&self) -> page_moves::OrganizationClient {
+        page_moves::OrganizationClient(self.clone())
+    }
+    pub fn page_stats_client(&self) -> page_stats::OrganizationClient {
+        page_stats::OrganizationClient(self.clone())
+    }
+    pub fn pages_client(&self) -> pages::OrganizationClient {
+        pages::OrganizationClient(self.clone())
+    }
+    pub fn pages_batch_client(&self) -> pages_batch::OrganizationClient {
+        pages_batch::OrganizationClient(self.clone())
+    }
+    pub fn wikis_client(&self) -> wikis::OrganizationClient {
+        wikis::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn page_moves_client(&self) -> page_moves::ProjectClient {
+        page_moves::ProjectClient(self.clone())
+    }
+    pub fn page_stats_client(&self) -> page_stats::ProjectClient {
+        page_stats::ProjectClient(self.clone())
+    }
+    pub fn pages_client(&self) -> pages::ProjectClient {
+        pages::ProjectClient(self.clone())
+    }
+    pub fn pages_batch_client(&self) -> pages_batch::ProjectClient {
+        pages_batch::ProjectClient(self.clone())
+    }
+    pub fn wikis_client(&self) -> wikis::ProjectClient {
+        wikis::ProjectClient(self.clone())
+    }
+}
 pub mod wikis {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -252,6 +355,135 @@ pub mod wikis {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets all wikis in a project or collection."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Creates the wiki resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for the wiki creation."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiCreateParametersV2>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), wiki_identifier, project)
+        }
+        #[doc = "Updates the wiki corresponding to the wiki ID or wiki name provided using the update parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Update parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                wiki_identifier,
+                project,
+            )
+        }
+        #[doc = "Deletes the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            wiki_identifier: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                wiki_identifier,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets all wikis in a project or collection."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Creates the wiki resource."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Parameters for the wiki creation."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiCreateParametersV2>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(&self, wiki_identifier: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                wiki_identifier,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Updates the wiki corresponding to the wiki ID or wiki name provided using the update parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Update parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                wiki_identifier,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Deletes the wiki corresponding to the wiki ID or wiki name provided."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn delete(&self, wiki_identifier: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                wiki_identifier,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -880,6 +1112,54 @@ pub mod attachments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates an attachment in the wiki."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `name`: Wiki attachment name."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates an attachment in the wiki."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `name`: Wiki attachment name."]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            name: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                wiki_identifier,
+                name,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -909,7 +1189,7 @@ pub mod attachments {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1099,6 +1379,48 @@ pub mod page_moves {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates a page move operation that updates the path and order of the page as provided in the parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Page more operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiPageMoveParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates a page move operation that updates the path and order of the page as provided in the parameters."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Page more operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn create(
+            &self,
+            body: impl Into<models::WikiPageMoveParameters>,
+            wiki_identifier: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                wiki_identifier,
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1128,7 +1450,7 @@ pub mod page_moves {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1322,6 +1644,7 @@ pub mod pages {
                 version_descriptor_version_options: None,
                 version_descriptor_version_type: None,
                 include_content: None,
+                accept: None,
             }
         }
         #[doc = "Creates or edits a wiki page."]
@@ -1404,6 +1727,7 @@ pub mod pages {
                 id,
                 recursion_level: None,
                 include_content: None,
+                accept: None,
             }
         }
         #[doc = "Edits a wiki page."]
@@ -1459,6 +1783,243 @@ pub mod pages {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets metadata or content of the wiki page for the provided path. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get_page(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> get_page::RequestBuilder {
+            Client(self.0.client.clone()).get_page(
+                self.0.organization.clone(),
+                project,
+                wiki_identifier,
+            )
+        }
+        #[doc = "Creates or edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki create or update operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+            if_match: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            Client(self.0.client.clone()).create_or_update(
+                self.0.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                path,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        pub fn delete_page(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete_page::RequestBuilder {
+            Client(self.0.client.clone()).delete_page(
+                self.0.organization.clone(),
+                project,
+                wiki_identifier,
+                path,
+            )
+        }
+        #[doc = "Gets metadata or content of the wiki page for the provided page id. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name.."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn get_page_by_id(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> get_page_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_page_by_id(
+                self.0.organization.clone(),
+                project,
+                wiki_identifier,
+                id,
+            )
+        }
+        #[doc = "Edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki update operation parameters."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+            if_match: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+                id,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn delete_page_by_id(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> delete_page_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_page_by_id(
+                self.0.organization.clone(),
+                project,
+                wiki_identifier,
+                id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets metadata or content of the wiki page for the provided path. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get_page(&self, wiki_identifier: impl Into<String>) -> get_page::RequestBuilder {
+            Client(self.0.client.clone()).get_page(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                wiki_identifier,
+            )
+        }
+        #[doc = "Creates or edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki create or update operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+            if_match: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            Client(self.0.client.clone()).create_or_update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                wiki_identifier,
+                path,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `path`: Wiki page path."]
+        pub fn delete_page(
+            &self,
+            wiki_identifier: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete_page::RequestBuilder {
+            Client(self.0.client.clone()).delete_page(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                wiki_identifier,
+                path,
+            )
+        }
+        #[doc = "Gets metadata or content of the wiki page for the provided page id. Content negotiation is done based on the `Accept` header sent in the request."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name.."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn get_page_by_id(
+            &self,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> get_page_by_id::RequestBuilder {
+            Client(self.0.client.clone()).get_page_by_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                wiki_identifier,
+                id,
+            )
+        }
+        #[doc = "Edits a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki update operation parameters."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        #[doc = "* `if_match`: Version of the page on which the change is to be made. Mandatory for `Edit` scenario. To be populated in the If-Match header of the request."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WikiPageCreateOrUpdateParameters>,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+            if_match: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                wiki_identifier,
+                id,
+                if_match,
+            )
+        }
+        #[doc = "Deletes a wiki page."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `id`: Wiki page ID."]
+        pub fn delete_page_by_id(
+            &self,
+            wiki_identifier: impl Into<String>,
+            id: i32,
+        ) -> delete_page_by_id::RequestBuilder {
+            Client(self.0.client.clone()).delete_page_by_id(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                wiki_identifier,
+                id,
+            )
+        }
+    }
     pub mod get_page {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1482,13 +2043,34 @@ pub mod pages {
                 })?;
                 Ok(body)
             }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1536,6 +2118,7 @@ pub mod pages {
             pub(crate) version_descriptor_version_options: Option<String>,
             pub(crate) version_descriptor_version_type: Option<String>,
             pub(crate) include_content: Option<bool>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Wiki page path."]
@@ -1578,6 +2161,11 @@ pub mod pages {
                 self.include_content = Some(include_content);
                 self
             }
+            #[doc = "The content type of the response: one of `application/json`, `text/plain`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -1631,6 +2219,9 @@ pub mod pages {
                                 .query_pairs_mut()
                                 .append_pair("includeContent", &include_content.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -1697,7 +2288,7 @@ pub mod pages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -1893,7 +2484,7 @@ pub mod pages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -2079,13 +2670,34 @@ pub mod pages {
                 })?;
                 Ok(body)
             }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -2130,6 +2742,7 @@ pub mod pages {
             pub(crate) id: i32,
             pub(crate) recursion_level: Option<String>,
             pub(crate) include_content: Option<bool>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Recursion level for subpages retrieval. Defaults to `None` (Optional)."]
@@ -2142,6 +2755,11 @@ pub mod pages {
                 self.include_content = Some(include_content);
                 self
             }
+            #[doc = "The content type of the response: one of `application/json`, `text/plain`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -2170,6 +2788,9 @@ pub mod pages {
                                 .query_pairs_mut()
                                 .append_pair("includeContent", &include_content.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -2237,7 +2858,7 @@ pub mod pages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -2382,7 +3003,7 @@ pub mod pages {
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
-            pub fn headers(&self) -> Headers {
+            pub fn headers(&self) -> Headers<'_> {
                 Headers(self.0.headers())
             }
         }
@@ -2527,6 +3148,44 @@ pub mod page_stats {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns page detail corresponding to Page ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `page_id`: Wiki page ID."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+            page_id: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                wiki_identifier,
+                page_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns page detail corresponding to Page ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        #[doc = "* `page_id`: Wiki page ID."]
+        pub fn get(&self, wiki_identifier: impl Into<String>, page_id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                wiki_identifier,
+                page_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2693,6 +3352,48 @@ pub mod pages_batch {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns pageable list of Wiki Pages"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki batch page request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(
+            &self,
+            body: impl Into<models::WikiPagesBatchRequest>,
+            project: impl Into<String>,
+            wiki_identifier: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                body,
+                project,
+                wiki_identifier,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns pageable list of Wiki Pages"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Wiki batch page request."]
+        #[doc = "* `wiki_identifier`: Wiki ID or wiki name."]
+        pub fn get(
+            &self,
+            body: impl Into<models::WikiPagesBatchRequest>,
+            wiki_identifier: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                wiki_identifier,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,438 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Upload and download work item attachments.
+//!
+//! [`upload_attachment()`] uploads the contents of a reader, in a single request for small
+//! attachments or in chunks for large ones, and returns the [`AttachmentReference`] to link
+//! to a work item:
+//!
+//! ```rust
+//!     let contents = std::fs::read(path)?;
+//!     let size = contents.len() as u64;
+//!     let reader = futures::io::Cursor::new(contents);
+//!     let attachment =
+//!         wit::upload_attachment(&wit_client, organization, project, "log.txt", reader, size).await?;
+//!     let body = WorkItemPatch::new()
+//!         .add_attachment(&attachment.url.unwrap_or_default(), Some("Build log"))
+//!         .into();
+//! ```
+//!
+//! The reader and writer are `futures::io` traits. Tokio types can be adapted with
+//! `tokio_util::compat`.
+//!
+//! [`download_attachment()`] streams an attachment to a writer, without holding it in memory.
+use crate::wit::models::AttachmentReference;
+use crate::wit::Client;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+use std::future::IntoFuture;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+
+/// The default size of the chunks of a chunked upload.
+///
+/// Attachments no larger than the chunk size are uploaded in a single request.
+pub const DEFAULT_ATTACHMENT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The `api-version` of the generated `attachments_client()` operations.
+///
+/// Uploads are sent through the client's pipeline, so
+/// [`ApiVersionPolicy`](crate::server::ApiVersionPolicy) replaces it with the selected version.
+const ATTACHMENTS_API_VERSION: &str = "7.1-preview";
+
+/// Uploads a work item attachment.
+///
+/// The returned builder can be awaited for the [`AttachmentReference`] of the new attachment.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `file_name`: The name of the attachment
+/// * `reader`: The contents of the attachment
+/// * `size`: The size of the attachment in bytes, which `reader` must return exactly
+pub fn upload_attachment<R>(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    file_name: impl Into<String>,
+    reader: R,
+    size: u64,
+) -> UploadAttachment<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    UploadAttachment {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        file_name: file_name.into(),
+        reader,
+        size,
+        area_path: None,
+        chunk_size: DEFAULT_ATTACHMENT_CHUNK_SIZE,
+    }
+}
+
+/// Request builder returned by [`upload_attachment()`].
+pub struct UploadAttachment<R> {
+    client: Client,
+    organization: String,
+    project: String,
+    file_name: String,
+    reader: R,
+    size: u64,
+    area_path: Option<String>,
+    chunk_size: usize,
+}
+
+impl<R> UploadAttachment<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    /// Target project area path.
+    #[must_use]
+    pub fn area_path(mut self, area_path: impl Into<String>) -> Self {
+        self.area_path = Some(area_path.into());
+        self
+    }
+
+    /// The size of the chunks of a chunked upload. Attachments no larger than this are
+    /// uploaded in a single request.
+    #[must_use]
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// The attachments URL, or the URL of the attachment `id` when uploading a chunk.
+    fn url(&self, id: Option<&str>, chunked: bool) -> azure_core::Result<azure_core::Url> {
+        let mut url = azure_core::Url::parse(&format!(
+            "{}/{}/{}/_apis/wit/attachments",
+            self.client.endpoint(),
+            &self.organization,
+            &self.project
+        ))?;
+        if let Some(id) = id {
+            url.path_segments_mut()
+                .map_err(|()| Error::message(ErrorKind::Other, "invalid attachments url"))?
+                .push(id);
+        }
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("fileName", &self.file_name);
+            if chunked {
+                query.append_pair("uploadType", "Chunked");
+            }
+            if id.is_none() {
+                if let Some(area_path) = &self.area_path {
+                    query.append_pair("areaPath", area_path);
+                }
+            }
+            query.append_pair(
+                azure_core::query_param::API_VERSION,
+                ATTACHMENTS_API_VERSION,
+            );
+        }
+        Ok(url)
+    }
+
+    async fn upload(mut self) -> azure_core::Result<AttachmentReference> {
+        let chunk_size = self.chunk_size;
+        if self.size <= chunk_size as u64 {
+            let body = read_chunk(&mut self.reader, self.size as usize).await?;
+            check_end(&mut self.reader, self.size).await?;
+            let url = self.url(None, false)?;
+            return send(&self.client, url, azure_core::Method::Post, body, None).await;
+        }
+
+        // Start a chunked upload, then upload the chunks in order.
+        let url = self.url(None, true)?;
+        let attachment = send(
+            &self.client,
+            url,
+            azure_core::Method::Post,
+            Vec::new(),
+            None,
+        )
+        .await?;
+        let id = attachment
+            .id
+            .clone()
+            .ok_or_else(|| Error::message(ErrorKind::DataConversion, "attachment has no id"))?;
+        let url = self.url(Some(&id), false)?;
+        let mut start = 0;
+        while start < self.size {
+            let len = (self.size - start).min(chunk_size as u64) as usize;
+            let chunk = read_chunk(&mut self.reader, len).await?;
+            if start + len as u64 == self.size {
+                check_end(&mut self.reader, self.size).await?;
+            }
+            let content_range = content_range(start, len, self.size);
+            start += len as u64;
+            send(
+                &self.client,
+                url.clone(),
+                azure_core::Method::Put,
+                chunk,
+                Some(content_range),
+            )
+            .await?;
+        }
+        Ok(attachment)
+    }
+}
+
+impl<R> IntoFuture for UploadAttachment<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    type Output = azure_core::Result<AttachmentReference>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<AttachmentReference>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.upload())
+    }
+}
+
+/// Sends an upload request. This is shared by single and chunked uploads.
+///
+/// The generated `create()` and `upload_chunk()` operations send their body as a JSON string,
+/// so they cannot be used to upload the raw contents.
+async fn send(
+    client: &Client,
+    url: azure_core::Url,
+    method: azure_core::Method,
+    body: Vec<u8>,
+    content_range: Option<String>,
+) -> azure_core::Result<AttachmentReference> {
+    let mut req = azure_core::Request::new(url, method);
+    if let Some(auth_header) = client
+        .token_credential()
+        .http_authorization_header(&client.scopes())
+        .await?
+    {
+        req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+    }
+    req.insert_header("content-type", "application/octet-stream");
+    if let Some(content_range) = content_range {
+        req.insert_header("content-range", content_range);
+    }
+    req.set_body(bytes::Bytes::from(body));
+    let bytes = client.send(&mut req).await?.into_body().collect().await?;
+    serde_json::from_slice(&bytes).with_context(ErrorKind::DataConversion, || {
+        format!(
+            "Failed to deserialize response:\n{}",
+            String::from_utf8_lossy(&bytes)
+        )
+    })
+}
+
+/// Downloads a work item attachment to a writer.
+///
+/// The returned builder can be awaited for the number of bytes written.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `id`: Attachment ID
+/// * `writer`: Where the attachment is written
+pub fn download_attachment<W>(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    id: impl Into<String>,
+    writer: W,
+) -> DownloadAttachment<W>
+where
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    DownloadAttachment {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        id: id.into(),
+        writer,
+    }
+}
+
+/// Request builder returned by [`download_attachment()`].
+pub struct DownloadAttachment<W> {
+    client: Client,
+    organization: String,
+    project: String,
+    id: String,
+    writer: W,
+}
+
+impl<W> DownloadAttachment<W>
+where
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    async fn download(mut self) -> azure_core::Result<u64> {
+        self.client
+            .attachments_client()
+            .get(&self.organization, &self.id, &self.project)
+            .download(true)
+            .send()
+            .await?
+            .write_to(&mut self.writer)
+            .await
+    }
+}
+
+impl<W> IntoFuture for DownloadAttachment<W>
+where
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    type Output = azure_core::Result<u64>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<u64>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.download())
+    }
+}
+
+/// The `Content-Range` header of a chunk.
+fn content_range(start: u64, len: usize, total: u64) -> String {
+    format!("bytes {}-{}/{}", start, start + len as u64 - 1, total)
+}
+
+/// Reads exactly `len` bytes.
+async fn read_chunk<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    len: usize,
+) -> azure_core::Result<Vec<u8>> {
+    let mut chunk = Vec::with_capacity(len);
+    reader
+        .take(len as u64)
+        .read_to_end(&mut chunk)
+        .await
+        .context(ErrorKind::Io, "failed to read attachment")?;
+    if chunk.len() < len {
+        return Err(Error::message(
+            ErrorKind::Io,
+            "attachment is smaller than the expected size",
+        ));
+    }
+    Ok(chunk)
+}
+
+/// Checks that the reader has ended after returning `size` bytes.
+async fn check_end<R: AsyncRead + Unpin>(reader: &mut R, size: u64) -> azure_core::Result<()> {
+    let mut extra = [0u8; 1];
+    let read = reader
+        .read(&mut extra)
+        .await
+        .context(ErrorKind::Io, "failed to read attachment")?;
+    if read > 0 {
+        return Err(Error::message(
+            ErrorKind::Io,
+            format!("attachment is larger than the expected size of {size} bytes"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use azure_core::{headers::Headers, Context, Policy, PolicyResult, Request, Response};
+    use futures::io::Cursor;
+    use std::sync::{Arc, Mutex};
+
+    /// Records the requests, and responds to each with an attachment reference.
+    #[derive(Debug, Default)]
+    struct MockTransport {
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Policy for MockTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", request.method(), request.url()));
+            let body = bytes::Bytes::from_static(
+                br#"{"id":"abc","url":"https://dev.azure.com/org/_apis/wit/attachments/abc"}"#,
+            );
+            Ok(Response::new(
+                azure_core::StatusCode::Ok,
+                Headers::new(),
+                Box::pin(futures::stream::once(futures::future::ready(Ok(body)))),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_chunked_upload() {
+        let transport = Arc::new(MockTransport::default());
+        let client = crate::wit::ClientBuilder::new(crate::Credential::from_pat("abc"))
+            .per_call_policies(vec![crate::server::api_version_policy(
+                crate::server::ApiVersion::V6_0,
+            )])
+            .transport(azure_core::TransportOptions::new_custom_policy(
+                transport.clone(),
+            ))
+            .build();
+        let upload = upload_attachment(
+            &client,
+            "org",
+            "project",
+            "log.txt",
+            Cursor::new(b"0123456789".to_vec()),
+            10,
+        )
+        .chunk_size(4)
+        .into_future();
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&upload);
+        let attachment = futures::executor::block_on(upload).unwrap();
+        assert_eq!(attachment.id.as_deref(), Some("abc"));
+        assert_eq!(
+            *transport.requests.lock().unwrap(),
+            [
+                "POST https://dev.azure.com//org/project/_apis/wit/attachments?fileName=log.txt&uploadType=Chunked&api-version=6.0-preview",
+                "PUT https://dev.azure.com//org/project/_apis/wit/attachments/abc?fileName=log.txt&api-version=6.0-preview",
+                "PUT https://dev.azure.com//org/project/_apis/wit/attachments/abc?fileName=log.txt&api-version=6.0-preview",
+                "PUT https://dev.azure.com//org/project/_apis/wit/attachments/abc?fileName=log.txt&api-version=6.0-preview",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_content_range() {
+        assert_eq!(content_range(0, 10, 25), "bytes 0-9/25");
+        assert_eq!(content_range(20, 5, 25), "bytes 20-24/25");
+    }
+
+    #[test]
+    fn test_read_chunk() {
+        futures::executor::block_on(async {
+            let mut reader = Cursor::new(b"0123456789".to_vec());
+            assert_eq!(read_chunk(&mut reader, 4).await.unwrap(), b"0123");
+            assert_eq!(read_chunk(&mut reader, 6).await.unwrap(), b"456789");
+            assert!(check_end(&mut reader, 10).await.is_ok());
+
+            let mut reader = Cursor::new(b"0123".to_vec());
+            assert!(read_chunk(&mut reader, 5).await.is_err());
+
+            let mut reader = Cursor::new(b"0123".to_vec());
+            assert_eq!(read_chunk(&mut reader, 3).await.unwrap(), b"012");
+            assert!(check_end(&mut reader, 3).await.is_err());
+        });
+    }
+}
@@ -0,0 +1,462 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Create and update many work items with the `$batch` API.
+//!
+//! `work_items_client().update()` updates a single work item per request. [`update_work_items()`]
+//! sends the patches as `$batch` requests of up to 200 work items each, several at a time, and
+//! returns a result for each work item in the order they were added:
+//!
+//! ```rust
+//!     let mut batch = wit::update_work_items(&wit_client, organization);
+//!     for id in ids {
+//!         batch = batch.update(id, WorkItemPatch::new().set_field(field_names::STATE, "Closed"));
+//!     }
+//!     for result in batch.await? {
+//!         match result.result {
+//!             Ok(work_item) => println!("Updated {}", work_item.id),
+//!             Err(e) => println!("Failed to update {:?}: {e}", result.id),
+//!         }
+//!     }
+//! ```
+//!
+//! The `$batch` API is not transactional: each work item is created or updated independently.
+use super::{DEFAULT_MAX_CONCURRENCY, MAX_WORK_ITEMS_PER_BATCH};
+use crate::wit::models::{JsonPatchOperation, WitBatchRequest, WitBatchResponse, WorkItem};
+use crate::wit::Client;
+use azure_core::Url;
+use futures::{StreamExt, TryStreamExt};
+use serde_json::{json, Value};
+use std::fmt;
+use std::future::IntoFuture;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures::stream::LocalBoxStream as BoxStream;
+
+/// The `api-version` of the requests in a `$batch` body, which is that of the `$batch` request.
+///
+/// [`ApiVersionPolicy`](crate::server::ApiVersionPolicy) replaces it with the selected version,
+/// along with the version of the `$batch` request.
+const WORK_ITEMS_API_VERSION: &str = "7.1-preview";
+
+/// Creates and updates work items with `$batch` requests.
+///
+/// Add the changes with `create()` and `update()`, then await the returned builder for a
+/// [`WorkItemBatchResult`] per change, or convert it into a stream of results with `into_stream()`.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+pub fn update_work_items(client: &Client, organization: impl Into<String>) -> UpdateWorkItems {
+    UpdateWorkItems {
+        client: client.clone(),
+        organization: organization.into(),
+        changes: Vec::new(),
+        bypass_rules: false,
+        suppress_notifications: false,
+        max_concurrency: DEFAULT_MAX_CONCURRENCY,
+    }
+}
+
+/// Request builder returned by [`update_work_items()`].
+#[derive(Clone)]
+pub struct UpdateWorkItems {
+    client: Client,
+    organization: String,
+    changes: Vec<Change>,
+    bypass_rules: bool,
+    suppress_notifications: bool,
+    max_concurrency: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Change {
+    Create {
+        project: String,
+        work_item_type: String,
+        operations: Vec<JsonPatchOperation>,
+    },
+    Update {
+        id: i32,
+        operations: Vec<JsonPatchOperation>,
+    },
+}
+
+impl UpdateWorkItems {
+    /// Adds a work item to create.
+    #[must_use]
+    pub fn create(
+        mut self,
+        project: impl Into<String>,
+        work_item_type: impl Into<String>,
+        patch: impl Into<Vec<JsonPatchOperation>>,
+    ) -> Self {
+        self.changes.push(Change::Create {
+            project: project.into(),
+            work_item_type: work_item_type.into(),
+            operations: patch.into(),
+        });
+        self
+    }
+
+    /// Adds an update to an existing work item.
+    #[must_use]
+    pub fn update(mut self, id: i32, patch: impl Into<Vec<JsonPatchOperation>>) -> Self {
+        self.changes.push(Change::Update {
+            id,
+            operations: patch.into(),
+        });
+        self
+    }
+
+    /// Do not enforce the work item type rules on the changes. Requires the "Bypass rules on
+    /// work item updates" permission.
+    #[must_use]
+    pub fn bypass_rules(mut self, bypass_rules: bool) -> Self {
+        self.bypass_rules = bypass_rules;
+        self
+    }
+
+    /// Do not fire any notifications for the changes.
+    #[must_use]
+    pub fn suppress_notifications(mut self, suppress_notifications: bool) -> Self {
+        self.suppress_notifications = suppress_notifications;
+        self
+    }
+
+    /// The number of `$batch` requests to send concurrently.
+    #[must_use]
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// The number of changes added.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if no changes have been added.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns a stream of the results, in the order the changes were added.
+    ///
+    /// The changes are sent in `$batch` requests of up to [`MAX_WORK_ITEMS_PER_BATCH`] as the
+    /// stream is consumed. If a `$batch` request fails, the stream returns the error, but the
+    /// changes in other requests may already have been applied.
+    pub fn into_stream(self) -> BoxStream<'static, azure_core::Result<WorkItemBatchResult>> {
+        let Self {
+            client,
+            organization,
+            changes,
+            bypass_rules,
+            suppress_notifications,
+            max_concurrency,
+        } = self;
+        let changes: Vec<(usize, Change)> = changes.into_iter().enumerate().collect();
+        let batches: Vec<Vec<(usize, Change)>> = changes
+            .chunks(MAX_WORK_ITEMS_PER_BATCH)
+            .map(<[(usize, Change)]>::to_vec)
+            .collect();
+        Box::pin(
+            futures::stream::iter(batches)
+                .map(move |changes| {
+                    let requests = changes
+                        .iter()
+                        .map(|(_, change)| {
+                            batch_request(change, bypass_rules, suppress_notifications)
+                        })
+                        .collect();
+                    let response = client
+                        .work_items_client()
+                        .batch(&organization, requests)
+                        .into_future();
+                    async move {
+                        Ok::<_, azure_core::Error>(batch_results(changes, response.await?.value))
+                    }
+                })
+                .buffered(max_concurrency)
+                .map_ok(|results| futures::stream::iter(results.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+}
+
+impl IntoFuture for UpdateWorkItems {
+    type Output = azure_core::Result<Vec<WorkItemBatchResult>>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<Vec<WorkItemBatchResult>>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.into_stream().try_collect())
+    }
+}
+
+/// The result of a change sent by [`update_work_items()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemBatchResult {
+    /// The position of the change, in the order the changes were added.
+    pub index: usize,
+    /// The id of the updated work item, `None` for created work items.
+    pub id: Option<i32>,
+    /// The HTTP status code of the change.
+    pub code: i32,
+    /// The created or updated work item, or the reason the change failed.
+    pub result: Result<WorkItem, WorkItemBatchError>,
+}
+
+impl WorkItemBatchResult {
+    /// Returns `true` if the change succeeded.
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// The reason a change sent by [`update_work_items()`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkItemBatchError {
+    /// The HTTP status code of the change.
+    pub code: i32,
+    /// The error message returned by the service.
+    pub message: String,
+}
+
+impl fmt::Display for WorkItemBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for WorkItemBatchError {}
+
+/// The `$batch` request for a change, a `PATCH` of the work items API.
+fn batch_request(
+    change: &Change,
+    bypass_rules: bool,
+    suppress_notifications: bool,
+) -> WitBatchRequest {
+    let (segments, operations) = match change {
+        Change::Create {
+            project,
+            work_item_type,
+            operations,
+        } => (
+            vec![
+                project.clone(),
+                "_apis".to_string(),
+                "wit".to_string(),
+                "workitems".to_string(),
+                format!("${work_item_type}"),
+            ],
+            operations,
+        ),
+        Change::Update { id, operations } => (
+            vec![
+                "_apis".to_string(),
+                "wit".to_string(),
+                "workitems".to_string(),
+                id.to_string(),
+            ],
+            operations,
+        ),
+    };
+    // The URI is relative to the organization. Build it on a placeholder base URL so that the
+    // project and work item type are percent-encoded.
+    let mut url = Url::parse("https://localhost/").expect("valid URL");
+    url.path_segments_mut()
+        .expect("URL can be a base")
+        .extend(segments);
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("api-version", WORK_ITEMS_API_VERSION);
+        if bypass_rules {
+            query.append_pair("bypassRules", "true");
+        }
+        if suppress_notifications {
+            query.append_pair("suppressNotifications", "true");
+        }
+    }
+    WitBatchRequest {
+        method: "PATCH".to_string(),
+        uri: format!("{}?{}", url.path(), url.query().unwrap_or_default()),
+        headers: Some(json!({ "Content-Type": "application/json-patch+json" })),
+        body: operations.clone(),
+    }
+}
+
+/// Matches the responses of a `$batch` request to the changes it sent.
+fn batch_results(
+    changes: Vec<(usize, Change)>,
+    responses: Vec<WitBatchResponse>,
+) -> Vec<WorkItemBatchResult> {
+    let mut responses = responses.into_iter();
+    changes
+        .into_iter()
+        .map(|(index, change)| {
+            let id = match change {
+                Change::Create { .. } => None,
+                Change::Update { id, .. } => Some(id),
+            };
+            let (code, result) = match responses.next() {
+                Some(response) => (response.code, response_result(response)),
+                None => (
+                    0,
+                    Err(WorkItemBatchError {
+                        code: 0,
+                        message: "no response returned for the change".to_string(),
+                    }),
+                ),
+            };
+            WorkItemBatchResult {
+                index,
+                id,
+                code,
+                result,
+            }
+        })
+        .collect()
+}
+
+fn response_result(response: WitBatchResponse) -> Result<WorkItem, WorkItemBatchError> {
+    let body = response.body.unwrap_or_default();
+    let error = |message: String| WorkItemBatchError {
+        code: response.code,
+        message,
+    };
+    if !(200..300).contains(&response.code) {
+        return Err(error(error_message(&body)));
+    }
+    serde_json::from_str(&body).map_err(|e| error(format!("failed to deserialize work item: {e}")))
+}
+
+/// Extracts the message from an error response body, which is of the form
+/// `{"count": 1, "value": {"Message": "..."}}`.
+fn error_message(body: &str) -> String {
+    let value: Value = match serde_json::from_str(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_string(),
+    };
+    [&value["value"], &value]
+        .iter()
+        .find_map(|value| {
+            value
+                .get("Message")
+                .or_else(|| value.get("message"))
+                .and_then(Value::as_str)
+        })
+        .map_or_else(|| body.to_string(), ToOwned::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wit::{field_names, WorkItemPatch};
+
+    #[test]
+    fn test_batch_request() {
+        let patch = WorkItemPatch::new().set_field(field_names::TITLE, "Title");
+        let request = batch_request(
+            &Change::Update {
+                id: 42,
+                operations: patch.clone().into(),
+            },
+            false,
+            false,
+        );
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(
+            request.uri,
+            "/_apis/wit/workitems/42?api-version=7.1-preview"
+        );
+        assert_eq!(
+            request.headers,
+            Some(json!({ "Content-Type": "application/json-patch+json" }))
+        );
+        assert_eq!(request.body, patch.operations());
+
+        let request = batch_request(
+            &Change::Create {
+                project: "My Project".to_string(),
+                work_item_type: "User Story".to_string(),
+                operations: patch.into(),
+            },
+            true,
+            true,
+        );
+        assert_eq!(
+            request.uri,
+            "/My%20Project/_apis/wit/workitems/$User%20Story?api-version=7.1-preview&bypassRules=true&suppressNotifications=true"
+        );
+    }
+
+    #[test]
+    fn test_batch_results() {
+        let changes = vec![
+            (
+                200,
+                Change::Update {
+                    id: 1,
+                    operations: Vec::new(),
+                },
+            ),
+            (
+                201,
+                Change::Update {
+                    id: 2,
+                    operations: Vec::new(),
+                },
+            ),
+            (
+                202,
+                Change::Create {
+                    project: "project".to_string(),
+                    work_item_type: "Bug".to_string(),
+                    operations: Vec::new(),
+                },
+            ),
+        ];
+        let responses: Vec<WitBatchResponse> = serde_json::from_value(json!([
+            {
+                "code": 200,
+                "body": json!({ "id": 1, "rev": 2, "fields": {}, "url": "" }).to_string()
+            },
+            {
+                "code": 400,
+                "body": json!({ "count": 1, "value": { "Message": "TF401320: Rule error" } }).to_string()
+            }
+        ]))
+        .unwrap();
+        let results = batch_results(changes, responses);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].index, 200);
+        assert_eq!(results[0].id, Some(1));
+        assert_eq!(results[0].result.as_ref().unwrap().rev, Some(2));
+        assert!(!results[1].is_success());
+        assert_eq!(
+            results[1].result,
+            Err(WorkItemBatchError {
+                code: 400,
+                message: "TF401320: Rule error".to_string()
+            })
+        );
+        assert_eq!(results[2].id, None);
+        assert_eq!(results[2].code, 0);
+        assert!(!results[2].is_success());
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            error_message(r#"{"message": "Not found", "typeKey": "X"}"#),
+            "Not found"
+        );
+        assert_eq!(error_message("Bad gateway"), "Bad gateway");
+    }
+}
@@ -0,0 +1,487 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Work item links and hierarchy traversal.
+//!
+//! Work item relations identify the linked work item by URL, e.g.
+//! `https://dev.azure.com/{organization}/{project}/_apis/wit/workItems/{id}`.
+//! [`WorkItemRelation::work_item_id()`] extracts the id, and [`WorkItem::linked_ids()`] returns
+//! the ids of all work items linked with a given relation type.
+//!
+//! [`traverse()`] follows links from a set of root work items, fetching each level in batches,
+//! and returns a [`WorkItemGraph`]:
+//!
+//! ```rust
+//!     // Get the epic, and all of its features, stories and tasks
+//!     let graph = wit::traverse(&wit_client, organization, project, [epic_id]).await?;
+//!     for (depth, work_item) in graph.walk() {
+//!         println!("{:indent$}{} {}", "", work_item.id, work_item.title().unwrap_or_default(), indent = depth * 2);
+//!     }
+//! ```
+//!
+//! Use [`RelationTypes`] to convert between relation type names (e.g. `Child`) and reference
+//! names (e.g. `System.LinkTypes.Hierarchy-Forward`).
+use super::link_types;
+use super::query::{get_work_items, DEFAULT_MAX_CONCURRENCY};
+use crate::wit::models::{
+    work_item_batch_get_request::{ErrorPolicy, Expand},
+    WorkItem, WorkItemBatchGetRequest, WorkItemRelation, WorkItemRelationType,
+};
+use crate::wit::Client;
+use futures::TryStreamExt;
+use std::collections::{HashMap, HashSet};
+use std::future::IntoFuture;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+
+/// Extracts the work item id from a work item URL, e.g.
+/// `https://dev.azure.com/{organization}/{project}/_apis/wit/workItems/{id}`.
+///
+/// Returns `None` if the URL is not a work item URL.
+pub fn work_item_id_from_url(url: &str) -> Option<i32> {
+    let (path, id) = url.trim_end_matches('/').rsplit_once('/')?;
+    if !path.to_ascii_lowercase().ends_with("/_apis/wit/workitems") {
+        return None;
+    }
+    id.parse().ok()
+}
+
+impl WorkItemRelation {
+    /// The id of the linked work item, or `None` if the relation is not to a work item
+    /// (e.g. an attachment or hyperlink).
+    pub fn work_item_id(&self) -> Option<i32> {
+        work_item_id_from_url(&self.link.url)
+    }
+}
+
+impl WorkItem {
+    /// The ids of the work items linked with the given relation type, see [`link_types`].
+    ///
+    /// Relations are only returned if the work item was requested with `$expand=relations`.
+    pub fn linked_ids(&self, rel: &str) -> Vec<i32> {
+        self.relations
+            .iter()
+            .filter(|relation| relation.link.rel == rel)
+            .filter_map(WorkItemRelation::work_item_id)
+            .collect()
+    }
+}
+
+/// The work item relation types of an organization, from `work_item_relation_types_client().list()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationTypes {
+    relation_types: Vec<WorkItemRelationType>,
+}
+
+impl RelationTypes {
+    /// Get the relation types of an organization.
+    pub async fn load(
+        client: &Client,
+        organization: impl Into<String>,
+    ) -> azure_core::Result<Self> {
+        let relation_types = client
+            .work_item_relation_types_client()
+            .list(organization)
+            .await?
+            .value;
+        Ok(Self::new(relation_types))
+    }
+
+    pub fn new(relation_types: Vec<WorkItemRelationType>) -> Self {
+        Self { relation_types }
+    }
+
+    /// Find a relation type by name (e.g. `Child`) or reference name
+    /// (e.g. `System.LinkTypes.Hierarchy-Forward`), ignoring case.
+    pub fn get(&self, name: &str) -> Option<&WorkItemRelationType> {
+        let matches = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map_or(false, |value| value.eq_ignore_ascii_case(name))
+        };
+        self.relation_types.iter().find(|relation_type| {
+            let reference = &relation_type.work_item_tracking_reference;
+            matches(&reference.reference_name) || matches(&reference.name)
+        })
+    }
+
+    /// The reference name of a relation type, given its name or reference name.
+    pub fn reference_name(&self, name: &str) -> Option<&str> {
+        self.get(name)?
+            .work_item_tracking_reference
+            .reference_name
+            .as_deref()
+    }
+
+    /// The name of a relation type, given its name or reference name.
+    pub fn name(&self, name: &str) -> Option<&str> {
+        self.get(name)?.work_item_tracking_reference.name.as_deref()
+    }
+
+    /// All of the relation types.
+    pub fn iter(&self) -> impl Iterator<Item = &WorkItemRelationType> {
+        self.relation_types.iter()
+    }
+}
+
+/// A link between two work items in a [`WorkItemGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorkItemEdge {
+    pub source: i32,
+    pub target: i32,
+    /// The relation type reference name.
+    pub rel: String,
+}
+
+/// Follows links from a set of root work items, and returns the linked work items as a [`WorkItemGraph`].
+///
+/// By default, this follows `System.LinkTypes.Hierarchy-Forward` links, i.e. returns the
+/// descendants of the root work items. Use `link_types()` to follow other relation types,
+/// e.g. `System.LinkTypes.Hierarchy-Reverse` to get the ancestors.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `root_ids`: The ids of the work items to start from
+pub fn traverse(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    root_ids: impl IntoIterator<Item = i32>,
+) -> Traverse {
+    Traverse {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        root_ids: root_ids.into_iter().collect(),
+        link_types: vec![link_types::CHILD.to_string()],
+        max_depth: None,
+        max_concurrency: DEFAULT_MAX_CONCURRENCY,
+    }
+}
+
+/// Request builder returned by [`traverse()`].
+#[derive(Clone)]
+pub struct Traverse {
+    client: Client,
+    organization: String,
+    project: String,
+    root_ids: Vec<i32>,
+    link_types: Vec<String>,
+    max_depth: Option<usize>,
+    max_concurrency: usize,
+}
+
+impl Traverse {
+    /// The relation types to follow, see [`link_types`].
+    #[must_use]
+    pub fn link_types<I, S>(mut self, link_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.link_types = link_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The maximum number of links to follow from the root work items.
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// The number of `get_work_items_batch()` requests to send concurrently.
+    #[must_use]
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    async fn run(self) -> azure_core::Result<WorkItemGraph> {
+        let mut graph = WorkItemGraph::default();
+        let mut seen: HashSet<i32> = HashSet::new();
+        let mut level: Vec<i32> = self
+            .root_ids
+            .iter()
+            .copied()
+            .filter(|id| seen.insert(*id))
+            .collect();
+        let mut depth = 0;
+        while !level.is_empty() {
+            // Deleted or inaccessible work items are omitted rather than failing the traversal
+            let request = WorkItemBatchGetRequest {
+                expand: Some(Expand::Relations),
+                error_policy: Some(ErrorPolicy::Omit),
+                ..Default::default()
+            };
+            let work_items: Vec<WorkItem> = get_work_items(
+                &self.client,
+                &self.organization,
+                &self.project,
+                level,
+                request,
+                self.max_concurrency,
+            )
+            .try_collect()
+            .await?;
+
+            let expand = self.max_depth.map_or(true, |max_depth| depth < max_depth);
+            let mut next = Vec::new();
+            for work_item in work_items {
+                if depth == 0 {
+                    graph.roots.push(work_item.id);
+                }
+                if expand {
+                    for rel in &self.link_types {
+                        for target in work_item.linked_ids(rel) {
+                            graph.edges.push(WorkItemEdge {
+                                source: work_item.id,
+                                target,
+                                rel: rel.clone(),
+                            });
+                            if seen.insert(target) {
+                                next.push(target);
+                            }
+                        }
+                    }
+                }
+                graph.work_items.insert(work_item.id, work_item);
+            }
+            level = next;
+            depth += 1;
+        }
+        // Remove links to work items that could not be fetched
+        let work_items = &graph.work_items;
+        graph
+            .edges
+            .retain(|edge| work_items.contains_key(&edge.target));
+        Ok(graph)
+    }
+}
+
+impl IntoFuture for Traverse {
+    type Output = azure_core::Result<WorkItemGraph>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<WorkItemGraph>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.run())
+    }
+}
+
+/// Work items and the links between them, returned by [`traverse()`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkItemGraph {
+    roots: Vec<i32>,
+    work_items: HashMap<i32, WorkItem>,
+    edges: Vec<WorkItemEdge>,
+}
+
+impl WorkItemGraph {
+    /// Create a graph from work items and the links between them.
+    pub fn new(
+        roots: Vec<i32>,
+        work_items: impl IntoIterator<Item = WorkItem>,
+        edges: Vec<WorkItemEdge>,
+    ) -> Self {
+        Self {
+            roots,
+            work_items: work_items
+                .into_iter()
+                .map(|work_item| (work_item.id, work_item))
+                .collect(),
+            edges,
+        }
+    }
+
+    /// The ids of the root work items.
+    pub fn roots(&self) -> &[i32] {
+        &self.roots
+    }
+
+    /// Get a work item by id.
+    pub fn get(&self, id: i32) -> Option<&WorkItem> {
+        self.work_items.get(&id)
+    }
+
+    /// All of the work items, in no particular order.
+    pub fn work_items(&self) -> impl Iterator<Item = &WorkItem> {
+        self.work_items.values()
+    }
+
+    /// The number of work items.
+    pub fn len(&self) -> usize {
+        self.work_items.len()
+    }
+
+    /// Returns `true` if there are no work items.
+    pub fn is_empty(&self) -> bool {
+        self.work_items.is_empty()
+    }
+
+    /// All of the links.
+    pub fn edges(&self) -> &[WorkItemEdge] {
+        &self.edges
+    }
+
+    /// The ids of the work items linked from `id`, e.g. the children for `Hierarchy-Forward` links.
+    pub fn children(&self, id: i32) -> Vec<i32> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.source == id)
+            .map(|edge| edge.target)
+            .collect()
+    }
+
+    /// The ids of the work items that link to `id`.
+    pub fn parents(&self, id: i32) -> Vec<i32> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.target == id)
+            .map(|edge| edge.source)
+            .collect()
+    }
+
+    /// The links that lead back to a work item on the path from a root, i.e. that form a cycle.
+    ///
+    /// Hierarchy links cannot form cycles, but other relation types (e.g. `Related`) can.
+    pub fn cycles(&self) -> Vec<&WorkItemEdge> {
+        let mut cycles = Vec::new();
+        let mut visited = HashSet::new();
+        for root in &self.roots {
+            self.depth_first(
+                *root,
+                0,
+                &mut Vec::new(),
+                &mut visited,
+                &mut |_, _| {},
+                &mut cycles,
+            );
+        }
+        cycles
+    }
+
+    /// Walks the graph depth first from the roots, returning each work item with its depth.
+    ///
+    /// Each work item is returned once, under the first work item found to link to it.
+    /// Links that form a cycle are not followed.
+    pub fn walk(&self) -> Vec<(usize, &WorkItem)> {
+        let mut items = Vec::new();
+        let mut visited = HashSet::new();
+        for root in &self.roots {
+            self.depth_first(
+                *root,
+                0,
+                &mut Vec::new(),
+                &mut visited,
+                &mut |depth, work_item| items.push((depth, work_item)),
+                &mut Vec::new(),
+            );
+        }
+        items
+    }
+
+    fn depth_first<'a>(
+        &'a self,
+        id: i32,
+        depth: usize,
+        path: &mut Vec<i32>,
+        visited: &mut HashSet<i32>,
+        visit: &mut dyn FnMut(usize, &'a WorkItem),
+        cycles: &mut Vec<&'a WorkItemEdge>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+        if let Some(work_item) = self.work_items.get(&id) {
+            visit(depth, work_item);
+        }
+        path.push(id);
+        for edge in self.edges.iter().filter(|edge| edge.source == id) {
+            if path.contains(&edge.target) {
+                cycles.push(edge);
+            } else {
+                self.depth_first(edge.target, depth + 1, path, visited, visit, cycles);
+            }
+        }
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn work_item(id: i32, children: &[i32]) -> WorkItem {
+        let relations: Vec<_> = children
+            .iter()
+            .map(|child| {
+                json!({
+                    "rel": link_types::CHILD,
+                    "url": format!("https://dev.azure.com/org/project/_apis/wit/workItems/{child}"),
+                    "attributes": { "isLocked": false, "name": "Child" }
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "id": id,
+            "fields": {},
+            "relations": relations,
+            "url": format!("https://dev.azure.com/org/project/_apis/wit/workItems/{id}")
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_work_item_id_from_url() {
+        assert_eq!(
+            work_item_id_from_url("https://dev.azure.com/org/project/_apis/wit/workItems/123"),
+            Some(123)
+        );
+        assert_eq!(
+            work_item_id_from_url("https://tfs.corp/tfs/DefaultCollection/_apis/wit/workitems/7/"),
+            Some(7)
+        );
+        assert_eq!(
+            work_item_id_from_url("https://dev.azure.com/org/_apis/wit/attachments/1234"),
+            None
+        );
+        assert_eq!(
+            work_item(1, &[2, 3]).linked_ids(link_types::CHILD),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn test_work_item_graph() {
+        let edge = |source, target| WorkItemEdge {
+            source,
+            target,
+            rel: link_types::CHILD.to_string(),
+        };
+        let graph = WorkItemGraph::new(
+            vec![1],
+            [
+                work_item(1, &[2, 3]),
+                work_item(2, &[4]),
+                work_item(3, &[4]),
+                work_item(4, &[1]),
+            ],
+            vec![edge(1, 2), edge(1, 3), edge(2, 4), edge(3, 4), edge(4, 1)],
+        );
+        let walk: Vec<(usize, i32)> = graph
+            .walk()
+            .into_iter()
+            .map(|(depth, work_item)| (depth, work_item.id))
+            .collect();
+        assert_eq!(walk, vec![(0, 1), (1, 2), (2, 4), (1, 3)]);
+        assert_eq!(graph.children(1), vec![2, 3]);
+        assert_eq!(graph.parents(4), vec![2, 3]);
+        assert_eq!(graph.cycles(), vec![&edge(4, 1)]);
+    }
+}
@@ -0,0 +1,580 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Work item change history.
+//!
+//! [`work_item_timeline()`] fetches all of the updates to a work item and returns them as a
+//! [`WorkItemTimeline`] of typed field and relation changes:
+//!
+//! ```rust
+//!     let timeline = wit::work_item_timeline(&wit_client, organization, project, id).await?;
+//!     for change in timeline.changes() {
+//!         for field in &change.fields {
+//!             println!("{}: {:?} -> {:?}", field.field, field.old_value, field.new_value);
+//!         }
+//!     }
+//!     for transition in timeline.state_transitions() {
+//!         println!("{:?} -> {} at {:?}", transition.from, transition.to, transition.changed_date);
+//!     }
+//!     let cycle_time = timeline.cycle_time(&["Active"], &["Closed"]);
+//! ```
+//!
+//! A timeline can also be built from full work item revisions (`revisions_client().list()`)
+//! with [`WorkItemTimeline::from_revisions()`], which diffs consecutive revisions.
+use super::field_names;
+use crate::wit::models::{IdentityRef, WorkItem, WorkItemRelation, WorkItemUpdate};
+use crate::wit::Client;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::future::IntoFuture;
+use time::{Duration, OffsetDateTime};
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+
+/// The number of updates requested per page.
+const UPDATES_PAGE_SIZE: i32 = 200;
+
+/// A change to a work item field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The field reference name, e.g. `System.State`.
+    pub field: String,
+    /// The value before the change, `None` if the field was not set.
+    pub old_value: Option<Value>,
+    /// The value after the change, `None` if the field was cleared.
+    pub new_value: Option<Value>,
+}
+
+impl FieldChange {
+    /// The old value of a string field.
+    pub fn old_str(&self) -> Option<&str> {
+        self.old_value.as_ref()?.as_str()
+    }
+
+    /// The new value of a string field.
+    pub fn new_str(&self) -> Option<&str> {
+        self.new_value.as_ref()?.as_str()
+    }
+}
+
+/// A change to a work item relation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelationChange {
+    Added(WorkItemRelation),
+    Removed(WorkItemRelation),
+    /// The attributes of the relation, e.g. its comment, were changed.
+    Updated(WorkItemRelation),
+}
+
+impl RelationChange {
+    /// The changed relation.
+    pub fn relation(&self) -> &WorkItemRelation {
+        match self {
+            RelationChange::Added(relation)
+            | RelationChange::Removed(relation)
+            | RelationChange::Updated(relation) => relation,
+        }
+    }
+}
+
+/// A single revision of a work item: the changes made to it, by whom and when.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemChange {
+    /// The revision number created by the change.
+    pub rev: Option<i32>,
+    /// The identity that made the change, from `System.ChangedBy`.
+    pub changed_by: Option<IdentityRef>,
+    /// When the change was made, from `System.ChangedDate`.
+    pub changed_date: Option<OffsetDateTime>,
+    /// The changed fields, ordered by reference name.
+    pub fields: Vec<FieldChange>,
+    /// The added, removed and updated relations.
+    pub relations: Vec<RelationChange>,
+}
+
+impl WorkItemChange {
+    /// Builds a change from a work item update (`updates_client().list()`).
+    pub fn from_update(update: &WorkItemUpdate) -> Self {
+        let mut fields: Vec<FieldChange> = update
+            .fields
+            .as_ref()
+            .and_then(Value::as_object)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|(field, value)| FieldChange {
+                        field: field.clone(),
+                        old_value: non_null(value.get("oldValue")),
+                        new_value: non_null(value.get("newValue")),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+        let relations = update
+            .relations
+            .as_ref()
+            .map(|relations| {
+                let added = relations.added.iter().cloned().map(RelationChange::Added);
+                let removed = relations
+                    .removed
+                    .iter()
+                    .cloned()
+                    .map(RelationChange::Removed);
+                let updated = relations
+                    .updated
+                    .iter()
+                    .cloned()
+                    .map(RelationChange::Updated);
+                added.chain(removed).chain(updated).collect()
+            })
+            .unwrap_or_default();
+        let changed_by = update.revised_by.as_ref().and_then(|revised_by| {
+            serde_json::to_value(revised_by)
+                .ok()
+                .and_then(|value| IdentityRef::deserialize(value).ok())
+        });
+        Self::new(update.rev, changed_by, fields, relations)
+    }
+
+    fn new(
+        rev: Option<i32>,
+        changed_by: Option<IdentityRef>,
+        fields: Vec<FieldChange>,
+        relations: Vec<RelationChange>,
+    ) -> Self {
+        let mut change = Self {
+            rev,
+            changed_by,
+            changed_date: None,
+            fields,
+            relations,
+        };
+        if let Some(value) = change.new_value(field_names::CHANGED_BY) {
+            if let Ok(changed_by) = IdentityRef::deserialize(value) {
+                change.changed_by = Some(changed_by);
+            }
+        }
+        change.changed_date = change
+            .new_value(field_names::CHANGED_DATE)
+            .and_then(|value| crate::date_time::rfc3339::deserialize(value).ok());
+        change
+    }
+
+    /// The change to a field, if it was changed.
+    pub fn field(&self, reference_name: &str) -> Option<&FieldChange> {
+        self.fields
+            .iter()
+            .find(|change| change.field == reference_name)
+    }
+
+    fn new_value(&self, reference_name: &str) -> Option<&Value> {
+        self.field(reference_name)?.new_value.as_ref()
+    }
+}
+
+/// A change of a work item's `System.State`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateTransition {
+    /// The revision number created by the change.
+    pub rev: Option<i32>,
+    /// The previous state, `None` when the work item was created.
+    pub from: Option<String>,
+    /// The new state.
+    pub to: String,
+    /// The identity that changed the state.
+    pub changed_by: Option<IdentityRef>,
+    /// When the state was changed.
+    pub changed_date: Option<OffsetDateTime>,
+}
+
+/// The change history of a work item, oldest change first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WorkItemTimeline {
+    changes: Vec<WorkItemChange>,
+}
+
+impl WorkItemTimeline {
+    /// Builds a timeline from the updates of a work item (`updates_client().list()`).
+    pub fn from_updates<'a>(updates: impl IntoIterator<Item = &'a WorkItemUpdate>) -> Self {
+        let mut changes: Vec<WorkItemChange> = updates
+            .into_iter()
+            .map(WorkItemChange::from_update)
+            .collect();
+        changes.sort_by_key(|change| change.rev);
+        Self { changes }
+    }
+
+    /// Builds a timeline by diffing consecutive revisions of a work item
+    /// (`revisions_client().list()`).
+    ///
+    /// Relation changes are only included if the revisions were requested with relations.
+    pub fn from_revisions<'a>(revisions: impl IntoIterator<Item = &'a WorkItem>) -> Self {
+        let mut revisions: Vec<&WorkItem> = revisions.into_iter().collect();
+        revisions.sort_by_key(|revision| revision.rev);
+        let empty = Map::new();
+        let mut previous: Option<&WorkItem> = None;
+        let mut changes = Vec::with_capacity(revisions.len());
+        for revision in revisions {
+            let old_fields = previous
+                .and_then(|previous| previous.fields.as_object())
+                .unwrap_or(&empty);
+            let new_fields = revision.fields.as_object().unwrap_or(&empty);
+            let old_relations = previous.map_or(&[][..], |previous| &previous.relations[..]);
+            changes.push(WorkItemChange::new(
+                revision.rev,
+                None,
+                diff_fields(old_fields, new_fields),
+                diff_relations(old_relations, &revision.relations),
+            ));
+            previous = Some(revision);
+        }
+        Self { changes }
+    }
+
+    /// The changes, oldest first.
+    pub fn changes(&self) -> &[WorkItemChange] {
+        &self.changes
+    }
+
+    /// The changes to a field, oldest first.
+    pub fn field_changes<'a>(
+        &'a self,
+        reference_name: &'a str,
+    ) -> impl Iterator<Item = (&'a WorkItemChange, &'a FieldChange)> + 'a {
+        self.changes
+            .iter()
+            .filter_map(move |change| Some((change, change.field(reference_name)?)))
+    }
+
+    /// The changes of `System.State`, oldest first.
+    pub fn state_transitions(&self) -> Vec<StateTransition> {
+        self.field_changes(field_names::STATE)
+            .filter_map(|(change, field)| {
+                Some(StateTransition {
+                    rev: change.rev,
+                    from: field.old_str().map(ToOwned::to_owned),
+                    to: field.new_str()?.to_owned(),
+                    changed_by: change.changed_by.clone(),
+                    changed_date: change.changed_date,
+                })
+            })
+            .collect()
+    }
+
+    /// When the work item was created.
+    pub fn created_date(&self) -> Option<OffsetDateTime> {
+        self.changes.first()?.changed_date
+    }
+
+    /// When the work item first entered one of `states`.
+    pub fn first_entered(&self, states: &[&str]) -> Option<OffsetDateTime> {
+        self.state_transitions()
+            .into_iter()
+            .find(|transition| states.contains(&transition.to.as_str()))?
+            .changed_date
+    }
+
+    /// When the work item last entered one of `states`, if it is still in one of them.
+    ///
+    /// For completed states, this ignores a work item that was completed and later reopened.
+    pub fn entered(&self, states: &[&str]) -> Option<OffsetDateTime> {
+        let transitions = self.state_transitions();
+        if !states.contains(&transitions.last()?.to.as_str()) {
+            return None;
+        }
+        // Moving between the states, e.g. from "Resolved" to "Closed", does not re-enter them.
+        transitions
+            .into_iter()
+            .rev()
+            .take_while(|transition| states.contains(&transition.to.as_str()))
+            .last()?
+            .changed_date
+    }
+
+    /// The time from creation until the work item entered one of the `done_states`, if it
+    /// is currently in one of them.
+    pub fn lead_time(&self, done_states: &[&str]) -> Option<Duration> {
+        Some(self.entered(done_states)? - self.created_date()?)
+    }
+
+    /// The time from when the work item first entered one of the `started_states` until it
+    /// entered one of the `done_states`, if it is currently in one of them.
+    pub fn cycle_time(&self, started_states: &[&str], done_states: &[&str]) -> Option<Duration> {
+        Some(self.entered(done_states)? - self.first_entered(started_states)?)
+    }
+}
+
+impl FromIterator<WorkItemChange> for WorkItemTimeline {
+    fn from_iter<I: IntoIterator<Item = WorkItemChange>>(iter: I) -> Self {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Gets the change history of a work item.
+///
+/// The returned builder can be awaited for a [`WorkItemTimeline`].
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `id`: The work item id
+pub fn work_item_timeline(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    id: i32,
+) -> GetWorkItemTimeline {
+    GetWorkItemTimeline {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        id,
+    }
+}
+
+/// Request builder returned by [`work_item_timeline()`].
+#[derive(Clone)]
+pub struct GetWorkItemTimeline {
+    client: Client,
+    organization: String,
+    project: String,
+    id: i32,
+}
+
+impl GetWorkItemTimeline {
+    /// Gets all of the updates to the work item, oldest first.
+    pub async fn updates(&self) -> azure_core::Result<Vec<WorkItemUpdate>> {
+        let mut updates = Vec::new();
+        loop {
+            let page = self
+                .client
+                .updates_client()
+                .list(&self.organization, self.id, &self.project)
+                .top(UPDATES_PAGE_SIZE)
+                .skip(updates.len() as i32)
+                .await?
+                .value;
+            let done = page.len() < UPDATES_PAGE_SIZE as usize;
+            updates.extend(page);
+            if done {
+                return Ok(updates);
+            }
+        }
+    }
+}
+
+impl IntoFuture for GetWorkItemTimeline {
+    type Output = azure_core::Result<WorkItemTimeline>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<WorkItemTimeline>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let updates = self.updates().await?;
+            Ok(WorkItemTimeline::from_updates(&updates))
+        })
+    }
+}
+
+fn non_null(value: Option<&Value>) -> Option<Value> {
+    value.filter(|value| !value.is_null()).cloned()
+}
+
+fn diff_fields(old: &Map<String, Value>, new: &Map<String, Value>) -> Vec<FieldChange> {
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old_value = non_null(old.get(name));
+            let new_value = non_null(new.get(name));
+            (old_value != new_value).then(|| FieldChange {
+                field: name.clone(),
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+fn diff_relations(old: &[WorkItemRelation], new: &[WorkItemRelation]) -> Vec<RelationChange> {
+    let find = |relations: &[WorkItemRelation], relation: &WorkItemRelation| {
+        relations
+            .iter()
+            .find(|r| r.link.rel == relation.link.rel && r.link.url == relation.link.url)
+            .cloned()
+    };
+    let added_or_updated = new.iter().filter_map(|relation| match find(old, relation) {
+        None => Some(RelationChange::Added(relation.clone())),
+        Some(old_relation) if old_relation != *relation => {
+            Some(RelationChange::Updated(relation.clone()))
+        }
+        Some(_) => None,
+    });
+    let removed = old
+        .iter()
+        .filter(|relation| find(new, relation).is_none())
+        .cloned()
+        .map(RelationChange::Removed);
+    added_or_updated.chain(removed).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn updates() -> Vec<WorkItemUpdate> {
+        serde_json::from_value(json!([
+            {
+                "id": 1, "rev": 1, "workItemId": 7, "url": "",
+                "fields": {
+                    "System.State": { "newValue": "New" },
+                    "System.Title": { "newValue": "Title" },
+                    "System.ChangedDate": { "newValue": "2024-01-01T00:00:00Z" },
+                    "System.ChangedBy": { "newValue": { "displayName": "Jane Doe" } }
+                }
+            },
+            {
+                "id": 2, "rev": 2, "workItemId": 7, "url": "",
+                "fields": {
+                    "System.State": { "oldValue": "New", "newValue": "Active" },
+                    "System.ChangedDate": { "oldValue": "2024-01-01T00:00:00Z", "newValue": "2024-01-03T00:00:00Z" }
+                },
+                "relations": {
+                    "added": [{ "rel": "System.LinkTypes.Hierarchy-Reverse", "url": "https://dev.azure.com/org/_apis/wit/workItems/1", "attributes": {} }]
+                }
+            },
+            {
+                "id": 3, "rev": 3, "workItemId": 7, "url": "",
+                "fields": {
+                    "System.State": { "oldValue": "Active", "newValue": "Resolved" },
+                    "System.ChangedDate": { "oldValue": "2024-01-03T00:00:00Z", "newValue": "2024-01-06T00:00:00Z" }
+                }
+            },
+            {
+                "id": 4, "rev": 4, "workItemId": 7, "url": "",
+                "fields": {
+                    "System.State": { "oldValue": "Resolved", "newValue": "Closed" },
+                    "System.ChangedDate": { "oldValue": "2024-01-06T00:00:00Z", "newValue": "2024-01-08T00:00:00Z" }
+                }
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_updates() {
+        let timeline = WorkItemTimeline::from_updates(&updates());
+        assert_eq!(timeline.changes().len(), 4);
+        let first = &timeline.changes()[0];
+        assert_eq!(
+            first
+                .changed_by
+                .as_ref()
+                .and_then(|identity| identity.graph_subject_base.display_name.as_deref()),
+            Some("Jane Doe")
+        );
+        assert_eq!(
+            first.field(field_names::TITLE).unwrap().new_str(),
+            Some("Title")
+        );
+        assert_eq!(timeline.changes()[1].relations.len(), 1);
+        assert!(matches!(
+            timeline.changes()[1].relations[0],
+            RelationChange::Added(_)
+        ));
+
+        let transitions = timeline.state_transitions();
+        let states: Vec<(Option<&str>, &str)> = transitions
+            .iter()
+            .map(|transition| (transition.from.as_deref(), transition.to.as_str()))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (None, "New"),
+                (Some("New"), "Active"),
+                (Some("Active"), "Resolved"),
+                (Some("Resolved"), "Closed")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lead_and_cycle_time() {
+        let timeline = WorkItemTimeline::from_updates(&updates());
+        assert_eq!(
+            timeline.lead_time(&["Resolved", "Closed"]),
+            Some(Duration::days(5))
+        );
+        assert_eq!(timeline.lead_time(&["Closed"]), Some(Duration::days(7)));
+        assert_eq!(
+            timeline.cycle_time(&["Active"], &["Resolved", "Closed"]),
+            Some(Duration::days(3))
+        );
+        assert_eq!(timeline.lead_time(&["Removed"]), None);
+        assert_eq!(timeline.cycle_time(&["Committed"], &["Closed"]), None);
+    }
+
+    #[test]
+    fn test_from_revisions() {
+        let revisions: Vec<WorkItem> = serde_json::from_value(json!([
+            {
+                "id": 7, "rev": 2, "url": "",
+                "fields": { "System.State": "Active", "System.Title": "Title" },
+                "relations": [
+                    { "rel": "System.LinkTypes.Related", "url": "https://dev.azure.com/org/_apis/wit/workItems/2", "attributes": { "comment": "new" } }
+                ]
+            },
+            {
+                "id": 7, "rev": 1, "url": "",
+                "fields": { "System.State": "New", "System.Title": "Title", "System.Description": "Text" },
+                "relations": [
+                    { "rel": "System.LinkTypes.Related", "url": "https://dev.azure.com/org/_apis/wit/workItems/2", "attributes": {} },
+                    { "rel": "System.LinkTypes.Related", "url": "https://dev.azure.com/org/_apis/wit/workItems/3", "attributes": {} }
+                ]
+            }
+        ]))
+        .unwrap();
+        let timeline = WorkItemTimeline::from_revisions(&revisions);
+        assert_eq!(timeline.changes().len(), 2);
+        assert_eq!(timeline.changes()[0].fields.len(), 3);
+        assert_eq!(timeline.changes()[0].relations.len(), 2);
+
+        let change = &timeline.changes()[1];
+        assert_eq!(change.rev, Some(2));
+        assert_eq!(
+            change.fields,
+            vec![
+                FieldChange {
+                    field: field_names::DESCRIPTION.to_string(),
+                    old_value: Some(json!("Text")),
+                    new_value: None,
+                },
+                FieldChange {
+                    field: field_names::STATE.to_string(),
+                    old_value: Some(json!("New")),
+                    new_value: Some(json!("Active")),
+                },
+            ]
+        );
+        let relation_ids: Vec<(bool, Option<i32>)> = change
+            .relations
+            .iter()
+            .map(|change| {
+                (
+                    matches!(change, RelationChange::Updated(_)),
+                    change.relation().work_item_id(),
+                )
+            })
+            .collect();
+        assert_eq!(relation_ids, vec![(true, Some(2)), (false, Some(3))]);
+    }
+}
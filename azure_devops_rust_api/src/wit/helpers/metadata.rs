@@ -0,0 +1,501 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Cached process metadata: work item types, fields, allowed values and states.
+//!
+//! [`ProcessMetadataCache`] loads a project's metadata once, and shares it until it is refreshed:
+//!
+//! ```rust
+//!     let cache = wit::ProcessMetadataCache::new(&wit_client, organization, project);
+//!     let metadata = cache.get().await?;
+//!     let bug = metadata.work_item_type("Bug").unwrap();
+//!     println!("Priorities: {:?}", bug.allowed_values(field_names::PRIORITY));
+//!     println!("Done states: {:?}", bug.states_in_category("Completed"));
+//!
+//!     let patch = WorkItemPatch::new().set_field(field_names::STATE, "Closed");
+//!     if let Err(errors) = metadata.validate("Bug", patch.operations()) {
+//!         for error in errors {
+//!             println!("{error}");
+//!         }
+//!     }
+//! ```
+use super::DEFAULT_MAX_CONCURRENCY;
+use crate::wit::models::{
+    json_patch_operation::Op, JsonPatchOperation, WorkItemField2, WorkItemStateColor, WorkItemType,
+    WorkItemTypeFieldWithReferences,
+};
+use crate::wit::work_item_types_field::list::Expand;
+use crate::wit::Client;
+use futures::lock::Mutex;
+use futures::{StreamExt, TryStreamExt};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::future::IntoFuture;
+use std::sync::Arc;
+
+/// A work item type with its fields and states.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemTypeMetadata {
+    /// The work item type.
+    pub work_item_type: WorkItemType,
+    /// The fields of the work item type, with their allowed values.
+    pub fields: Vec<WorkItemTypeFieldWithReferences>,
+    /// The states of the work item type.
+    pub states: Vec<WorkItemStateColor>,
+}
+
+impl WorkItemTypeMetadata {
+    /// The name of the work item type.
+    pub fn name(&self) -> &str {
+        self.work_item_type.name.as_deref().unwrap_or_default()
+    }
+
+    /// A field of the work item type, by reference name.
+    pub fn field(&self, reference_name: &str) -> Option<&WorkItemTypeFieldWithReferences> {
+        self.fields.iter().find(|field| {
+            field
+                .work_item_type_field_instance_base
+                .work_item_field_reference
+                .reference_name
+                .as_deref()
+                .map_or(false, |name| name.eq_ignore_ascii_case(reference_name))
+        })
+    }
+
+    /// The allowed values of a field, empty if any value is allowed.
+    pub fn allowed_values(&self, reference_name: &str) -> &[Value] {
+        self.field(reference_name)
+            .map_or(&[], |field| &field.allowed_values[..])
+    }
+
+    /// The category of a state, e.g. `Proposed`, `InProgress`, `Resolved`, `Completed` or `Removed`.
+    pub fn state_category(&self, state: &str) -> Option<&str> {
+        self.states
+            .iter()
+            .find(|s| {
+                s.name
+                    .as_deref()
+                    .map_or(false, |name| name.eq_ignore_ascii_case(state))
+            })?
+            .category
+            .as_deref()
+    }
+
+    /// The names of the states in a category.
+    pub fn states_in_category(&self, category: &str) -> Vec<&str> {
+        self.states
+            .iter()
+            .filter(|state| state.category.as_deref() == Some(category))
+            .filter_map(|state| state.name.as_deref())
+            .collect()
+    }
+}
+
+/// The metadata of a project's process.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProcessMetadata {
+    fields: BTreeMap<String, WorkItemField2>,
+    /// Lowercase field names and reference names, to field reference names.
+    field_names: HashMap<String, String>,
+    work_item_types: BTreeMap<String, WorkItemTypeMetadata>,
+}
+
+impl ProcessMetadata {
+    pub fn new(
+        fields: impl IntoIterator<Item = WorkItemField2>,
+        work_item_types: impl IntoIterator<Item = WorkItemTypeMetadata>,
+    ) -> Self {
+        let mut metadata = Self::default();
+        for field in fields {
+            let reference_name = match field.work_item_field.reference_name.clone() {
+                Some(reference_name) => reference_name,
+                None => continue,
+            };
+            if let Some(name) = &field.work_item_field.name {
+                metadata
+                    .field_names
+                    .insert(name.to_lowercase(), reference_name.clone());
+            }
+            metadata
+                .field_names
+                .insert(reference_name.to_lowercase(), reference_name.clone());
+            metadata.fields.insert(reference_name, field);
+        }
+        for work_item_type in work_item_types {
+            metadata
+                .work_item_types
+                .insert(work_item_type.name().to_lowercase(), work_item_type);
+        }
+        metadata
+    }
+
+    /// Loads the metadata of a project.
+    ///
+    /// The fields and states of each work item type are requested concurrently.
+    pub async fn load(
+        client: &Client,
+        organization: &str,
+        project: &str,
+    ) -> azure_core::Result<Self> {
+        let fields = client
+            .fields_client()
+            .list(organization, project)
+            .await?
+            .value;
+        let work_item_types = client
+            .work_item_types_client()
+            .list(organization, project)
+            .await?
+            .value;
+        let work_item_types: Vec<WorkItemTypeMetadata> =
+            futures::stream::iter(work_item_types.into_iter().map(|work_item_type| {
+                let name = work_item_type.name.clone().unwrap_or_default();
+                let fields = client
+                    .work_item_types_field_client()
+                    .list(organization, project, &name)
+                    .expand(Expand::AllowedValues)
+                    .into_future();
+                let states = client
+                    .work_item_type_states_client()
+                    .list(organization, project, &name)
+                    .into_future();
+                async move {
+                    let (fields, states) = futures::try_join!(fields, states)?;
+                    Ok::<_, azure_core::Error>(WorkItemTypeMetadata {
+                        work_item_type,
+                        fields: fields.value,
+                        states: states.value,
+                    })
+                }
+            }))
+            .buffered(DEFAULT_MAX_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(Self::new(fields, work_item_types))
+    }
+
+    /// A field, by name or reference name.
+    pub fn field(&self, name: &str) -> Option<&WorkItemField2> {
+        self.fields.get(self.reference_name(name)?)
+    }
+
+    /// The reference name of a field, by name or reference name.
+    pub fn reference_name(&self, name: &str) -> Option<&str> {
+        self.field_names
+            .get(&name.to_lowercase())
+            .map(String::as_str)
+    }
+
+    /// All of the fields, ordered by reference name.
+    pub fn fields(&self) -> impl Iterator<Item = &WorkItemField2> {
+        self.fields.values()
+    }
+
+    /// A work item type, by name.
+    pub fn work_item_type(&self, name: &str) -> Option<&WorkItemTypeMetadata> {
+        self.work_item_types.get(&name.to_lowercase())
+    }
+
+    /// All of the work item types, ordered by name.
+    pub fn work_item_types(&self) -> impl Iterator<Item = &WorkItemTypeMetadata> {
+        self.work_item_types.values()
+    }
+
+    /// Checks the field updates of a patch for a work item of type `work_item_type`.
+    ///
+    /// Reports fields that are unknown or not part of the work item type, read-only fields,
+    /// and values that are not allowed. Operations on other paths, e.g. relations, are not checked.
+    pub fn validate(
+        &self,
+        work_item_type: &str,
+        operations: &[JsonPatchOperation],
+    ) -> Result<(), Vec<PatchValidationError>> {
+        let metadata = match self.work_item_type(work_item_type) {
+            Some(metadata) => metadata,
+            None => {
+                return Err(vec![PatchValidationError::UnknownWorkItemType(
+                    work_item_type.to_string(),
+                )])
+            }
+        };
+        let errors: Vec<PatchValidationError> = operations
+            .iter()
+            .filter_map(|operation| self.validate_operation(metadata, operation))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate_operation(
+        &self,
+        metadata: &WorkItemTypeMetadata,
+        operation: &JsonPatchOperation,
+    ) -> Option<PatchValidationError> {
+        let name = operation.path.as_deref()?.strip_prefix("/fields/")?;
+        if operation.op == Some(Op::Test) {
+            return None;
+        }
+        let field = match self.field(name) {
+            Some(field) if metadata.field(self.reference_name(name)?).is_some() => field,
+            _ => {
+                return Some(PatchValidationError::UnknownField {
+                    field: name.to_string(),
+                    work_item_type: metadata.name().to_string(),
+                })
+            }
+        };
+        let reference_name = field.work_item_field.reference_name.as_deref()?;
+        if field.work_item_field.read_only == Some(true) {
+            return Some(PatchValidationError::ReadOnlyField {
+                field: reference_name.to_string(),
+            });
+        }
+        let value = operation.value.as_ref().filter(|value| !value.is_null())?;
+        let allowed_values = metadata.allowed_values(reference_name);
+        let suggested = field.work_item_field.is_picklist_suggested == Some(true);
+        if allowed_values.is_empty() || suggested {
+            return None;
+        }
+        let value_str = value_to_string(value);
+        if allowed_values
+            .iter()
+            .any(|allowed| value_to_string(allowed).eq_ignore_ascii_case(&value_str))
+        {
+            return None;
+        }
+        Some(PatchValidationError::InvalidValue {
+            field: reference_name.to_string(),
+            value: value.clone(),
+            allowed_values: allowed_values.to_vec(),
+        })
+    }
+}
+
+/// A problem with a work item patch found by [`ProcessMetadata::validate()`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchValidationError {
+    /// The work item type does not exist in the project.
+    UnknownWorkItemType(String),
+    /// The field does not exist, or is not part of the work item type.
+    UnknownField {
+        field: String,
+        work_item_type: String,
+    },
+    /// The field is read-only.
+    ReadOnlyField { field: String },
+    /// The value is not one of the field's allowed values.
+    InvalidValue {
+        field: String,
+        value: Value,
+        allowed_values: Vec<Value>,
+    },
+}
+
+impl fmt::Display for PatchValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchValidationError::UnknownWorkItemType(work_item_type) => {
+                write!(f, "unknown work item type {work_item_type}")
+            }
+            PatchValidationError::UnknownField {
+                field,
+                work_item_type,
+            } => write!(f, "field {field} is not a field of {work_item_type}"),
+            PatchValidationError::ReadOnlyField { field } => {
+                write!(f, "field {field} is read-only")
+            }
+            PatchValidationError::InvalidValue {
+                field,
+                value,
+                allowed_values,
+            } => {
+                let allowed_values: Vec<String> =
+                    allowed_values.iter().map(value_to_string).collect();
+                write!(
+                    f,
+                    "{} is not an allowed value of field {field}, expected one of: {}",
+                    value_to_string(value),
+                    allowed_values.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatchValidationError {}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Loads the process metadata of a project on first use, and shares it until it is refreshed.
+///
+/// Clones of the cache share the same metadata.
+#[derive(Clone)]
+pub struct ProcessMetadataCache {
+    client: Client,
+    organization: String,
+    project: String,
+    metadata: Arc<Mutex<Option<Arc<ProcessMetadata>>>>,
+}
+
+impl ProcessMetadataCache {
+    /// Arguments:
+    /// * `client`: The work item tracking client.
+    /// * `organization`: The name of the Azure DevOps organization.
+    /// * `project`: Project ID or project name
+    pub fn new(
+        client: &Client,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            organization: organization.into(),
+            project: project.into(),
+            metadata: Default::default(),
+        }
+    }
+
+    /// Returns the metadata, loading it if it has not been loaded.
+    ///
+    /// Concurrent callers wait for the same load.
+    pub async fn get(&self) -> azure_core::Result<Arc<ProcessMetadata>> {
+        let mut metadata = self.metadata.lock().await;
+        if let Some(metadata) = metadata.as_ref() {
+            return Ok(metadata.clone());
+        }
+        let loaded =
+            Arc::new(ProcessMetadata::load(&self.client, &self.organization, &self.project).await?);
+        *metadata = Some(loaded.clone());
+        Ok(loaded)
+    }
+
+    /// Reloads the metadata, e.g. after the process has been customized.
+    pub async fn refresh(&self) -> azure_core::Result<Arc<ProcessMetadata>> {
+        self.invalidate().await;
+        self.get().await
+    }
+
+    /// Discards the cached metadata, so that it is reloaded on next use.
+    pub async fn invalidate(&self) {
+        *self.metadata.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wit::{field_names, WorkItemPatch};
+    use serde_json::json;
+
+    fn metadata() -> ProcessMetadata {
+        let fields: Vec<WorkItemField2> = serde_json::from_value(json!([
+            { "name": "Title", "referenceName": "System.Title", "url": "" },
+            { "name": "State", "referenceName": "System.State", "url": "" },
+            { "name": "ID", "referenceName": "System.Id", "readOnly": true, "url": "" },
+            { "name": "Priority", "referenceName": "Microsoft.VSTS.Common.Priority", "url": "" },
+            { "name": "Story Points", "referenceName": "Microsoft.VSTS.Scheduling.StoryPoints", "url": "" }
+        ]))
+        .unwrap();
+        let bug = WorkItemTypeMetadata {
+            work_item_type: serde_json::from_value(json!({ "name": "Bug", "url": "" })).unwrap(),
+            fields: serde_json::from_value(json!([
+                { "referenceName": "System.Title" },
+                { "referenceName": "System.Id" },
+                { "referenceName": "System.State", "allowedValues": ["New", "Active", "Resolved", "Closed"] },
+                { "referenceName": "Microsoft.VSTS.Common.Priority", "allowedValues": [1, 2, 3, 4] }
+            ]))
+            .unwrap(),
+            states: serde_json::from_value(json!([
+                { "name": "New", "category": "Proposed" },
+                { "name": "Active", "category": "InProgress" },
+                { "name": "Resolved", "category": "Resolved" },
+                { "name": "Closed", "category": "Completed" }
+            ]))
+            .unwrap(),
+        };
+        ProcessMetadata::new(fields, [bug])
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = metadata();
+        assert_eq!(
+            metadata.reference_name("story points"),
+            Some("Microsoft.VSTS.Scheduling.StoryPoints")
+        );
+        assert_eq!(
+            metadata.reference_name("system.title"),
+            Some("System.Title")
+        );
+        assert!(metadata.field("Effort").is_none());
+
+        let bug = metadata.work_item_type("bug").unwrap();
+        assert_eq!(bug.name(), "Bug");
+        assert_eq!(bug.state_category("active"), Some("InProgress"));
+        assert_eq!(bug.states_in_category("Completed"), vec!["Closed"]);
+        assert_eq!(bug.allowed_values(field_names::PRIORITY).len(), 4);
+        assert!(bug.allowed_values(field_names::TITLE).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let metadata = metadata();
+        let patch = WorkItemPatch::new()
+            .set_field(field_names::TITLE, "Title")
+            .set_field(field_names::STATE, "active")
+            .set_field(field_names::PRIORITY, "2")
+            .add_hyperlink("https://example.com", None);
+        assert_eq!(metadata.validate("Bug", patch.operations()), Ok(()));
+
+        let patch = WorkItemPatch::new()
+            .set_field(field_names::ID, 5)
+            .set_field(field_names::STATE, "Done")
+            .set_field("Story Points", 3)
+            .set_field("Effort", 3)
+            .set_field(field_names::PRIORITY, 5);
+        let errors = metadata.validate("Bug", patch.operations()).unwrap_err();
+        assert_eq!(errors.len(), 5);
+        assert_eq!(
+            errors[0],
+            PatchValidationError::ReadOnlyField {
+                field: field_names::ID.to_string()
+            }
+        );
+        assert!(matches!(
+            &errors[1],
+            PatchValidationError::InvalidValue { field, .. } if field == field_names::STATE
+        ));
+        assert_eq!(
+            errors[1].to_string(),
+            "Done is not an allowed value of field System.State, expected one of: New, Active, Resolved, Closed"
+        );
+        assert!(matches!(
+            &errors[2],
+            PatchValidationError::UnknownField { field, .. } if field == "Story Points"
+        ));
+        assert!(matches!(
+            &errors[3],
+            PatchValidationError::UnknownField { field, .. } if field == "Effort"
+        ));
+        assert!(matches!(
+            &errors[4],
+            PatchValidationError::InvalidValue { field, .. } if field == field_names::PRIORITY
+        ));
+
+        assert_eq!(
+            metadata.validate("Epic", &[]),
+            Err(vec![PatchValidationError::UnknownWorkItemType(
+                "Epic".to_string()
+            )])
+        );
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Handwritten helpers for working with work items.
+//!
+//! These are re-exported from the `wit` module.
+mod attachment;
+mod batch;
+mod graph;
+mod history;
+mod metadata;
+mod patch;
+mod query;
+mod sync;
+mod wiql;
+mod work_item;
+
+pub use attachment::{
+    download_attachment, upload_attachment, DownloadAttachment, UploadAttachment,
+    DEFAULT_ATTACHMENT_CHUNK_SIZE,
+};
+pub use batch::{update_work_items, UpdateWorkItems, WorkItemBatchError, WorkItemBatchResult};
+pub use graph::{
+    traverse, work_item_id_from_url, RelationTypes, Traverse, WorkItemEdge, WorkItemGraph,
+};
+pub use history::{
+    work_item_timeline, FieldChange, GetWorkItemTimeline, RelationChange, StateTransition,
+    WorkItemChange, WorkItemTimeline,
+};
+pub use metadata::{
+    PatchValidationError, ProcessMetadata, ProcessMetadataCache, WorkItemTypeMetadata,
+};
+pub use patch::{field_names, link_types, WorkItemPatch};
+pub use query::{
+    query_work_items, QueryWorkItems, WorkItemLinkPair, WorkItemQueryResults,
+    DEFAULT_MAX_CONCURRENCY, MAX_WORK_ITEMS_PER_BATCH,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use sync::FileWatermarkStore;
+pub use sync::{
+    sync_revisions, MemoryWatermarkStore, RevisionBatch, SyncRevisions, WatermarkStore,
+};
+pub use wiql::{
+    field, link_type, source_field, target_field, LinkQueryMode, WiqlCondition, WiqlField,
+    WiqlQuery, WiqlSource, WiqlValue, MAX_WIQL_LENGTH,
+};
@@ -0,0 +1,237 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Work item JSON patch documents.
+//!
+//! Work items are created and updated by sending a list of JSON patch operations.
+//! [`WorkItemPatch`] builds that list:
+//!
+//! ```rust
+//!     let body = WorkItemPatch::new()
+//!         .set_field(field_names::TITLE, "Example User Story title")
+//!         .set_field(field_names::ITERATION_PATH, "my-project\\my-iteration")
+//!         .add_relation(link_types::PARENT, &parent_url)
+//!         .into();
+//!     let work_item = wit_client
+//!         .work_items_client()
+//!         .create(organization, body, project, "User Story")
+//!         .await?;
+//! ```
+use crate::wit::models::{json_patch_operation::Op, JsonPatchOperation};
+use serde_json::{json, Value};
+
+/// Reference names of common work item fields.
+pub mod field_names {
+    pub const ID: &str = "System.Id";
+    pub const REV: &str = "System.Rev";
+    pub const TITLE: &str = "System.Title";
+    pub const DESCRIPTION: &str = "System.Description";
+    pub const WORK_ITEM_TYPE: &str = "System.WorkItemType";
+    pub const STATE: &str = "System.State";
+    pub const REASON: &str = "System.Reason";
+    pub const ASSIGNED_TO: &str = "System.AssignedTo";
+    pub const AREA_PATH: &str = "System.AreaPath";
+    pub const ITERATION_PATH: &str = "System.IterationPath";
+    pub const TEAM_PROJECT: &str = "System.TeamProject";
+    pub const TAGS: &str = "System.Tags";
+    pub const PARENT: &str = "System.Parent";
+    pub const HISTORY: &str = "System.History";
+    pub const CREATED_BY: &str = "System.CreatedBy";
+    pub const CREATED_DATE: &str = "System.CreatedDate";
+    pub const CHANGED_BY: &str = "System.ChangedBy";
+    pub const CHANGED_DATE: &str = "System.ChangedDate";
+    pub const PRIORITY: &str = "Microsoft.VSTS.Common.Priority";
+    pub const SEVERITY: &str = "Microsoft.VSTS.Common.Severity";
+    pub const ACCEPTANCE_CRITERIA: &str = "Microsoft.VSTS.Common.AcceptanceCriteria";
+    pub const STACK_RANK: &str = "Microsoft.VSTS.Common.StackRank";
+    pub const ACTIVATED_DATE: &str = "Microsoft.VSTS.Common.ActivatedDate";
+    pub const RESOLVED_DATE: &str = "Microsoft.VSTS.Common.ResolvedDate";
+    pub const CLOSED_DATE: &str = "Microsoft.VSTS.Common.ClosedDate";
+    pub const STORY_POINTS: &str = "Microsoft.VSTS.Scheduling.StoryPoints";
+    pub const EFFORT: &str = "Microsoft.VSTS.Scheduling.Effort";
+    pub const ORIGINAL_ESTIMATE: &str = "Microsoft.VSTS.Scheduling.OriginalEstimate";
+    pub const REMAINING_WORK: &str = "Microsoft.VSTS.Scheduling.RemainingWork";
+    pub const COMPLETED_WORK: &str = "Microsoft.VSTS.Scheduling.CompletedWork";
+    pub const REPRO_STEPS: &str = "Microsoft.VSTS.TCM.ReproSteps";
+}
+
+/// Reference names of common work item relation types.
+pub mod link_types {
+    /// Links to the parent work item.
+    pub const PARENT: &str = "System.LinkTypes.Hierarchy-Reverse";
+    /// Links to a child work item.
+    pub const CHILD: &str = "System.LinkTypes.Hierarchy-Forward";
+    pub const RELATED: &str = "System.LinkTypes.Related";
+    pub const PREDECESSOR: &str = "System.LinkTypes.Dependency-Reverse";
+    pub const SUCCESSOR: &str = "System.LinkTypes.Dependency-Forward";
+    pub const DUPLICATE: &str = "System.LinkTypes.Duplicate-Forward";
+    pub const DUPLICATE_OF: &str = "System.LinkTypes.Duplicate-Reverse";
+    pub const TESTED_BY: &str = "Microsoft.VSTS.Common.TestedBy-Forward";
+    pub const TESTS: &str = "Microsoft.VSTS.Common.TestedBy-Reverse";
+    /// Links to an attachment uploaded with `attachments_client().create()`.
+    pub const ATTACHED_FILE: &str = "AttachedFile";
+    pub const HYPERLINK: &str = "Hyperlink";
+    /// Links to an artifact such as a commit, branch, pull request or build.
+    pub const ARTIFACT_LINK: &str = "ArtifactLink";
+}
+
+/// Builder for the JSON patch document used to create or update a work item.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkItemPatch {
+    operations: Vec<JsonPatchOperation>,
+}
+
+impl WorkItemPatch {
+    /// Create an empty patch document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a field, e.g. `set_field(field_names::TITLE, "My title")`.
+    #[must_use]
+    pub fn set_field(self, reference_name: &str, value: impl Into<Value>) -> Self {
+        self.operation(
+            Op::Add,
+            format!("/fields/{reference_name}"),
+            Some(value.into()),
+        )
+    }
+
+    /// Remove the value of a field.
+    #[must_use]
+    pub fn remove_field(self, reference_name: &str) -> Self {
+        self.operation(Op::Remove, format!("/fields/{reference_name}"), None)
+    }
+
+    /// Add a relation of the given type (see [`link_types`]) to the work item, artifact or
+    /// resource at `url`.
+    ///
+    /// Work item URLs are of the form `https://dev.azure.com/{organization}/{project}/_apis/wit/workItems/{id}`.
+    #[must_use]
+    pub fn add_relation(self, rel: &str, url: &str) -> Self {
+        self.relation(rel, url, None)
+    }
+
+    /// Remove the relation at `index` in the work item's `relations`.
+    #[must_use]
+    pub fn remove_relation(self, index: usize) -> Self {
+        self.operation(Op::Remove, format!("/relations/{index}"), None)
+    }
+
+    /// Add an attachment, using the URL returned when it was uploaded.
+    #[must_use]
+    pub fn add_attachment(self, url: &str, comment: Option<&str>) -> Self {
+        self.relation(link_types::ATTACHED_FILE, url, comment)
+    }
+
+    /// Add a hyperlink.
+    #[must_use]
+    pub fn add_hyperlink(self, url: &str, comment: Option<&str>) -> Self {
+        self.relation(link_types::HYPERLINK, url, comment)
+    }
+
+    /// Fail the update if the work item revision is not `rev`.
+    ///
+    /// This prevents overwriting changes made since the work item was read.
+    #[must_use]
+    pub fn test_rev(self, rev: i32) -> Self {
+        self.operation(Op::Test, "/rev".to_string(), Some(json!(rev)))
+    }
+
+    /// Add an arbitrary operation.
+    #[must_use]
+    pub fn push(mut self, operation: JsonPatchOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// The operations in this patch document.
+    pub fn operations(&self) -> &[JsonPatchOperation] {
+        &self.operations
+    }
+
+    /// Returns `true` if there are no operations in this patch document.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn relation(self, rel: &str, url: &str, comment: Option<&str>) -> Self {
+        let mut value = json!({ "rel": rel, "url": url });
+        if let Some(comment) = comment {
+            value["attributes"] = json!({ "comment": comment });
+        }
+        self.operation(Op::Add, "/relations/-".to_string(), Some(value))
+    }
+
+    fn operation(self, op: Op, path: String, value: Option<Value>) -> Self {
+        self.push(JsonPatchOperation {
+            from: None,
+            op: Some(op),
+            path: Some(path),
+            value,
+        })
+    }
+}
+
+impl From<WorkItemPatch> for Vec<JsonPatchOperation> {
+    fn from(patch: WorkItemPatch) -> Self {
+        patch.operations
+    }
+}
+
+impl FromIterator<JsonPatchOperation> for WorkItemPatch {
+    fn from_iter<I: IntoIterator<Item = JsonPatchOperation>>(iter: I) -> Self {
+        Self {
+            operations: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_item_patch() {
+        let body: Vec<JsonPatchOperation> = WorkItemPatch::new()
+            .test_rev(3)
+            .set_field(field_names::TITLE, "Title")
+            .set_field(field_names::PRIORITY, 2)
+            .remove_field(field_names::TAGS)
+            .add_relation(
+                link_types::PARENT,
+                "https://dev.azure.com/org/project/_apis/wit/workItems/1",
+            )
+            .add_hyperlink("https://example.com", Some("Example"))
+            .remove_relation(0)
+            .into();
+
+        assert_eq!(
+            serde_json::to_value(&body).unwrap(),
+            json!([
+                { "op": "test", "path": "/rev", "value": 3 },
+                { "op": "add", "path": "/fields/System.Title", "value": "Title" },
+                { "op": "add", "path": "/fields/Microsoft.VSTS.Common.Priority", "value": 2 },
+                { "op": "remove", "path": "/fields/System.Tags" },
+                {
+                    "op": "add",
+                    "path": "/relations/-",
+                    "value": {
+                        "rel": "System.LinkTypes.Hierarchy-Reverse",
+                        "url": "https://dev.azure.com/org/project/_apis/wit/workItems/1"
+                    }
+                },
+                {
+                    "op": "add",
+                    "path": "/relations/-",
+                    "value": {
+                        "rel": "Hyperlink",
+                        "url": "https://example.com",
+                        "attributes": { "comment": "Example" }
+                    }
+                },
+                { "op": "remove", "path": "/relations/0" },
+            ])
+        );
+    }
+}
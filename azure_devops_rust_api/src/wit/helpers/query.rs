@@ -0,0 +1,463 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Run WIQL queries and fetch the resulting work items.
+//!
+//! The WIQL query API only returns work item ids, and `get_work_items_batch()` accepts at most
+//! 200 ids per request. [`query_work_items()`] runs the query, then fetches the work items in
+//! batches, several at a time, and returns them in query order:
+//!
+//! ```rust
+//!     let results = wit::query_work_items(&wit_client, organization, project, team, wiql)
+//!         .fields([field_names::TITLE, field_names::STATE])
+//!         .await?;
+//!     for work_item in results.work_items {
+//!         println!("{} {}", work_item.id, work_item.title().unwrap_or_default());
+//!     }
+//! ```
+//!
+//! Use `into_stream()` to process work items as each batch arrives rather than waiting for all of them.
+use crate::wit::models::{
+    work_item_batch_get_request::ErrorPolicy, Wiql, WorkItem, WorkItemBatchGetRequest,
+    WorkItemLink, WorkItemQueryResult,
+};
+use crate::wit::Client;
+use azure_core::error::{Error, ErrorKind};
+use futures::{StreamExt, TryStreamExt};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::future::IntoFuture;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures::stream::LocalBoxStream as BoxStream;
+
+/// The maximum number of work items that can be requested by a single `get_work_items_batch()` request.
+pub const MAX_WORK_ITEMS_PER_BATCH: usize = 200;
+
+/// The default number of `get_work_items_batch()` requests sent concurrently.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// A link between two work items, returned by a link query (`SELECT ... FROM WorkItemLinks`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkItemLinkPair {
+    /// The link type reference name, e.g. `System.LinkTypes.Hierarchy-Forward`.
+    ///
+    /// This is `None` for the top-level work items of a tree query.
+    pub rel: Option<String>,
+    /// The source work item id, `None` for the top-level work items of a tree query.
+    pub source: Option<i32>,
+    /// The target work item id.
+    pub target: i32,
+}
+
+impl WorkItemLinkPair {
+    fn from_link(link: &WorkItemLink) -> Option<Self> {
+        Some(Self {
+            rel: link.rel.clone(),
+            source: link.source.as_ref().and_then(|source| source.id),
+            target: link.target.as_ref()?.id?,
+        })
+    }
+}
+
+/// The results of [`query_work_items()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemQueryResults {
+    /// The work items, in query order.
+    ///
+    /// For link queries, these are all of the work items that appear in `links`.
+    pub work_items: Vec<WorkItem>,
+    /// The links returned by a link query, empty for flat queries.
+    pub links: Vec<WorkItemLinkPair>,
+}
+
+/// Runs a WIQL query and fetches the full work items it returns.
+///
+/// The returned builder can be awaited for a [`WorkItemQueryResults`], or converted into a
+/// stream of work items with `into_stream()`.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `team`: Team ID or team name
+/// * `wiql`: The text of the WIQL query
+pub fn query_work_items(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    team: impl Into<String>,
+    wiql: impl Into<String>,
+) -> QueryWorkItems {
+    QueryWorkItems {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        team: team.into(),
+        wiql: wiql.into(),
+        fields: Vec::new(),
+        top: None,
+        max_concurrency: DEFAULT_MAX_CONCURRENCY,
+    }
+}
+
+/// Request builder returned by [`query_work_items()`].
+#[derive(Clone)]
+pub struct QueryWorkItems {
+    client: Client,
+    organization: String,
+    project: String,
+    team: String,
+    wiql: String,
+    fields: Vec<String>,
+    top: Option<i32>,
+    max_concurrency: usize,
+}
+
+impl QueryWorkItems {
+    /// The fields to return for each work item. All fields are returned if not set.
+    #[must_use]
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The maximum number of results to return from the query.
+    #[must_use]
+    pub fn top(mut self, top: i32) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// The number of `get_work_items_batch()` requests to send concurrently.
+    #[must_use]
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Returns a stream of the work items, in query order.
+    ///
+    /// The query is run when the stream is first polled, and each batch of work items is
+    /// requested as the stream is consumed.
+    pub fn into_stream(self) -> BoxStream<'static, azure_core::Result<WorkItem>> {
+        Box::pin(
+            futures::stream::once(async move {
+                let result = self.query().await?;
+                let ids = work_item_ids(&result);
+                Ok::<_, azure_core::Error>(self.work_items(ids))
+            })
+            .try_flatten(),
+        )
+    }
+
+    async fn query(&self) -> azure_core::Result<WorkItemQueryResult> {
+        let mut request = self.client.wiql_client().query_by_wiql(
+            &self.organization,
+            Wiql {
+                query: Some(self.wiql.clone()),
+            },
+            &self.project,
+            &self.team,
+        );
+        if let Some(top) = self.top {
+            request = request.top(top);
+        }
+        request.await
+    }
+
+    fn work_items(self, ids: Vec<i32>) -> BoxStream<'static, azure_core::Result<WorkItem>> {
+        get_work_items(
+            &self.client,
+            &self.organization,
+            &self.project,
+            ids,
+            self.batch_request(),
+            self.max_concurrency,
+        )
+    }
+
+    fn batch_request(&self) -> WorkItemBatchGetRequest {
+        // Work items deleted or made inaccessible since the query ran are omitted rather than
+        // failing the query
+        WorkItemBatchGetRequest {
+            fields: self.fields.clone(),
+            error_policy: Some(ErrorPolicy::Omit),
+            ..Default::default()
+        }
+    }
+}
+
+/// Gets work items in batches of [`MAX_WORK_ITEMS_PER_BATCH`], sending up to `max_concurrency`
+/// requests at a time, and returns them in the order of `ids`.
+///
+/// `request` holds the options for each batch request, its `ids` are ignored.
+/// Work items that the service returns as `null` (with `ErrorPolicy::Omit`) are skipped.
+pub(crate) fn get_work_items(
+    client: &Client,
+    organization: &str,
+    project: &str,
+    ids: Vec<i32>,
+    request: WorkItemBatchGetRequest,
+    max_concurrency: usize,
+) -> BoxStream<'static, azure_core::Result<WorkItem>> {
+    let client = client.clone();
+    let organization = organization.to_string();
+    let project = project.to_string();
+    let batches: Vec<Vec<i32>> = ids
+        .chunks(MAX_WORK_ITEMS_PER_BATCH)
+        .map(<[i32]>::to_vec)
+        .collect();
+    Box::pin(
+        futures::stream::iter(batches)
+            .map(move |ids| {
+                let response = client
+                    .work_items_client()
+                    .get_work_items_batch(
+                        &organization,
+                        WorkItemBatchGetRequest {
+                            ids: ids.clone(),
+                            ..request.clone()
+                        },
+                        &project,
+                    )
+                    .send();
+                async move {
+                    let bytes = response
+                        .await?
+                        .into_raw_response()
+                        .into_body()
+                        .collect()
+                        .await?;
+                    Ok::<_, azure_core::Error>(in_id_order(&ids, WorkItemBatch::parse(&bytes)?))
+                }
+            })
+            .buffered(max_concurrency.max(1))
+            .map_ok(|work_items| futures::stream::iter(work_items.into_iter().map(Ok)))
+            .try_flatten(),
+    )
+}
+
+/// The body of a `get_work_items_batch()` response.
+///
+/// With `ErrorPolicy::Omit`, work items that could not be fetched (e.g. deleted or inaccessible)
+/// are returned as `null`, which `WorkItemList` cannot represent.
+#[derive(Deserialize)]
+struct WorkItemBatch {
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default"
+    )]
+    value: Vec<Option<WorkItem>>,
+}
+
+impl WorkItemBatch {
+    /// Parses the response body, dropping the work items that could not be fetched.
+    fn parse(bytes: &[u8]) -> azure_core::Result<Vec<WorkItem>> {
+        let batch: Self = serde_json::from_slice(bytes).map_err(|e| {
+            Error::full(
+                ErrorKind::DataConversion,
+                e,
+                format!(
+                    "Failed to deserialize response:\n{}",
+                    String::from_utf8_lossy(bytes)
+                ),
+            )
+        })?;
+        Ok(batch.value.into_iter().flatten().collect())
+    }
+}
+
+impl IntoFuture for QueryWorkItems {
+    type Output = azure_core::Result<WorkItemQueryResults>;
+    type IntoFuture = BoxFuture<'static, azure_core::Result<WorkItemQueryResults>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let result = self.query().await?;
+            let ids = work_item_ids(&result);
+            let links = result
+                .work_item_relations
+                .iter()
+                .filter_map(WorkItemLinkPair::from_link)
+                .collect();
+            let work_items = self.work_items(ids).try_collect().await?;
+            Ok(WorkItemQueryResults { work_items, links })
+        })
+    }
+}
+
+/// The ids of the work items returned by a query, in query order.
+///
+/// Link queries return links rather than work items, so the ids are taken from the links,
+/// in order of first appearance.
+fn work_item_ids(result: &WorkItemQueryResult) -> Vec<i32> {
+    if !result.work_items.is_empty() {
+        return result
+            .work_items
+            .iter()
+            .filter_map(|work_item| work_item.id)
+            .collect();
+    }
+    let mut seen = HashSet::new();
+    result
+        .work_item_relations
+        .iter()
+        .flat_map(|link| [link.source.as_ref(), link.target.as_ref()])
+        .filter_map(|work_item| work_item?.id)
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+/// Sorts a batch of work items into the order of the requested ids.
+fn in_id_order(ids: &[i32], mut work_items: Vec<WorkItem>) -> Vec<WorkItem> {
+    let position: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    work_items.sort_by_key(|work_item| position.get(&work_item.id).copied().unwrap_or(usize::MAX));
+    work_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use azure_core::{headers::Headers, Context, Policy, PolicyResult, Request, Response};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    /// Responds to every request with a `get_work_items_batch()` body.
+    #[derive(Debug)]
+    struct MockTransport(serde_json::Value);
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Policy for MockTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let body = bytes::Bytes::from(self.0.to_string());
+            Ok(Response::new(
+                azure_core::StatusCode::Ok,
+                Headers::new(),
+                Box::pin(futures::stream::once(futures::future::ready(Ok(body)))),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_work_item_batch() {
+        let body = json!({
+            "count": 3,
+            "value": [
+                { "id": 1, "rev": 2, "fields": {}, "url": "https://dev.azure.com/org/project/_apis/wit/workItems/1" },
+                null,
+                { "id": 3, "rev": 1, "fields": {}, "url": "https://dev.azure.com/org/project/_apis/wit/workItems/3" }
+            ]
+        });
+        let work_items = WorkItemBatch::parse(body.to_string().as_bytes()).unwrap();
+        let ids: Vec<i32> = work_items.iter().map(|work_item| work_item.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+
+        let work_items = WorkItemBatch::parse(br#"{"count": 0, "value": null}"#).unwrap();
+        assert!(work_items.is_empty());
+        assert!(WorkItemBatch::parse(b"not json").is_err());
+    }
+
+    #[test]
+    fn test_get_work_items() {
+        let transport = MockTransport(json!({
+            "count": 3,
+            "value": [
+                { "id": 1, "rev": 2, "fields": {}, "url": "https://dev.azure.com/org/project/_apis/wit/workItems/1" },
+                null,
+                { "id": 3, "rev": 1, "fields": {}, "url": "https://dev.azure.com/org/project/_apis/wit/workItems/3" }
+            ]
+        }));
+        let client = crate::wit::ClientBuilder::new(crate::Credential::from_pat("abc"))
+            .transport(azure_core::TransportOptions::new_custom_policy(Arc::new(
+                transport,
+            )))
+            .build();
+        let work_items: Vec<WorkItem> = futures::executor::block_on(
+            get_work_items(
+                &client,
+                "org",
+                "project",
+                vec![3, 2, 1],
+                WorkItemBatchGetRequest::default(),
+                DEFAULT_MAX_CONCURRENCY,
+            )
+            .try_collect(),
+        )
+        .unwrap();
+        let ids: Vec<i32> = work_items.iter().map(|work_item| work_item.id).collect();
+        assert_eq!(ids, vec![3, 1]);
+    }
+
+    #[test]
+    fn test_batch_request() {
+        let client = crate::wit::ClientBuilder::new(crate::Credential::from_pat("abc")).build();
+        let request = query_work_items(&client, "org", "project", "team", "SELECT [System.Id]")
+            .fields(["System.Title"])
+            .batch_request();
+        assert_eq!(request.fields, ["System.Title"]);
+        assert_eq!(request.error_policy, Some(ErrorPolicy::Omit));
+    }
+
+    #[test]
+    fn test_work_item_ids() {
+        let result: WorkItemQueryResult = serde_json::from_value(json!({
+            "workItems": [{ "id": 3 }, { "id": 1 }, { "id": 2 }]
+        }))
+        .unwrap();
+        assert_eq!(work_item_ids(&result), vec![3, 1, 2]);
+
+        let result: WorkItemQueryResult = serde_json::from_value(json!({
+            "workItemRelations": [
+                { "target": { "id": 1 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 5 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 1 }, "target": { "id": 4 } },
+                { "rel": "System.LinkTypes.Hierarchy-Forward", "source": { "id": 5 }, "target": { "id": 4 } }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(work_item_ids(&result), vec![1, 5, 4]);
+        let links: Vec<_> = result
+            .work_item_relations
+            .iter()
+            .filter_map(WorkItemLinkPair::from_link)
+            .collect();
+        assert_eq!(links.len(), 4);
+        assert_eq!(
+            links[0],
+            WorkItemLinkPair {
+                rel: None,
+                source: None,
+                target: 1
+            }
+        );
+        assert_eq!(links[3].source, Some(5));
+    }
+
+    #[test]
+    fn test_in_id_order() {
+        let work_item = |id: i32| -> WorkItem {
+            serde_json::from_value(json!({ "id": id, "fields": {}, "url": "" })).unwrap()
+        };
+        let work_items = in_id_order(&[3, 1, 2], vec![work_item(1), work_item(2), work_item(3)]);
+        let ids: Vec<i32> = work_items.iter().map(|work_item| work_item.id).collect();
+        assert_eq!(ids, vec![3, 1, 2]);
+    }
+}
@@ -0,0 +1,422 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Incremental sync of work item revisions.
+//!
+//! The reporting revisions API returns all work item revisions since a watermark, in batches.
+//! [`sync_revisions()`] streams the batches since the last saved watermark and saves the new
+//! watermark to a [`WatermarkStore`], so that the next sync, or a restart after a crash,
+//! continues from where it stopped:
+//!
+//! ```rust
+//!     let store = wit::FileWatermarkStore::new("revisions.watermark");
+//!     let mut batches = wit::sync_revisions(&wit_client, organization, project, store)
+//!         .fields([field_names::TITLE, field_names::STATE])
+//!         .into_stream();
+//!     while let Some(batch) = batches.try_next().await? {
+//!         warehouse.insert(batch.revisions).await?;
+//!     }
+//! ```
+//!
+//! A batch's watermark is saved when the next batch is requested, or when the stream ends,
+//! so a batch that was not fully processed is returned again by the next sync.
+use crate::wit::models::{
+    ReportingWorkItemRevision, ReportingWorkItemRevisionsBatch, ReportingWorkItemRevisionsFilter,
+};
+use crate::wit::Client;
+use async_trait::async_trait;
+use std::future::IntoFuture;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures::stream::LocalBoxStream as BoxStream;
+
+/// Persists the watermark of a revisions sync.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait WatermarkStore: Send + Sync {
+    /// Loads the saved watermark, `None` if the sync has not run before.
+    async fn load(&self) -> azure_core::Result<Option<String>>;
+
+    /// Saves the watermark.
+    async fn save(&self, watermark: &str) -> azure_core::Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T: WatermarkStore + ?Sized> WatermarkStore for Arc<T> {
+    async fn load(&self) -> azure_core::Result<Option<String>> {
+        (**self).load().await
+    }
+
+    async fn save(&self, watermark: &str) -> azure_core::Result<()> {
+        (**self).save(watermark).await
+    }
+}
+
+/// A [`WatermarkStore`] that keeps the watermark in memory.
+#[derive(Debug, Default)]
+pub struct MemoryWatermarkStore {
+    watermark: Mutex<Option<String>>,
+}
+
+impl MemoryWatermarkStore {
+    pub fn new(watermark: Option<String>) -> Self {
+        Self {
+            watermark: Mutex::new(watermark),
+        }
+    }
+
+    /// The current watermark.
+    pub fn watermark(&self) -> Option<String> {
+        self.watermark.lock().unwrap().clone()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl WatermarkStore for MemoryWatermarkStore {
+    async fn load(&self) -> azure_core::Result<Option<String>> {
+        Ok(self.watermark())
+    }
+
+    async fn save(&self, watermark: &str) -> azure_core::Result<()> {
+        *self.watermark.lock().unwrap() = Some(watermark.to_string());
+        Ok(())
+    }
+}
+
+/// A [`WatermarkStore`] that keeps the watermark in a file.
+///
+/// The file is replaced atomically, so it is never left partially written.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileWatermarkStore {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileWatermarkStore {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl WatermarkStore for FileWatermarkStore {
+    async fn load(&self) -> azure_core::Result<Option<String>> {
+        use azure_core::error::{ErrorKind, ResultExt};
+        match std::fs::read_to_string(&self.path) {
+            Ok(watermark) => Ok(Some(watermark.trim().to_string()).filter(|w| !w.is_empty())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(ErrorKind::Io, || {
+                format!("failed to read watermark from {}", self.path.display())
+            }),
+        }
+    }
+
+    async fn save(&self, watermark: &str) -> azure_core::Result<()> {
+        use azure_core::error::{ErrorKind, ResultExt};
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        std::fs::write(&temp_path, watermark)
+            .and_then(|()| std::fs::rename(&temp_path, &self.path))
+            .with_context(ErrorKind::Io, || {
+                format!("failed to save watermark to {}", self.path.display())
+            })
+    }
+}
+
+/// A batch of work item revisions returned by [`sync_revisions()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevisionBatch {
+    /// The revisions, in the order they were made.
+    pub revisions: Vec<ReportingWorkItemRevision>,
+    /// The watermark after this batch.
+    pub watermark: Option<String>,
+    /// `true` if there are no more revisions available, for now.
+    pub is_last_batch: bool,
+}
+
+/// Streams the work item revisions made since the watermark saved in `store`.
+///
+/// Arguments:
+/// * `client`: The work item tracking client.
+/// * `organization`: The name of the Azure DevOps organization.
+/// * `project`: Project ID or project name
+/// * `store`: Where the watermark is loaded from and saved to.
+pub fn sync_revisions(
+    client: &Client,
+    organization: impl Into<String>,
+    project: impl Into<String>,
+    store: impl WatermarkStore + 'static,
+) -> SyncRevisions {
+    SyncRevisions {
+        client: client.clone(),
+        organization: organization.into(),
+        project: project.into(),
+        store: Arc::new(store),
+        filter: ReportingWorkItemRevisionsFilter::default(),
+        start_date_time: None,
+    }
+}
+
+/// Request builder returned by [`sync_revisions()`].
+#[derive(Clone)]
+pub struct SyncRevisions {
+    client: Client,
+    organization: String,
+    project: String,
+    store: Arc<dyn WatermarkStore>,
+    filter: ReportingWorkItemRevisionsFilter,
+    start_date_time: Option<OffsetDateTime>,
+}
+
+impl SyncRevisions {
+    /// The fields to return for each revision. All reportable fields are returned if not set.
+    #[must_use]
+    pub fn fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.filter.fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The work item types to return. All types are returned if not set.
+    #[must_use]
+    pub fn types<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.filter.types = types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Include revisions of deleted work items.
+    #[must_use]
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.filter.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Return identity references rather than strings for identity fields.
+    #[must_use]
+    pub fn include_identity_ref(mut self, include_identity_ref: bool) -> Self {
+        self.filter.include_identity_ref = Some(include_identity_ref);
+        self
+    }
+
+    /// Where to start the first sync, when `store` has no watermark. Defaults to the first revision.
+    #[must_use]
+    pub fn start_date_time(mut self, start_date_time: OffsetDateTime) -> Self {
+        self.start_date_time = Some(start_date_time);
+        self
+    }
+
+    /// Returns a stream of the revision batches since the saved watermark.
+    pub fn into_stream(self) -> BoxStream<'static, azure_core::Result<RevisionBatch>> {
+        let store = self.store.clone();
+        revision_batches(store, move |watermark| {
+            let mut request = self
+                .client
+                .reporting_work_item_revisions_client()
+                .read_reporting_revisions_post(
+                    &self.organization,
+                    self.filter.clone(),
+                    &self.project,
+                );
+            match (watermark, self.start_date_time) {
+                (Some(watermark), _) => request = request.continuation_token(watermark),
+                (None, Some(start_date_time)) => request = request.start_date_time(start_date_time),
+                (None, None) => {}
+            }
+            request.into_future()
+        })
+    }
+}
+
+struct SyncState<F> {
+    store: Arc<dyn WatermarkStore>,
+    fetch: F,
+    /// The watermark to request the next batch from, `None` before it is loaded.
+    watermark: Option<Option<String>>,
+    /// The watermark of the last returned batch, to save once it has been processed.
+    unsaved: Option<String>,
+    done: bool,
+}
+
+impl<F> SyncState<F>
+where
+    F: FnMut(
+        Option<String>,
+    ) -> BoxFuture<'static, azure_core::Result<ReportingWorkItemRevisionsBatch>>,
+{
+    async fn next_batch(&mut self) -> azure_core::Result<Option<RevisionBatch>> {
+        if let Some(watermark) = self.unsaved.take() {
+            self.store.save(&watermark).await?;
+        }
+        if self.done {
+            return Ok(None);
+        }
+        let watermark = match self.watermark.take() {
+            Some(watermark) => watermark,
+            None => self.store.load().await?,
+        };
+        let batch = (self.fetch)(watermark.clone()).await?;
+        let is_last_batch =
+            batch.is_last_batch.unwrap_or(false) || batch.continuation_token.is_none();
+        self.done = is_last_batch;
+        self.watermark = Some(batch.continuation_token.clone().or(watermark));
+        if batch.values.is_empty() && is_last_batch {
+            // Nothing to process, so the watermark can be saved now.
+            if let Some(watermark) = &batch.continuation_token {
+                self.store.save(watermark).await?;
+            }
+            return Ok(None);
+        }
+        self.unsaved = batch.continuation_token.clone();
+        Ok(Some(RevisionBatch {
+            revisions: batch.values,
+            watermark: batch.continuation_token,
+            is_last_batch,
+        }))
+    }
+}
+
+/// Returns the batches fetched from the watermark in `store`, saving each batch's watermark
+/// when the next batch is requested.
+fn revision_batches<F>(
+    store: Arc<dyn WatermarkStore>,
+    fetch: F,
+) -> BoxStream<'static, azure_core::Result<RevisionBatch>>
+where
+    F: FnMut(
+            Option<String>,
+        ) -> BoxFuture<'static, azure_core::Result<ReportingWorkItemRevisionsBatch>>
+        + Send
+        + 'static,
+{
+    let state = SyncState {
+        store,
+        fetch,
+        watermark: None,
+        unsaved: None,
+        done: false,
+    };
+    Box::pin(futures::stream::unfold(state, |mut state| async move {
+        match state.next_batch().await {
+            Ok(Some(batch)) => Some((Ok(batch), state)),
+            Ok(None) => None,
+            Err(e) => {
+                state.done = true;
+                Some((Err(e), state))
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use serde_json::json;
+    use std::collections::VecDeque;
+
+    fn batch(token: &str, ids: &[i32], is_last_batch: bool) -> ReportingWorkItemRevisionsBatch {
+        serde_json::from_value(json!({
+            "continuationToken": token,
+            "isLastBatch": is_last_batch,
+            "values": ids.iter().map(|id| json!({ "id": id, "rev": 1, "fields": {} })).collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    /// Returns a fetch function that returns `batches` in turn and records the requested watermarks.
+    fn fetch(
+        batches: Vec<ReportingWorkItemRevisionsBatch>,
+        requests: Arc<Mutex<Vec<Option<String>>>>,
+    ) -> impl FnMut(
+        Option<String>,
+    ) -> BoxFuture<'static, azure_core::Result<ReportingWorkItemRevisionsBatch>>
+           + Send
+           + 'static {
+        let mut batches = VecDeque::from(batches);
+        move |watermark| {
+            requests.lock().unwrap().push(watermark);
+            let batch = batches.pop_front().expect("unexpected request");
+            Box::pin(async move { Ok(batch) })
+        }
+    }
+
+    #[test]
+    fn test_revision_batches() {
+        let store = Arc::new(MemoryWatermarkStore::new(Some("w0".to_string())));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let batches = vec![
+            batch("w1", &[1, 2], false),
+            batch("w2", &[3], false),
+            batch("w3", &[], true),
+        ];
+        let mut stream = revision_batches(store.clone(), fetch(batches, requests.clone()));
+        futures::executor::block_on(async {
+            let first = stream.try_next().await.unwrap().unwrap();
+            assert_eq!(first.revisions.len(), 2);
+            assert_eq!(first.watermark.as_deref(), Some("w1"));
+            // Not saved until the batch has been processed.
+            assert_eq!(store.watermark().as_deref(), Some("w0"));
+
+            let second = stream.try_next().await.unwrap().unwrap();
+            assert_eq!(second.revisions[0].id, 3);
+            assert_eq!(store.watermark().as_deref(), Some("w1"));
+
+            assert!(stream.try_next().await.unwrap().is_none());
+            assert_eq!(store.watermark().as_deref(), Some("w3"));
+        });
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                Some("w0".to_string()),
+                Some("w1".to_string()),
+                Some("w2".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_revision_batches_resume() {
+        let store = Arc::new(MemoryWatermarkStore::default());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let batches = vec![batch("w1", &[1], false), batch("w2", &[2], true)];
+        let mut stream = revision_batches(store.clone(), fetch(batches, requests.clone()));
+        futures::executor::block_on(async {
+            let first = stream.try_next().await.unwrap().unwrap();
+            assert_eq!(first.revisions[0].id, 1);
+            // Stop before the first batch is processed, as if the process crashed.
+            drop(stream);
+            assert_eq!(store.watermark(), None);
+
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let batches = vec![batch("w1", &[1], false), batch("w2", &[2], true)];
+            let stream = revision_batches(store.clone(), fetch(batches, requests.clone()));
+            let batches: Vec<RevisionBatch> = stream.try_collect().await.unwrap();
+            assert_eq!(batches.len(), 2);
+            assert!(batches[1].is_last_batch);
+            assert_eq!(store.watermark().as_deref(), Some("w2"));
+            assert_eq!(
+                *requests.lock().unwrap(),
+                vec![None, Some("w1".to_string())]
+            );
+        });
+    }
+}
@@ -0,0 +1,728 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! WIQL query builder.
+//!
+//! [`WiqlQuery`] builds the text of a [Work Item Query Language](https://learn.microsoft.com/en-us/azure/devops/boards/queries/wiql-syntax)
+//! query, quoting and escaping values so that paths and titles containing `'` are handled correctly:
+//!
+//! ```rust
+//!     use azure_devops_rust_api::wit::{field, field_names, models::Wiql, WiqlValue};
+//!
+//!     let query = Wiql::select([field_names::ID, field_names::TITLE])
+//!         .from_work_items()
+//!         .where_(field(field_names::TEAM_PROJECT).eq(WiqlValue::Project))
+//!         .and(field(field_names::AREA_PATH).under(r"My Project\Bob's Team"))
+//!         .and(field(field_names::ASSIGNED_TO).eq(WiqlValue::Me))
+//!         .and(field(field_names::CHANGED_DATE).ge(WiqlValue::days_ago(7)))
+//!         .order_by_desc(field_names::CHANGED_DATE)
+//!         .build()?;
+//!     let result = wit_client
+//!         .wiql_client()
+//!         .query_by_wiql(organization, query, project, team)
+//!         .await?;
+//! ```
+//!
+//! Link queries (`FROM WorkItemLinks`) compare fields of the source and target work items,
+//! see [`source_field()`] and [`target_field()`].
+use super::field_names;
+use crate::wit::models::Wiql;
+use azure_core::error::{Error, ErrorKind};
+use time::{OffsetDateTime, UtcOffset};
+
+/// The maximum length of a WIQL query.
+pub const MAX_WIQL_LENGTH: usize = 32768;
+
+/// The link type reference name field of a link query.
+const LINK_TYPE: &str = "System.Links.LinkType";
+
+impl Wiql {
+    /// Start building a query that returns the given fields.
+    pub fn select<I, S>(fields: I) -> WiqlQuery
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        WiqlQuery::select(fields)
+    }
+}
+
+/// The source of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WiqlSource {
+    /// `FROM WorkItems`, which returns a list of work items.
+    WorkItems,
+    /// `FROM WorkItemLinks`, which returns links between work items.
+    WorkItemLinks,
+}
+
+/// The `MODE` of a link query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkQueryMode {
+    /// Return only source work items that have a matching link.
+    MustContain,
+    /// Return all matching source work items, whether or not they have a matching link.
+    MayContain,
+    /// Return only source work items that have no matching link.
+    DoesNotContain,
+    /// Return a tree of work items, following a tree link type such as `System.LinkTypes.Hierarchy-Forward`.
+    Recursive,
+}
+
+impl LinkQueryMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LinkQueryMode::MustContain => "MustContain",
+            LinkQueryMode::MayContain => "MayContain",
+            LinkQueryMode::DoesNotContain => "DoesNotContain",
+            LinkQueryMode::Recursive => "Recursive",
+        }
+    }
+}
+
+/// A value in a WIQL condition.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WiqlValue {
+    String(String),
+    Integer(i64),
+    /// A floating point number. NaN and infinite values are rejected when the query is built.
+    Float(f64),
+    Bool(bool),
+    /// A date and time, sent as UTC.
+    ///
+    /// Queries that compare times rather than dates must be sent with `time_precision(true)`.
+    DateTime(OffsetDateTime),
+    /// Another field, e.g. `[System.ChangedDate] > [System.CreatedDate]`.
+    Field(String),
+    /// `@Me`, the current user.
+    Me,
+    /// `@Today`, offset by the given number of days, e.g. `@Today - 7`.
+    Today(i32),
+    /// `@Project`, the project the query is run in.
+    Project,
+    /// `@CurrentIteration`, the current iteration of the team the query is run for.
+    CurrentIteration,
+}
+
+impl WiqlValue {
+    /// `@Today`
+    pub fn today() -> Self {
+        WiqlValue::Today(0)
+    }
+
+    /// `@Today - days`
+    pub fn days_ago(days: i32) -> Self {
+        WiqlValue::Today(-days)
+    }
+
+    fn write(&self, f: &mut String) -> azure_core::Result<()> {
+        match self {
+            WiqlValue::String(value) => write_string(f, value),
+            WiqlValue::Integer(value) => f.push_str(&value.to_string()),
+            WiqlValue::Float(value) if !value.is_finite() => {
+                return Err(invalid(format!("invalid float value: {value}")))
+            }
+            WiqlValue::Float(value) => f.push_str(&value.to_string()),
+            WiqlValue::Bool(value) => f.push_str(&value.to_string()),
+            WiqlValue::DateTime(value) => write_string(
+                f,
+                &crate::date_time::format_date_time(&value.to_offset(UtcOffset::UTC))?,
+            ),
+            WiqlValue::Field(reference_name) => write_field(f, None, reference_name)?,
+            WiqlValue::Me => f.push_str("@Me"),
+            WiqlValue::Today(0) => f.push_str("@Today"),
+            WiqlValue::Today(days) if *days < 0 => {
+                f.push_str(&format!("@Today - {}", days.unsigned_abs()))
+            }
+            WiqlValue::Today(days) => f.push_str(&format!("@Today + {days}")),
+            WiqlValue::Project => f.push_str("@Project"),
+            WiqlValue::CurrentIteration => f.push_str("@CurrentIteration"),
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for WiqlValue {
+    fn from(value: &str) -> Self {
+        WiqlValue::String(value.to_string())
+    }
+}
+
+impl From<String> for WiqlValue {
+    fn from(value: String) -> Self {
+        WiqlValue::String(value)
+    }
+}
+
+impl From<i32> for WiqlValue {
+    fn from(value: i32) -> Self {
+        WiqlValue::Integer(value.into())
+    }
+}
+
+impl From<i64> for WiqlValue {
+    fn from(value: i64) -> Self {
+        WiqlValue::Integer(value)
+    }
+}
+
+impl From<f64> for WiqlValue {
+    fn from(value: f64) -> Self {
+        WiqlValue::Float(value)
+    }
+}
+
+impl From<bool> for WiqlValue {
+    fn from(value: bool) -> Self {
+        WiqlValue::Bool(value)
+    }
+}
+
+impl From<OffsetDateTime> for WiqlValue {
+    fn from(value: OffsetDateTime) -> Self {
+        WiqlValue::DateTime(value)
+    }
+}
+
+/// Which work item of a link query a field belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Source,
+    Target,
+}
+
+/// A field in a WIQL condition, created by [`field()`], [`source_field()`] or [`target_field()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WiqlField {
+    side: Option<Side>,
+    reference_name: String,
+}
+
+/// A field of the work items in the query, e.g. `field(field_names::STATE).eq("Active")`.
+pub fn field(reference_name: impl Into<String>) -> WiqlField {
+    WiqlField {
+        side: None,
+        reference_name: reference_name.into(),
+    }
+}
+
+/// A field of the source work item of a link query.
+pub fn source_field(reference_name: impl Into<String>) -> WiqlField {
+    WiqlField {
+        side: Some(Side::Source),
+        reference_name: reference_name.into(),
+    }
+}
+
+/// A field of the target work item of a link query.
+pub fn target_field(reference_name: impl Into<String>) -> WiqlField {
+    WiqlField {
+        side: Some(Side::Target),
+        reference_name: reference_name.into(),
+    }
+}
+
+/// The link type of a link query, e.g. `link_type().eq(link_types::CHILD)`.
+pub fn link_type() -> WiqlField {
+    field(LINK_TYPE)
+}
+
+impl WiqlField {
+    fn compare(self, operator: &'static str, value: Option<WiqlValue>) -> WiqlCondition {
+        WiqlCondition(Condition::Compare {
+            field: self,
+            operator,
+            value: value.map(Operand::Value),
+        })
+    }
+
+    fn compare_list<I, V>(self, operator: &'static str, values: I) -> WiqlCondition
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<WiqlValue>,
+    {
+        WiqlCondition(Condition::Compare {
+            field: self,
+            operator,
+            value: Some(Operand::List(values.into_iter().map(Into::into).collect())),
+        })
+    }
+
+    /// `=`
+    pub fn eq(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("=", Some(value.into()))
+    }
+
+    /// `<>`
+    pub fn ne(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("<>", Some(value.into()))
+    }
+
+    /// `>`
+    pub fn gt(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare(">", Some(value.into()))
+    }
+
+    /// `>=`
+    pub fn ge(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare(">=", Some(value.into()))
+    }
+
+    /// `<`
+    pub fn lt(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("<", Some(value.into()))
+    }
+
+    /// `<=`
+    pub fn le(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("<=", Some(value.into()))
+    }
+
+    /// `CONTAINS`, a substring match.
+    pub fn contains(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("CONTAINS", Some(value.into()))
+    }
+
+    /// `NOT CONTAINS`
+    pub fn not_contains(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("NOT CONTAINS", Some(value.into()))
+    }
+
+    /// `CONTAINS WORDS`, a full-text match, for long text fields.
+    pub fn contains_words(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("CONTAINS WORDS", Some(value.into()))
+    }
+
+    /// `NOT CONTAINS WORDS`
+    pub fn not_contains_words(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("NOT CONTAINS WORDS", Some(value.into()))
+    }
+
+    /// `UNDER`, for area and iteration paths.
+    pub fn under(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("UNDER", Some(value.into()))
+    }
+
+    /// `NOT UNDER`
+    pub fn not_under(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("NOT UNDER", Some(value.into()))
+    }
+
+    /// `EVER`, whether the field ever had the value.
+    pub fn ever(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("EVER", Some(value.into()))
+    }
+
+    /// `IN GROUP`, whether the field is in a group or work item type category.
+    pub fn in_group(self, value: impl Into<WiqlValue>) -> WiqlCondition {
+        self.compare("IN GROUP", Some(value.into()))
+    }
+
+    /// `IN (...)`
+    pub fn in_<I, V>(self, values: I) -> WiqlCondition
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<WiqlValue>,
+    {
+        self.compare_list("IN", values)
+    }
+
+    /// `NOT IN (...)`
+    pub fn not_in<I, V>(self, values: I) -> WiqlCondition
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<WiqlValue>,
+    {
+        self.compare_list("NOT IN", values)
+    }
+
+    /// `IS EMPTY`
+    pub fn is_empty(self) -> WiqlCondition {
+        self.compare("IS EMPTY", None)
+    }
+
+    /// `IS NOT EMPTY`
+    pub fn is_not_empty(self) -> WiqlCondition {
+        self.compare("IS NOT EMPTY", None)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Value(WiqlValue),
+    List(Vec<WiqlValue>),
+}
+
+/// A WIQL condition, combined with [`WiqlCondition::and()`] and [`WiqlCondition::or()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WiqlCondition(Condition);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Compare {
+        field: WiqlField,
+        operator: &'static str,
+        value: Option<Operand>,
+    },
+    And(Vec<WiqlCondition>),
+    Or(Vec<WiqlCondition>),
+}
+
+impl WiqlCondition {
+    /// Both conditions must match.
+    #[must_use]
+    pub fn and(self, other: WiqlCondition) -> Self {
+        match self.0 {
+            Condition::And(mut conditions) => {
+                conditions.push(other);
+                WiqlCondition(Condition::And(conditions))
+            }
+            condition => WiqlCondition(Condition::And(vec![WiqlCondition(condition), other])),
+        }
+    }
+
+    /// Either condition must match.
+    #[must_use]
+    pub fn or(self, other: WiqlCondition) -> Self {
+        match self.0 {
+            Condition::Or(mut conditions) => {
+                conditions.push(other);
+                WiqlCondition(Condition::Or(conditions))
+            }
+            condition => WiqlCondition(Condition::Or(vec![WiqlCondition(condition), other])),
+        }
+    }
+
+    fn write(&self, f: &mut String, source: WiqlSource) -> azure_core::Result<()> {
+        match &self.0 {
+            Condition::Compare {
+                field,
+                operator,
+                value,
+            } => {
+                if field.side.is_some() && source == WiqlSource::WorkItems {
+                    return Err(invalid(format!(
+                        "[Source] and [Target] fields can only be used in link queries: {}",
+                        field.reference_name
+                    )));
+                }
+                write_field(f, field.side, &field.reference_name)?;
+                f.push(' ');
+                f.push_str(operator);
+                match value {
+                    Some(Operand::Value(value)) => {
+                        f.push(' ');
+                        value.write(f)?;
+                    }
+                    Some(Operand::List(values)) => {
+                        if values.is_empty() {
+                            return Err(invalid(format!(
+                                "{operator} requires at least one value: {}",
+                                field.reference_name
+                            )));
+                        }
+                        f.push_str(" (");
+                        for (i, value) in values.iter().enumerate() {
+                            if i > 0 {
+                                f.push_str(", ");
+                            }
+                            value.write(f)?;
+                        }
+                        f.push(')');
+                    }
+                    None => {}
+                }
+            }
+            Condition::And(conditions) => write_conditions(f, conditions, " AND ", source)?,
+            Condition::Or(conditions) => write_conditions(f, conditions, " OR ", source)?,
+        }
+        Ok(())
+    }
+}
+
+fn write_conditions(
+    f: &mut String,
+    conditions: &[WiqlCondition],
+    separator: &str,
+    source: WiqlSource,
+) -> azure_core::Result<()> {
+    for (i, condition) in conditions.iter().enumerate() {
+        if i > 0 {
+            f.push_str(separator);
+        }
+        // Nested `AND`/`OR` conditions are grouped so that the precedence is as built
+        if matches!(condition.0, Condition::And(_) | Condition::Or(_)) {
+            f.push('(');
+            condition.write(f, source)?;
+            f.push(')');
+        } else {
+            condition.write(f, source)?;
+        }
+    }
+    Ok(())
+}
+
+/// A WIQL query, created by [`Wiql::select()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WiqlQuery {
+    fields: Vec<String>,
+    source: WiqlSource,
+    condition: Option<WiqlCondition>,
+    order_by: Vec<(String, bool)>,
+    as_of: Option<OffsetDateTime>,
+    mode: Option<LinkQueryMode>,
+}
+
+impl WiqlQuery {
+    /// Start building a query that returns the given fields.
+    pub fn select<I, S>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+            source: WiqlSource::WorkItems,
+            condition: None,
+            order_by: Vec::new(),
+            as_of: None,
+            mode: None,
+        }
+    }
+
+    /// Query work items (`FROM WorkItems`). This is the default.
+    #[must_use]
+    pub fn from_work_items(mut self) -> Self {
+        self.source = WiqlSource::WorkItems;
+        self
+    }
+
+    /// Query links between work items (`FROM WorkItemLinks`).
+    #[must_use]
+    pub fn from_work_item_links(mut self) -> Self {
+        self.source = WiqlSource::WorkItemLinks;
+        self
+    }
+
+    /// Set the `WHERE` condition, replacing any existing condition.
+    #[must_use]
+    pub fn where_(mut self, condition: WiqlCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Add a condition that must also match.
+    #[must_use]
+    pub fn and(mut self, condition: WiqlCondition) -> Self {
+        self.condition = Some(match self.condition {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// Add a condition that may match instead.
+    #[must_use]
+    pub fn or(mut self, condition: WiqlCondition) -> Self {
+        self.condition = Some(match self.condition {
+            Some(existing) => existing.or(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// Sort by a field, ascending.
+    #[must_use]
+    pub fn order_by(mut self, reference_name: impl Into<String>) -> Self {
+        self.order_by.push((reference_name.into(), false));
+        self
+    }
+
+    /// Sort by a field, descending.
+    #[must_use]
+    pub fn order_by_desc(mut self, reference_name: impl Into<String>) -> Self {
+        self.order_by.push((reference_name.into(), true));
+        self
+    }
+
+    /// Run the query against the work items as they were at the given time (`ASOF`).
+    #[must_use]
+    pub fn as_of(mut self, as_of: OffsetDateTime) -> Self {
+        self.as_of = Some(as_of);
+        self
+    }
+
+    /// Set the `MODE` of a link query.
+    #[must_use]
+    pub fn mode(mut self, mode: LinkQueryMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Returns the text of the query, or an error if the query is not valid.
+    pub fn text(&self) -> azure_core::Result<String> {
+        if self.fields.is_empty() {
+            return Err(invalid("a query must select at least one field"));
+        }
+        if self.mode.is_some() && self.source == WiqlSource::WorkItems {
+            return Err(invalid("MODE can only be used in link queries"));
+        }
+        let mut f = String::from("SELECT ");
+        for (i, reference_name) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.push_str(", ");
+            }
+            write_field(&mut f, None, reference_name)?;
+        }
+        f.push_str(match self.source {
+            WiqlSource::WorkItems => " FROM WorkItems",
+            WiqlSource::WorkItemLinks => " FROM WorkItemLinks",
+        });
+        if let Some(condition) = &self.condition {
+            f.push_str(" WHERE ");
+            condition.write(&mut f, self.source)?;
+        }
+        for (i, (reference_name, descending)) in self.order_by.iter().enumerate() {
+            f.push_str(if i == 0 { " ORDER BY " } else { ", " });
+            write_field(&mut f, None, reference_name)?;
+            f.push_str(if *descending { " DESC" } else { " ASC" });
+        }
+        if let Some(as_of) = &self.as_of {
+            f.push_str(" ASOF ");
+            WiqlValue::DateTime(*as_of).write(&mut f)?;
+        }
+        if let Some(mode) = &self.mode {
+            f.push_str(&format!(" MODE ({})", mode.as_str()));
+        }
+        if f.len() > MAX_WIQL_LENGTH {
+            return Err(invalid(format!(
+                "the query is {} characters long, the maximum is {MAX_WIQL_LENGTH}",
+                f.len()
+            )));
+        }
+        Ok(f)
+    }
+
+    /// Returns the query as the body of a `query_by_wiql()` request, or an error if the query is not valid.
+    pub fn build(&self) -> azure_core::Result<Wiql> {
+        Ok(Wiql {
+            query: Some(self.text()?),
+        })
+    }
+}
+
+impl Default for WiqlQuery {
+    /// `SELECT [System.Id] FROM WorkItems`
+    fn default() -> Self {
+        Self::select([field_names::ID])
+    }
+}
+
+fn invalid(message: impl Into<std::borrow::Cow<'static, str>>) -> Error {
+    Error::message(ErrorKind::DataConversion, message)
+}
+
+fn write_field(f: &mut String, side: Option<Side>, reference_name: &str) -> azure_core::Result<()> {
+    if reference_name.is_empty() || reference_name.contains(['[', ']']) {
+        return Err(invalid(format!(
+            "invalid field reference name: {reference_name:?}"
+        )));
+    }
+    match side {
+        Some(Side::Source) => f.push_str("[Source]."),
+        Some(Side::Target) => f.push_str("[Target]."),
+        None => {}
+    }
+    f.push_str(&format!("[{reference_name}]"));
+    Ok(())
+}
+
+/// Writes a WIQL string literal, escaping `'` as `''`.
+fn write_string(f: &mut String, value: &str) {
+    f.push('\'');
+    f.push_str(&value.replace('\'', "''"));
+    f.push('\'');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wit::link_types;
+
+    #[test]
+    fn test_wiql_query() {
+        let query = Wiql::select([field_names::ID, field_names::TITLE])
+            .from_work_items()
+            .where_(field(field_names::TEAM_PROJECT).eq(WiqlValue::Project))
+            .and(field(field_names::AREA_PATH).under(r"My Project\Bob's Team"))
+            .and(
+                field(field_names::STATE)
+                    .in_(["Active", "New"])
+                    .or(field(field_names::ASSIGNED_TO).eq(WiqlValue::Me)),
+            )
+            .and(field(field_names::CHANGED_DATE).ge(WiqlValue::days_ago(7)))
+            .and(field(field_names::TAGS).is_not_empty())
+            .order_by_desc(field_names::CHANGED_DATE)
+            .order_by(field_names::ID);
+        assert_eq!(
+            query.text().unwrap(),
+            "SELECT [System.Id], [System.Title] FROM WorkItems \
+             WHERE [System.TeamProject] = @Project \
+             AND [System.AreaPath] UNDER 'My Project\\Bob''s Team' \
+             AND ([System.State] IN ('Active', 'New') OR [System.AssignedTo] = @Me) \
+             AND [System.ChangedDate] >= @Today - 7 \
+             AND [System.Tags] IS NOT EMPTY \
+             ORDER BY [System.ChangedDate] DESC, [System.Id] ASC"
+        );
+    }
+
+    #[test]
+    fn test_wiql_link_query() {
+        let query = Wiql::select([field_names::ID])
+            .from_work_item_links()
+            .where_(source_field(field_names::WORK_ITEM_TYPE).eq("Epic"))
+            .and(link_type().eq(link_types::CHILD))
+            .and(target_field(field_names::STATE).ne("Removed"))
+            .mode(LinkQueryMode::Recursive);
+        assert_eq!(
+            query.text().unwrap(),
+            "SELECT [System.Id] FROM WorkItemLinks \
+             WHERE [Source].[System.WorkItemType] = 'Epic' \
+             AND [System.Links.LinkType] = 'System.LinkTypes.Hierarchy-Forward' \
+             AND [Target].[System.State] <> 'Removed' \
+             MODE (Recursive)"
+        );
+    }
+
+    #[test]
+    fn test_wiql_validation() {
+        let no_fields: [&str; 0] = [];
+        assert!(Wiql::select(no_fields).text().is_err());
+        assert!(Wiql::select(["System.Id]"]).text().is_err());
+        assert!(WiqlQuery::default()
+            .where_(field(field_names::ID).in_(Vec::<i32>::new()))
+            .text()
+            .is_err());
+        assert!(WiqlQuery::default()
+            .where_(source_field(field_names::ID).eq(1))
+            .text()
+            .is_err());
+        assert!(WiqlQuery::default()
+            .mode(LinkQueryMode::MustContain)
+            .text()
+            .is_err());
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(WiqlQuery::default()
+                .where_(field(field_names::STORY_POINTS).gt(value))
+                .text()
+                .is_err());
+        }
+        assert_eq!(
+            WiqlQuery::default()
+                .where_(field(field_names::STORY_POINTS).gt(2.5))
+                .text()
+                .unwrap(),
+            "SELECT [System.Id] FROM WorkItems WHERE [Microsoft.VSTS.Scheduling.StoryPoints] > 2.5"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Typed access to work item fields.
+//!
+//! [`WorkItem::fields`] is a JSON map of field reference names to values. The accessors
+//! here return the common fields as Rust types:
+//!
+//! ```rust
+//!     println!("{} [{}]", work_item.title().unwrap_or_default(), work_item.state().unwrap_or_default());
+//!     if let Some(assigned_to) = work_item.assigned_to() {
+//!         println!("Assigned to {}", assigned_to.graph_subject_base.display_name.unwrap_or_default());
+//!     }
+//! ```
+//!
+//! To read many fields at once, deserialize them into your own struct with [`WorkItem::fields_as()`]:
+//!
+//! ```rust
+//!     #[derive(Deserialize)]
+//!     struct Story {
+//!         #[serde(rename = "System.Title")]
+//!         title: String,
+//!         #[serde(rename = "Microsoft.VSTS.Scheduling.StoryPoints")]
+//!         story_points: Option<f64>,
+//!     }
+//!
+//!     let story: Story = work_item.fields_as()?;
+//! ```
+use super::field_names;
+use crate::wit::models::{IdentityRef, WorkItem};
+use azure_core::error::{ErrorKind, ResultExt};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+impl WorkItem {
+    /// The raw value of a field, if it is set.
+    pub fn field_value(&self, reference_name: &str) -> Option<&Value> {
+        self.fields
+            .get(reference_name)
+            .filter(|value| !value.is_null())
+    }
+
+    /// Deserialize the value of a field, returning `None` if it is not set.
+    pub fn field<T: DeserializeOwned>(
+        &self,
+        reference_name: &str,
+    ) -> azure_core::Result<Option<T>> {
+        self.field_value(reference_name)
+            .map(T::deserialize)
+            .transpose()
+            .with_context(ErrorKind::DataConversion, || {
+                format!("failed to deserialize work item field {reference_name}")
+            })
+    }
+
+    /// Deserialize all of the fields into `T`, whose members are renamed to the field reference names.
+    pub fn fields_as<T: DeserializeOwned>(&self) -> azure_core::Result<T> {
+        T::deserialize(&self.fields).with_context(ErrorKind::DataConversion, || {
+            format!("failed to deserialize the fields of work item {}", self.id)
+        })
+    }
+
+    /// The value of a string field.
+    pub fn field_str(&self, reference_name: &str) -> Option<&str> {
+        self.field_value(reference_name)?.as_str()
+    }
+
+    /// The value of a date-time field.
+    pub fn field_date_time(&self, reference_name: &str) -> Option<OffsetDateTime> {
+        crate::date_time::rfc3339::deserialize(self.field_value(reference_name)?).ok()
+    }
+
+    /// The value of an identity field, such as `System.AssignedTo`.
+    pub fn field_identity(&self, reference_name: &str) -> Option<IdentityRef> {
+        IdentityRef::deserialize(self.field_value(reference_name)?).ok()
+    }
+
+    /// `System.Title`
+    pub fn title(&self) -> Option<&str> {
+        self.field_str(field_names::TITLE)
+    }
+
+    /// `System.WorkItemType`
+    pub fn work_item_type(&self) -> Option<&str> {
+        self.field_str(field_names::WORK_ITEM_TYPE)
+    }
+
+    /// `System.State`
+    pub fn state(&self) -> Option<&str> {
+        self.field_str(field_names::STATE)
+    }
+
+    /// `System.Reason`
+    pub fn reason(&self) -> Option<&str> {
+        self.field_str(field_names::REASON)
+    }
+
+    /// `System.AreaPath`
+    pub fn area_path(&self) -> Option<&str> {
+        self.field_str(field_names::AREA_PATH)
+    }
+
+    /// `System.IterationPath`
+    pub fn iteration_path(&self) -> Option<&str> {
+        self.field_str(field_names::ITERATION_PATH)
+    }
+
+    /// `System.TeamProject`
+    pub fn team_project(&self) -> Option<&str> {
+        self.field_str(field_names::TEAM_PROJECT)
+    }
+
+    /// `System.Description`
+    pub fn description(&self) -> Option<&str> {
+        self.field_str(field_names::DESCRIPTION)
+    }
+
+    /// `System.AssignedTo`
+    pub fn assigned_to(&self) -> Option<IdentityRef> {
+        self.field_identity(field_names::ASSIGNED_TO)
+    }
+
+    /// `System.CreatedBy`
+    pub fn created_by(&self) -> Option<IdentityRef> {
+        self.field_identity(field_names::CREATED_BY)
+    }
+
+    /// `System.ChangedBy`
+    pub fn changed_by(&self) -> Option<IdentityRef> {
+        self.field_identity(field_names::CHANGED_BY)
+    }
+
+    /// `System.CreatedDate`
+    pub fn created_date(&self) -> Option<OffsetDateTime> {
+        self.field_date_time(field_names::CREATED_DATE)
+    }
+
+    /// `System.ChangedDate`
+    pub fn changed_date(&self) -> Option<OffsetDateTime> {
+        self.field_date_time(field_names::CHANGED_DATE)
+    }
+
+    /// `System.Tags`, which is stored as a `;` separated list.
+    pub fn tags(&self) -> Vec<String> {
+        self.field_str(field_names::TAGS)
+            .map(|tags| {
+                tags.split(';')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(ToOwned::to_owned)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// `System.Parent`, the id of the parent work item.
+    pub fn parent_id(&self) -> Option<i32> {
+        self.field_value(field_names::PARENT)?
+            .as_i64()
+            .and_then(|id| i32::try_from(id).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn work_item() -> WorkItem {
+        serde_json::from_value(json!({
+            "id": 42,
+            "rev": 3,
+            "fields": {
+                "System.Title": "Fix the thing",
+                "System.State": "Active",
+                "System.Tags": "bug; urgent ;",
+                "System.Parent": 7,
+                "System.CreatedDate": "2024-01-02T03:04:05.678Z",
+                "System.AssignedTo": {
+                    "displayName": "Jane Doe",
+                    "uniqueName": "jane@example.com"
+                },
+                "System.Description": null,
+                "Microsoft.VSTS.Scheduling.StoryPoints": 3.0
+            },
+            "url": "https://dev.azure.com/org/project/_apis/wit/workItems/42"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_work_item_fields() {
+        let work_item = work_item();
+        assert_eq!(work_item.title(), Some("Fix the thing"));
+        assert_eq!(work_item.state(), Some("Active"));
+        assert_eq!(work_item.description(), None);
+        assert_eq!(work_item.area_path(), None);
+        assert_eq!(work_item.tags(), vec!["bug", "urgent"]);
+        assert_eq!(work_item.parent_id(), Some(7));
+        assert_eq!(
+            work_item.created_date().map(|date| date.unix_timestamp()),
+            Some(1704164645)
+        );
+        assert_eq!(
+            work_item
+                .assigned_to()
+                .and_then(|identity| identity.graph_subject_base.display_name),
+            Some("Jane Doe".to_string())
+        );
+        assert_eq!(
+            work_item
+                .field::<f64>("Microsoft.VSTS.Scheduling.StoryPoints")
+                .unwrap(),
+            Some(3.0)
+        );
+        assert!(work_item.field::<i32>(field_names::TITLE).is_err());
+    }
+
+    #[test]
+    fn test_work_item_fields_as() {
+        #[derive(Deserialize)]
+        struct Story {
+            #[serde(rename = "System.Title")]
+            title: String,
+            #[serde(rename = "Microsoft.VSTS.Scheduling.StoryPoints")]
+            story_points: Option<f64>,
+            #[serde(rename = "Microsoft.VSTS.Common.Priority")]
+            priority: Option<i32>,
+        }
+
+        let story: Story = work_item().fields_as().unwrap();
+        assert_eq!(story.title, "Fix the thing");
+        assert_eq!(story.story_points, Some(3.0));
+        assert_eq!(story.priority, None);
+    }
+}
@@ -6,7 +6,9 @@
 #![allow(clippy::redundant_clone)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::module_inception)]
+pub mod helpers;
 pub mod models;
+pub use helpers::*;
 #[derive(Clone)]
 pub struct Client {
     endpoint: azure_core::Url,
@@ -119,8 +121,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +131,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn account_my_work_recent_activity_client(
         &self,
     ) -> account_my_work_recent_activity::Client {
@@ -229,6 +258,254 @@ impl Client {
         work_items::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn account_my_work_recent_activity_client(
+        &self,
+    ) -> account_my_work_recent_activity::OrganizationClient {
+        account_my_work_recent_activity::OrganizationClient(self.clone())
+    }
+    pub fn artifact_link_types_client(&self) -> artifact_link_types::OrganizationClient {
+        artifact_link_types::OrganizationClient(self.clone())
+    }
+    pub fn artifact_uri_query_client(&self) -> artifact_uri_query::OrganizationClient {
+        artifact_uri_query::OrganizationClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::OrganizationClient {
+        attachments::OrganizationClient(self.clone())
+    }
+    pub fn classification_nodes_client(&self) -> classification_nodes::OrganizationClient {
+        classification_nodes::OrganizationClient(self.clone())
+    }
+    pub fn comment_reactions_engaged_users_client(
+        &self,
+    ) -> comment_reactions_engaged_users::OrganizationClient {
+        comment_reactions_engaged_users::OrganizationClient(self.clone())
+    }
+    pub fn comments_client(&self) -> comments::OrganizationClient {
+        comments::OrganizationClient(self.clone())
+    }
+    pub fn comments_reactions_client(&self) -> comments_reactions::OrganizationClient {
+        comments_reactions::OrganizationClient(self.clone())
+    }
+    pub fn comments_versions_client(&self) -> comments_versions::OrganizationClient {
+        comments_versions::OrganizationClient(self.clone())
+    }
+    pub fn fields_client(&self) -> fields::OrganizationClient {
+        fields::OrganizationClient(self.clone())
+    }
+    pub fn project_process_migration_client(
+        &self,
+    ) -> project_process_migration::OrganizationClient {
+        project_process_migration::OrganizationClient(self.clone())
+    }
+    pub fn queries_client(&self) -> queries::OrganizationClient {
+        queries::OrganizationClient(self.clone())
+    }
+    pub fn recyclebin_client(&self) -> recyclebin::OrganizationClient {
+        recyclebin::OrganizationClient(self.clone())
+    }
+    pub fn reporting_work_item_links_client(
+        &self,
+    ) -> reporting_work_item_links::OrganizationClient {
+        reporting_work_item_links::OrganizationClient(self.clone())
+    }
+    pub fn reporting_work_item_revisions_client(
+        &self,
+    ) -> reporting_work_item_revisions::OrganizationClient {
+        reporting_work_item_revisions::OrganizationClient(self.clone())
+    }
+    pub fn revisions_client(&self) -> revisions::OrganizationClient {
+        revisions::OrganizationClient(self.clone())
+    }
+    pub fn send_mail_client(&self) -> send_mail::OrganizationClient {
+        send_mail::OrganizationClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::OrganizationClient {
+        tags::OrganizationClient(self.clone())
+    }
+    pub fn temp_queries_client(&self) -> temp_queries::OrganizationClient {
+        temp_queries::OrganizationClient(self.clone())
+    }
+    pub fn templates_client(&self) -> templates::OrganizationClient {
+        templates::OrganizationClient(self.clone())
+    }
+    pub fn updates_client(&self) -> updates::OrganizationClient {
+        updates::OrganizationClient(self.clone())
+    }
+    pub fn wiql_client(&self) -> wiql::OrganizationClient {
+        wiql::OrganizationClient(self.clone())
+    }
+    pub fn work_item_icons_client(&self) -> work_item_icons::OrganizationClient {
+        work_item_icons::OrganizationClient(self.clone())
+    }
+    pub fn work_item_relation_types_client(&self) -> work_item_relation_types::OrganizationClient {
+        work_item_relation_types::OrganizationClient(self.clone())
+    }
+    pub fn work_item_revisions_discussions_client(
+        &self,
+    ) -> work_item_revisions_discussions::OrganizationClient {
+        work_item_revisions_discussions::OrganizationClient(self.clone())
+    }
+    pub fn work_item_transitions_client(&self) -> work_item_transitions::OrganizationClient {
+        work_item_transitions::OrganizationClient(self.clone())
+    }
+    pub fn work_item_type_categories_client(
+        &self,
+    ) -> work_item_type_categories::OrganizationClient {
+        work_item_type_categories::OrganizationClient(self.clone())
+    }
+    pub fn work_item_type_states_client(&self) -> work_item_type_states::OrganizationClient {
+        work_item_type_states::OrganizationClient(self.clone())
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::OrganizationClient {
+        work_item_types::OrganizationClient(self.clone())
+    }
+    pub fn work_item_types_field_client(&self) -> work_item_types_field::OrganizationClient {
+        work_item_types_field::OrganizationClient(self.clone())
+    }
+    pub fn work_items_client(&self) -> work_items::OrganizationClient {
+        work_items::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn account_my_work_recent_activity_client(
+        &self,
+    ) -> account_my_work_recent_activity::ProjectClient {
+        account_my_work_recent_activity::ProjectClient(self.clone())
+    }
+    pub fn artifact_link_types_client(&self) -> artifact_link_types::ProjectClient {
+        artifact_link_types::ProjectClient(self.clone())
+    }
+    pub fn artifact_uri_query_client(&self) -> artifact_uri_query::ProjectClient {
+        artifact_uri_query::ProjectClient(self.clone())
+    }
+    pub fn attachments_client(&self) -> attachments::ProjectClient {
+        attachments::ProjectClient(self.clone())
+    }
+    pub fn classification_nodes_client(&self) -> classification_nodes::ProjectClient {
+        classification_nodes::ProjectClient(self.clone())
+    }
+    pub fn comment_reactions_engaged_users_client(
+        &self,
+    ) -> comment_reactions_engaged_users::ProjectClient {
+        comment_reactions_engaged_users::ProjectClient(self.clone())
+    }
+    pub fn comments_client(&self) -> comments::ProjectClient {
+        comments::ProjectClient(self.clone())
+    }
+    pub fn comments_reactions_client(&self) -> comments_reactions::ProjectClient {
+        comments_reactions::ProjectClient(self.clone())
+    }
+    pub fn comments_versions_client(&self) -> comments_versions::ProjectClient {
+        comments_versions::ProjectClient(self.clone())
+    }
+    pub fn fields_client(&self) -> fields::ProjectClient {
+        fields::ProjectClient(self.clone())
+    }
+    pub fn project_process_migration_client(&self) -> project_process_migration::ProjectClient {
+        project_process_migration::ProjectClient(self.clone())
+    }
+    pub fn queries_client(&self) -> queries::ProjectClient {
+        queries::ProjectClient(self.clone())
+    }
+    pub fn recyclebin_client(&self) -> recyclebin::ProjectClient {
+        recyclebin::ProjectClient(self.clone())
+    }
+    pub fn reporting_work_item_links_client(&self) -> reporting_work_item_links::ProjectClient {
+        reporting_work_item_links::ProjectClient(self.clone())
+    }
+    pub fn reporting_work_item_revisions_client(
+        &self,
+    ) -> reporting_work_item_revisions::ProjectClient {
+        reporting_work_item_revisions::ProjectClient(self.clone())
+    }
+    pub fn revisions_client(&self) -> revisions::ProjectClient {
+        revisions::ProjectClient(self.clone())
+    }
+    pub fn send_mail_client(&self) -> send_mail::ProjectClient {
+        send_mail::ProjectClient(self.clone())
+    }
+    pub fn tags_client(&self) -> tags::ProjectClient {
+        tags::ProjectClient(self.clone())
+    }
+    pub fn temp_queries_client(&self) -> temp_queries::ProjectClient {
+        temp_queries::ProjectClient(self.clone())
+    }
+    pub fn templates_client(&self) -> templates::ProjectClient {
+        templates::ProjectClient(self.clone())
+    }
+    pub fn updates_client(&self) -> updates::ProjectClient {
+        updates::ProjectClient(self.clone())
+    }
+    pub fn wiql_client(&self) -> wiql::ProjectClient {
+        wiql::ProjectClient(self.clone())
+    }
+    pub fn work_item_icons_client(&self) -> work_item_icons::ProjectClient {
+        work_item_icons::ProjectClient(self.clone())
+    }
+    pub fn work_item_relation_types_client(&self) -> work_item_relation_types::ProjectClient {
+        work_item_relation_types::ProjectClient(self.clone())
+    }
+    pub fn work_item_revisions_discussions_client(
+        &self,
+    ) -> work_item_revisions_discussions::ProjectClient {
+        work_item_revisions_discussions::ProjectClient(self.clone())
+    }
+    pub fn work_item_transitions_client(&self) -> work_item_transitions::ProjectClient {
+        work_item_transitions::ProjectClient(self.clone())
+    }
+    pub fn work_item_type_categories_client(&self) -> work_item_type_categories::ProjectClient {
+        work_item_type_categories::ProjectClient(self.clone())
+    }
+    pub fn work_item_type_states_client(&self) -> work_item_type_states::ProjectClient {
+        work_item_type_states::ProjectClient(self.clone())
+    }
+    pub fn work_item_types_client(&self) -> work_item_types::ProjectClient {
+        work_item_types::ProjectClient(self.clone())
+    }
+    pub fn work_item_types_field_client(&self) -> work_item_types_field::ProjectClient {
+        work_item_types_field::ProjectClient(self.clone())
+    }
+    pub fn work_items_client(&self) -> work_items::ProjectClient {
+        work_items::ProjectClient(self.clone())
+    }
+}
 pub mod classification_nodes {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -372,6 +649,210 @@ pub mod classification_nodes {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets root classification nodes or list of classification nodes for a given list of nodes ids, for a given project. In case ids parameter is supplied you will  get list of classification nodes for those ids. Otherwise you will get root classification nodes for this project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `ids`: Comma separated integer classification nodes ids. It's not required, if you want root nodes."]
+        pub fn get_classification_nodes(
+            &self,
+            project: impl Into<String>,
+            ids: impl Into<String>,
+        ) -> get_classification_nodes::RequestBuilder {
+            Client(self.0.client.clone()).get_classification_nodes(
+                self.0.organization.clone(),
+                project,
+                ids,
+            )
+        }
+        #[doc = "Gets root classification nodes under the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_root_nodes(&self, project: impl Into<String>) -> get_root_nodes::RequestBuilder {
+            Client(self.0.client.clone()).get_root_nodes(self.0.organization.clone(), project)
+        }
+        #[doc = "Gets the classification node for a given node path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Create new or update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            Client(self.0.client.clone()).create_or_update(
+                self.0.organization.clone(),
+                body,
+                project,
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Delete an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                structure_group,
+                path,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets root classification nodes or list of classification nodes for a given list of nodes ids, for a given project. In case ids parameter is supplied you will  get list of classification nodes for those ids. Otherwise you will get root classification nodes for this project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated integer classification nodes ids. It's not required, if you want root nodes."]
+        pub fn get_classification_nodes(
+            &self,
+            ids: impl Into<String>,
+        ) -> get_classification_nodes::RequestBuilder {
+            Client(self.0.client.clone()).get_classification_nodes(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                ids,
+            )
+        }
+        #[doc = "Gets root classification nodes under the project."]
+        pub fn get_root_nodes(&self) -> get_root_nodes::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_root_nodes(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Gets the classification node for a given node path."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn get(
+            &self,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Create new or update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn create_or_update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> create_or_update::RequestBuilder {
+            Client(self.0.client.clone()).create_or_update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Update an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Node to create or update."]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemClassificationNode>,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+        #[doc = "Delete an existing classification node."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `structure_group`: Structure group of the classification node, area or iteration."]
+        #[doc = "* `path`: Path of the classification node."]
+        pub fn delete(
+            &self,
+            structure_group: impl Into<String>,
+            path: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                structure_group,
+                path,
+            )
+        }
+    }
     pub mod get_classification_nodes {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -415,6 +896,29 @@ pub mod classification_nodes {
                 self.as_raw_response()
             }
         }
+        #[doc = "Flag to handle errors in getting some nodes. Possible options are Fail and Omit."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum ErrorPolicy {
+            Fail,
+            Omit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl ErrorPolicy {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Fail => "fail",
+                    Self::Omit => "omit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for ErrorPolicy {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -437,7 +941,7 @@ pub mod classification_nodes {
             pub(crate) project: String,
             pub(crate) ids: String,
             pub(crate) depth: Option<i32>,
-            pub(crate) error_policy: Option<String>,
+            pub(crate) error_policy: Option<ErrorPolicy>,
         }
         impl RequestBuilder {
             #[doc = "Depth of children to fetch."]
@@ -446,7 +950,7 @@ pub mod classification_nodes {
                 self
             }
             #[doc = "Flag to handle errors in getting some nodes. Possible options are Fail and Omit."]
-            pub fn error_policy(mut self, error_policy: impl Into<String>) -> Self {
+            pub fn error_policy(mut self, error_policy: impl Into<ErrorPolicy>) -> Self {
                 self.error_policy = Some(error_policy.into());
                 self
             }
@@ -478,7 +982,7 @@ pub mod classification_nodes {
                         if let Some(error_policy) = &this.error_policy {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("errorPolicy", error_policy);
+                                .append_pair("errorPolicy", error_policy.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -1307,15 +1811,193 @@ pub mod queries {
             }
         }
     }
-    pub mod search_queries {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Searches all queries the user has access to in the current project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `filter`: The text to filter the queries with."]
+        pub fn search_queries(
+            &self,
+            project: impl Into<String>,
+            filter: impl Into<String>,
+        ) -> search_queries::RequestBuilder {
+            Client(self.0.client.clone()).search_queries(
+                self.0.organization.clone(),
+                project,
+                filter,
+            )
+        }
+        #[doc = "Gets the root queries and their children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Retrieves an individual query and its children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: ID or path of the query."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, query)
+        }
+        #[doc = "Creates a query, or moves a query.\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to create."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: The parent id or path under which the query is to be created."]
+        pub fn create(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, query)
+        }
+        #[doc = "Update a query or a folder. This allows you to update, rename and move queries and folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to update."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: The ID or path for the query to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, query)
+        }
+        #[doc = "Delete a query or a folder. This deletes any permission change on the deleted query or folder and any of its descendants if it is a folder. It is important to note that the deleted permission changes cannot be recovered upon undeleting the query or folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `query`: ID or path of the query or folder to delete."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            query: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, query)
+        }
+        #[doc = "Gets a list of queries by ids (Maximum 1000)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_queries_batch(
+            &self,
+            body: impl Into<models::QueryBatchGetRequest>,
+            project: impl Into<String>,
+        ) -> get_queries_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_queries_batch(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Searches all queries the user has access to in the current project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `filter`: The text to filter the queries with."]
+        pub fn search_queries(&self, filter: impl Into<String>) -> search_queries::RequestBuilder {
+            Client(self.0.client.clone()).search_queries(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                filter,
+            )
+        }
+        #[doc = "Gets the root queries and their children"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Retrieves an individual query and its children"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `query`: ID or path of the query."]
+        pub fn get(&self, query: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                query,
+            )
+        }
+        #[doc = "Creates a query, or moves a query.\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to create."]
+        #[doc = "* `query`: The parent id or path under which the query is to be created."]
+        pub fn create(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            query: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                query,
+            )
+        }
+        #[doc = "Update a query or a folder. This allows you to update, rename and move queries and folders."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query to update."]
+        #[doc = "* `query`: The ID or path for the query to update."]
+        pub fn update(
+            &self,
+            body: impl Into<models::QueryHierarchyItem>,
+            query: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                query,
+            )
+        }
+        #[doc = "Delete a query or a folder. This deletes any permission change on the deleted query or folder and any of its descendants if it is a folder. It is important to note that the deleted permission changes cannot be recovered upon undeleting the query or folder."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `query`: ID or path of the query or folder to delete."]
+        pub fn delete(&self, query: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                query,
+            )
+        }
+        #[doc = "Gets a list of queries by ids (Maximum 1000)"]
+        pub fn get_queries_batch(
+            &self,
+            body: impl Into<models::QueryBatchGetRequest>,
+        ) -> get_queries_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_queries_batch(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
+    pub mod search_queries {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::QueryHierarchyItemsResult> {
                 let bytes = self.0.into_body().collect().await?;
                 let body: models::QueryHierarchyItemsResult = serde_json::from_slice(&bytes)
@@ -1348,6 +2030,34 @@ pub mod queries {
                 self.as_raw_response()
             }
         }
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Wiql,
+            Clauses,
+            All,
+            Minimal,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Wiql => "wiql",
+                    Self::Clauses => "clauses",
+                    Self::All => "all",
+                    Self::Minimal => "minimal",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -1370,7 +2080,7 @@ pub mod queries {
             pub(crate) project: String,
             pub(crate) filter: String,
             pub(crate) top: Option<i32>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
             pub(crate) include_deleted: Option<bool>,
         }
         impl RequestBuilder {
@@ -1379,7 +2089,7 @@ pub mod queries {
                 self.top = Some(top);
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1418,7 +2128,7 @@ pub mod queries {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(include_deleted) = &this.include_deleted {
                             req.url_mut()
@@ -1504,6 +2214,35 @@ pub mod queries {
                 self.as_raw_response()
             }
         }
+        #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Wiql,
+            Clauses,
+            All,
+            Minimal,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Wiql => "wiql",
+                    Self::Clauses => "clauses",
+                    Self::All => "all",
+                    Self::Minimal => "minimal",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -1524,13 +2263,13 @@ pub mod queries {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
             pub(crate) depth: Option<i32>,
             pub(crate) include_deleted: Option<bool>,
         }
         impl RequestBuilder {
             #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1565,7 +2304,7 @@ pub mod queries {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(depth) = &this.depth {
                             req.url_mut()
@@ -1655,6 +2394,35 @@ pub mod queries {
                 self.as_raw_response()
             }
         }
+        #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Wiql,
+            Clauses,
+            All,
+            Minimal,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Wiql => "wiql",
+                    Self::Clauses => "clauses",
+                    Self::All => "all",
+                    Self::Minimal => "minimal",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -1676,14 +2444,14 @@ pub mod queries {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) query: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
             pub(crate) depth: Option<i32>,
             pub(crate) include_deleted: Option<bool>,
             pub(crate) use_iso_date_format: Option<bool>,
         }
         impl RequestBuilder {
             #[doc = "Include the query string (wiql), clauses, query result columns, and sort options in the results."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -1723,7 +2491,7 @@ pub mod queries {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(depth) = &this.depth {
                             req.url_mut()
@@ -2374,6 +3142,144 @@ pub mod recyclebin {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the work items from the recycle bin, whose IDs have been specified in the parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated list of IDs of the deleted work items to be returned"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_work_items(
+            &self,
+            ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get_deleted_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_deleted_work_items(
+                self.0.organization.clone(),
+                ids,
+                project,
+            )
+        }
+        #[doc = "Gets a list of the IDs and the URLs of the deleted the work items in the Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_deleted_work_item_shallow_references(
+            &self,
+            project: impl Into<String>,
+        ) -> get_deleted_work_item_shallow_references::RequestBuilder {
+            Client(self.0.client.clone())
+                .get_deleted_work_item_shallow_references(self.0.organization.clone(), project)
+        }
+        #[doc = "Gets a deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be returned"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, id: i32, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Restores the deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Paylod with instructions to update the IsDeleted flag to false"]
+        #[doc = "* `id`: ID of the work item to be restored"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn restore_work_item(
+            &self,
+            body: impl Into<models::WorkItemDeleteUpdate>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> restore_work_item::RequestBuilder {
+            Client(self.0.client.clone()).restore_work_item(
+                self.0.organization.clone(),
+                body,
+                id,
+                project,
+            )
+        }
+        #[doc = "Destroys the specified work item permanently from the Recycle Bin. This action can not be undone."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be destroyed permanently"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn destroy_work_item(
+            &self,
+            id: i32,
+            project: impl Into<String>,
+        ) -> destroy_work_item::RequestBuilder {
+            Client(self.0.client.clone()).destroy_work_item(
+                self.0.organization.clone(),
+                id,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the work items from the recycle bin, whose IDs have been specified in the parameters"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: Comma separated list of IDs of the deleted work items to be returned"]
+        pub fn get_deleted_work_items(
+            &self,
+            ids: impl Into<String>,
+        ) -> get_deleted_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_deleted_work_items(
+                self.0.organization.clone(),
+                ids,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets a list of the IDs and the URLs of the deleted the work items in the Recycle Bin."]
+        pub fn get_deleted_work_item_shallow_references(
+            &self,
+        ) -> get_deleted_work_item_shallow_references::RequestBuilder {
+            Client(self.0.client.clone()).get_deleted_work_item_shallow_references(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets a deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be returned"]
+        pub fn get(&self, id: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Restores the deleted work item from Recycle Bin."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Paylod with instructions to update the IsDeleted flag to false"]
+        #[doc = "* `id`: ID of the work item to be restored"]
+        pub fn restore_work_item(
+            &self,
+            body: impl Into<models::WorkItemDeleteUpdate>,
+            id: i32,
+        ) -> restore_work_item::RequestBuilder {
+            Client(self.0.client.clone()).restore_work_item(
+                self.0.organization.clone(),
+                body,
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Destroys the specified work item permanently from the Recycle Bin. This action can not be undone."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be destroyed permanently"]
+        pub fn destroy_work_item(&self, id: i32) -> destroy_work_item::RequestBuilder {
+            Client(self.0.client.clone()).destroy_work_item(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod get_deleted_work_items {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3164,19 +4070,318 @@ pub mod comments {
             }
         }
     }
-    pub mod add_work_item_comment {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::Comment> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Comment = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        #[doc = "* `format`: Format of a work item comment (Markdown or Html)."]
+        pub fn add_work_item_comment(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            format: impl Into<String>,
+        ) -> add_work_item_comment::RequestBuilder {
+            Client(self.0.client.clone()).add_work_item_comment(
+                self.0.organization.clone(),
+                body,
+                project,
+                work_item_id,
+                format,
+            )
+        }
+        #[doc = "Returns a list of work item comments, pageable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        pub fn get_comments(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+        ) -> get_comments::RequestBuilder {
+            Client(self.0.client.clone()).get_comments(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+            )
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        #[doc = "* `format`: Format of a work item comment (Markdown or Html)."]
+        pub fn update_work_item_comment(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            format: impl Into<String>,
+        ) -> update_work_item_comment::RequestBuilder {
+            Client(self.0.client.clone()).update_work_item_comment(
+                self.0.organization.clone(),
+                body,
+                project,
+                work_item_id,
+                comment_id,
+                format,
+            )
+        }
+        #[doc = "Returns a list of work item comments by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        #[doc = "* `ids`: Comma-separated list of comment ids to return."]
+        pub fn get_comments_batch(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            ids: impl Into<String>,
+        ) -> get_comments_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_comments_batch(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                ids,
+            )
+        }
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn add_comment(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+        ) -> add_comment::RequestBuilder {
+            Client(self.0.client.clone()).add_comment(
+                self.0.organization.clone(),
+                body,
+                project,
+                work_item_id,
+            )
+        }
+        #[doc = "Returns a work item comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item to get the comment."]
+        #[doc = "* `comment_id`: Id of the comment to return."]
+        pub fn get_comment(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> get_comment::RequestBuilder {
+            Client(self.0.client.clone()).get_comment(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn update_comment(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> update_comment::RequestBuilder {
+            Client(self.0.client.clone()).update_comment(
+                self.0.organization.clone(),
+                body,
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Delete a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        #[doc = "* `format`: Format of a work item comment (Markdown or Html)."]
+        pub fn add_work_item_comment(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            work_item_id: i32,
+            format: impl Into<String>,
+        ) -> add_work_item_comment::RequestBuilder {
+            Client(self.0.client.clone()).add_work_item_comment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                work_item_id,
+                format,
+            )
+        }
+        #[doc = "Returns a list of work item comments, pageable."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        pub fn get_comments(&self, work_item_id: i32) -> get_comments::RequestBuilder {
+            Client(self.0.client.clone()).get_comments(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+            )
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        #[doc = "* `format`: Format of a work item comment (Markdown or Html)."]
+        pub fn update_work_item_comment(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            work_item_id: i32,
+            comment_id: i32,
+            format: impl Into<String>,
+        ) -> update_work_item_comment::RequestBuilder {
+            Client(self.0.client.clone()).update_work_item_comment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+                format,
+            )
+        }
+        #[doc = "Returns a list of work item comments by ids."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get comments for."]
+        #[doc = "* `ids`: Comma-separated list of comment ids to return."]
+        pub fn get_comments_batch(
+            &self,
+            work_item_id: i32,
+            ids: impl Into<String>,
+        ) -> get_comments_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_comments_batch(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                ids,
+            )
+        }
+        #[doc = "Add a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment create request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn add_comment(
+            &self,
+            body: impl Into<models::CommentCreate>,
+            work_item_id: i32,
+        ) -> add_comment::RequestBuilder {
+            Client(self.0.client.clone()).add_comment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                work_item_id,
+            )
+        }
+        #[doc = "Returns a work item comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item to get the comment."]
+        #[doc = "* `comment_id`: Id of the comment to return."]
+        pub fn get_comment(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> get_comment::RequestBuilder {
+            Client(self.0.client.clone()).get_comment(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Update a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Comment update request."]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn update_comment(
+            &self,
+            body: impl Into<models::CommentUpdate>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> update_comment::RequestBuilder {
+            Client(self.0.client.clone()).update_comment(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Delete a comment on a work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: Id of a work item."]
+        pub fn delete(&self, work_item_id: i32, comment_id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+    }
+    pub mod add_work_item_comment {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::Comment> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::Comment = serde_json::from_slice(&bytes).map_err(|e| {
+                    azure_core::error::Error::full(
                         azure_core::error::ErrorKind::DataConversion,
                         e,
                         format!(
@@ -3330,6 +4535,35 @@ pub mod comments {
                 self.as_raw_response()
             }
         }
+        #[doc = "Specifies the additional data retrieval options for work item comments."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Reactions,
+            RenderedText,
+            RenderedTextOnly,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Reactions => "reactions",
+                    Self::RenderedText => "renderedText",
+                    Self::RenderedTextOnly => "renderedTextOnly",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3354,7 +4588,7 @@ pub mod comments {
             pub(crate) top: Option<i32>,
             pub(crate) continuation_token: Option<String>,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
             pub(crate) order: Option<String>,
         }
         impl RequestBuilder {
@@ -3374,7 +4608,7 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -3419,7 +4653,7 @@ pub mod comments {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(order) = &this.order {
                             req.url_mut().query_pairs_mut().append_pair("order", order);
@@ -3629,6 +4863,35 @@ pub mod comments {
                 self.as_raw_response()
             }
         }
+        #[doc = "Specifies the additional data retrieval options for work item comments."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Reactions,
+            RenderedText,
+            RenderedTextOnly,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Reactions => "reactions",
+                    Self::RenderedText => "renderedText",
+                    Self::RenderedTextOnly => "renderedTextOnly",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3652,7 +4915,7 @@ pub mod comments {
             pub(crate) work_item_id: i32,
             pub(crate) ids: String,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Specify if the deleted comments should be retrieved."]
@@ -3661,7 +4924,7 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -3693,7 +4956,7 @@ pub mod comments {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -3892,6 +5155,35 @@ pub mod comments {
                 self.as_raw_response()
             }
         }
+        #[doc = "Specifies the additional data retrieval options for work item comments."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Reactions,
+            RenderedText,
+            RenderedTextOnly,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Reactions => "reactions",
+                    Self::RenderedText => "renderedText",
+                    Self::RenderedTextOnly => "renderedTextOnly",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -3915,7 +5207,7 @@ pub mod comments {
             pub(crate) work_item_id: i32,
             pub(crate) comment_id: i32,
             pub(crate) include_deleted: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Specify if the deleted comment should be retrieved."]
@@ -3924,7 +5216,7 @@ pub mod comments {
                 self
             }
             #[doc = "Specifies the additional data retrieval options for work item comments."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -3954,7 +5246,7 @@ pub mod comments {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -4245,11 +5537,25 @@ pub mod artifact_link_types {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the list of work item tracking outbound artifact link types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the list of work item tracking outbound artifact link types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
         use futures::future::LocalBoxFuture as BoxFuture;
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
@@ -4398,6 +5704,34 @@ pub mod work_item_icons {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of all work item icons."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a work item icon given the friendly name and icon color."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `icon`: The name of the icon"]
+        pub fn get(&self, icon: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(icon, self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of all work item icons."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Get a work item icon given the friendly name and icon color."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `icon`: The name of the icon"]
+        pub fn get(&self, icon: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(icon, self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4685,6 +6019,34 @@ pub mod work_item_relation_types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the work item relation types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets the work item relation type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `relation`: The relation name"]
+        pub fn get(&self, relation: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), relation)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the work item relation types."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+        #[doc = "Gets the work item relation type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `relation`: The relation name"]
+        pub fn get(&self, relation: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), relation)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -4945,6 +6307,26 @@ pub mod work_item_transitions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the next state on the given work item IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: list of work item ids"]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), ids)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the next state on the given work item IDs."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: list of work item ids"]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), ids)
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5097,6 +6479,20 @@ pub mod account_my_work_recent_activity {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets recent work item activities"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets recent work item activities"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5246,6 +6642,35 @@ pub mod artifact_uri_query {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Queries work items linked to a given list of artifact URI."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Defines a list of artifact URI for querying work items."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn query(
+            &self,
+            body: impl Into<models::ArtifactUriQuery>,
+            project: impl Into<String>,
+        ) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Queries work items linked to a given list of artifact URI."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Defines a list of artifact URI for querying work items."]
+        pub fn query(&self, body: impl Into<models::ArtifactUriQuery>) -> query::RequestBuilder {
+            Client(self.0.client.clone()).query(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod query {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5416,6 +6841,7 @@ pub mod attachments {
                 project: project.into(),
                 file_name: None,
                 download: None,
+                accept: None,
             }
         }
         #[doc = "Uploads an attachment chunk.\n\nBefore performing [**Upload a Chunk**](#upload-a-chunk), make sure to have an attachment id returned in **Start a Chunked Upload** example on **Create** section. Specify the byte range of the chunk using Content-Length. For example: \"Content - Length\": \"bytes 0 - 39999 / 50000\" for the first 40000 bytes of a 50000 byte file."]
@@ -5445,6 +6871,100 @@ pub mod attachments {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Uploads an attachment.\n\nOn accounts with higher attachment upload limits (>130MB), you will need to use chunked upload.\nTo upload an attachment in multiple chunks, you first need to [**Start a Chunked Upload**](#start_a_chunked_upload) and then follow the example from the **Upload Chunk** section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<String>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Downloads an attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Attachment ID"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Uploads an attachment chunk.\n\nBefore performing [**Upload a Chunk**](#upload-a-chunk), make sure to have an attachment id returned in **Start a Chunked Upload** example on **Create** section. Specify the byte range of the chunk using Content-Length. For example: \"Content - Length\": \"bytes 0 - 39999 / 50000\" for the first 40000 bytes of a 50000 byte file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `id`: The id of the attachment"]
+        #[doc = "* `content_range_header`: starting and ending byte positions for chunked file upload, format is \"Content-Range\": \"bytes 0-10000/50000\""]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn upload_chunk(
+            &self,
+            body: impl Into<String>,
+            id: impl Into<String>,
+            content_range_header: impl Into<String>,
+            project: impl Into<String>,
+        ) -> upload_chunk::RequestBuilder {
+            Client(self.0.client.clone()).upload_chunk(
+                self.0.organization.clone(),
+                body,
+                id,
+                content_range_header,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Uploads an attachment.\n\nOn accounts with higher attachment upload limits (>130MB), you will need to use chunked upload.\nTo upload an attachment in multiple chunks, you first need to [**Start a Chunked Upload**](#start_a_chunked_upload) and then follow the example from the **Upload Chunk** section."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        pub fn create(&self, body: impl Into<String>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Downloads an attachment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Attachment ID"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Uploads an attachment chunk.\n\nBefore performing [**Upload a Chunk**](#upload-a-chunk), make sure to have an attachment id returned in **Start a Chunked Upload** example on **Create** section. Specify the byte range of the chunk using Content-Length. For example: \"Content - Length\": \"bytes 0 - 39999 / 50000\" for the first 40000 bytes of a 50000 byte file."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Stream to upload"]
+        #[doc = "* `id`: The id of the attachment"]
+        #[doc = "* `content_range_header`: starting and ending byte positions for chunked file upload, format is \"Content-Range\": \"bytes 0-10000/50000\""]
+        pub fn upload_chunk(
+            &self,
+            body: impl Into<String>,
+            id: impl Into<String>,
+            content_range_header: impl Into<String>,
+        ) -> upload_chunk::RequestBuilder {
+            Client(self.0.client.clone()).upload_chunk(
+                self.0.organization.clone(),
+                body,
+                id,
+                content_range_header,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5606,19 +7126,29 @@ pub mod attachments {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -5660,6 +7190,7 @@ pub mod attachments {
             pub(crate) project: String,
             pub(crate) file_name: Option<String>,
             pub(crate) download: Option<bool>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Name of the file"]
@@ -5672,6 +7203,11 @@ pub mod attachments {
                 self.download = Some(download);
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`, `application/zip`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -5690,6 +7226,7 @@ pub mod attachments {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         if let Some(file_name) = &this.file_name {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -5700,6 +7237,9 @@ pub mod attachments {
                                 .query_pairs_mut()
                                 .append_pair("download", &download.to_string());
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -5725,8 +7265,8 @@ pub mod attachments {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -5976,6 +7516,136 @@ pub mod fields {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns information for all fields. The project ID/name parameter is optional."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Create a new field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New field definition"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemField2>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Gets information on a specific field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            field_name_or_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                field_name_or_ref_name,
+                project,
+            )
+        }
+        #[doc = "Update a field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Payload contains desired value of the field's properties"]
+        #[doc = "* `field_name_or_ref_name`: Name/reference name of the field to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::FieldUpdate>,
+            field_name_or_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                field_name_or_ref_name,
+                project,
+            )
+        }
+        #[doc = "Deletes the field. To undelete a filed, see \"Update Field\" API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(
+            &self,
+            field_name_or_ref_name: impl Into<String>,
+            project: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                field_name_or_ref_name,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns information for all fields. The project ID/name parameter is optional."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Create a new field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: New field definition"]
+        pub fn create(&self, body: impl Into<models::WorkItemField2>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets information on a specific field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        pub fn get(&self, field_name_or_ref_name: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                field_name_or_ref_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Update a field."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Payload contains desired value of the field's properties"]
+        #[doc = "* `field_name_or_ref_name`: Name/reference name of the field to be updated"]
+        pub fn update(
+            &self,
+            body: impl Into<models::FieldUpdate>,
+            field_name_or_ref_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                field_name_or_ref_name,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Deletes the field. To undelete a filed, see \"Update Field\" API."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `field_name_or_ref_name`: Field simple name or reference name"]
+        pub fn delete(&self, field_name_or_ref_name: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                field_name_or_ref_name,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6017,6 +7687,31 @@ pub mod fields {
                 self.as_raw_response()
             }
         }
+        #[doc = "Use ExtensionFields to include extension fields, otherwise exclude them. Unless the feature flag for this parameter is enabled, extension fields are always included."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            ExtensionFields,
+            IncludeDeleted,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::ExtensionFields => "extensionFields",
+                    Self::IncludeDeleted => "includeDeleted",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -6037,11 +7732,11 @@ pub mod fields {
             pub(crate) client: super::super::Client,
             pub(crate) organization: String,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Use ExtensionFields to include extension fields, otherwise exclude them. Unless the feature flag for this parameter is enabled, extension fields are always included."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -6066,7 +7761,7 @@ pub mod fields {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -6595,6 +8290,38 @@ pub mod project_process_migration {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Migrates a project to a different process within the same OOB type. For example, you can only migrate a project from agile/custom-agile to agile/custom-agile."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn migrate_projects_process(
+            &self,
+            body: impl Into<models::ProcessIdModel>,
+            project: impl Into<String>,
+        ) -> migrate_projects_process::RequestBuilder {
+            Client(self.0.client.clone()).migrate_projects_process(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Migrates a project to a different process within the same OOB type. For example, you can only migrate a project from agile/custom-agile to agile/custom-agile."]
+        pub fn migrate_projects_process(
+            &self,
+            body: impl Into<models::ProcessIdModel>,
+        ) -> migrate_projects_process::RequestBuilder {
+            Client(self.0.client.clone()).migrate_projects_process(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod migrate_projects_process {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6747,6 +8474,23 @@ pub mod reporting_work_item_links {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a batch of work item links"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a batch of work item links"]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6871,9 +8615,10 @@ pub mod reporting_work_item_links {
                                 .append_pair("continuationToken", continuation_token);
                         }
                         if let Some(start_date_time) = &this.start_date_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startDateTime", &start_date_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startDateTime",
+                                &crate::date_time::format_date_time(start_date_time)?,
+                            );
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -6971,6 +8716,58 @@ pub mod reporting_work_item_revisions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a batch of work item revisions with the option of including deleted items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_revisions_get(
+            &self,
+            project: impl Into<String>,
+        ) -> read_reporting_revisions_get::RequestBuilder {
+            Client(self.0.client.clone())
+                .read_reporting_revisions_get(self.0.organization.clone(), project)
+        }
+        #[doc = "Get a batch of work item revisions. This request may be used if your list of fields is large enough that it may run the URL over the length limit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains request settings: field filter, type filter, identity format"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_revisions_post(
+            &self,
+            body: impl Into<models::ReportingWorkItemRevisionsFilter>,
+            project: impl Into<String>,
+        ) -> read_reporting_revisions_post::RequestBuilder {
+            Client(self.0.client.clone()).read_reporting_revisions_post(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a batch of work item revisions with the option of including deleted items"]
+        pub fn read_reporting_revisions_get(&self) -> read_reporting_revisions_get::RequestBuilder {
+            Client(self.0.client.clone())
+                .read_reporting_revisions_get(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get a batch of work item revisions. This request may be used if your list of fields is large enough that it may run the URL over the length limit."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: An object that contains request settings: field filter, type filter, identity format"]
+        pub fn read_reporting_revisions_post(
+            &self,
+            body: impl Into<models::ReportingWorkItemRevisionsFilter>,
+        ) -> read_reporting_revisions_post::RequestBuilder {
+            Client(self.0.client.clone()).read_reporting_revisions_post(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod read_reporting_revisions_get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7014,6 +8811,29 @@ pub mod reporting_work_item_revisions {
                 self.as_raw_response()
             }
         }
+        #[doc = "Return all the fields in work item revisions, including long text fields which are not returned by default"]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Fields,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Fields => "fields",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -7042,7 +8862,7 @@ pub mod reporting_work_item_revisions {
             pub(crate) include_deleted: Option<bool>,
             pub(crate) include_tag_ref: Option<bool>,
             pub(crate) include_latest_only: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
             pub(crate) include_discussion_changes_only: Option<bool>,
             pub(crate) max_page_size: Option<i32>,
         }
@@ -7091,7 +8911,7 @@ pub mod reporting_work_item_revisions {
                 self
             }
             #[doc = "Return all the fields in work item revisions, including long text fields which are not returned by default"]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7140,9 +8960,10 @@ pub mod reporting_work_item_revisions {
                                 .append_pair("continuationToken", continuation_token);
                         }
                         if let Some(start_date_time) = &this.start_date_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startDateTime", &start_date_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startDateTime",
+                                &crate::date_time::format_date_time(start_date_time)?,
+                            );
                         }
                         if let Some(include_identity_ref) = &this.include_identity_ref {
                             req.url_mut().query_pairs_mut().append_pair(
@@ -7168,7 +8989,7 @@ pub mod reporting_work_item_revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(include_discussion_changes_only) =
                             &this.include_discussion_changes_only
@@ -7263,6 +9084,28 @@ pub mod reporting_work_item_revisions {
                 self.as_raw_response()
             }
         }
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Fields,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Fields => "fields",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -7286,7 +9129,7 @@ pub mod reporting_work_item_revisions {
             pub(crate) project: String,
             pub(crate) continuation_token: Option<String>,
             pub(crate) start_date_time: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Specifies the watermark to start the batch from. Omit this parameter to get the first batch of revisions."]
@@ -7302,7 +9145,7 @@ pub mod reporting_work_item_revisions {
                 self.start_date_time = Some(start_date_time.into());
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -7332,14 +9175,15 @@ pub mod reporting_work_item_revisions {
                                 .append_pair("continuationToken", continuation_token);
                         }
                         if let Some(start_date_time) = &this.start_date_time {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startDateTime", &start_date_time.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startDateTime",
+                                &crate::date_time::format_date_time(start_date_time)?,
+                            );
                         }
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -7403,6 +9247,25 @@ pub mod work_item_revisions_discussions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn read_reporting_discussions(
+            &self,
+            project: impl Into<String>,
+        ) -> read_reporting_discussions::RequestBuilder {
+            Client(self.0.client.clone())
+                .read_reporting_discussions(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn read_reporting_discussions(&self) -> read_reporting_discussions::RequestBuilder {
+            Client(self.0.client.clone())
+                .read_reporting_discussions(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod read_reporting_discussions {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7571,6 +9434,34 @@ pub mod send_mail {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "RESTful method to send mail for selected/queried work items."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn send_mail(
+            &self,
+            body: impl Into<models::SendMailBody>,
+            project: impl Into<String>,
+        ) -> send_mail::RequestBuilder {
+            Client(self.0.client.clone()).send_mail(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "RESTful method to send mail for selected/queried work items."]
+        pub fn send_mail(
+            &self,
+            body: impl Into<models::SendMailBody>,
+        ) -> send_mail::RequestBuilder {
+            Client(self.0.client.clone()).send_mail(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod send_mail {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -7763,6 +9654,107 @@ pub mod tags {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all the tags for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Get the tag for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, tag_id_or_name)
+        }
+        #[doc = "Update the tag for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTagDefinition>,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                tag_id_or_name,
+            )
+        }
+        #[doc = "Delete the tag for the project. Please note, that the deleted tag will be removed from all Work Items as well as Pull Requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            tag_id_or_name: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                tag_id_or_name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all the tags for the project."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get the tag for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn get(&self, tag_id_or_name: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                tag_id_or_name,
+            )
+        }
+        #[doc = "Update the tag for the project."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn update(
+            &self,
+            body: impl Into<models::WorkItemTagDefinition>,
+            tag_id_or_name: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                tag_id_or_name,
+            )
+        }
+        #[doc = "Delete the tag for the project. Please note, that the deleted tag will be removed from all Work Items as well as Pull Requests."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `tag_id_or_name`: Tag ID or tag name."]
+        pub fn delete(&self, tag_id_or_name: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                tag_id_or_name,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8257,6 +10249,38 @@ pub mod temp_queries {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Creates a temporary query\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The temporary query to create"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TemporaryQueryRequestModel>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Creates a temporary query\n\nLearn more about Work Item Query Language (WIQL) syntax [here](https://docs.microsoft.com/en-us/vsts/collaborate/wiql-syntax?toc=/vsts/work/track/toc.json&bc=/vsts/work/track/breadcrumb/toc.json&view=vsts)."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The temporary query to create"]
+        pub fn create(
+            &self,
+            body: impl Into<models::TemporaryQueryRequestModel>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod create {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8563,6 +10587,251 @@ pub mod work_items {
                 project: project.into(),
             }
         }
+        #[doc = "Submits a batch of work item create and update requests (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `body`: The requests to submit"]
+        pub fn batch(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::WitBatchRequest>,
+        ) -> batch::RequestBuilder {
+            batch::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a list of work items (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: The comma-separated list of requested work item ids. (Maximum 200 ids allowed)."]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            ids: impl Into<String>,
+            project: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), ids, project)
+        }
+        #[doc = "Returns a single work item from a template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The work item type name"]
+        pub fn get_work_item_template(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get_work_item_template::RequestBuilder {
+            Client(self.0.client.clone()).get_work_item_template(
+                self.0.organization.clone(),
+                project,
+                type_,
+            )
+        }
+        #[doc = "Creates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when creating a Work Item"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The work item type of the work item to create"]
+        pub fn create(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, type_)
+        }
+        #[doc = "Returns a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The work item id"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_item(
+            &self,
+            id: i32,
+            project: impl Into<String>,
+        ) -> get_work_item::RequestBuilder {
+            Client(self.0.client.clone()).get_work_item(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Updates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when updating a Work Item"]
+        #[doc = "* `id`: The id of the work item to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn update(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            id: i32,
+            project: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, id, project)
+        }
+        #[doc = "Deletes the specified work item and sends it to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion. It is recommended NOT to use this parameter. If you do, please use this parameter with extreme caution."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be deleted"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete(&self, id: i32, project: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Gets work items for a list of work item ids (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get_work_items_batch(
+            &self,
+            body: impl Into<models::WorkItemBatchGetRequest>,
+            project: impl Into<String>,
+        ) -> get_work_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_work_items_batch(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Deletes specified work items and sends them to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn delete_work_items(
+            &self,
+            body: impl Into<models::WorkItemDeleteBatchRequest>,
+            project: impl Into<String>,
+        ) -> delete_work_items::RequestBuilder {
+            Client(self.0.client.clone()).delete_work_items(
+                self.0.organization.clone(),
+                body,
+                project,
+            )
+        }
+        #[doc = "Submits a batch of work item create and update requests (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The requests to submit"]
+        pub fn batch(&self, body: Vec<models::WitBatchRequest>) -> batch::RequestBuilder {
+            Client(self.0.client.clone()).batch(self.0.organization.clone(), body)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a list of work items (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `ids`: The comma-separated list of requested work item ids. (Maximum 200 ids allowed)."]
+        pub fn list(&self, ids: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                ids,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Returns a single work item from a template."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: The work item type name"]
+        pub fn get_work_item_template(
+            &self,
+            type_: impl Into<String>,
+        ) -> get_work_item_template::RequestBuilder {
+            Client(self.0.client.clone()).get_work_item_template(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_,
+            )
+        }
+        #[doc = "Creates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when creating a Work Item"]
+        #[doc = "* `type_`: The work item type of the work item to create"]
+        pub fn create(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            type_: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                type_,
+            )
+        }
+        #[doc = "Returns a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The work item id"]
+        pub fn get_work_item(&self, id: i32) -> get_work_item::RequestBuilder {
+            Client(self.0.client.clone()).get_work_item(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Updates a single work item."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: A list of operations to perform when updating a Work Item"]
+        #[doc = "* `id`: The id of the work item to update"]
+        pub fn update(
+            &self,
+            body: Vec<models::JsonPatchOperation>,
+            id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Deletes the specified work item and sends it to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion. It is recommended NOT to use this parameter. If you do, please use this parameter with extreme caution."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: ID of the work item to be deleted"]
+        pub fn delete(&self, id: i32) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Gets work items for a list of work item ids (Maximum 200)"]
+        pub fn get_work_items_batch(
+            &self,
+            body: impl Into<models::WorkItemBatchGetRequest>,
+        ) -> get_work_items_batch::RequestBuilder {
+            Client(self.0.client.clone()).get_work_items_batch(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Deletes specified work items and sends them to the Recycle Bin, so that it can be restored back, if required. Optionally, if the destroy parameter has been set to true, it destroys the work item permanently. WARNING: If the destroy parameter is set to true, work items deleted by this command will NOT go to recycle-bin and there is no way to restore/recover them after deletion."]
+        pub fn delete_work_items(
+            &self,
+            body: impl Into<models::WorkItemDeleteBatchRequest>,
+        ) -> delete_work_items::RequestBuilder {
+            Client(self.0.client.clone()).delete_work_items(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Submits a batch of work item create and update requests (Maximum 200)"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The requests to submit"]
+        pub fn batch(&self, body: Vec<models::WitBatchRequest>) -> batch::RequestBuilder {
+            Client(self.0.client.clone()).batch(self.0.organization.clone(), body)
+        }
     }
     pub mod list {
         use super::models;
@@ -8604,6 +10873,58 @@ pub mod work_items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        #[doc = "The flag to control error policy in a bulk get work items request. Possible options are {Fail, Omit}."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum ErrorPolicy {
+            Fail,
+            Omit,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl ErrorPolicy {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::Fail => "fail",
+                    Self::Omit => "omit",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for ErrorPolicy {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -8627,8 +10948,8 @@ pub mod work_items {
             pub(crate) project: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
-            pub(crate) error_policy: Option<String>,
+            pub(crate) expand: Option<Expand>,
+            pub(crate) error_policy: Option<ErrorPolicy>,
         }
         impl RequestBuilder {
             #[doc = "Comma-separated list of requested fields"]
@@ -8642,12 +10963,12 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
             #[doc = "The flag to control error policy in a bulk get work items request. Possible options are {Fail, Omit}."]
-            pub fn error_policy(mut self, error_policy: impl Into<String>) -> Self {
+            pub fn error_policy(mut self, error_policy: impl Into<ErrorPolicy>) -> Self {
                 self.error_policy = Some(error_policy.into());
                 self
             }
@@ -8679,17 +11000,17 @@ pub mod work_items {
                         if let Some(as_of) = &this.as_of {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("asOf", &as_of.to_string());
+                                .append_pair("asOf", &crate::date_time::format_date_time(as_of)?);
                         }
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         if let Some(error_policy) = &this.error_policy {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("errorPolicy", error_policy);
+                                .append_pair("errorPolicy", error_policy.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -8767,6 +11088,35 @@ pub mod work_items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -8790,7 +11140,7 @@ pub mod work_items {
             pub(crate) type_: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Comma-separated list of requested fields"]
@@ -8804,7 +11154,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -8834,12 +11184,12 @@ pub mod work_items {
                         if let Some(as_of) = &this.as_of {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("asOf", &as_of.to_string());
+                                .append_pair("asOf", &crate::date_time::format_date_time(as_of)?);
                         }
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -8918,6 +11268,35 @@ pub mod work_items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -8943,7 +11322,7 @@ pub mod work_items {
             pub(crate) validate_only: Option<bool>,
             pub(crate) bypass_rules: Option<bool>,
             pub(crate) suppress_notifications: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Indicate if you only want to validate the changes without saving the work item"]
@@ -8962,7 +11341,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -9005,7 +11384,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9083,6 +11462,35 @@ pub mod work_items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -9106,7 +11514,7 @@ pub mod work_items {
             pub(crate) project: String,
             pub(crate) fields: Option<String>,
             pub(crate) as_of: Option<time::OffsetDateTime>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Comma-separated list of requested fields"]
@@ -9120,7 +11528,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -9150,12 +11558,12 @@ pub mod work_items {
                         if let Some(as_of) = &this.as_of {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("asOf", &as_of.to_string());
+                                .append_pair("asOf", &crate::date_time::format_date_time(as_of)?);
                         }
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -9234,6 +11642,35 @@ pub mod work_items {
                 self.as_raw_response()
             }
         }
+        #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -9259,7 +11696,7 @@ pub mod work_items {
             pub(crate) validate_only: Option<bool>,
             pub(crate) bypass_rules: Option<bool>,
             pub(crate) suppress_notifications: Option<bool>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Indicate if you only want to validate the changes without saving the work item"]
@@ -9278,7 +11715,7 @@ pub mod work_items {
                 self
             }
             #[doc = "The expand parameters for work item attributes. Possible options are { None, Relations, Fields, Links, All }."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -9321,7 +11758,7 @@ pub mod work_items {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -9725,6 +12162,123 @@ pub mod work_items {
             }
         }
     }
+    pub mod batch {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::WitBatchResponseList> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::WitBatchResponseList =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
+        #[derive(Clone)]
+        #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
+        #[doc = r""]
+        #[doc = r" Each `RequestBuilder` parameter method call returns `Self`, so setting of multiple"]
+        #[doc = r" parameters can be chained."]
+        #[doc = r""]
+        #[doc = r" To finalize and submit the request, invoke `.await`, which"]
+        #[doc = r" converts the [`RequestBuilder`] into a future,"]
+        #[doc = r" executes the request and returns a `Result` with the parsed"]
+        #[doc = r" response."]
+        #[doc = r""]
+        #[doc = r" If you need lower-level access to the raw response details"]
+        #[doc = r" (e.g. to inspect response headers or raw body data) then you"]
+        #[doc = r" can finalize the request using the"]
+        #[doc = r" [`RequestBuilder::send()`] method which returns a future"]
+        #[doc = r" that resolves to a lower-level [`Response`] value."]
+        pub struct RequestBuilder {
+            pub(crate) client: super::super::Client,
+            pub(crate) organization: String,
+            pub(crate) body: Vec<models::WitBatchRequest>,
+        }
+        impl RequestBuilder {
+            #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
+            #[doc = ""]
+            #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
+            #[doc = "However, this function can provide more flexibility when required."]
+            pub fn send(self) -> BoxFuture<'static, azure_core::Result<Response>> {
+                Box::pin({
+                    let this = self.clone();
+                    async move {
+                        let url = this.url()?;
+                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                        if let Some(auth_header) = this
+                            .client
+                            .token_credential()
+                            .http_authorization_header(&this.client.scopes())
+                            .await?
+                        {
+                            req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
+                        }
+                        req.insert_header("content-type", "application/json");
+                        let req_body = azure_core::to_json(&this.body)?;
+                        req.set_body(req_body);
+                        Ok(Response(this.client.send(&mut req).await?))
+                    }
+                })
+            }
+            fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/{}/_apis/wit/$batch",
+                    self.client.endpoint(),
+                    &self.organization
+                ))?;
+                let has_api_version_already = url
+                    .query_pairs()
+                    .any(|(k, _)| k == azure_core::query_param::API_VERSION);
+                if !has_api_version_already {
+                    url.query_pairs_mut()
+                        .append_pair(azure_core::query_param::API_VERSION, "7.1-preview");
+                }
+                Ok(url)
+            }
+        }
+        impl std::future::IntoFuture for RequestBuilder {
+            type Output = azure_core::Result<models::WitBatchResponseList>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<models::WitBatchResponseList>>;
+            #[doc = "Returns a future that sends the request and returns the parsed response body."]
+            #[doc = ""]
+            #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
+            #[doc = ""]
+            #[doc = "See [IntoFuture documentation](https://doc.rust-lang.org/std/future/trait.IntoFuture.html) for more details."]
+            fn into_future(self) -> Self::IntoFuture {
+                Box::pin(async move { self.send().await?.into_body().await })
+            }
+        }
+    }
 }
 pub mod revisions {
     use super::models;
@@ -9777,6 +12331,53 @@ pub mod revisions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the list of fully hydrated work item revisions, paged."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, id: i32, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Returns a fully hydrated work item for the requested revision"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: i32,
+            revision_number: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                revision_number,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the list of fully hydrated work item revisions, paged."]
+        pub fn list(&self, id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Returns a fully hydrated work item for the requested revision"]
+        pub fn get(&self, id: i32, revision_number: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                revision_number,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -9817,6 +12418,34 @@ pub mod revisions {
                 self.as_raw_response()
             }
         }
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -9840,7 +12469,7 @@ pub mod revisions {
             pub(crate) project: String,
             pub(crate) top: Option<i32>,
             pub(crate) skip: Option<i32>,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             pub fn top(mut self, top: i32) -> Self {
@@ -9851,7 +12480,7 @@ pub mod revisions {
                 self.skip = Some(skip);
                 self
             }
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -9886,7 +12515,7 @@ pub mod revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -9911,6 +12540,18 @@ pub mod revisions {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::WorkItem> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WorkItemList>;
@@ -9965,6 +12606,34 @@ pub mod revisions {
                 self.as_raw_response()
             }
         }
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            Relations,
+            Fields,
+            Links,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::Relations => "relations",
+                    Self::Fields => "fields",
+                    Self::Links => "links",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -9987,10 +12656,10 @@ pub mod revisions {
             pub(crate) id: i32,
             pub(crate) revision_number: i32,
             pub(crate) project: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -10015,7 +12684,7 @@ pub mod revisions {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -10105,6 +12774,53 @@ pub mod updates {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns a the deltas between work item revisions"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, id: i32, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), id, project)
+        }
+        #[doc = "Returns a single update for a work item"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            id: i32,
+            update_number: i32,
+            project: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                update_number,
+                project,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns a the deltas between work item revisions"]
+        pub fn list(&self, id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Returns a single update for a work item"]
+        pub fn get(&self, id: i32, update_number: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                update_number,
+                self.0.project.clone(),
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10230,6 +12946,18 @@ pub mod updates {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::WorkItemUpdate> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::WorkItemUpdateList>;
@@ -10380,70 +13108,192 @@ pub mod comments_reactions {
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
-        pub fn list(
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                work_item_id,
+                comment_id,
+            }
+        }
+        #[doc = "Adds a new reaction to a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn create(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> create::RequestBuilder {
+            create::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                work_item_id,
+                comment_id,
+                reaction_type: reaction_type.into(),
+            }
+        }
+        #[doc = "Deletes an existing reaction on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn delete(
             &self,
             organization: impl Into<String>,
             project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
+            reaction_type: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
                 client: self.0.clone(),
                 organization: organization.into(),
                 project: project.into(),
                 work_item_id,
                 comment_id,
+                reaction_type: reaction_type.into(),
             }
         }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets reactions of a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
         #[doc = "Adds a new reaction to a comment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
         #[doc = "* `reaction_type`: Type of the reaction"]
         pub fn create(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
             reaction_type: impl Into<String>,
         ) -> create::RequestBuilder {
-            create::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                project,
                 work_item_id,
                 comment_id,
-                reaction_type: reaction_type.into(),
-            }
+                reaction_type,
+            )
         }
         #[doc = "Deletes an existing reaction on a comment."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `work_item_id`: WorkItem ID"]
         #[doc = "* `comment_id`: Comment ID"]
         #[doc = "* `reaction_type`: Type of the reaction"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             work_item_id: i32,
             comment_id: i32,
             reaction_type: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
                 work_item_id,
                 comment_id,
-                reaction_type: reaction_type.into(),
-            }
+                reaction_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets reactions of a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        pub fn list(&self, work_item_id: i32, comment_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Adds a new reaction to a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn create(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+        #[doc = "Deletes an existing reaction on a comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID"]
+        #[doc = "* `comment_id`: Comment ID"]
+        #[doc = "* `reaction_type`: Type of the reaction"]
+        pub fn delete(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
         }
     }
     pub mod list {
@@ -10850,6 +13700,54 @@ pub mod comment_reactions_engaged_users {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get users who reacted on the comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `work_item_id`: WorkItem ID."]
+        #[doc = "* `comment_id`: Comment ID."]
+        #[doc = "* `reaction_type`: Type of the reaction."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get users who reacted on the comment."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `work_item_id`: WorkItem ID."]
+        #[doc = "* `comment_id`: Comment ID."]
+        #[doc = "* `reaction_type`: Type of the reaction."]
+        pub fn list(
+            &self,
+            work_item_id: i32,
+            comment_id: i32,
+            reaction_type: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+                reaction_type,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -10979,6 +13877,18 @@ pub mod comment_reactions_engaged_users {
                 }
                 Ok(url)
             }
+            #[doc = "Returns a stream of pages of results."]
+            #[doc = ""]
+            #[doc = "Each page is requested as the stream is polled, advancing `$skip` by the number of items"]
+            #[doc = "returned with the previous page, until all results have been fetched."]
+            #[doc = "Use [`Pageable::items()`](crate::paging::Pageable::items) to stream the individual items."]
+            pub fn into_stream(self) -> crate::paging::Pageable<models::IdentityRef> {
+                crate::paging::Pageable::by_skip(self.skip, self.top, move |skip| {
+                    let mut this = self.clone();
+                    this.skip = Some(skip);
+                    Box::pin(async move { Ok(this.send().await?.into_raw_response()) })
+                })
+            }
         }
         impl std::future::IntoFuture for RequestBuilder {
             type Output = azure_core::Result<models::IdentityRefList>;
@@ -11041,6 +13951,61 @@ pub mod comments_versions {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            work_item_id: i32,
+            comment_id: i32,
+            version: i32,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                work_item_id,
+                comment_id,
+                version,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        pub fn list(&self, work_item_id: i32, comment_id: i32) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+            )
+        }
+        pub fn get(&self, work_item_id: i32, comment_id: i32, version: i32) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                work_item_id,
+                comment_id,
+                version,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11329,6 +14294,46 @@ pub mod work_item_type_categories {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get all work item type categories."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Get specific work item type category by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `category`: The category name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            category: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, category)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get all work item type categories."]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Get specific work item type category by name."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `category`: The category name"]
+        pub fn get(&self, category: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                category,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11611,6 +14616,46 @@ pub mod work_item_types {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the list of work item types"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Returns a work item type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, type_)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the list of work item types"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Returns a work item type definition."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Work item type name"]
+        pub fn get(&self, type_: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -11858,44 +14903,102 @@ pub mod work_item_types_field {
         #[doc = "Get a list of fields for a work item type with detailed references."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn list(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                type_: type_.into(),
+                expand: None,
+            }
+        }
+        #[doc = "Get a field for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+            field: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                type_: type_.into(),
+                field: field.into(),
+                expand: None,
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a list of fields for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, type_)
+        }
+        #[doc = "Get a field for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `type_`: Work item type."]
-        pub fn list(
+        pub fn get(
             &self,
-            organization: impl Into<String>,
             project: impl Into<String>,
             type_: impl Into<String>,
-        ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                type_: type_.into(),
-                expand: None,
-            }
+            field: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, type_, field)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a list of fields for a work item type with detailed references."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: Work item type."]
+        pub fn list(&self, type_: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_,
+            )
         }
         #[doc = "Get a field for a work item type with detailed references."]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `type_`: Work item type."]
         pub fn get(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             type_: impl Into<String>,
             field: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                type_: type_.into(),
-                field: field.into(),
-                expand: None,
-            }
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_,
+                field,
+            )
         }
     }
     pub mod list {
@@ -11941,6 +15044,33 @@ pub mod work_item_types_field {
                 self.as_raw_response()
             }
         }
+        #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            AllowedValues,
+            DependentFields,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::AllowedValues => "allowedValues",
+                    Self::DependentFields => "dependentFields",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -11962,11 +15092,11 @@ pub mod work_item_types_field {
             pub(crate) organization: String,
             pub(crate) project: String,
             pub(crate) type_: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -11991,7 +15121,7 @@ pub mod work_item_types_field {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -12074,6 +15204,33 @@ pub mod work_item_types_field {
                 self.as_raw_response()
             }
         }
+        #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum Expand {
+            None,
+            AllowedValues,
+            DependentFields,
+            All,
+            #[doc = "A value that is not one of the values listed in the specification."]
+            Other(String),
+        }
+        impl Expand {
+            #[doc = "Returns the value that is sent in the query string."]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    Self::None => "none",
+                    Self::AllowedValues => "allowedValues",
+                    Self::DependentFields => "dependentFields",
+                    Self::All => "all",
+                    Self::Other(value) => value.as_str(),
+                }
+            }
+        }
+        impl std::fmt::Display for Expand {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
         #[derive(Clone)]
         #[doc = r" `RequestBuilder` provides a mechanism for setting optional parameters on a request."]
         #[doc = r""]
@@ -12096,11 +15253,11 @@ pub mod work_item_types_field {
             pub(crate) project: String,
             pub(crate) type_: String,
             pub(crate) field: String,
-            pub(crate) expand: Option<String>,
+            pub(crate) expand: Option<Expand>,
         }
         impl RequestBuilder {
             #[doc = "Expand level for the API response. Properties: to include allowedvalues, default value, isRequired etc. as a part of response; None: to skip these properties."]
-            pub fn expand(mut self, expand: impl Into<String>) -> Self {
+            pub fn expand(mut self, expand: impl Into<Expand>) -> Self {
                 self.expand = Some(expand.into());
                 self
             }
@@ -12125,7 +15282,7 @@ pub mod work_item_types_field {
                         if let Some(expand) = &this.expand {
                             req.url_mut()
                                 .query_pairs_mut()
-                                .append_pair("$expand", expand);
+                                .append_pair("$expand", expand.as_str());
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -12195,6 +15352,35 @@ pub mod work_item_type_states {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the state names and colors for a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `type_`: The state name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            type_: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, type_)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the state names and colors for a work item type."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `type_`: The state name"]
+        pub fn list(&self, type_: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                type_,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -12436,6 +15622,180 @@ pub mod templates {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Creates a template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project, team)
+        }
+        #[doc = "Gets the template with specified id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template Id"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Replace template contents"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents to replace with"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn replace_template(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> replace_template::RequestBuilder {
+            Client(self.0.client.clone()).replace_template(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Deletes the template with given id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                project,
+                team,
+                template_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Creates a template"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            team: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Gets the template with specified id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template Id"]
+        pub fn get(
+            &self,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Replace template contents"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Template contents to replace with"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn replace_template(
+            &self,
+            body: impl Into<models::WorkItemTemplate>,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> replace_template::RequestBuilder {
+            Client(self.0.client.clone()).replace_template(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+                template_id,
+            )
+        }
+        #[doc = "Deletes the template with given id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `template_id`: Template id"]
+        pub fn delete(
+            &self,
+            team: impl Into<String>,
+            template_id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                template_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -13125,6 +16485,111 @@ pub mod wiql {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets the results of the query given its WIQL."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query containing the WIQL."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_wiql(
+            &self,
+            body: impl Into<models::Wiql>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_wiql::RequestBuilder {
+            Client(self.0.client.clone()).query_by_wiql(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_id(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_id::RequestBuilder {
+            Client(self.0.client.clone()).query_by_id(
+                self.0.organization.clone(),
+                id,
+                project,
+                team,
+            )
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            id: impl Into<String>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), id, project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets the results of the query given its WIQL."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: The query containing the WIQL."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_wiql(
+            &self,
+            body: impl Into<models::Wiql>,
+            team: impl Into<String>,
+        ) -> query_by_wiql::RequestBuilder {
+            Client(self.0.client.clone()).query_by_wiql(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn query_by_id(
+            &self,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> query_by_id::RequestBuilder {
+            Client(self.0.client.clone()).query_by_id(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Gets the results of the query given the query ID."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: The query ID."]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                id,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod query_by_wiql {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
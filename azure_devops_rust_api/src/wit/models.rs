@@ -1558,10 +1558,52 @@ impl ReportingWorkItemLinksBatch {
         Self::default()
     }
 }
+#[doc = "A work item revision returned by the reporting revisions APIs."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReportingWorkItemRevision {
+    #[doc = "Map of field reference names to the values of the revision."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Value>,
+    #[doc = "The work item ID."]
+    pub id: i32,
+    #[doc = "The revision number."]
+    pub rev: i32,
+}
+impl ReportingWorkItemRevision {
+    pub fn new(id: i32, rev: i32) -> Self {
+        Self {
+            fields: None,
+            id,
+            rev,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ReportingWorkItemRevisionsBatch {
-    #[serde(flatten)]
-    pub streamed_batch: StreamedBatch,
+    #[doc = "ContinuationToken acts as a waterMark. Used while querying large results."]
+    #[serde(
+        rename = "continuationToken",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub continuation_token: Option<String>,
+    #[doc = "Returns 'true' if it's last batch, 'false' otherwise."]
+    #[serde(
+        rename = "isLastBatch",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_last_batch: Option<bool>,
+    #[doc = "The next link for the work item."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+    #[doc = "The work item revisions."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub values: Vec<ReportingWorkItemRevision>,
 }
 impl ReportingWorkItemRevisionsBatch {
     pub fn new() -> Self {
@@ -1796,6 +1838,71 @@ impl Wiql {
         Self::default()
     }
 }
+#[doc = "A request in a work item tracking batch request."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WitBatchRequest {
+    #[doc = "The operations to perform on the work item."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub body: Vec<JsonPatchOperation>,
+    #[doc = "The request headers, e.g. `Content-Type: application/json-patch+json`."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<serde_json::Value>,
+    #[doc = "The HTTP method, e.g. `PATCH`."]
+    pub method: String,
+    #[doc = "The request URI, relative to the organization, e.g. `/_apis/wit/workitems/1?api-version=7.1`."]
+    pub uri: String,
+}
+impl WitBatchRequest {
+    pub fn new(method: String, uri: String) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: None,
+            method,
+            uri,
+        }
+    }
+}
+#[doc = "The response to a request in a work item tracking batch request."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WitBatchResponse {
+    #[doc = "The response body, a JSON encoded work item or error."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[doc = "The HTTP status code."]
+    pub code: i32,
+    #[doc = "The response headers."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<serde_json::Value>,
+}
+impl WitBatchResponse {
+    pub fn new(code: i32) -> Self {
+        Self {
+            body: None,
+            code,
+            headers: None,
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct WitBatchResponseList {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<WitBatchResponse>,
+}
+impl WitBatchResponseList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 #[doc = "A work artifact link describes an outbound artifact link type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct WorkArtifactLink {
@@ -119,8 +119,8 @@ impl Client {
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
             options,
-            Vec::new(),
-            Vec::new(),
+            vec![crate::error::retry_error_policy()],
+            vec![crate::error::error_policy()],
         );
         Self {
             endpoint,
@@ -129,6 +129,33 @@ impl Client {
             pipeline,
         }
     }
+    #[doc = "Create a new `Client` that sends requests through an existing pipeline."]
+    #[doc = "If no endpoint is specified, the default endpoint for this service is used."]
+    #[must_use]
+    pub(crate) fn from_pipeline(
+        endpoint: Option<azure_core::Url>,
+        credential: crate::Credential,
+        scopes: Vec<String>,
+        pipeline: azure_core::Pipeline,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
+        Self {
+            endpoint,
+            credential,
+            scopes,
+            pipeline,
+        }
+    }
+    #[doc = "Create a client scoped to an organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` argument."]
+    #[must_use]
+    pub fn for_organization(&self, organization: impl Into<String>) -> OrganizationClient {
+        OrganizationClient {
+            client: self.clone(),
+            organization: organization.into(),
+        }
+    }
     pub fn backlogconfiguration_client(&self) -> backlogconfiguration::Client {
         backlogconfiguration::Client(self.clone())
     }
@@ -205,6 +232,196 @@ impl Client {
         workitemsorder::Client(self.clone())
     }
 }
+#[doc = "A client scoped to an organization, created by `Client::for_organization`."]
+#[derive(Clone)]
+pub struct OrganizationClient {
+    client: Client,
+    organization: String,
+}
+impl OrganizationClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "Create a client scoped to a project within this organization."]
+    #[doc = ""]
+    #[doc = "The functions of the scoped client omit the `organization` and `project` arguments."]
+    #[must_use]
+    pub fn for_project(&self, project: impl Into<String>) -> ProjectClient {
+        ProjectClient {
+            client: self.client.clone(),
+            organization: self.organization.clone(),
+            project: project.into(),
+        }
+    }
+    pub fn backlogconfiguration_client(&self) -> backlogconfiguration::OrganizationClient {
+        backlogconfiguration::OrganizationClient(self.clone())
+    }
+    pub fn backlogs_client(&self) -> backlogs::OrganizationClient {
+        backlogs::OrganizationClient(self.clone())
+    }
+    pub fn boardcolumns_client(&self) -> boardcolumns::OrganizationClient {
+        boardcolumns::OrganizationClient(self.clone())
+    }
+    pub fn boardparents_client(&self) -> boardparents::OrganizationClient {
+        boardparents::OrganizationClient(self.clone())
+    }
+    pub fn boardrows_client(&self) -> boardrows::OrganizationClient {
+        boardrows::OrganizationClient(self.clone())
+    }
+    pub fn boards_client(&self) -> boards::OrganizationClient {
+        boards::OrganizationClient(self.clone())
+    }
+    pub fn boardusersettings_client(&self) -> boardusersettings::OrganizationClient {
+        boardusersettings::OrganizationClient(self.clone())
+    }
+    pub fn capacities_client(&self) -> capacities::OrganizationClient {
+        capacities::OrganizationClient(self.clone())
+    }
+    pub fn cardrulesettings_client(&self) -> cardrulesettings::OrganizationClient {
+        cardrulesettings::OrganizationClient(self.clone())
+    }
+    pub fn cardsettings_client(&self) -> cardsettings::OrganizationClient {
+        cardsettings::OrganizationClient(self.clone())
+    }
+    pub fn chartimages_client(&self) -> chartimages::OrganizationClient {
+        chartimages::OrganizationClient(self.clone())
+    }
+    pub fn charts_client(&self) -> charts::OrganizationClient {
+        charts::OrganizationClient(self.clone())
+    }
+    pub fn columns_client(&self) -> columns::OrganizationClient {
+        columns::OrganizationClient(self.clone())
+    }
+    pub fn deliverytimeline_client(&self) -> deliverytimeline::OrganizationClient {
+        deliverytimeline::OrganizationClient(self.clone())
+    }
+    pub fn iterationcapacities_client(&self) -> iterationcapacities::OrganizationClient {
+        iterationcapacities::OrganizationClient(self.clone())
+    }
+    pub fn iterations_client(&self) -> iterations::OrganizationClient {
+        iterations::OrganizationClient(self.clone())
+    }
+    pub fn plans_client(&self) -> plans::OrganizationClient {
+        plans::OrganizationClient(self.clone())
+    }
+    pub fn processconfiguration_client(&self) -> processconfiguration::OrganizationClient {
+        processconfiguration::OrganizationClient(self.clone())
+    }
+    pub fn rows_client(&self) -> rows::OrganizationClient {
+        rows::OrganizationClient(self.clone())
+    }
+    pub fn taskboard_columns_client(&self) -> taskboard_columns::OrganizationClient {
+        taskboard_columns::OrganizationClient(self.clone())
+    }
+    pub fn taskboard_work_items_client(&self) -> taskboard_work_items::OrganizationClient {
+        taskboard_work_items::OrganizationClient(self.clone())
+    }
+    pub fn teamdaysoff_client(&self) -> teamdaysoff::OrganizationClient {
+        teamdaysoff::OrganizationClient(self.clone())
+    }
+    pub fn teamfieldvalues_client(&self) -> teamfieldvalues::OrganizationClient {
+        teamfieldvalues::OrganizationClient(self.clone())
+    }
+    pub fn teamsettings_client(&self) -> teamsettings::OrganizationClient {
+        teamsettings::OrganizationClient(self.clone())
+    }
+    pub fn workitemsorder_client(&self) -> workitemsorder::OrganizationClient {
+        workitemsorder::OrganizationClient(self.clone())
+    }
+}
+#[doc = "A client scoped to a project, created by `OrganizationClient::for_project`."]
+#[derive(Clone)]
+pub struct ProjectClient {
+    client: Client,
+    organization: String,
+    project: String,
+}
+impl ProjectClient {
+    #[doc = "The organization this client is scoped to."]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+    #[doc = "The project this client is scoped to."]
+    pub fn project(&self) -> &str {
+        &self.project
+    }
+    pub fn backlogconfiguration_client(&self) -> backlogconfiguration::ProjectClient {
+        backlogconfiguration::ProjectClient(self.clone())
+    }
+    pub fn backlogs_client(&self) -> backlogs::ProjectClient {
+        backlogs::ProjectClient(self.clone())
+    }
+    pub fn boardcolumns_client(&self) -> boardcolumns::ProjectClient {
+        boardcolumns::ProjectClient(self.clone())
+    }
+    pub fn boardparents_client(&self) -> boardparents::ProjectClient {
+        boardparents::ProjectClient(self.clone())
+    }
+    pub fn boardrows_client(&self) -> boardrows::ProjectClient {
+        boardrows::ProjectClient(self.clone())
+    }
+    pub fn boards_client(&self) -> boards::ProjectClient {
+        boards::ProjectClient(self.clone())
+    }
+    pub fn boardusersettings_client(&self) -> boardusersettings::ProjectClient {
+        boardusersettings::ProjectClient(self.clone())
+    }
+    pub fn capacities_client(&self) -> capacities::ProjectClient {
+        capacities::ProjectClient(self.clone())
+    }
+    pub fn cardrulesettings_client(&self) -> cardrulesettings::ProjectClient {
+        cardrulesettings::ProjectClient(self.clone())
+    }
+    pub fn cardsettings_client(&self) -> cardsettings::ProjectClient {
+        cardsettings::ProjectClient(self.clone())
+    }
+    pub fn chartimages_client(&self) -> chartimages::ProjectClient {
+        chartimages::ProjectClient(self.clone())
+    }
+    pub fn charts_client(&self) -> charts::ProjectClient {
+        charts::ProjectClient(self.clone())
+    }
+    pub fn columns_client(&self) -> columns::ProjectClient {
+        columns::ProjectClient(self.clone())
+    }
+    pub fn deliverytimeline_client(&self) -> deliverytimeline::ProjectClient {
+        deliverytimeline::ProjectClient(self.clone())
+    }
+    pub fn iterationcapacities_client(&self) -> iterationcapacities::ProjectClient {
+        iterationcapacities::ProjectClient(self.clone())
+    }
+    pub fn iterations_client(&self) -> iterations::ProjectClient {
+        iterations::ProjectClient(self.clone())
+    }
+    pub fn plans_client(&self) -> plans::ProjectClient {
+        plans::ProjectClient(self.clone())
+    }
+    pub fn processconfiguration_client(&self) -> processconfiguration::ProjectClient {
+        processconfiguration::ProjectClient(self.clone())
+    }
+    pub fn rows_client(&self) -> rows::ProjectClient {
+        rows::ProjectClient(self.clone())
+    }
+    pub fn taskboard_columns_client(&self) -> taskboard_columns::ProjectClient {
+        taskboard_columns::ProjectClient(self.clone())
+    }
+    pub fn taskboard_work_items_client(&self) -> taskboard_work_items::ProjectClient {
+        taskboard_work_items::ProjectClient(self.clone())
+    }
+    pub fn teamdaysoff_client(&self) -> teamdaysoff::ProjectClient {
+        teamdaysoff::ProjectClient(self.clone())
+    }
+    pub fn teamfieldvalues_client(&self) -> teamfieldvalues::ProjectClient {
+        teamfieldvalues::ProjectClient(self.clone())
+    }
+    pub fn teamsettings_client(&self) -> teamsettings::ProjectClient {
+        teamsettings::ProjectClient(self.clone())
+    }
+    pub fn workitemsorder_client(&self) -> workitemsorder::ProjectClient {
+        workitemsorder::ProjectClient(self.clone())
+    }
+}
 pub mod boardcolumns {
     use super::models;
     #[cfg(not(target_arch = "wasm32"))]
@@ -230,6 +447,23 @@ pub mod boardcolumns {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get available board columns in a project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get available board columns in a project"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -374,6 +608,23 @@ pub mod boardrows {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get available board rows in a project"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get available board rows in a project"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -521,6 +772,35 @@ pub mod iterationcapacities {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get an iteration's capacity for all teams in iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, iteration_id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get an iteration's capacity for all teams in iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        pub fn get(&self, iteration_id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                iteration_id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -745,6 +1025,123 @@ pub mod plans {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get the information for all the plans configured for the given team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn list(&self, project: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project)
+        }
+        #[doc = "Add a new plan for the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn create(
+            &self,
+            body: impl Into<models::CreatePlan>,
+            project: impl Into<String>,
+        ) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(self.0.organization.clone(), body, project)
+        }
+        #[doc = "Get the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, id)
+        }
+        #[doc = "Update the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition to be updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdatePlan>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, id)
+        }
+        #[doc = "Delete the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get the information for all the plans configured for the given team"]
+        pub fn list(&self) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), self.0.project.clone())
+        }
+        #[doc = "Add a new plan for the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition"]
+        pub fn create(&self, body: impl Into<models::CreatePlan>) -> create::RequestBuilder {
+            Client(self.0.client.clone()).create(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+            )
+        }
+        #[doc = "Get the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+            )
+        }
+        #[doc = "Update the information for the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Plan definition to be updated"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdatePlan>,
+            id: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                id,
+            )
+        }
+        #[doc = "Delete the specified plan"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier of the plan"]
+        pub fn delete(&self, id: impl Into<String>) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1356,6 +1753,35 @@ pub mod deliverytimeline {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get Delivery View Data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: Identifier for delivery view"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, id)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get Delivery View Data"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: Identifier for delivery view"]
+        pub fn get(&self, id: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1462,14 +1888,16 @@ pub mod deliverytimeline {
                                 .append_pair("revision", &revision.to_string());
                         }
                         if let Some(start_date) = &this.start_date {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("startDate", &start_date.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "startDate",
+                                &crate::date_time::format_date_time(start_date)?,
+                            );
                         }
                         if let Some(end_date) = &this.end_date {
-                            req.url_mut()
-                                .query_pairs_mut()
-                                .append_pair("endDate", &end_date.to_string());
+                            req.url_mut().query_pairs_mut().append_pair(
+                                "endDate",
+                                &crate::date_time::format_date_time(end_date)?,
+                            );
                         }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
@@ -1534,6 +1962,23 @@ pub mod processconfiguration {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get process configuration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        pub fn get(&self, project: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get process configuration"]
+        pub fn get(&self) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), self.0.project.clone())
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1680,6 +2125,35 @@ pub mod backlogconfiguration {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Gets backlog configuration for a team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Gets backlog configuration for a team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -1871,18 +2345,117 @@ pub mod backlogs {
             }
         }
     }
-    pub mod list {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(
-                self,
-            ) -> azure_core::Result<models::BacklogLevelConfigurationList> {
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "List all backlog levels"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Get a list of work items within a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_backlog_level_work_items(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            backlog_id: impl Into<String>,
+        ) -> get_backlog_level_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_backlog_level_work_items(
+                self.0.organization.clone(),
+                project,
+                team,
+                backlog_id,
+            )
+        }
+        #[doc = "Get a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `id`: The id of the backlog level"]
+        pub fn get_backlog(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get_backlog::RequestBuilder {
+            Client(self.0.client.clone()).get_backlog(
+                self.0.organization.clone(),
+                project,
+                team,
+                id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "List all backlog levels"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get a list of work items within a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_backlog_level_work_items(
+            &self,
+            team: impl Into<String>,
+            backlog_id: impl Into<String>,
+        ) -> get_backlog_level_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_backlog_level_work_items(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                backlog_id,
+            )
+        }
+        #[doc = "Get a backlog level"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `id`: The id of the backlog level"]
+        pub fn get_backlog(
+            &self,
+            team: impl Into<String>,
+            id: impl Into<String>,
+        ) -> get_backlog::RequestBuilder {
+            Client(self.0.client.clone()).get_backlog(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                id,
+            )
+        }
+    }
+    pub mod list {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(
+                self,
+            ) -> azure_core::Result<models::BacklogLevelConfigurationList> {
                 let bytes = self.0.into_body().collect().await?;
                 let body: models::BacklogLevelConfigurationList = serde_json::from_slice(&bytes)
                     .map_err(|e| {
@@ -2312,6 +2885,104 @@ pub mod boards {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get boards"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Get board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, id, team)
+        }
+        #[doc = "Update board options"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: options to updated"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: identifier for board, either category plural name (Eg:\"Stories\") or guid"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn set_board_options(
+            &self,
+            body: impl Into<serde_json::Value>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> set_board_options::RequestBuilder {
+            Client(self.0.client.clone()).set_board_options(
+                self.0.organization.clone(),
+                body,
+                project,
+                id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get boards"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `id`: identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+                team,
+            )
+        }
+        #[doc = "Update board options"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: options to updated"]
+        #[doc = "* `id`: identifier for board, either category plural name (Eg:\"Stories\") or guid"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn set_board_options(
+            &self,
+            body: impl Into<serde_json::Value>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> set_board_options::RequestBuilder {
+            Client(self.0.client.clone()).set_board_options(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                id,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -2728,6 +3399,81 @@ pub mod boardusersettings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get board user settings for a board id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Board ID or Name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, board, team)
+        }
+        #[doc = "Update board user settings for the board id\n\nWe don't want stakeholders to update board settings (currently just autorefresh). The BacklogManagement feature check validates this."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<serde_json::Value>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get board user settings for a board id"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Board ID or Name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update board user settings for the board id\n\nWe don't want stakeholders to update board settings (currently just autorefresh). The BacklogManagement feature check validates this."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<serde_json::Value>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3047,45 +3793,152 @@ pub mod cardrulesettings {
             }
         }
     }
-    pub mod get {
-        use super::models;
-        #[cfg(not(target_arch = "wasm32"))]
-        use futures::future::BoxFuture;
-        #[cfg(target_arch = "wasm32")]
-        use futures::future::LocalBoxFuture as BoxFuture;
-        #[derive(Debug)]
-        pub struct Response(azure_core::Response);
-        impl Response {
-            pub async fn into_body(self) -> azure_core::Result<models::BoardCardRuleSettings> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::BoardCardRuleSettings =
-                    serde_json::from_slice(&bytes).map_err(|e| {
-                        azure_core::error::Error::full(
-                            azure_core::error::ErrorKind::DataConversion,
-                            e,
-                            format!(
-                                "Failed to deserialize response:\n{}",
-                                String::from_utf8_lossy(&bytes)
-                            ),
-                        )
-                    })?;
-                Ok(body)
-            }
-            pub fn into_raw_response(self) -> azure_core::Response {
-                self.0
-            }
-            pub fn as_raw_response(&self) -> &azure_core::Response {
-                &self.0
-            }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, board, team)
         }
-        impl From<Response> for azure_core::Response {
-            fn from(rsp: Response) -> Self {
-                rsp.into_raw_response()
-            }
+        #[doc = "Update board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_rule_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_board_card_rule_settings(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
         }
-        impl AsRef<azure_core::Response> for Response {
-            fn as_ref(&self) -> &azure_core::Response {
-                self.as_raw_response()
+        #[doc = "Update taskboard card Rule settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_rule_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_taskboard_card_rule_settings(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update board card Rule settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_rule_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_board_card_rule_settings(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card Rule settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_rule_settings(
+            &self,
+            body: impl Into<models::BoardCardRuleSettings>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_rule_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_taskboard_card_rule_settings(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
+    pub mod get {
+        use super::models;
+        #[cfg(not(target_arch = "wasm32"))]
+        use futures::future::BoxFuture;
+        #[cfg(target_arch = "wasm32")]
+        use futures::future::LocalBoxFuture as BoxFuture;
+        #[derive(Debug)]
+        pub struct Response(azure_core::Response);
+        impl Response {
+            pub async fn into_body(self) -> azure_core::Result<models::BoardCardRuleSettings> {
+                let bytes = self.0.into_body().collect().await?;
+                let body: models::BoardCardRuleSettings =
+                    serde_json::from_slice(&bytes).map_err(|e| {
+                        azure_core::error::Error::full(
+                            azure_core::error::ErrorKind::DataConversion,
+                            e,
+                            format!(
+                                "Failed to deserialize response:\n{}",
+                                String::from_utf8_lossy(&bytes)
+                            ),
+                        )
+                    })?;
+                Ok(body)
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
             }
         }
         #[derive(Clone)]
@@ -3475,6 +4328,113 @@ pub mod cardsettings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, board, team)
+        }
+        #[doc = "Update board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_board_card_settings(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_taskboard_card_settings(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update board card settings for the board id or board by name"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_board_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update_board_card_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_board_card_settings(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update taskboard card settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update_taskboard_card_settings(
+            &self,
+            body: impl Into<models::BoardCardSettings>,
+            team: impl Into<String>,
+        ) -> update_taskboard_card_settings::RequestBuilder {
+            Client(self.0.client.clone()).update_taskboard_card_settings(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3864,6 +4824,7 @@ pub mod chartimages {
                 height: None,
                 show_details: None,
                 title: None,
+                accept: None,
             }
         }
         #[doc = "Get an iteration chart image."]
@@ -3893,6 +4854,7 @@ pub mod chartimages {
                 height: None,
                 show_details: None,
                 title: None,
+                accept: None,
             }
         }
         #[doc = "Get an iterations chart image."]
@@ -3920,9 +4882,136 @@ pub mod chartimages {
                 height: None,
                 show_details: None,
                 title: None,
+                accept: None,
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a board chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (e.g. \"Issues\") or Id."]
+        #[doc = "* `name`: The chart name. e.g. CumulativeFlow."]
+        pub fn get_board_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_board_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_board_chart_image(
+                self.0.organization.clone(),
+                project,
+                team,
+                board,
+                name,
+            )
+        }
+        #[doc = "Get an iteration chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: ID of the iteration."]
+        #[doc = "* `name`: The chart name. e.g. Burndown."]
+        pub fn get_iteration_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iteration_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_iteration_chart_image(
+                self.0.organization.clone(),
+                project,
+                team,
+                iteration_id,
+                name,
+            )
+        }
+        #[doc = "Get an iterations chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `name`: The chart name. e.g. Velocity."]
+        pub fn get_iterations_chart_image(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iterations_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_iterations_chart_image(
+                self.0.organization.clone(),
+                project,
+                team,
+                name,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a board chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (e.g. \"Issues\") or Id."]
+        #[doc = "* `name`: The chart name. e.g. CumulativeFlow."]
+        pub fn get_board_chart_image(
+            &self,
+            team: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_board_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_board_chart_image(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                board,
+                name,
+            )
+        }
+        #[doc = "Get an iteration chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: ID of the iteration."]
+        #[doc = "* `name`: The chart name. e.g. Burndown."]
+        pub fn get_iteration_chart_image(
+            &self,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iteration_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_iteration_chart_image(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                iteration_id,
+                name,
+            )
+        }
+        #[doc = "Get an iterations chart image."]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `name`: The chart name. e.g. Velocity."]
+        pub fn get_iterations_chart_image(
+            &self,
+            team: impl Into<String>,
+            name: impl Into<String>,
+        ) -> get_iterations_chart_image::RequestBuilder {
+            Client(self.0.client.clone()).get_iterations_chart_image(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                name,
+            )
+        }
+    }
     pub mod get_board_chart_image {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -3932,19 +5021,29 @@ pub mod chartimages {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -3990,6 +5089,7 @@ pub mod chartimages {
             pub(crate) height: Option<i32>,
             pub(crate) show_details: Option<bool>,
             pub(crate) title: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The width of the chart in pixels. Must be greater than 0."]
@@ -4012,6 +5112,11 @@ pub mod chartimages {
                 self.title = Some(title.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4030,6 +5135,7 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         if let Some(width) = &this.width {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4048,6 +5154,9 @@ pub mod chartimages {
                         if let Some(title) = &this.title {
                             req.url_mut().query_pairs_mut().append_pair("title", title);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4075,8 +5184,8 @@ pub mod chartimages {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -4096,19 +5205,29 @@ pub mod chartimages {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4154,6 +5273,7 @@ pub mod chartimages {
             pub(crate) height: Option<i32>,
             pub(crate) show_details: Option<bool>,
             pub(crate) title: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "The width of the chart in pixels. Must be greater than 0."]
@@ -4176,6 +5296,11 @@ pub mod chartimages {
                 self.title = Some(title.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4194,6 +5319,7 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         if let Some(width) = &this.width {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4212,6 +5338,9 @@ pub mod chartimages {
                         if let Some(title) = &this.title {
                             req.url_mut().query_pairs_mut().append_pair("title", title);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4239,8 +5368,8 @@ pub mod chartimages {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -4260,19 +5389,29 @@ pub mod chartimages {
         #[derive(Debug)]
         pub struct Response(azure_core::Response);
         impl Response {
-            pub async fn into_body(self) -> azure_core::Result<String> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: String = serde_json::from_slice(&bytes).map_err(|e| {
-                    azure_core::error::Error::full(
-                        azure_core::error::ErrorKind::DataConversion,
-                        e,
-                        format!(
-                            "Failed to deserialize response:\n{}",
-                            String::from_utf8_lossy(&bytes)
-                        ),
-                    )
-                })?;
-                Ok(body)
+            pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+                self.into_bytes().await
+            }
+            #[doc = "Returns the response body as-is, e.g. the contents of a file or archive."]
+            pub async fn into_bytes(self) -> azure_core::Result<bytes::Bytes> {
+                self.0.into_body().collect().await
+            }
+            #[doc = "Returns the response body as UTF-8 text."]
+            pub async fn into_text(self) -> azure_core::Result<String> {
+                self.0.into_body().collect_string().await
+            }
+            #[doc = "Returns a stream of the response body, which is read as it is received."]
+            pub fn into_stream(
+                self,
+            ) -> impl futures::Stream<Item = azure_core::Result<bytes::Bytes>> {
+                self.0.into_body()
+            }
+            #[doc = "Writes the response body to `writer` as it is received, returning the number of bytes written."]
+            pub async fn write_to<W>(self, writer: &mut W) -> azure_core::Result<u64>
+            where
+                W: futures::io::AsyncWrite + Unpin + ?Sized,
+            {
+                crate::response_body::write_to(self.0, writer).await
             }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
@@ -4318,6 +5457,7 @@ pub mod chartimages {
             pub(crate) height: Option<i32>,
             pub(crate) show_details: Option<bool>,
             pub(crate) title: Option<String>,
+            pub(crate) accept: Option<String>,
         }
         impl RequestBuilder {
             #[doc = "Number of iterations the chart is for."]
@@ -4345,6 +5485,11 @@ pub mod chartimages {
                 self.title = Some(title.into());
                 self
             }
+            #[doc = "The content type of the response: one of `application/octet-stream`."]
+            pub fn accept(mut self, accept: impl Into<String>) -> Self {
+                self.accept = Some(accept.into());
+                self
+            }
             #[doc = "Returns a future that sends the request and returns a [`Response`] object that provides low-level access to full response details."]
             #[doc = ""]
             #[doc = "You should typically use `.await` (which implicitly calls `IntoFuture::into_future()`) to finalize and send requests rather than `send()`."]
@@ -4363,6 +5508,7 @@ pub mod chartimages {
                         {
                             req.insert_header(azure_core::headers::AUTHORIZATION, auth_header);
                         }
+                        req.insert_header(azure_core::headers::ACCEPT, "application/octet-stream");
                         if let Some(iterations_number) = &this.iterations_number {
                             req.url_mut()
                                 .query_pairs_mut()
@@ -4386,6 +5532,9 @@ pub mod chartimages {
                         if let Some(title) = &this.title {
                             req.url_mut().query_pairs_mut().append_pair("title", title);
                         }
+                        if let Some(accept) = &this.accept {
+                            req.insert_header("accept", accept);
+                        }
                         let req_body = azure_core::EMPTY_BODY;
                         req.set_body(req_body);
                         Ok(Response(this.client.send(&mut req).await?))
@@ -4412,8 +5561,8 @@ pub mod chartimages {
             }
         }
         impl std::future::IntoFuture for RequestBuilder {
-            type Output = azure_core::Result<String>;
-            type IntoFuture = BoxFuture<'static, azure_core::Result<String>>;
+            type Output = azure_core::Result<bytes::Bytes>;
+            type IntoFuture = BoxFuture<'static, azure_core::Result<bytes::Bytes>>;
             #[doc = "Returns a future that sends the request and returns the parsed response body."]
             #[doc = ""]
             #[doc = "You should not normally call this method directly, simply invoke `.await` which implicitly calls `IntoFuture::into_future`."]
@@ -4442,70 +5591,195 @@ pub mod charts {
         #[doc = "* `team`: Team ID or team name"]
         pub fn list(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            list::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                board: board.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Get a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                board: board.into(),
+                name: name.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Update a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: impl Into<models::BoardChart>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body: body.into(),
+                project: project.into(),
+                board: board.into(),
+                name: name.into(),
+                team: team.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get board charts"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, board, team)
+        }
+        #[doc = "Get a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                board,
+                name,
+                team,
+            )
+        }
+        #[doc = "Update a board chart"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `name`: The chart name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::BoardChart>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            name: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                name,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get board charts"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
             board: impl Into<String>,
             team: impl Into<String>,
         ) -> list::RequestBuilder {
-            list::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                board: board.into(),
-                team: team.into(),
-            }
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
         }
         #[doc = "Get a board chart"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
         #[doc = "* `name`: The chart name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn get(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             board: impl Into<String>,
             name: impl Into<String>,
             team: impl Into<String>,
         ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                board: board.into(),
-                name: name.into(),
-                team: team.into(),
-            }
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                name,
+                team,
+            )
         }
         #[doc = "Update a board chart"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `board`: Identifier for board, either board's backlog level name (Eg:\"Stories\") or Id"]
         #[doc = "* `name`: The chart name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: impl Into<models::BoardChart>,
-            project: impl Into<String>,
             board: impl Into<String>,
             name: impl Into<String>,
             team: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                body: body.into(),
-                project: project.into(),
-                board: board.into(),
-                name: name.into(),
-                team: team.into(),
-            }
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                name,
+                team,
+            )
         }
     }
     pub mod list {
@@ -4933,6 +6207,85 @@ pub mod columns {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, board, team)
+        }
+        #[doc = "Update columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board columns to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardColumn>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update columns on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board columns to update"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardColumn>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5234,6 +6587,85 @@ pub mod rows {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, board, team)
+        }
+        #[doc = "Update rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board rows to update"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardRow>,
+            project: impl Into<String>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                board,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+        #[doc = "Update rows on a board"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: List of board rows to update"]
+        #[doc = "* `board`: Name or ID of the specific board"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::BoardRow>,
+            board: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                board,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5510,6 +6942,50 @@ pub mod boardparents {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Returns the list of parent field filter model for the given list of workitem ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            child_backlog_context_category_ref_name: impl Into<String>,
+            workitem_ids: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                child_backlog_context_category_ref_name,
+                workitem_ids,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Returns the list of parent field filter model for the given list of workitem ids"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            child_backlog_context_category_ref_name: impl Into<String>,
+            workitem_ids: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                child_backlog_context_category_ref_name,
+                workitem_ids,
+                team,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5696,6 +7172,86 @@ pub mod workitemsorder {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Reorder Sprint Backlog/Taskboard Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: The id of the iteration"]
+        pub fn reorder_iteration_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> reorder_iteration_work_items::RequestBuilder {
+            Client(self.0.client.clone()).reorder_iteration_work_items(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Reorder Product Backlog/Boards Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn reorder_backlog_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> reorder_backlog_work_items::RequestBuilder {
+            Client(self.0.client.clone()).reorder_backlog_work_items(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Reorder Sprint Backlog/Taskboard Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        #[doc = "* `iteration_id`: The id of the iteration"]
+        pub fn reorder_iteration_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> reorder_iteration_work_items::RequestBuilder {
+            Client(self.0.client.clone()).reorder_iteration_work_items(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Reorder Product Backlog/Boards Work Items"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn reorder_backlog_work_items(
+            &self,
+            body: impl Into<models::ReorderOperation>,
+            team: impl Into<String>,
+        ) -> reorder_backlog_work_items::RequestBuilder {
+            Client(self.0.client.clone()).reorder_backlog_work_items(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod reorder_iteration_work_items {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -5967,23 +7523,73 @@ pub mod taskboard_columns {
             }
         }
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            organization: impl Into<String>,
+            body: Vec<models::UpdateTaskboardColumn>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            update::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                body,
+                project: project.into(),
+                team: team.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Arguments:"]
         #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn update(
             &self,
-            organization: impl Into<String>,
             body: Vec<models::UpdateTaskboardColumn>,
             project: impl Into<String>,
             team: impl Into<String>,
         ) -> update::RequestBuilder {
-            update::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: Vec<models::UpdateTaskboardColumn>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
                 body,
-                project: project.into(),
-                team: team.into(),
-            }
+                self.0.project.clone(),
+                team,
+            )
         }
     }
     pub mod get {
@@ -6278,6 +7884,80 @@ pub mod taskboard_work_items {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                project,
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateTaskboardWorkItemColumn>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            work_item_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+                iteration_id,
+                work_item_id,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+                iteration_id,
+            )
+        }
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::UpdateTaskboardWorkItemColumn>,
+            team: impl Into<String>,
+            iteration_id: impl Into<String>,
+            work_item_id: i32,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+                iteration_id,
+                work_item_id,
+            )
+        }
+    }
     pub mod list {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6566,6 +8246,66 @@ pub mod teamsettings {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Update a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TeamSettings changes"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsPatch>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Update a team's settings"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: TeamSettings changes"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsPatch>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -6858,68 +8598,227 @@ pub mod iterations {
         #[doc = "Get team's iteration by iterationId"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            get::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                id: id.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Delete a team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            delete::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                id: id.into(),
+                team: team.into(),
+            }
+        }
+        #[doc = "Get work items for iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `organization`: The name of the Azure DevOps organization."]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_iteration_work_items(
+            &self,
+            organization: impl Into<String>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_iteration_work_items::RequestBuilder {
+            get_iteration_work_items::RequestBuilder {
+                client: self.0.clone(),
+                organization: organization.into(),
+                project: project.into(),
+                iteration_id: iteration_id.into(),
+                team: team.into(),
+            }
+        }
+    }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a team's iterations using timeframe filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Add an iteration to the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Iteration to add"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn post_team_iteration(
+            &self,
+            body: impl Into<models::TeamSettingsIteration>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> post_team_iteration::RequestBuilder {
+            Client(self.0.client.clone()).post_team_iteration(
+                self.0.organization.clone(),
+                body,
+                project,
+                team,
+            )
+        }
+        #[doc = "Get team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, id, team)
+        }
+        #[doc = "Delete a team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn delete(
+            &self,
+            project: impl Into<String>,
+            id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> delete::RequestBuilder {
+            Client(self.0.client.clone()).delete(self.0.organization.clone(), project, id, team)
+        }
+        #[doc = "Get work items for iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_iteration_work_items(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_iteration_work_items::RequestBuilder {
+            Client(self.0.client.clone()).get_iteration_work_items(
+                self.0.organization.clone(),
+                project,
+                iteration_id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a team's iterations using timeframe filter"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn list(&self, team: impl Into<String>) -> list::RequestBuilder {
+            Client(self.0.client.clone()).list(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Add an iteration to the team"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Iteration to add"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn post_team_iteration(
+            &self,
+            body: impl Into<models::TeamSettingsIteration>,
+            team: impl Into<String>,
+        ) -> post_team_iteration::RequestBuilder {
+            Client(self.0.client.clone()).post_team_iteration(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Get team's iteration by iterationId"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
         #[doc = "* `id`: ID of the iteration"]
         #[doc = "* `team`: Team ID or team name"]
-        pub fn get(
-            &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
-            id: impl Into<String>,
-            team: impl Into<String>,
-        ) -> get::RequestBuilder {
-            get::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                id: id.into(),
-                team: team.into(),
-            }
+        pub fn get(&self, id: impl Into<String>, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+                team,
+            )
         }
         #[doc = "Delete a team's iteration by iterationId"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `id`: ID of the iteration"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn delete(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             id: impl Into<String>,
             team: impl Into<String>,
         ) -> delete::RequestBuilder {
-            delete::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                id: id.into(),
-                team: team.into(),
-            }
+            Client(self.0.client.clone()).delete(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                id,
+                team,
+            )
         }
         #[doc = "Get work items for iteration"]
         #[doc = ""]
         #[doc = "Arguments:"]
-        #[doc = "* `organization`: The name of the Azure DevOps organization."]
-        #[doc = "* `project`: Project ID or project name"]
         #[doc = "* `iteration_id`: ID of the iteration"]
         #[doc = "* `team`: Team ID or team name"]
         pub fn get_iteration_work_items(
             &self,
-            organization: impl Into<String>,
-            project: impl Into<String>,
             iteration_id: impl Into<String>,
             team: impl Into<String>,
         ) -> get_iteration_work_items::RequestBuilder {
-            get_iteration_work_items::RequestBuilder {
-                client: self.0.clone(),
-                organization: organization.into(),
-                project: project.into(),
-                iteration_id: iteration_id.into(),
-                team: team.into(),
-            }
+            Client(self.0.client.clone()).get_iteration_work_items(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                iteration_id,
+                team,
+            )
         }
     }
     pub mod list {
@@ -7635,6 +9534,180 @@ pub mod capacities {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a team's capacity including total capacity and days off"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacities_with_identity_ref_and_totals(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
+            Client(self.0.client.clone()).get_capacities_with_identity_ref_and_totals(
+                self.0.organization.clone(),
+                project,
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Replace a team's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team capacity to replace"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_capacities_with_identity_ref(
+            &self,
+            body: Vec<models::TeamMemberCapacityIdentityRef>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_capacities_with_identity_ref::RequestBuilder {
+            Client(self.0.client.clone()).replace_capacities_with_identity_ref(
+                self.0.organization.clone(),
+                body,
+                project,
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Get a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacity_with_identity_ref(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacity_with_identity_ref::RequestBuilder {
+            Client(self.0.client.clone()).get_capacity_with_identity_ref(
+                self.0.organization.clone(),
+                project,
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+        #[doc = "Update a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated capacity"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::CapacityPatch>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a team's capacity including total capacity and days off"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacities_with_identity_ref_and_totals(
+            &self,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacities_with_identity_ref_and_totals::RequestBuilder {
+            Client(self.0.client.clone()).get_capacities_with_identity_ref_and_totals(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Replace a team's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team capacity to replace"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn replace_capacities_with_identity_ref(
+            &self,
+            body: Vec<models::TeamMemberCapacityIdentityRef>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> replace_capacities_with_identity_ref::RequestBuilder {
+            Client(self.0.client.clone()).replace_capacities_with_identity_ref(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Get a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get_capacity_with_identity_ref(
+            &self,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get_capacity_with_identity_ref::RequestBuilder {
+            Client(self.0.client.clone()).get_capacity_with_identity_ref(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+        #[doc = "Update a team member's capacity"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Updated capacity"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team_member_id`: ID of the team member"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::CapacityPatch>,
+            iteration_id: impl Into<String>,
+            team_member_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                iteration_id,
+                team_member_id,
+                team,
+            )
+        }
+    }
     pub mod get_capacities_with_identity_ref_and_totals {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8192,6 +10265,90 @@ pub mod teamdaysoff {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                project,
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Set a team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team's days off patch containing a list of start and end dates"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsDaysOffPatch>,
+            project: impl Into<String>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                project,
+                iteration_id,
+                team,
+            )
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+        #[doc = "Set a team's days off for an iteration"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `body`: Team's days off patch containing a list of start and end dates"]
+        #[doc = "* `iteration_id`: ID of the iteration"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamSettingsDaysOffPatch>,
+            iteration_id: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                iteration_id,
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
@@ -8486,6 +10643,64 @@ pub mod teamfieldvalues {
             }
         }
     }
+    pub struct OrganizationClient(pub(crate) super::OrganizationClient);
+    impl OrganizationClient {
+        #[doc = "Get a collection of team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(
+            &self,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(self.0.organization.clone(), project, team)
+        }
+        #[doc = "Update team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `project`: Project ID or project name"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamFieldValuesPatch>,
+            project: impl Into<String>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(self.0.organization.clone(), body, project, team)
+        }
+    }
+    pub struct ProjectClient(pub(crate) super::ProjectClient);
+    impl ProjectClient {
+        #[doc = "Get a collection of team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn get(&self, team: impl Into<String>) -> get::RequestBuilder {
+            Client(self.0.client.clone()).get(
+                self.0.organization.clone(),
+                self.0.project.clone(),
+                team,
+            )
+        }
+        #[doc = "Update team field values"]
+        #[doc = ""]
+        #[doc = "Arguments:"]
+        #[doc = "* `team`: Team ID or team name"]
+        pub fn update(
+            &self,
+            body: impl Into<models::TeamFieldValuesPatch>,
+            team: impl Into<String>,
+        ) -> update::RequestBuilder {
+            Client(self.0.client.clone()).update(
+                self.0.organization.clone(),
+                body,
+                self.0.project.clone(),
+                team,
+            )
+        }
+    }
     pub mod get {
         use super::models;
         #[cfg(not(target_arch = "wasm32"))]
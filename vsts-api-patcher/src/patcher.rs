@@ -86,6 +86,7 @@ impl Patcher {
         Patcher::patch_git_commit_change_counts,
         Patcher::patch_git_change,
         Patcher::patch_git_policy_configuration,
+        Patcher::patch_tfvc_change,
         Patcher::patch_git_pull_request_create,
         Patcher::patch_git_pull_request_update,
         Patcher::patch_ims_identity_base,
@@ -94,6 +95,8 @@ impl Patcher {
         Patcher::patch_operation_status_in_releases,
         Patcher::patch_extension_flags,
         Patcher::patch_wit_create_update_item,
+        Patcher::patch_wit_batch,
+        Patcher::patch_wit_reporting_revisions,
         Patcher::patch_wit_identity_reference,
         Patcher::patch_wiki_pages_update,
         Patcher::patch_jobjects,
@@ -501,15 +504,13 @@ impl Patcher {
             return None;
         }
         match key {
+            // The item is a generic type parameter in the spec, which is a `GitItem` for git changes.
             ["definitions", "Change", "properties", "item"] => {
                 println!("Replace git Change item definition");
 
                 Some(json::object! {
-                    "type": "object",
-                    "additionalProperties": {
-                      "type": "integer",
-                      "format": "int32"
-                    }
+                    "description": "Current version.",
+                    "$ref": "#/definitions/GitItem"
                 })
             }
             ["definitions", "Change", "properties", "changeType", "enum"] => {
@@ -524,6 +525,25 @@ impl Patcher {
         }
     }
 
+    // The TFVC `Change` item is specified as a string, but is a `TfvcItem`.
+    fn patch_tfvc_change(&mut self, key: &[&str], _value: &JsonValue) -> Option<JsonValue> {
+        // Only applies to tfvc specs
+        if !self.spec_path.ends_with("tfvc.json") {
+            return None;
+        }
+        match key {
+            ["definitions", "Change", "properties", "item"] => {
+                println!("Replace tfvc Change item definition");
+
+                Some(json::object! {
+                    "description": "Current version.",
+                    "$ref": "#/definitions/TfvcItem"
+                })
+            }
+            _ => None,
+        }
+    }
+
     // The git `PolicyConfiguration` proerties.settings field is specified as:
     //     "settings": {
     //         "description": "The policy configuration settings.",
@@ -826,6 +846,14 @@ impl Patcher {
                           "autoCompleteSetBy": {
                             "description": "If set, auto-complete is enabled for this pull request and this is the identity that enabled it.",
                             "$ref": "#/definitions/IdentityRef"
+                          },
+                          "completionOptions": {
+                            "description": "Preferences about how the pull request should be completed.",
+                            "$ref": "#/definitions/GitPullRequestCompletionOptions"
+                          },
+                          "lastMergeSourceCommit": {
+                            "description": "The commit at the head of the source branch. Required when completing the pull request.",
+                            "$ref": "#/definitions/GitCommitRef"
                           }
                         }
                     },
@@ -1731,6 +1759,213 @@ impl Patcher {
         }
     }
 
+    /// Add the work item tracking `$batch` operation, which is missing from the spec.
+    ///
+    /// This submits up to 200 work item create/update requests in a single call. Each request
+    /// is a JSON patch document for `/_apis/wit/workitems`, and the response contains the status
+    /// code and body of each request.
+    fn patch_wit_batch(&mut self, key: &[&str], value: &JsonValue) -> Option<JsonValue> {
+        const WIT_BATCH_PATH: &str = "/{organization}/_apis/wit/$batch";
+        if !self.spec_path.ends_with("workItemTracking.json") {
+            return None;
+        }
+        match key {
+            ["paths"] if !value.has_key(WIT_BATCH_PATH) => {
+                println!("Add wit $batch operation");
+                self.new_definitions.insert(
+                    "WitBatchRequest".to_string(),
+                    json::object! {
+                        "description": "A request in a work item tracking batch request.",
+                        "type": "object",
+                        "required": [ "method", "uri" ],
+                        "properties": {
+                            "method": {
+                                "description": "The HTTP method, e.g. `PATCH`.",
+                                "type": "string"
+                            },
+                            "uri": {
+                                "description": "The request URI, relative to the organization, e.g. `/_apis/wit/workitems/1?api-version=7.1`.",
+                                "type": "string"
+                            },
+                            "headers": {
+                                "description": "The request headers, e.g. `Content-Type: application/json-patch+json`.",
+                                "type": "object"
+                            },
+                            "body": {
+                                "description": "The operations to perform on the work item.",
+                                "type": "array",
+                                "items": {
+                                    "$ref": "#/definitions/JsonPatchOperation"
+                                }
+                            }
+                        }
+                    },
+                );
+                self.new_definitions.insert(
+                    "WitBatchResponse".to_string(),
+                    json::object! {
+                        "description": "The response to a request in a work item tracking batch request.",
+                        "type": "object",
+                        "required": [ "code" ],
+                        "properties": {
+                            "code": {
+                                "description": "The HTTP status code.",
+                                "type": "integer",
+                                "format": "int32"
+                            },
+                            "headers": {
+                                "description": "The response headers.",
+                                "type": "object"
+                            },
+                            "body": {
+                                "description": "The response body, a JSON encoded work item or error.",
+                                "type": "string"
+                            }
+                        }
+                    },
+                );
+                self.new_definitions.insert(
+                    "WitBatchResponseList".to_string(),
+                    json::object! {
+                        "type": "object",
+                        "properties": {
+                            "count": {
+                                "type": "integer",
+                                "format": "int32"
+                            },
+                            "value": {
+                                "type": "array",
+                                "items": {
+                                    "$ref": "#/definitions/WitBatchResponse"
+                                }
+                            }
+                        }
+                    },
+                );
+                let mut value = value.clone();
+                value[WIT_BATCH_PATH] = json::object! {
+                    "post": {
+                        "tags": [ "Work Items" ],
+                        "operationId": "Work Items_Batch",
+                        "description": "Submits a batch of work item create and update requests (Maximum 200)",
+                        "consumes": [ "application/json" ],
+                        "produces": [ "application/json" ],
+                        "parameters": [
+                            {
+                                "in": "path",
+                                "name": "organization",
+                                "description": "The name of the Azure DevOps organization.",
+                                "required": true,
+                                "type": "string"
+                            },
+                            {
+                                "in": "body",
+                                "name": "body",
+                                "description": "The requests to submit",
+                                "required": true,
+                                "schema": {
+                                    "type": "array",
+                                    "items": {
+                                        "$ref": "#/definitions/WitBatchRequest"
+                                    }
+                                }
+                            },
+                            {
+                                "in": "query",
+                                "name": "api-version",
+                                "description": "Version of the API to use.  This should be set to '7.1-preview' to use this version of the api.",
+                                "required": true,
+                                "type": "string"
+                            }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "successful operation",
+                                "schema": {
+                                    "$ref": "#/definitions/WitBatchResponseList"
+                                }
+                            }
+                        },
+                        "security": [
+                            {
+                                "oauth2": [ "vso.work_write" ]
+                            }
+                        ]
+                    }
+                };
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// `ReportingWorkItemRevisionsBatch` inherits `values` from `StreamedBatch`, where it is
+    /// defined as an array of strings, but the reporting revisions APIs return work item
+    /// revisions, which fail to deserialize as strings.
+    fn patch_wit_reporting_revisions(
+        &mut self,
+        key: &[&str],
+        _value: &JsonValue,
+    ) -> Option<JsonValue> {
+        if !self.spec_path.ends_with("workItemTracking.json") {
+            return None;
+        }
+        match key {
+            ["definitions", "ReportingWorkItemRevisionsBatch"] => {
+                println!("Replace ReportingWorkItemRevisionsBatch values type");
+                self.new_definitions.insert(
+                    "ReportingWorkItemRevision".to_string(),
+                    json::object! {
+                        "description": "A work item revision returned by the reporting revisions APIs.",
+                        "type": "object",
+                        "required": [ "id", "rev" ],
+                        "properties": {
+                            "fields": {
+                                "description": "Map of field reference names to the values of the revision.",
+                                "type": "object"
+                            },
+                            "id": {
+                                "description": "The work item ID.",
+                                "type": "integer",
+                                "format": "int32"
+                            },
+                            "rev": {
+                                "description": "The revision number.",
+                                "type": "integer",
+                                "format": "int32"
+                            }
+                        }
+                    },
+                );
+                Some(json::object! {
+                    "type": "object",
+                    "properties": {
+                        "continuationToken": {
+                            "description": "ContinuationToken acts as a waterMark. Used while querying large results.",
+                            "type": "string"
+                        },
+                        "isLastBatch": {
+                            "description": "Returns 'true' if it's last batch, 'false' otherwise.",
+                            "type": "boolean"
+                        },
+                        "nextLink": {
+                            "description": "The next link for the work item.",
+                            "type": "string"
+                        },
+                        "values": {
+                            "description": "The work item revisions.",
+                            "type": "array",
+                            "items": {
+                                "$ref": "#/definitions/ReportingWorkItemRevision"
+                            }
+                        }
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
     // The definition of IdentityReference has issues because it defines a property called `id` which is also in `IdentityRef` which is included via `allOf`.
     // This causes the deserialization to fail as it doesn't know which `id` to use.
     // Fix is to replace the definition of `IdentityReference` with the definition of `IdentityRef`.